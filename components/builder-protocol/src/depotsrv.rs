@@ -40,7 +40,7 @@ impl Decodable for OriginKeyIdent {
 
 impl Decodable for Package {
     fn decode<D: Decoder>(d: &mut D) -> result::Result<Self, D::Error> {
-        d.read_struct("Package", 7, |d| {
+        d.read_struct("Package", 8, |d| {
             let mut package = Package::new();
             package.set_ident(try!(d.read_struct_field("ident", 0, |d| Decodable::decode(d))));
             package.set_checksum(try!(d.read_struct_field("checksum", 1, |d| Decodable::decode(d))));
@@ -53,6 +53,9 @@ impl Decodable for Package {
             if let Some(cfg) = try!(d.read_struct_field("config", 6, |d| Ok(Decodable::decode(d).ok()))) {
                 package.set_config(cfg);
             }
+            if let Some(prov) = try!(d.read_struct_field("provenance", 7, |d| Ok(Decodable::decode(d).ok()))) {
+                package.set_provenance(prov);
+            }
             Ok(package)
         })
     }
@@ -167,6 +170,7 @@ impl FromArchive for Package {
         let tdeps = try!(archive.tdeps()).into_iter().map(|d| d.into()).collect();
         let exposes = try!(archive.exposes()).into_iter().map(|d| d as u32).collect();
         let config = try!(archive.config());
+        let provenance = try!(archive.provenance());
         let checksum = try!(archive.checksum());
 
         let mut package = Package::new();
@@ -178,6 +182,9 @@ impl FromArchive for Package {
         if let Some(cfg) = config {
             package.set_config(cfg);
         }
+        if let Some(prov) = provenance {
+            package.set_provenance(prov);
+        }
         package.set_checksum(checksum);
         Ok(package)
     }
@@ -291,6 +298,7 @@ impl ToJson for Package {
         m.insert("tdeps".to_string(), self.get_tdeps().to_vec().to_json());
         m.insert("exposes".to_string(), self.get_exposes().to_json());
         m.insert("config".to_string(), self.get_config().to_json());
+        m.insert("provenance".to_string(), self.get_provenance().to_json());
         Json::Object(m)
     }
 }
@@ -22,6 +22,7 @@ pub struct Origin {
     id: ::std::option::Option<u64>,
     name: ::protobuf::SingularField<::std::string::String>,
     owner_id: ::std::option::Option<u64>,
+    private: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -46,6 +47,7 @@ impl Origin {
                     id: ::std::option::Option::None,
                     name: ::protobuf::SingularField::none(),
                     owner_id: ::std::option::Option::None,
+                    private: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -126,6 +128,25 @@ impl Origin {
     pub fn get_owner_id(&self) -> u64 {
         self.owner_id.unwrap_or(0)
     }
+
+    // optional bool private = 4;
+
+    pub fn clear_private(&mut self) {
+        self.private = ::std::option::Option::None;
+    }
+
+    pub fn has_private(&self) -> bool {
+        self.private.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_private(&mut self, v: bool) {
+        self.private = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_private(&self) -> bool {
+        self.private.unwrap_or(false)
+    }
 }
 
 impl ::protobuf::Message for Origin {
@@ -163,6 +184,13 @@ impl ::protobuf::Message for Origin {
                     let tmp = try!(is.read_uint64());
                     self.owner_id = ::std::option::Option::Some(tmp);
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.private = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -184,6 +212,9 @@ impl ::protobuf::Message for Origin {
         for value in self.owner_id.iter() {
             my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        if self.private.is_some() {
+            my_size += 2;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -199,6 +230,9 @@ impl ::protobuf::Message for Origin {
         if let Some(v) = self.owner_id {
             try!(os.write_uint64(3, v));
         };
+        if let Some(v) = self.private {
+            try!(os.write_bool(4, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -256,6 +290,11 @@ impl ::protobuf::MessageStatic for Origin {
                     Origin::has_owner_id,
                     Origin::get_owner_id,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "private",
+                    Origin::has_private,
+                    Origin::get_private,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Origin>(
                     "Origin",
                     fields,
@@ -271,6 +310,7 @@ impl ::protobuf::Clear for Origin {
         self.clear_id();
         self.clear_name();
         self.clear_owner_id();
+        self.clear_private();
         self.unknown_fields.clear();
     }
 }
@@ -280,6 +320,7 @@ impl ::std::cmp::PartialEq for Origin {
         self.id == other.id &&
         self.name == other.name &&
         self.owner_id == other.owner_id &&
+        self.private == other.private &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -296,6 +337,7 @@ pub struct OriginCreate {
     name: ::protobuf::SingularField<::std::string::String>,
     owner_id: ::std::option::Option<u64>,
     owner_name: ::protobuf::SingularField<::std::string::String>,
+    private: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -320,6 +362,7 @@ impl OriginCreate {
                     name: ::protobuf::SingularField::none(),
                     owner_id: ::std::option::Option::None,
                     owner_name: ::protobuf::SingularField::none(),
+                    private: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -417,6 +460,25 @@ impl OriginCreate {
             None => "",
         }
     }
+
+    // optional bool private = 4;
+
+    pub fn clear_private(&mut self) {
+        self.private = ::std::option::Option::None;
+    }
+
+    pub fn has_private(&self) -> bool {
+        self.private.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_private(&mut self, v: bool) {
+        self.private = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_private(&self) -> bool {
+        self.private.unwrap_or(false)
+    }
 }
 
 impl ::protobuf::Message for OriginCreate {
@@ -450,6 +512,13 @@ impl ::protobuf::Message for OriginCreate {
                 3 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.owner_name));
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.private = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -471,6 +540,9 @@ impl ::protobuf::Message for OriginCreate {
         for value in self.owner_name.iter() {
             my_size += ::protobuf::rt::string_size(3, &value);
         };
+        if self.private.is_some() {
+            my_size += 2;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -486,6 +558,9 @@ impl ::protobuf::Message for OriginCreate {
         if let Some(v) = self.owner_name.as_ref() {
             try!(os.write_string(3, &v));
         };
+        if let Some(v) = self.private {
+            try!(os.write_bool(4, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -543,6 +618,11 @@ impl ::protobuf::MessageStatic for OriginCreate {
                     OriginCreate::has_owner_name,
                     OriginCreate::get_owner_name,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "private",
+                    OriginCreate::has_private,
+                    OriginCreate::get_private,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<OriginCreate>(
                     "OriginCreate",
                     fields,
@@ -558,6 +638,7 @@ impl ::protobuf::Clear for OriginCreate {
         self.clear_name();
         self.clear_owner_id();
         self.clear_owner_name();
+        self.clear_private();
         self.unknown_fields.clear();
     }
 }
@@ -567,6 +648,7 @@ impl ::std::cmp::PartialEq for OriginCreate {
         self.name == other.name &&
         self.owner_id == other.owner_id &&
         self.owner_name == other.owner_name &&
+        self.private == other.private &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -578,31 +660,35 @@ impl ::std::fmt::Debug for OriginCreate {
 }
 
 #[derive(Clone,Default)]
-pub struct OriginDelete {
+pub struct OriginUpdate {
     // message fields
-    name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    requester_id: ::std::option::Option<u64>,
+    private: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginDelete {}
+unsafe impl ::std::marker::Sync for OriginUpdate {}
 
-impl OriginDelete {
-    pub fn new() -> OriginDelete {
+impl OriginUpdate {
+    pub fn new() -> OriginUpdate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginDelete {
-        static mut instance: ::protobuf::lazy::Lazy<OriginDelete> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginUpdate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginUpdate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginDelete,
+            ptr: 0 as *const OriginUpdate,
         };
         unsafe {
             instance.get(|| {
-                OriginDelete {
-                    name: ::protobuf::SingularField::none(),
+                OriginUpdate {
+                    origin_id: ::std::option::Option::None,
+                    requester_id: ::std::option::Option::None,
+                    private: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -610,46 +696,70 @@ impl OriginDelete {
         }
     }
 
-    // required string name = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    // required uint64 requester_id = 2;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+
+    // optional bool private = 3;
+
+    pub fn clear_private(&mut self) {
+        self.private = ::std::option::Option::None;
+    }
+
+    pub fn has_private(&self) -> bool {
+        self.private.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_private(&mut self, v: bool) {
+        self.private = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_private(&self) -> bool {
+        self.private.unwrap_or(false)
     }
 }
 
-impl ::protobuf::Message for OriginDelete {
+impl ::protobuf::Message for OriginUpdate {
     fn is_initialized(&self) -> bool {
-        if self.name.is_none() {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
             return false;
         };
         true
@@ -660,7 +770,25 @@ impl ::protobuf::Message for OriginDelete {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.private = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -674,8 +802,14 @@ impl ::protobuf::Message for OriginDelete {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.private.is_some() {
+            my_size += 2;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -683,8 +817,14 @@ impl ::protobuf::Message for OriginDelete {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(1, &v));
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.private {
+            try!(os.write_bool(3, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -703,7 +843,7 @@ impl ::protobuf::Message for OriginDelete {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginDelete>()
+        ::std::any::TypeId::of::<OriginUpdate>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -715,12 +855,12 @@ impl ::protobuf::Message for OriginDelete {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginDelete {
-    fn new() -> OriginDelete {
-        OriginDelete::new()
+impl ::protobuf::MessageStatic for OriginUpdate {
+    fn new() -> OriginUpdate {
+        OriginUpdate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginUpdate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -728,13 +868,23 @@ impl ::protobuf::MessageStatic for OriginDelete {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    OriginDelete::has_name,
-                    OriginDelete::get_name,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginUpdate::has_origin_id,
+                    OriginUpdate::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginDelete>(
-                    "OriginDelete",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginUpdate::has_requester_id,
+                    OriginUpdate::get_requester_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "private",
+                    OriginUpdate::has_private,
+                    OriginUpdate::get_private,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginUpdate>(
+                    "OriginUpdate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -743,52 +893,58 @@ impl ::protobuf::MessageStatic for OriginDelete {
     }
 }
 
-impl ::protobuf::Clear for OriginDelete {
+impl ::protobuf::Clear for OriginUpdate {
     fn clear(&mut self) {
-        self.clear_name();
+        self.clear_origin_id();
+        self.clear_requester_id();
+        self.clear_private();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginDelete {
-    fn eq(&self, other: &OriginDelete) -> bool {
-        self.name == other.name &&
+impl ::std::cmp::PartialEq for OriginUpdate {
+    fn eq(&self, other: &OriginUpdate) -> bool {
+        self.origin_id == other.origin_id &&
+        self.requester_id == other.requester_id &&
+        self.private == other.private &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginDelete {
+impl ::std::fmt::Debug for OriginUpdate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginGet {
+pub struct OriginDelete {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
+    requester_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginGet {}
+unsafe impl ::std::marker::Sync for OriginDelete {}
 
-impl OriginGet {
-    pub fn new() -> OriginGet {
+impl OriginDelete {
+    pub fn new() -> OriginDelete {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginGet {
-        static mut instance: ::protobuf::lazy::Lazy<OriginGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginDelete {
+        static mut instance: ::protobuf::lazy::Lazy<OriginDelete> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginGet,
+            ptr: 0 as *const OriginDelete,
         };
         unsafe {
             instance.get(|| {
-                OriginGet {
+                OriginDelete {
                     name: ::protobuf::SingularField::none(),
+                    requester_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -831,13 +987,35 @@ impl OriginGet {
             None => "",
         }
     }
+
+    // required uint64 requester_id = 2;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
 }
 
-impl ::protobuf::Message for OriginGet {
+impl ::protobuf::Message for OriginDelete {
     fn is_initialized(&self) -> bool {
         if self.name.is_none() {
             return false;
         };
+        if self.requester_id.is_none() {
+            return false;
+        };
         true
     }
 
@@ -848,6 +1026,13 @@ impl ::protobuf::Message for OriginGet {
                 1 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -863,6 +1048,9 @@ impl ::protobuf::Message for OriginGet {
         for value in self.name.iter() {
             my_size += ::protobuf::rt::string_size(1, &value);
         };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -872,6 +1060,9 @@ impl ::protobuf::Message for OriginGet {
         if let Some(v) = self.name.as_ref() {
             try!(os.write_string(1, &v));
         };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(2, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -889,7 +1080,7 @@ impl ::protobuf::Message for OriginGet {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginGet>()
+        ::std::any::TypeId::of::<OriginDelete>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -901,12 +1092,12 @@ impl ::protobuf::Message for OriginGet {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginGet {
-    fn new() -> OriginGet {
-        OriginGet::new()
+impl ::protobuf::MessageStatic for OriginDelete {
+    fn new() -> OriginDelete {
+        OriginDelete::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -916,11 +1107,16 @@ impl ::protobuf::MessageStatic for OriginGet {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
                     "name",
-                    OriginGet::has_name,
-                    OriginGet::get_name,
+                    OriginDelete::has_name,
+                    OriginDelete::get_name,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginGet>(
-                    "OriginGet",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginDelete::has_requester_id,
+                    OriginDelete::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginDelete>(
+                    "OriginDelete",
                     fields,
                     file_descriptor_proto()
                 )
@@ -929,54 +1125,54 @@ impl ::protobuf::MessageStatic for OriginGet {
     }
 }
 
-impl ::protobuf::Clear for OriginGet {
+impl ::protobuf::Clear for OriginDelete {
     fn clear(&mut self) {
         self.clear_name();
+        self.clear_requester_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginGet {
-    fn eq(&self, other: &OriginGet) -> bool {
+impl ::std::cmp::PartialEq for OriginDelete {
+    fn eq(&self, other: &OriginDelete) -> bool {
         self.name == other.name &&
+        self.requester_id == other.requester_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginGet {
+impl ::std::fmt::Debug for OriginDelete {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginMemberRemove {
+pub struct OriginGet {
     // message fields
-    origin_id: ::std::option::Option<u64>,
-    user_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginMemberRemove {}
+unsafe impl ::std::marker::Sync for OriginGet {}
 
-impl OriginMemberRemove {
-    pub fn new() -> OriginMemberRemove {
+impl OriginGet {
+    pub fn new() -> OriginGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginMemberRemove {
-        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRemove> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginMemberRemove,
+            ptr: 0 as *const OriginGet,
         };
         unsafe {
             instance.get(|| {
-                OriginMemberRemove {
-                    origin_id: ::std::option::Option::None,
-                    user_id: ::std::option::Option::None,
+                OriginGet {
+                    name: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -984,51 +1180,46 @@ impl OriginMemberRemove {
         }
     }
 
-    // required uint64 origin_id = 1;
+    // required string name = 1;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
-    }
-
-    // required uint64 user_id = 2;
-
-    pub fn clear_user_id(&mut self) {
-        self.user_id = ::std::option::Option::None;
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn has_user_id(&self) -> bool {
-        self.user_id.is_some()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_user_id(&mut self, v: u64) {
-        self.user_id = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_user_id(&self) -> u64 {
-        self.user_id.unwrap_or(0)
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 }
 
-impl ::protobuf::Message for OriginMemberRemove {
+impl ::protobuf::Message for OriginGet {
     fn is_initialized(&self) -> bool {
-        if self.origin_id.is_none() {
-            return false;
-        };
-        if self.user_id.is_none() {
+        if self.name.is_none() {
             return false;
         };
         true
@@ -1039,18 +1230,7 @@ impl ::protobuf::Message for OriginMemberRemove {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.user_id = ::std::option::Option::Some(tmp);
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1064,11 +1244,8 @@ impl ::protobuf::Message for OriginMemberRemove {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.user_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1076,11 +1253,8 @@ impl ::protobuf::Message for OriginMemberRemove {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin_id {
-            try!(os.write_uint64(1, v));
-        };
-        if let Some(v) = self.user_id {
-            try!(os.write_uint64(2, v));
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -1099,7 +1273,7 @@ impl ::protobuf::Message for OriginMemberRemove {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginMemberRemove>()
+        ::std::any::TypeId::of::<OriginGet>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1111,12 +1285,12 @@ impl ::protobuf::Message for OriginMemberRemove {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginMemberRemove {
-    fn new() -> OriginMemberRemove {
-        OriginMemberRemove::new()
+impl ::protobuf::MessageStatic for OriginGet {
+    fn new() -> OriginGet {
+        OriginGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginMemberRemove>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1124,18 +1298,13 @@ impl ::protobuf::MessageStatic for OriginMemberRemove {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "origin_id",
-                    OriginMemberRemove::has_origin_id,
-                    OriginMemberRemove::get_origin_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "user_id",
-                    OriginMemberRemove::has_user_id,
-                    OriginMemberRemove::get_user_id,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginGet::has_name,
+                    OriginGet::get_name,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRemove>(
-                    "OriginMemberRemove",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginGet>(
+                    "OriginGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1144,54 +1313,58 @@ impl ::protobuf::MessageStatic for OriginMemberRemove {
     }
 }
 
-impl ::protobuf::Clear for OriginMemberRemove {
+impl ::protobuf::Clear for OriginGet {
     fn clear(&mut self) {
-        self.clear_origin_id();
-        self.clear_user_id();
+        self.clear_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginMemberRemove {
-    fn eq(&self, other: &OriginMemberRemove) -> bool {
-        self.origin_id == other.origin_id &&
-        self.user_id == other.user_id &&
+impl ::std::cmp::PartialEq for OriginGet {
+    fn eq(&self, other: &OriginGet) -> bool {
+        self.name == other.name &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginMemberRemove {
+impl ::std::fmt::Debug for OriginGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginMemberListRequest {
+pub struct OriginMemberRemove {
     // message fields
     origin_id: ::std::option::Option<u64>,
+    user_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    requester_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginMemberListRequest {}
+unsafe impl ::std::marker::Sync for OriginMemberRemove {}
 
-impl OriginMemberListRequest {
-    pub fn new() -> OriginMemberListRequest {
+impl OriginMemberRemove {
+    pub fn new() -> OriginMemberRemove {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginMemberListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberRemove {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRemove> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginMemberListRequest,
+            ptr: 0 as *const OriginMemberRemove,
         };
         unsafe {
             instance.get(|| {
-                OriginMemberListRequest {
+                OriginMemberRemove {
                     origin_id: ::std::option::Option::None,
+                    user_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    requester_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1217,13 +1390,96 @@ impl OriginMemberListRequest {
     pub fn get_origin_id(&self) -> u64 {
         self.origin_id.unwrap_or(0)
     }
+
+    // required uint64 user_id = 2;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id = ::std::option::Option::None;
+    }
+
+    pub fn has_user_id(&self) -> bool {
+        self.user_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u64) {
+        self.user_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id.unwrap_or(0)
+    }
+
+    // required string account_name = 3;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 requester_id = 4;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
 }
 
-impl ::protobuf::Message for OriginMemberListRequest {
+impl ::protobuf::Message for OriginMemberRemove {
     fn is_initialized(&self) -> bool {
         if self.origin_id.is_none() {
             return false;
         };
+        if self.user_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
         true
     }
 
@@ -1238,6 +1494,23 @@ impl ::protobuf::Message for OriginMemberListRequest {
                     let tmp = try!(is.read_uint64());
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.user_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -1253,6 +1526,15 @@ impl ::protobuf::Message for OriginMemberListRequest {
         for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.user_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1262,6 +1544,15 @@ impl ::protobuf::Message for OriginMemberListRequest {
         if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
+        if let Some(v) = self.user_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(4, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1279,7 +1570,7 @@ impl ::protobuf::Message for OriginMemberListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginMemberListRequest>()
+        ::std::any::TypeId::of::<OriginMemberRemove>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1291,12 +1582,12 @@ impl ::protobuf::Message for OriginMemberListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginMemberListRequest {
-    fn new() -> OriginMemberListRequest {
-        OriginMemberListRequest::new()
+impl ::protobuf::MessageStatic for OriginMemberRemove {
+    fn new() -> OriginMemberRemove {
+        OriginMemberRemove::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginMemberListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberRemove>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1306,11 +1597,26 @@ impl ::protobuf::MessageStatic for OriginMemberListRequest {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginMemberListRequest::has_origin_id,
-                    OriginMemberListRequest::get_origin_id,
+                    OriginMemberRemove::has_origin_id,
+                    OriginMemberRemove::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListRequest>(
-                    "OriginMemberListRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "user_id",
+                    OriginMemberRemove::has_user_id,
+                    OriginMemberRemove::get_user_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginMemberRemove::has_account_name,
+                    OriginMemberRemove::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginMemberRemove::has_requester_id,
+                    OriginMemberRemove::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRemove>(
+                    "OriginMemberRemove",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1319,54 +1625,64 @@ impl ::protobuf::MessageStatic for OriginMemberListRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginMemberListRequest {
+impl ::protobuf::Clear for OriginMemberRemove {
     fn clear(&mut self) {
         self.clear_origin_id();
+        self.clear_user_id();
+        self.clear_account_name();
+        self.clear_requester_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginMemberListRequest {
-    fn eq(&self, other: &OriginMemberListRequest) -> bool {
+impl ::std::cmp::PartialEq for OriginMemberRemove {
+    fn eq(&self, other: &OriginMemberRemove) -> bool {
         self.origin_id == other.origin_id &&
+        self.user_id == other.user_id &&
+        self.account_name == other.account_name &&
+        self.requester_id == other.requester_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginMemberListRequest {
+impl ::std::fmt::Debug for OriginMemberRemove {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginMemberListResponse {
+pub struct OriginTransfer {
     // message fields
     origin_id: ::std::option::Option<u64>,
-    members: ::protobuf::RepeatedField<::std::string::String>,
+    requester_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginMemberListResponse {}
+unsafe impl ::std::marker::Sync for OriginTransfer {}
 
-impl OriginMemberListResponse {
-    pub fn new() -> OriginMemberListResponse {
+impl OriginTransfer {
+    pub fn new() -> OriginTransfer {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginMemberListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginTransfer {
+        static mut instance: ::protobuf::lazy::Lazy<OriginTransfer> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginMemberListResponse,
+            ptr: 0 as *const OriginTransfer,
         };
         unsafe {
             instance.get(|| {
-                OriginMemberListResponse {
+                OriginTransfer {
                     origin_id: ::std::option::Option::None,
-                    members: ::protobuf::RepeatedField::new(),
+                    requester_id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1393,37 +1709,95 @@ impl OriginMemberListResponse {
         self.origin_id.unwrap_or(0)
     }
 
-    // repeated string members = 2;
+    // required uint64 requester_id = 2;
 
-    pub fn clear_members(&mut self) {
-        self.members.clear();
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_members(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.members = v;
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+
+    // required uint64 account_id = 3;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 4;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_members(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.members
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_members(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.members, ::protobuf::RepeatedField::new())
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_members(&self) -> &[::std::string::String] {
-        &self.members
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 }
 
-impl ::protobuf::Message for OriginMemberListResponse {
+impl ::protobuf::Message for OriginTransfer {
     fn is_initialized(&self) -> bool {
         if self.origin_id.is_none() {
             return false;
         };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
         true
     }
 
@@ -1439,7 +1813,21 @@ impl ::protobuf::Message for OriginMemberListResponse {
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.members));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1456,8 +1844,14 @@ impl ::protobuf::Message for OriginMemberListResponse {
         for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.members.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1468,8 +1862,14 @@ impl ::protobuf::Message for OriginMemberListResponse {
         if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        for v in self.members.iter() {
-            try!(os.write_string(2, &v));
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(4, &v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -1488,7 +1888,7 @@ impl ::protobuf::Message for OriginMemberListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginMemberListResponse>()
+        ::std::any::TypeId::of::<OriginTransfer>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1500,12 +1900,12 @@ impl ::protobuf::Message for OriginMemberListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginMemberListResponse {
-    fn new() -> OriginMemberListResponse {
-        OriginMemberListResponse::new()
+impl ::protobuf::MessageStatic for OriginTransfer {
+    fn new() -> OriginTransfer {
+        OriginTransfer::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginMemberListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginTransfer>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1515,15 +1915,26 @@ impl ::protobuf::MessageStatic for OriginMemberListResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginMemberListResponse::has_origin_id,
-                    OriginMemberListResponse::get_origin_id,
+                    OriginTransfer::has_origin_id,
+                    OriginTransfer::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
-                    "members",
-                    OriginMemberListResponse::get_members,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginTransfer::has_requester_id,
+                    OriginTransfer::get_requester_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListResponse>(
-                    "OriginMemberListResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginTransfer::has_account_id,
+                    OriginTransfer::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginTransfer::has_account_name,
+                    OriginTransfer::get_account_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginTransfer>(
+                    "OriginTransfer",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1532,54 +1943,58 @@ impl ::protobuf::MessageStatic for OriginMemberListResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginMemberListResponse {
+impl ::protobuf::Clear for OriginTransfer {
     fn clear(&mut self) {
         self.clear_origin_id();
-        self.clear_members();
+        self.clear_requester_id();
+        self.clear_account_id();
+        self.clear_account_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginMemberListResponse {
-    fn eq(&self, other: &OriginMemberListResponse) -> bool {
+impl ::std::cmp::PartialEq for OriginTransfer {
+    fn eq(&self, other: &OriginTransfer) -> bool {
         self.origin_id == other.origin_id &&
-        self.members == other.members &&
+        self.requester_id == other.requester_id &&
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginMemberListResponse {
+impl ::std::fmt::Debug for OriginTransfer {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct AccountOriginListRequest {
+pub struct OriginMemberListRequest {
     // message fields
-    account_id: ::std::option::Option<u64>,
+    origin_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginListRequest {}
+unsafe impl ::std::marker::Sync for OriginMemberListRequest {}
 
-impl AccountOriginListRequest {
-    pub fn new() -> AccountOriginListRequest {
+impl OriginMemberListRequest {
+    pub fn new() -> OriginMemberListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginListRequest,
+            ptr: 0 as *const OriginMemberListRequest,
         };
         unsafe {
             instance.get(|| {
-                AccountOriginListRequest {
-                    account_id: ::std::option::Option::None,
+                OriginMemberListRequest {
+                    origin_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1587,29 +2002,29 @@ impl AccountOriginListRequest {
         }
     }
 
-    // required uint64 account_id = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for AccountOriginListRequest {
+impl ::protobuf::Message for OriginMemberListRequest {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
+        if self.origin_id.is_none() {
             return false;
         };
         true
@@ -1624,7 +2039,7 @@ impl ::protobuf::Message for AccountOriginListRequest {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1638,7 +2053,7 @@ impl ::protobuf::Message for AccountOriginListRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -1647,7 +2062,7 @@ impl ::protobuf::Message for AccountOriginListRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
@@ -1667,7 +2082,7 @@ impl ::protobuf::Message for AccountOriginListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountOriginListRequest>()
+        ::std::any::TypeId::of::<OriginMemberListRequest>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1679,12 +2094,12 @@ impl ::protobuf::Message for AccountOriginListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginListRequest {
-    fn new() -> AccountOriginListRequest {
-        AccountOriginListRequest::new()
+impl ::protobuf::MessageStatic for OriginMemberListRequest {
+    fn new() -> OriginMemberListRequest {
+        OriginMemberListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1693,12 +2108,12 @@ impl ::protobuf::MessageStatic for AccountOriginListRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    AccountOriginListRequest::has_account_id,
-                    AccountOriginListRequest::get_account_id,
+                    "origin_id",
+                    OriginMemberListRequest::has_origin_id,
+                    OriginMemberListRequest::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListRequest>(
-                    "AccountOriginListRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListRequest>(
+                    "OriginMemberListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1707,54 +2122,56 @@ impl ::protobuf::MessageStatic for AccountOriginListRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginListRequest {
+impl ::protobuf::Clear for OriginMemberListRequest {
     fn clear(&mut self) {
-        self.clear_account_id();
+        self.clear_origin_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountOriginListRequest {
-    fn eq(&self, other: &AccountOriginListRequest) -> bool {
-        self.account_id == other.account_id &&
+impl ::std::cmp::PartialEq for OriginMemberListRequest {
+    fn eq(&self, other: &OriginMemberListRequest) -> bool {
+        self.origin_id == other.origin_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginListRequest {
+impl ::std::fmt::Debug for OriginMemberListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct AccountOriginListResponse {
+pub struct OriginMemberListResponse {
     // message fields
-    account_id: ::std::option::Option<u64>,
-    origins: ::protobuf::RepeatedField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    members: ::protobuf::RepeatedField<::std::string::String>,
+    roles: ::protobuf::RepeatedField<OriginMember>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginListResponse {}
+unsafe impl ::std::marker::Sync for OriginMemberListResponse {}
 
-impl AccountOriginListResponse {
-    pub fn new() -> AccountOriginListResponse {
+impl OriginMemberListResponse {
+    pub fn new() -> OriginMemberListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginListResponse,
+            ptr: 0 as *const OriginMemberListResponse,
         };
         unsafe {
             instance.get(|| {
-                AccountOriginListResponse {
-                    account_id: ::std::option::Option::None,
-                    origins: ::protobuf::RepeatedField::new(),
+                OriginMemberListResponse {
+                    origin_id: ::std::option::Option::None,
+                    members: ::protobuf::RepeatedField::new(),
+                    roles: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1762,54 +2179,79 @@ impl AccountOriginListResponse {
         }
     }
 
-    // required uint64 account_id = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    // repeated string origins = 2;
+    // repeated string members = 2;
 
-    pub fn clear_origins(&mut self) {
-        self.origins.clear();
+    pub fn clear_members(&mut self) {
+        self.members.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.origins = v;
+    pub fn set_members(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.members = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.origins
+    pub fn mut_members(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.members
     }
 
     // Take field
-    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
+    pub fn take_members(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.members, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_origins(&self) -> &[::std::string::String] {
-        &self.origins
+    pub fn get_members(&self) -> &[::std::string::String] {
+        &self.members
+    }
+
+    // repeated .vault.OriginMember roles = 3;
+
+    pub fn clear_roles(&mut self) {
+        self.roles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roles(&mut self, v: ::protobuf::RepeatedField<OriginMember>) {
+        self.roles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_roles(&mut self) -> &mut ::protobuf::RepeatedField<OriginMember> {
+        &mut self.roles
+    }
+
+    // Take field
+    pub fn take_roles(&mut self) -> ::protobuf::RepeatedField<OriginMember> {
+        ::std::mem::replace(&mut self.roles, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_roles(&self) -> &[OriginMember] {
+        &self.roles
     }
 }
 
-impl ::protobuf::Message for AccountOriginListResponse {
+impl ::protobuf::Message for OriginMemberListResponse {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
+        if self.origin_id.is_none() {
             return false;
         };
         true
@@ -1824,10 +2266,13 @@ impl ::protobuf::Message for AccountOriginListResponse {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins));
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.members));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.roles));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1841,24 +2286,33 @@ impl ::protobuf::Message for AccountOriginListResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.origins.iter() {
+        for value in self.members.iter() {
             my_size += ::protobuf::rt::string_size(2, &value);
         };
+        for value in self.roles.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        for v in self.origins.iter() {
+        for v in self.members.iter() {
             try!(os.write_string(2, &v));
         };
+        for v in self.roles.iter() {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1876,7 +2330,7 @@ impl ::protobuf::Message for AccountOriginListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountOriginListResponse>()
+        ::std::any::TypeId::of::<OriginMemberListResponse>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1888,12 +2342,12 @@ impl ::protobuf::Message for AccountOriginListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginListResponse {
-    fn new() -> AccountOriginListResponse {
-        AccountOriginListResponse::new()
+impl ::protobuf::MessageStatic for OriginMemberListResponse {
+    fn new() -> OriginMemberListResponse {
+        OriginMemberListResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1902,16 +2356,20 @@ impl ::protobuf::MessageStatic for AccountOriginListResponse {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    AccountOriginListResponse::has_account_id,
-                    AccountOriginListResponse::get_account_id,
+                    "origin_id",
+                    OriginMemberListResponse::has_origin_id,
+                    OriginMemberListResponse::get_origin_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
-                    "origins",
-                    AccountOriginListResponse::get_origins,
+                    "members",
+                    OriginMemberListResponse::get_members,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListResponse>(
-                    "AccountOriginListResponse",
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "roles",
+                    OriginMemberListResponse::get_roles,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListResponse>(
+                    "OriginMemberListResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1920,68 +2378,62 @@ impl ::protobuf::MessageStatic for AccountOriginListResponse {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginListResponse {
+impl ::protobuf::Clear for OriginMemberListResponse {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_origins();
+        self.clear_origin_id();
+        self.clear_members();
+        self.clear_roles();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountOriginListResponse {
-    fn eq(&self, other: &AccountOriginListResponse) -> bool {
-        self.account_id == other.account_id &&
-        self.origins == other.origins &&
+impl ::std::cmp::PartialEq for OriginMemberListResponse {
+    fn eq(&self, other: &OriginMemberListResponse) -> bool {
+        self.origin_id == other.origin_id &&
+        self.members == other.members &&
+        self.roles == other.roles &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginListResponse {
+impl ::std::fmt::Debug for OriginMemberListResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct CheckOriginAccessRequest {
-    // message oneof groups
-    account_info: ::std::option::Option<CheckOriginAccessRequest_oneof_account_info>,
-    origin_info: ::std::option::Option<CheckOriginAccessRequest_oneof_origin_info>,
+pub struct OriginMember {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    role: ::std::option::Option<OriginRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for CheckOriginAccessRequest {}
-
-#[derive(Clone,PartialEq)]
-pub enum CheckOriginAccessRequest_oneof_account_info {
-    account_id(u64),
-    account_name(::std::string::String),
-}
-
-#[derive(Clone,PartialEq)]
-pub enum CheckOriginAccessRequest_oneof_origin_info {
-    origin_id(u64),
-    origin_name(::std::string::String),
-}
+unsafe impl ::std::marker::Sync for OriginMember {}
 
-impl CheckOriginAccessRequest {
-    pub fn new() -> CheckOriginAccessRequest {
+impl OriginMember {
+    pub fn new() -> OriginMember {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static CheckOriginAccessRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMember {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMember> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CheckOriginAccessRequest,
+            ptr: 0 as *const OriginMember,
         };
         unsafe {
             instance.get(|| {
-                CheckOriginAccessRequest {
-                    account_info: ::std::option::Option::None,
-                    origin_info: ::std::option::Option::None,
+                OriginMember {
+                    origin_id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1989,159 +2441,114 @@ impl CheckOriginAccessRequest {
         }
     }
 
-    // optional uint64 account_id = 1;
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required uint64 account_id = 2;
 
     pub fn clear_account_id(&mut self) {
-        self.account_info = ::std::option::Option::None;
+        self.account_id = ::std::option::Option::None;
     }
 
     pub fn has_account_id(&self) -> bool {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(..)) => true,
-            _ => false,
-        }
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
     pub fn set_account_id(&mut self, v: u64) {
-        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v))
+        self.account_id = ::std::option::Option::Some(v);
     }
 
     pub fn get_account_id(&self) -> u64 {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v)) => v,
-            _ => 0,
-        }
+        self.account_id.unwrap_or(0)
     }
 
-    // optional string account_name = 2;
+    // required string account_name = 3;
 
     pub fn clear_account_name(&mut self) {
-        self.account_info = ::std::option::Option::None;
+        self.account_name.clear();
     }
 
     pub fn has_account_name(&self) -> bool {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(..)) => true,
-            _ => false,
-        }
+        self.account_name.is_some()
     }
 
     // Param is passed by value, moved
     pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v))
+        self.account_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(_)) = self.account_info {
-        } else {
-            self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(::std::string::String::new()));
-        }
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref mut v)) => v,
-            _ => panic!(),
-        }
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
     }
 
     // Take field
     pub fn take_account_name(&mut self) -> ::std::string::String {
-        if self.has_account_name() {
-            match self.account_info.take() {
-                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::string::String::new()
-        }
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
     pub fn get_account_name(&self) -> &str {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref v)) => v,
-            _ => "",
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // optional uint64 origin_id = 3;
+    // required .vault.OriginRole role = 4;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_info = ::std::option::Option::None;
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v))
-    }
-
-    pub fn get_origin_id(&self) -> u64 {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v)) => v,
-            _ => 0,
-        }
-    }
-
-    // optional string origin_name = 4;
-
-    pub fn clear_origin_name(&mut self) {
-        self.origin_info = ::std::option::Option::None;
-    }
-
-    pub fn has_origin_name(&self) -> bool {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(..)) => true,
-            _ => false,
-        }
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v))
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(_)) = self.origin_info {
-        } else {
-            self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(::std::string::String::new()));
-        }
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        if self.has_origin_name() {
-            match self.origin_info.take() {
-                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::string::String::new()
-        }
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v)) => v,
-            _ => "",
-        }
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::ReadOnly)
     }
 }
 
-impl ::protobuf::Message for CheckOriginAccessRequest {
+impl ::protobuf::Message for OriginMember {
     fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
         true
     }
 
@@ -2153,25 +2560,25 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(try!(is.read_uint64())));
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(try!(is.read_string())));
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(try!(is.read_uint64())));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
                 },
                 4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(try!(is.read_string())));
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2185,25 +2592,17 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.account_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
-                    my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-                },
-                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
-                    my_size += ::protobuf::rt::string_size(2, &v);
-                },
-            };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        if let ::std::option::Option::Some(ref v) = self.origin_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
-                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
-                },
-                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
-                    my_size += ::protobuf::rt::string_size(4, &v);
-                },
-            };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(4, *value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2211,25 +2610,17 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.account_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
-                    try!(os.write_uint64(1, v));
-                },
-                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
-                    try!(os.write_string(2, v));
-                },
-            };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
         };
-        if let ::std::option::Option::Some(ref v) = self.origin_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
-                    try!(os.write_uint64(3, v));
-                },
-                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
-                    try!(os.write_string(4, v));
-                },
-            };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(4, v.value()));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -2248,7 +2639,7 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<CheckOriginAccessRequest>()
+        ::std::any::TypeId::of::<OriginMember>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2260,12 +2651,12 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
-    fn new() -> CheckOriginAccessRequest {
-        CheckOriginAccessRequest::new()
+impl ::protobuf::MessageStatic for OriginMember {
+    fn new() -> OriginMember {
+        OriginMember::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMember>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2273,28 +2664,28 @@ impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginMember::has_origin_id,
+                    OriginMember::get_origin_id,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "account_id",
-                    CheckOriginAccessRequest::has_account_id,
-                    CheckOriginAccessRequest::get_account_id,
+                    OriginMember::has_account_id,
+                    OriginMember::get_account_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
                     "account_name",
-                    CheckOriginAccessRequest::has_account_name,
-                    CheckOriginAccessRequest::get_account_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "origin_id",
-                    CheckOriginAccessRequest::has_origin_id,
-                    CheckOriginAccessRequest::get_origin_id,
+                    OriginMember::has_account_name,
+                    OriginMember::get_account_name,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "origin_name",
-                    CheckOriginAccessRequest::has_origin_name,
-                    CheckOriginAccessRequest::get_origin_name,
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginMember::has_role,
+                    OriginMember::get_role,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessRequest>(
-                    "CheckOriginAccessRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMember>(
+                    "OriginMember",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2303,56 +2694,66 @@ impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
     }
 }
 
-impl ::protobuf::Clear for CheckOriginAccessRequest {
+impl ::protobuf::Clear for OriginMember {
     fn clear(&mut self) {
+        self.clear_origin_id();
         self.clear_account_id();
         self.clear_account_name();
-        self.clear_origin_id();
-        self.clear_origin_name();
+        self.clear_role();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for CheckOriginAccessRequest {
-    fn eq(&self, other: &CheckOriginAccessRequest) -> bool {
-        self.account_info == other.account_info &&
-        self.origin_info == other.origin_info &&
+impl ::std::cmp::PartialEq for OriginMember {
+    fn eq(&self, other: &OriginMember) -> bool {
+        self.origin_id == other.origin_id &&
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.role == other.role &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for CheckOriginAccessRequest {
+impl ::std::fmt::Debug for OriginMember {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct CheckOriginAccessResponse {
+pub struct OriginMemberRoleUpdate {
     // message fields
-    has_access: ::std::option::Option<bool>,
+    origin_id: ::std::option::Option<u64>,
+    requester_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    role: ::std::option::Option<OriginRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for CheckOriginAccessResponse {}
+unsafe impl ::std::marker::Sync for OriginMemberRoleUpdate {}
 
-impl CheckOriginAccessResponse {
-    pub fn new() -> CheckOriginAccessResponse {
+impl OriginMemberRoleUpdate {
+    pub fn new() -> OriginMemberRoleUpdate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static CheckOriginAccessResponse {
-        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberRoleUpdate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRoleUpdate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CheckOriginAccessResponse,
+            ptr: 0 as *const OriginMemberRoleUpdate,
         };
         unsafe {
             instance.get(|| {
-                CheckOriginAccessResponse {
-                    has_access: ::std::option::Option::None,
+                OriginMemberRoleUpdate {
+                    origin_id: ::std::option::Option::None,
+                    requester_id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2360,204 +2761,136 @@ impl CheckOriginAccessResponse {
         }
     }
 
-    // required bool has_access = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_has_access(&mut self) {
-        self.has_access = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_has_access(&self) -> bool {
-        self.has_access.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_has_access(&mut self, v: bool) {
-        self.has_access = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_has_access(&self) -> bool {
-        self.has_access.unwrap_or(false)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
-}
 
-impl ::protobuf::Message for CheckOriginAccessResponse {
-    fn is_initialized(&self) -> bool {
-        if self.has_access.is_none() {
-            return false;
-        };
-        true
-    }
+    // required uint64 requester_id = 2;
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_bool());
-                    self.has_access = ::std::option::Option::Some(tmp);
-                },
-                _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if self.has_access.is_some() {
-            my_size += 2;
-        };
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.has_access {
-            try!(os.write_bool(1, v));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
-        ::std::result::Result::Ok(())
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    // required uint64 account_id = 3;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
-    }
-
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<CheckOriginAccessResponse>()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
-}
 
-impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
-    fn new() -> CheckOriginAccessResponse {
-        CheckOriginAccessResponse::new()
-    }
+    // required string account_name = 4;
 
-    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
-                    "has_access",
-                    CheckOriginAccessResponse::has_has_access,
-                    CheckOriginAccessResponse::get_has_access,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessResponse>(
-                    "CheckOriginAccessResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
     }
-}
 
-impl ::protobuf::Clear for CheckOriginAccessResponse {
-    fn clear(&mut self) {
-        self.clear_has_access();
-        self.unknown_fields.clear();
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
     }
-}
 
-impl ::std::cmp::PartialEq for CheckOriginAccessResponse {
-    fn eq(&self, other: &CheckOriginAccessResponse) -> bool {
-        self.has_access == other.has_access &&
-        self.unknown_fields == other.unknown_fields
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
     }
-}
 
-impl ::std::fmt::Debug for CheckOriginAccessResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
     }
-}
-
-#[derive(Clone,Default)]
-pub struct AccountInvitationListRequest {
-    // message fields
-    account_id: ::std::option::Option<u64>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
-}
 
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountInvitationListRequest {}
-
-impl AccountInvitationListRequest {
-    pub fn new() -> AccountInvitationListRequest {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn default_instance() -> &'static AccountInvitationListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountInvitationListRequest,
-        };
-        unsafe {
-            instance.get(|| {
-                AccountInvitationListRequest {
-                    account_id: ::std::option::Option::None,
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // required uint64 account_id = 1;
+    // required .vault.OriginRole role = 5;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::ReadOnly)
     }
 }
 
-impl ::protobuf::Message for AccountInvitationListRequest {
+impl ::protobuf::Message for OriginMemberRoleUpdate {
     fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
         if self.account_id.is_none() {
             return false;
         };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
         true
     }
 
@@ -2566,12 +2899,36 @@ impl ::protobuf::Message for AccountInvitationListRequest {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2584,18 +2941,42 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(5, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(5, v.value()));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -2613,7 +2994,7 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountInvitationListRequest>()
+        ::std::any::TypeId::of::<OriginMemberRoleUpdate>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2625,12 +3006,12 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountInvitationListRequest {
-    fn new() -> AccountInvitationListRequest {
-        AccountInvitationListRequest::new()
+impl ::protobuf::MessageStatic for OriginMemberRoleUpdate {
+    fn new() -> OriginMemberRoleUpdate {
+        OriginMemberRoleUpdate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberRoleUpdate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2638,13 +3019,33 @@ impl ::protobuf::MessageStatic for AccountInvitationListRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginMemberRoleUpdate::has_origin_id,
+                    OriginMemberRoleUpdate::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginMemberRoleUpdate::has_requester_id,
+                    OriginMemberRoleUpdate::get_requester_id,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "account_id",
-                    AccountInvitationListRequest::has_account_id,
-                    AccountInvitationListRequest::get_account_id,
+                    OriginMemberRoleUpdate::has_account_id,
+                    OriginMemberRoleUpdate::get_account_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListRequest>(
-                    "AccountInvitationListRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginMemberRoleUpdate::has_account_name,
+                    OriginMemberRoleUpdate::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginMemberRoleUpdate::has_role,
+                    OriginMemberRoleUpdate::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRoleUpdate>(
+                    "OriginMemberRoleUpdate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2653,54 +3054,60 @@ impl ::protobuf::MessageStatic for AccountInvitationListRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountInvitationListRequest {
+impl ::protobuf::Clear for OriginMemberRoleUpdate {
     fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_requester_id();
         self.clear_account_id();
+        self.clear_account_name();
+        self.clear_role();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountInvitationListRequest {
-    fn eq(&self, other: &AccountInvitationListRequest) -> bool {
+impl ::std::cmp::PartialEq for OriginMemberRoleUpdate {
+    fn eq(&self, other: &OriginMemberRoleUpdate) -> bool {
+        self.origin_id == other.origin_id &&
+        self.requester_id == other.requester_id &&
         self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.role == other.role &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountInvitationListRequest {
+impl ::std::fmt::Debug for OriginMemberRoleUpdate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct AccountInvitationListResponse {
+pub struct AccountOriginListRequest {
     // message fields
     account_id: ::std::option::Option<u64>,
-    invitations: ::protobuf::RepeatedField<OriginInvitation>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountInvitationListResponse {}
+unsafe impl ::std::marker::Sync for AccountOriginListRequest {}
 
-impl AccountInvitationListResponse {
-    pub fn new() -> AccountInvitationListResponse {
+impl AccountOriginListRequest {
+    pub fn new() -> AccountOriginListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountInvitationListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountInvitationListResponse,
+            ptr: 0 as *const AccountOriginListRequest,
         };
         unsafe {
             instance.get(|| {
-                AccountInvitationListResponse {
+                AccountOriginListRequest {
                     account_id: ::std::option::Option::None,
-                    invitations: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2726,34 +3133,9 @@ impl AccountInvitationListResponse {
     pub fn get_account_id(&self) -> u64 {
         self.account_id.unwrap_or(0)
     }
-
-    // repeated .vault.OriginInvitation invitations = 2;
-
-    pub fn clear_invitations(&mut self) {
-        self.invitations.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
-        self.invitations = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
-        &mut self.invitations
-    }
-
-    // Take field
-    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
-        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
-    }
-
-    pub fn get_invitations(&self) -> &[OriginInvitation] {
-        &self.invitations
-    }
 }
 
-impl ::protobuf::Message for AccountInvitationListResponse {
+impl ::protobuf::Message for AccountOriginListRequest {
     fn is_initialized(&self) -> bool {
         if self.account_id.is_none() {
             return false;
@@ -2772,9 +3154,6 @@ impl ::protobuf::Message for AccountInvitationListResponse {
                     let tmp = try!(is.read_uint64());
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
-                2 => {
-                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
-                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2790,10 +3169,6 @@ impl ::protobuf::Message for AccountInvitationListResponse {
         for value in self.account_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.invitations.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2803,11 +3178,6 @@ impl ::protobuf::Message for AccountInvitationListResponse {
         if let Some(v) = self.account_id {
             try!(os.write_uint64(1, v));
         };
-        for v in self.invitations.iter() {
-            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -2825,7 +3195,7 @@ impl ::protobuf::Message for AccountInvitationListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountInvitationListResponse>()
+        ::std::any::TypeId::of::<AccountOriginListRequest>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2837,12 +3207,12 @@ impl ::protobuf::Message for AccountInvitationListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountInvitationListResponse {
-    fn new() -> AccountInvitationListResponse {
-        AccountInvitationListResponse::new()
+impl ::protobuf::MessageStatic for AccountOriginListRequest {
+    fn new() -> AccountOriginListRequest {
+        AccountOriginListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2852,15 +3222,11 @@ impl ::protobuf::MessageStatic for AccountInvitationListResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "account_id",
-                    AccountInvitationListResponse::has_account_id,
-                    AccountInvitationListResponse::get_account_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
-                    "invitations",
-                    AccountInvitationListResponse::get_invitations,
+                    AccountOriginListRequest::has_account_id,
+                    AccountOriginListRequest::get_account_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListResponse>(
-                    "AccountInvitationListResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListRequest>(
+                    "AccountOriginListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2869,54 +3235,54 @@ impl ::protobuf::MessageStatic for AccountInvitationListResponse {
     }
 }
 
-impl ::protobuf::Clear for AccountInvitationListResponse {
+impl ::protobuf::Clear for AccountOriginListRequest {
     fn clear(&mut self) {
         self.clear_account_id();
-        self.clear_invitations();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountInvitationListResponse {
-    fn eq(&self, other: &AccountInvitationListResponse) -> bool {
+impl ::std::cmp::PartialEq for AccountOriginListRequest {
+    fn eq(&self, other: &AccountOriginListRequest) -> bool {
         self.account_id == other.account_id &&
-        self.invitations == other.invitations &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountInvitationListResponse {
+impl ::std::fmt::Debug for AccountOriginListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationListRequest {
+pub struct AccountOriginListResponse {
     // message fields
-    origin_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    origins: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationListRequest {}
+unsafe impl ::std::marker::Sync for AccountOriginListResponse {}
 
-impl OriginInvitationListRequest {
-    pub fn new() -> OriginInvitationListRequest {
+impl AccountOriginListResponse {
+    pub fn new() -> AccountOriginListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationListRequest,
+            ptr: 0 as *const AccountOriginListResponse,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationListRequest {
-                    origin_id: ::std::option::Option::None,
+                AccountOriginListResponse {
+                    account_id: ::std::option::Option::None,
+                    origins: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2924,29 +3290,54 @@ impl OriginInvitationListRequest {
         }
     }
 
-    // required uint64 origin_id = 1;
+    // required uint64 account_id = 1;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // repeated string origins = 2;
+
+    pub fn clear_origins(&mut self) {
+        self.origins.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.origins = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+
+    // Take field
+    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_origins(&self) -> &[::std::string::String] {
+        &self.origins
     }
 }
 
-impl ::protobuf::Message for OriginInvitationListRequest {
+impl ::protobuf::Message for AccountOriginListResponse {
     fn is_initialized(&self) -> bool {
-        if self.origin_id.is_none() {
+        if self.account_id.is_none() {
             return false;
         };
         true
@@ -2961,7 +3352,10 @@ impl ::protobuf::Message for OriginInvitationListRequest {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.origin_id = ::std::option::Option::Some(tmp);
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2975,18 +3369,24 @@ impl ::protobuf::Message for OriginInvitationListRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin_id.iter() {
+        for value in self.account_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.origins.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin_id {
+        if let Some(v) = self.account_id {
             try!(os.write_uint64(1, v));
         };
+        for v in self.origins.iter() {
+            try!(os.write_string(2, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -3004,7 +3404,7 @@ impl ::protobuf::Message for OriginInvitationListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationListRequest>()
+        ::std::any::TypeId::of::<AccountOriginListResponse>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -3016,12 +3416,12 @@ impl ::protobuf::Message for OriginInvitationListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationListRequest {
-    fn new() -> OriginInvitationListRequest {
-        OriginInvitationListRequest::new()
+impl ::protobuf::MessageStatic for AccountOriginListResponse {
+    fn new() -> AccountOriginListResponse {
+        AccountOriginListResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3030,12 +3430,16 @@ impl ::protobuf::MessageStatic for OriginInvitationListRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "origin_id",
-                    OriginInvitationListRequest::has_origin_id,
-                    OriginInvitationListRequest::get_origin_id,
+                    "account_id",
+                    AccountOriginListResponse::has_account_id,
+                    AccountOriginListResponse::get_account_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListRequest>(
-                    "OriginInvitationListRequest",
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "origins",
+                    AccountOriginListResponse::get_origins,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListResponse>(
+                    "AccountOriginListResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3044,54 +3448,71 @@ impl ::protobuf::MessageStatic for OriginInvitationListRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationListRequest {
+impl ::protobuf::Clear for AccountOriginListResponse {
     fn clear(&mut self) {
-        self.clear_origin_id();
+        self.clear_account_id();
+        self.clear_origins();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationListRequest {
-    fn eq(&self, other: &OriginInvitationListRequest) -> bool {
-        self.origin_id == other.origin_id &&
+impl ::std::cmp::PartialEq for AccountOriginListResponse {
+    fn eq(&self, other: &AccountOriginListResponse) -> bool {
+        self.account_id == other.account_id &&
+        self.origins == other.origins &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationListRequest {
+impl ::std::fmt::Debug for AccountOriginListResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationListResponse {
+pub struct CheckOriginAccessRequest {
     // message fields
-    origin_id: ::std::option::Option<u64>,
-    invitations: ::protobuf::RepeatedField<OriginInvitation>,
+    required_role: ::std::option::Option<OriginRole>,
+    // message oneof groups
+    account_info: ::std::option::Option<CheckOriginAccessRequest_oneof_account_info>,
+    origin_info: ::std::option::Option<CheckOriginAccessRequest_oneof_origin_info>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationListResponse {}
+unsafe impl ::std::marker::Sync for CheckOriginAccessRequest {}
 
-impl OriginInvitationListResponse {
-    pub fn new() -> OriginInvitationListResponse {
+#[derive(Clone,PartialEq)]
+pub enum CheckOriginAccessRequest_oneof_account_info {
+    account_id(u64),
+    account_name(::std::string::String),
+}
+
+#[derive(Clone,PartialEq)]
+pub enum CheckOriginAccessRequest_oneof_origin_info {
+    origin_id(u64),
+    origin_name(::std::string::String),
+}
+
+impl CheckOriginAccessRequest {
+    pub fn new() -> CheckOriginAccessRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static CheckOriginAccessRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationListResponse,
+            ptr: 0 as *const CheckOriginAccessRequest,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationListResponse {
-                    origin_id: ::std::option::Option::None,
-                    invitations: ::protobuf::RepeatedField::new(),
+                CheckOriginAccessRequest {
+                    required_role: ::std::option::Option::None,
+                    account_info: ::std::option::Option::None,
+                    origin_info: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3099,56 +3520,178 @@ impl OriginInvitationListResponse {
         }
     }
 
-    // required uint64 origin_id = 1;
+    // optional uint64 account_id = 1;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_account_id(&mut self) {
+        self.account_info = ::std::option::Option::None;
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_account_id(&self) -> bool {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(..)) => true,
+            _ => false,
+        }
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v))
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    pub fn get_account_id(&self) -> u64 {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v)) => v,
+            _ => 0,
+        }
     }
 
-    // repeated .vault.OriginInvitation invitations = 2;
+    // optional string account_name = 2;
 
-    pub fn clear_invitations(&mut self) {
-        self.invitations.clear();
+    pub fn clear_account_name(&mut self) {
+        self.account_info = ::std::option::Option::None;
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(..)) => true,
+            _ => false,
+        }
     }
 
     // Param is passed by value, moved
-    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
-        self.invitations = v;
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
-        &mut self.invitations
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(_)) = self.account_info {
+        } else {
+            self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(::std::string::String::new()));
+        }
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
 
     // Take field
-    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
-        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
-    }
-
-    pub fn get_invitations(&self) -> &[OriginInvitation] {
-        &self.invitations
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        if self.has_account_name() {
+            match self.account_info.take() {
+                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
     }
-}
 
-impl ::protobuf::Message for OriginInvitationListResponse {
+    pub fn get_account_name(&self) -> &str {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    // optional uint64 origin_id = 3;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_info = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v))
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v)) => v,
+            _ => 0,
+        }
+    }
+
+    // optional string origin_name = 4;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_info = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(_)) = self.origin_info {
+        } else {
+            self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(::std::string::String::new()));
+        }
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        if self.has_origin_name() {
+            match self.origin_info.take() {
+                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    // optional .vault.OriginRole required_role = 5;
+
+    pub fn clear_required_role(&mut self) {
+        self.required_role = ::std::option::Option::None;
+    }
+
+    pub fn has_required_role(&self) -> bool {
+        self.required_role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_required_role(&mut self, v: OriginRole) {
+        self.required_role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_required_role(&self) -> OriginRole {
+        self.required_role.unwrap_or(OriginRole::ReadOnly)
+    }
+}
+
+impl ::protobuf::Message for CheckOriginAccessRequest {
     fn is_initialized(&self) -> bool {
-        if self.origin_id.is_none() {
-            return false;
-        };
         true
     }
 
@@ -3160,11 +3703,32 @@ impl ::protobuf::Message for OriginInvitationListResponse {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_uint64());
-                    self.origin_id = ::std::option::Option::Some(tmp);
+                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(try!(is.read_uint64())));
                 },
                 2 => {
-                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(try!(is.read_string())));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(try!(is.read_uint64())));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(try!(is.read_string())));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.required_role = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -3178,12 +3742,28 @@ impl ::protobuf::Message for OriginInvitationListResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.required_role.iter() {
+            my_size += ::protobuf::rt::enum_size(5, *value);
         };
-        for value in self.invitations.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if let ::std::option::Option::Some(ref v) = self.account_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
+                    my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        };
+        if let ::std::option::Option::Some(ref v) = self.origin_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
+                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3191,13 +3771,28 @@ impl ::protobuf::Message for OriginInvitationListResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin_id {
-            try!(os.write_uint64(1, v));
+        if let Some(v) = self.required_role {
+            try!(os.write_enum(5, v.value()));
         };
-        for v in self.invitations.iter() {
-            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
+        if let ::std::option::Option::Some(ref v) = self.account_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
+                    try!(os.write_uint64(1, v));
+                },
+                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
+                    try!(os.write_string(2, v));
+                },
+            };
+        };
+        if let ::std::option::Option::Some(ref v) = self.origin_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
+                    try!(os.write_uint64(3, v));
+                },
+                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
+                    try!(os.write_string(4, v));
+                },
+            };
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -3216,7 +3811,7 @@ impl ::protobuf::Message for OriginInvitationListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationListResponse>()
+        ::std::any::TypeId::of::<CheckOriginAccessRequest>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -3228,12 +3823,12 @@ impl ::protobuf::Message for OriginInvitationListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationListResponse {
-    fn new() -> OriginInvitationListResponse {
-        OriginInvitationListResponse::new()
+impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
+    fn new() -> CheckOriginAccessRequest {
+        CheckOriginAccessRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3241,17 +3836,33 @@ impl ::protobuf::MessageStatic for OriginInvitationListResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    CheckOriginAccessRequest::has_account_id,
+                    CheckOriginAccessRequest::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    CheckOriginAccessRequest::has_account_name,
+                    CheckOriginAccessRequest::get_account_name,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginInvitationListResponse::has_origin_id,
-                    OriginInvitationListResponse::get_origin_id,
+                    CheckOriginAccessRequest::has_origin_id,
+                    CheckOriginAccessRequest::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
-                    "invitations",
-                    OriginInvitationListResponse::get_invitations,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    CheckOriginAccessRequest::has_origin_name,
+                    CheckOriginAccessRequest::get_origin_name,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListResponse>(
-                    "OriginInvitationListResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "required_role",
+                    CheckOriginAccessRequest::has_required_role,
+                    CheckOriginAccessRequest::get_required_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessRequest>(
+                    "CheckOriginAccessRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3260,64 +3871,60 @@ impl ::protobuf::MessageStatic for OriginInvitationListResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationListResponse {
+impl ::protobuf::Clear for CheckOriginAccessRequest {
     fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_account_name();
         self.clear_origin_id();
-        self.clear_invitations();
+        self.clear_origin_name();
+        self.clear_required_role();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationListResponse {
-    fn eq(&self, other: &OriginInvitationListResponse) -> bool {
-        self.origin_id == other.origin_id &&
-        self.invitations == other.invitations &&
+impl ::std::cmp::PartialEq for CheckOriginAccessRequest {
+    fn eq(&self, other: &CheckOriginAccessRequest) -> bool {
+        self.required_role == other.required_role &&
+        self.account_info == other.account_info &&
+        self.origin_info == other.origin_info &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationListResponse {
+impl ::std::fmt::Debug for CheckOriginAccessRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitation {
+pub struct CheckOriginAccessResponse {
     // message fields
-    id: ::std::option::Option<u64>,
-    account_id: ::std::option::Option<u64>,
-    account_name: ::protobuf::SingularField<::std::string::String>,
-    origin_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
-    owner_id: ::std::option::Option<u64>,
+    has_access: ::std::option::Option<bool>,
+    role: ::std::option::Option<OriginRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitation {}
+unsafe impl ::std::marker::Sync for CheckOriginAccessResponse {}
 
-impl OriginInvitation {
-    pub fn new() -> OriginInvitation {
+impl CheckOriginAccessResponse {
+    pub fn new() -> CheckOriginAccessResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitation {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitation> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static CheckOriginAccessResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitation,
+            ptr: 0 as *const CheckOriginAccessResponse,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitation {
-                    id: ::std::option::Option::None,
-                    account_id: ::std::option::Option::None,
-                    account_name: ::protobuf::SingularField::none(),
-                    origin_id: ::std::option::Option::None,
-                    origin_name: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
+                CheckOriginAccessResponse {
+                    has_access: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3325,136 +3932,3012 @@ impl OriginInvitation {
         }
     }
 
-    // required uint64 id = 1;
+    // required bool has_access = 1;
+
+    pub fn clear_has_access(&mut self) {
+        self.has_access = ::std::option::Option::None;
+    }
+
+    pub fn has_has_access(&self) -> bool {
+        self.has_access.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_access(&mut self, v: bool) {
+        self.has_access = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_has_access(&self) -> bool {
+        self.has_access.unwrap_or(false)
+    }
+
+    // optional .vault.OriginRole role = 2;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::ReadOnly)
+    }
+}
+
+impl ::protobuf::Message for CheckOriginAccessResponse {
+    fn is_initialized(&self) -> bool {
+        if self.has_access.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.has_access = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.has_access.is_some() {
+            my_size += 2;
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.has_access {
+            try!(os.write_bool(1, v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(2, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<CheckOriginAccessResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
+    fn new() -> CheckOriginAccessResponse {
+        CheckOriginAccessResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "has_access",
+                    CheckOriginAccessResponse::has_has_access,
+                    CheckOriginAccessResponse::get_has_access,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    CheckOriginAccessResponse::has_role,
+                    CheckOriginAccessResponse::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessResponse>(
+                    "CheckOriginAccessResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for CheckOriginAccessResponse {
+    fn clear(&mut self) {
+        self.clear_has_access();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for CheckOriginAccessResponse {
+    fn eq(&self, other: &CheckOriginAccessResponse) -> bool {
+        self.has_access == other.has_access &&
+        self.role == other.role &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for CheckOriginAccessResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountInvitationListRequest {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountInvitationListRequest {}
+
+impl AccountInvitationListRequest {
+    pub fn new() -> AccountInvitationListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountInvitationListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountInvitationListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountInvitationListRequest {
+                    account_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccountInvitationListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountInvitationListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountInvitationListRequest {
+    fn new() -> AccountInvitationListRequest {
+        AccountInvitationListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountInvitationListRequest::has_account_id,
+                    AccountInvitationListRequest::get_account_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListRequest>(
+                    "AccountInvitationListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountInvitationListRequest {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountInvitationListRequest {
+    fn eq(&self, other: &AccountInvitationListRequest) -> bool {
+        self.account_id == other.account_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountInvitationListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountInvitationListResponse {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    invitations: ::protobuf::RepeatedField<OriginInvitation>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountInvitationListResponse {}
+
+impl AccountInvitationListResponse {
+    pub fn new() -> AccountInvitationListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountInvitationListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountInvitationListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountInvitationListResponse {
+                    account_id: ::std::option::Option::None,
+                    invitations: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // repeated .vault.OriginInvitation invitations = 2;
+
+    pub fn clear_invitations(&mut self) {
+        self.invitations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
+        self.invitations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
+        &mut self.invitations
+    }
+
+    // Take field
+    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
+        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_invitations(&self) -> &[OriginInvitation] {
+        &self.invitations
+    }
+}
+
+impl ::protobuf::Message for AccountInvitationListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invitations.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.invitations.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountInvitationListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountInvitationListResponse {
+    fn new() -> AccountInvitationListResponse {
+        AccountInvitationListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountInvitationListResponse::has_account_id,
+                    AccountInvitationListResponse::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "invitations",
+                    AccountInvitationListResponse::get_invitations,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListResponse>(
+                    "AccountInvitationListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountInvitationListResponse {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_invitations();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountInvitationListResponse {
+    fn eq(&self, other: &AccountInvitationListResponse) -> bool {
+        self.account_id == other.account_id &&
+        self.invitations == other.invitations &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountInvitationListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationListRequest {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationListRequest {}
+
+impl OriginInvitationListRequest {
+    pub fn new() -> OriginInvitationListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationListRequest {
+                    origin_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationListRequest {
+    fn new() -> OriginInvitationListRequest {
+        OriginInvitationListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationListRequest::has_origin_id,
+                    OriginInvitationListRequest::get_origin_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListRequest>(
+                    "OriginInvitationListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationListRequest {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationListRequest {
+    fn eq(&self, other: &OriginInvitationListRequest) -> bool {
+        self.origin_id == other.origin_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationListResponse {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    invitations: ::protobuf::RepeatedField<OriginInvitation>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationListResponse {}
+
+impl OriginInvitationListResponse {
+    pub fn new() -> OriginInvitationListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationListResponse {
+                    origin_id: ::std::option::Option::None,
+                    invitations: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // repeated .vault.OriginInvitation invitations = 2;
+
+    pub fn clear_invitations(&mut self) {
+        self.invitations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
+        self.invitations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
+        &mut self.invitations
+    }
+
+    // Take field
+    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
+        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_invitations(&self) -> &[OriginInvitation] {
+        &self.invitations
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invitations.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.invitations.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationListResponse {
+    fn new() -> OriginInvitationListResponse {
+        OriginInvitationListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationListResponse::has_origin_id,
+                    OriginInvitationListResponse::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "invitations",
+                    OriginInvitationListResponse::get_invitations,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListResponse>(
+                    "OriginInvitationListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationListResponse {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_invitations();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationListResponse {
+    fn eq(&self, other: &OriginInvitationListResponse) -> bool {
+        self.origin_id == other.origin_id &&
+        self.invitations == other.invitations &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitation {
+    // message fields
+    id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitation {}
+
+impl OriginInvitation {
+    pub fn new() -> OriginInvitation {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitation {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitation> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitation,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitation {
+                    id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 3;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 origin_id = 4;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 5;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 6;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitation {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(6, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitation>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitation {
+    fn new() -> OriginInvitation {
+        OriginInvitation::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitation>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    OriginInvitation::has_id,
+                    OriginInvitation::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginInvitation::has_account_id,
+                    OriginInvitation::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginInvitation::has_account_name,
+                    OriginInvitation::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitation::has_origin_id,
+                    OriginInvitation::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginInvitation::has_origin_name,
+                    OriginInvitation::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginInvitation::has_owner_id,
+                    OriginInvitation::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitation>(
+                    "OriginInvitation",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitation {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitation {
+    fn eq(&self, other: &OriginInvitation) -> bool {
+        self.id == other.id &&
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationCreate {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationCreate {}
+
+impl OriginInvitationCreate {
+    pub fn new() -> OriginInvitationCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationCreate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationCreate {
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 2;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 origin_id = 3;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 4;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 5;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationCreate {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(5, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationCreate {
+    fn new() -> OriginInvitationCreate {
+        OriginInvitationCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginInvitationCreate::has_account_id,
+                    OriginInvitationCreate::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginInvitationCreate::has_account_name,
+                    OriginInvitationCreate::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationCreate::has_origin_id,
+                    OriginInvitationCreate::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginInvitationCreate::has_origin_name,
+                    OriginInvitationCreate::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginInvitationCreate::has_owner_id,
+                    OriginInvitationCreate::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationCreate>(
+                    "OriginInvitationCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationCreate {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationCreate {
+    fn eq(&self, other: &OriginInvitationCreate) -> bool {
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationAcceptRequest {
+    // message fields
+    account_accepting_request: ::std::option::Option<u64>,
+    invite_id: ::std::option::Option<u64>,
+    ignore: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationAcceptRequest {}
+
+impl OriginInvitationAcceptRequest {
+    pub fn new() -> OriginInvitationAcceptRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationAcceptRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationAcceptRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationAcceptRequest {
+                    account_accepting_request: ::std::option::Option::None,
+                    invite_id: ::std::option::Option::None,
+                    ignore: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_accepting_request = 1;
+
+    pub fn clear_account_accepting_request(&mut self) {
+        self.account_accepting_request = ::std::option::Option::None;
+    }
+
+    pub fn has_account_accepting_request(&self) -> bool {
+        self.account_accepting_request.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_accepting_request(&mut self, v: u64) {
+        self.account_accepting_request = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_accepting_request(&self) -> u64 {
+        self.account_accepting_request.unwrap_or(0)
+    }
+
+    // required uint64 invite_id = 2;
+
+    pub fn clear_invite_id(&mut self) {
+        self.invite_id = ::std::option::Option::None;
+    }
+
+    pub fn has_invite_id(&self) -> bool {
+        self.invite_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invite_id(&mut self, v: u64) {
+        self.invite_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_invite_id(&self) -> u64 {
+        self.invite_id.unwrap_or(0)
+    }
+
+    // required bool ignore = 3;
+
+    pub fn clear_ignore(&mut self) {
+        self.ignore = ::std::option::Option::None;
+    }
+
+    pub fn has_ignore(&self) -> bool {
+        self.ignore.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ignore(&mut self, v: bool) {
+        self.ignore = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_ignore(&self) -> bool {
+        self.ignore.unwrap_or(false)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationAcceptRequest {
+    fn is_initialized(&self) -> bool {
+        if self.account_accepting_request.is_none() {
+            return false;
+        };
+        if self.invite_id.is_none() {
+            return false;
+        };
+        if self.ignore.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_accepting_request = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.invite_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.ignore = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_accepting_request.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invite_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.ignore.is_some() {
+            my_size += 2;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_accepting_request {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.invite_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.ignore {
+            try!(os.write_bool(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationAcceptRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
+    fn new() -> OriginInvitationAcceptRequest {
+        OriginInvitationAcceptRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_accepting_request",
+                    OriginInvitationAcceptRequest::has_account_accepting_request,
+                    OriginInvitationAcceptRequest::get_account_accepting_request,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "invite_id",
+                    OriginInvitationAcceptRequest::has_invite_id,
+                    OriginInvitationAcceptRequest::get_invite_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "ignore",
+                    OriginInvitationAcceptRequest::has_ignore,
+                    OriginInvitationAcceptRequest::get_ignore,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptRequest>(
+                    "OriginInvitationAcceptRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationAcceptRequest {
+    fn clear(&mut self) {
+        self.clear_account_accepting_request();
+        self.clear_invite_id();
+        self.clear_ignore();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationAcceptRequest {
+    fn eq(&self, other: &OriginInvitationAcceptRequest) -> bool {
+        self.account_accepting_request == other.account_accepting_request &&
+        self.invite_id == other.invite_id &&
+        self.ignore == other.ignore &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationAcceptRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationAcceptResponse {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationAcceptResponse {}
+
+impl OriginInvitationAcceptResponse {
+    pub fn new() -> OriginInvitationAcceptResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationAcceptResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationAcceptResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationAcceptResponse {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationAcceptResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationAcceptResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationAcceptResponse {
+    fn new() -> OriginInvitationAcceptResponse {
+        OriginInvitationAcceptResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptResponse>(
+                    "OriginInvitationAcceptResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationAcceptResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationAcceptResponse {
+    fn eq(&self, other: &OriginInvitationAcceptResponse) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationAcceptResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationRescind {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    invite_id: ::std::option::Option<u64>,
+    requester_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationRescind {}
+
+impl OriginInvitationRescind {
+    pub fn new() -> OriginInvitationRescind {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationRescind {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationRescind> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationRescind,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationRescind {
+                    origin_id: ::std::option::Option::None,
+                    invite_id: ::std::option::Option::None,
+                    requester_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required uint64 invite_id = 2;
+
+    pub fn clear_invite_id(&mut self) {
+        self.invite_id = ::std::option::Option::None;
+    }
+
+    pub fn has_invite_id(&self) -> bool {
+        self.invite_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invite_id(&mut self, v: u64) {
+        self.invite_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_invite_id(&self) -> u64 {
+        self.invite_id.unwrap_or(0)
+    }
+
+    // required uint64 requester_id = 3;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationRescind {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.invite_id.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.invite_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invite_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.invite_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationRescind>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationRescind {
+    fn new() -> OriginInvitationRescind {
+        OriginInvitationRescind::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationRescind>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationRescind::has_origin_id,
+                    OriginInvitationRescind::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "invite_id",
+                    OriginInvitationRescind::has_invite_id,
+                    OriginInvitationRescind::get_invite_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginInvitationRescind::has_requester_id,
+                    OriginInvitationRescind::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationRescind>(
+                    "OriginInvitationRescind",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationRescind {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_invite_id();
+        self.clear_requester_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationRescind {
+    fn eq(&self, other: &OriginInvitationRescind) -> bool {
+        self.origin_id == other.origin_id &&
+        self.invite_id == other.invite_id &&
+        self.requester_id == other.requester_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationRescind {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretKey {
+    // message fields
+    id: ::std::option::Option<u64>,
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    revision: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretKey {}
+
+impl OriginSecretKey {
+    pub fn new() -> OriginSecretKey {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretKey {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKey> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretKey,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretKey {
+                    id: ::std::option::Option::None,
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    revision: ::protobuf::SingularField::none(),
+                    body: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
 
     pub fn clear_id(&mut self) {
         self.id = ::std::option::Option::None;
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 origin_id = 2;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string revision = 4;
+
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        if self.revision.is_none() {
+            self.revision.set_default();
+        };
+        self.revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_revision(&self) -> &str {
+        match self.revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes body = 5;
+
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
+        };
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required uint64 owner_id = 6;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecretKey {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.revision.is_none() {
+            return false;
+        };
+        if self.body.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.revision.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.body.iter() {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.revision.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.body.as_ref() {
+            try!(os.write_bytes(5, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(6, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretKey>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretKey {
+    fn new() -> OriginSecretKey {
+        OriginSecretKey::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    OriginSecretKey::has_id,
+                    OriginSecretKey::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretKey::has_origin_id,
+                    OriginSecretKey::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretKey::has_name,
+                    OriginSecretKey::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "revision",
+                    OriginSecretKey::has_revision,
+                    OriginSecretKey::get_revision,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "body",
+                    OriginSecretKey::has_body,
+                    OriginSecretKey::get_body,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginSecretKey::has_owner_id,
+                    OriginSecretKey::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKey>(
+                    "OriginSecretKey",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretKey {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_origin_id();
+        self.clear_name();
+        self.clear_revision();
+        self.clear_body();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+impl ::std::cmp::PartialEq for OriginSecretKey {
+    fn eq(&self, other: &OriginSecretKey) -> bool {
+        self.id == other.id &&
+        self.origin_id == other.origin_id &&
+        self.name == other.name &&
+        self.revision == other.revision &&
+        self.body == other.body &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
     }
+}
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+impl ::std::fmt::Debug for OriginSecretKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // required uint64 account_id = 2;
+#[derive(Clone,Default)]
+pub struct OriginSecretKeyCreate {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    revision: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretKeyCreate {}
+
+impl OriginSecretKeyCreate {
+    pub fn new() -> OriginSecretKeyCreate {
+        ::std::default::Default::default()
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn default_instance() -> &'static OriginSecretKeyCreate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKeyCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretKeyCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretKeyCreate {
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    revision: ::protobuf::SingularField::none(),
+                    body: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    // required string account_name = 3;
+    // required string name = 2;
 
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
         };
-        self.account_name.as_mut().unwrap()
+        self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    // required uint64 origin_id = 4;
+    // required string revision = 3;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        if self.revision.is_none() {
+            self.revision.set_default();
+        };
+        self.revision.as_mut().unwrap()
     }
 
-    // required string origin_name = 5;
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
 
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
+    pub fn get_revision(&self) -> &str {
+        match self.revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
+    // required bytes body = 4;
+
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
         };
-        self.origin_name.as_mut().unwrap()
+        self.body.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
             Some(v) => &v,
-            None => "",
+            None => &[],
         }
     }
 
-    // required uint64 owner_id = 6;
+    // required uint64 owner_id = 5;
 
     pub fn clear_owner_id(&mut self) {
         self.owner_id = ::std::option::Option::None;
@@ -3474,21 +6957,18 @@ impl OriginInvitation {
     }
 }
 
-impl ::protobuf::Message for OriginInvitation {
+impl ::protobuf::Message for OriginSecretKeyCreate {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
-            return false;
-        };
-        if self.account_id.is_none() {
+        if self.origin_id.is_none() {
             return false;
         };
-        if self.account_name.is_none() {
+        if self.name.is_none() {
             return false;
         };
-        if self.origin_id.is_none() {
+        if self.revision.is_none() {
             return false;
         };
-        if self.origin_name.is_none() {
+        if self.body.is_none() {
             return false;
         };
         if self.owner_id.is_none() {
@@ -3506,29 +6986,18 @@ impl ::protobuf::Message for OriginInvitation {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.id = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
                 },
                 4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                5 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
                 },
-                6 => {
+                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
@@ -3547,23 +7016,20 @@ impl ::protobuf::Message for OriginInvitation {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.account_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
         };
-        for value in self.account_name.iter() {
+        for value in self.revision.iter() {
             my_size += ::protobuf::rt::string_size(3, &value);
         };
-        for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.origin_name.iter() {
-            my_size += ::protobuf::rt::string_size(5, &value);
+        for value in self.body.iter() {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
         };
         for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3571,23 +7037,20 @@ impl ::protobuf::Message for OriginInvitation {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.account_id {
-            try!(os.write_uint64(2, v));
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
         };
-        if let Some(v) = self.account_name.as_ref() {
+        if let Some(v) = self.revision.as_ref() {
             try!(os.write_string(3, &v));
         };
-        if let Some(v) = self.origin_id {
-            try!(os.write_uint64(4, v));
-        };
-        if let Some(v) = self.origin_name.as_ref() {
-            try!(os.write_string(5, &v));
+        if let Some(v) = self.body.as_ref() {
+            try!(os.write_bytes(4, &v));
         };
         if let Some(v) = self.owner_id {
-            try!(os.write_uint64(6, v));
+            try!(os.write_uint64(5, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -3606,7 +7069,7 @@ impl ::protobuf::Message for OriginInvitation {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitation>()
+        ::std::any::TypeId::of::<OriginSecretKeyCreate>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -3618,12 +7081,12 @@ impl ::protobuf::Message for OriginInvitation {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitation {
-    fn new() -> OriginInvitation {
-        OriginInvitation::new()
+impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
+    fn new() -> OriginSecretKeyCreate {
+        OriginSecretKeyCreate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitation>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretKeyCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3632,37 +7095,32 @@ impl ::protobuf::MessageStatic for OriginInvitation {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "id",
-                    OriginInvitation::has_id,
-                    OriginInvitation::get_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    OriginInvitation::has_account_id,
-                    OriginInvitation::get_account_id,
+                    "origin_id",
+                    OriginSecretKeyCreate::has_origin_id,
+                    OriginSecretKeyCreate::get_origin_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "account_name",
-                    OriginInvitation::has_account_name,
-                    OriginInvitation::get_account_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "origin_id",
-                    OriginInvitation::has_origin_id,
-                    OriginInvitation::get_origin_id,
+                    "name",
+                    OriginSecretKeyCreate::has_name,
+                    OriginSecretKeyCreate::get_name,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "origin_name",
-                    OriginInvitation::has_origin_name,
-                    OriginInvitation::get_origin_name,
+                    "revision",
+                    OriginSecretKeyCreate::has_revision,
+                    OriginSecretKeyCreate::get_revision,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "body",
+                    OriginSecretKeyCreate::has_body,
+                    OriginSecretKeyCreate::get_body,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "owner_id",
-                    OriginInvitation::has_owner_id,
-                    OriginInvitation::get_owner_id,
+                    OriginSecretKeyCreate::has_owner_id,
+                    OriginSecretKeyCreate::get_owner_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitation>(
-                    "OriginInvitation",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKeyCreate>(
+                    "OriginSecretKeyCreate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3671,70 +7129,72 @@ impl ::protobuf::MessageStatic for OriginInvitation {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitation {
+impl ::protobuf::Clear for OriginSecretKeyCreate {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_account_id();
-        self.clear_account_name();
         self.clear_origin_id();
-        self.clear_origin_name();
+        self.clear_name();
+        self.clear_revision();
+        self.clear_body();
         self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitation {
-    fn eq(&self, other: &OriginInvitation) -> bool {
-        self.id == other.id &&
-        self.account_id == other.account_id &&
-        self.account_name == other.account_name &&
+impl ::std::cmp::PartialEq for OriginSecretKeyCreate {
+    fn eq(&self, other: &OriginSecretKeyCreate) -> bool {
         self.origin_id == other.origin_id &&
-        self.origin_name == other.origin_name &&
+        self.name == other.name &&
+        self.revision == other.revision &&
+        self.body == other.body &&
         self.owner_id == other.owner_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitation {
+impl ::std::fmt::Debug for OriginSecretKeyCreate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationCreate {
+pub struct OriginToken {
     // message fields
-    account_id: ::std::option::Option<u64>,
-    account_name: ::protobuf::SingularField<::std::string::String>,
+    id: ::std::option::Option<u64>,
     origin_id: ::std::option::Option<u64>,
     origin_name: ::protobuf::SingularField<::std::string::String>,
     owner_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    role: ::std::option::Option<OriginRole>,
+    token: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationCreate {}
+unsafe impl ::std::marker::Sync for OriginToken {}
 
-impl OriginInvitationCreate {
-    pub fn new() -> OriginInvitationCreate {
+impl OriginToken {
+    pub fn new() -> OriginToken {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationCreate {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginToken {
+        static mut instance: ::protobuf::lazy::Lazy<OriginToken> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationCreate,
+            ptr: 0 as *const OriginToken,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationCreate {
-                    account_id: ::std::option::Option::None,
-                    account_name: ::protobuf::SingularField::none(),
+                OriginToken {
+                    id: ::std::option::Option::None,
                     origin_id: ::std::option::Option::None,
                     origin_name: ::protobuf::SingularField::none(),
                     owner_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    role: ::std::option::Option::None,
+                    token: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3742,142 +7202,194 @@ impl OriginInvitationCreate {
         }
     }
 
-    // required uint64 account_id = 1;
+    // required uint64 id = 1;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
     }
 
-    // required string account_name = 2;
+    // required uint64 origin_id = 2;
 
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 3;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
         };
-        self.account_name.as_mut().unwrap()
+        self.origin_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    // required uint64 origin_id = 3;
+    // required uint64 owner_id = 4;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
     }
 
-    // required string origin_name = 4;
+    // required string name = 5;
 
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .vault.OriginRole role = 6;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::ReadOnly)
+    }
+
+    // optional string token = 7;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
     }
 
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
         };
-        self.origin_name.as_mut().unwrap()
+        self.token.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
-
-    // required uint64 owner_id = 5;
-
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
-    }
-
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
-    }
 }
 
-impl ::protobuf::Message for OriginInvitationCreate {
+impl ::protobuf::Message for OriginToken {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
-            return false;
-        };
-        if self.account_name.is_none() {
+        if self.id.is_none() {
             return false;
         };
         if self.origin_id.is_none() {
@@ -3889,6 +7401,12 @@ impl ::protobuf::Message for OriginInvitationCreate {
         if self.owner_id.is_none() {
             return false;
         };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
         true
     }
 
@@ -3901,28 +7419,38 @@ impl ::protobuf::Message for OriginInvitationCreate {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    self.id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
-                4 => {
+                3 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
                 },
-                5 => {
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
                     self.owner_id = ::std::option::Option::Some(tmp);
                 },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -3935,20 +7463,26 @@ impl ::protobuf::Message for OriginInvitationCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
+        for value in self.id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.account_name.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
         for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         for value in self.origin_name.iter() {
-            my_size += ::protobuf::rt::string_size(4, &value);
+            my_size += ::protobuf::rt::string_size(3, &value);
         };
         for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(6, *value);
+        };
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(7, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3956,20 +7490,26 @@ impl ::protobuf::Message for OriginInvitationCreate {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.account_name.as_ref() {
-            try!(os.write_string(2, &v));
-        };
         if let Some(v) = self.origin_id {
-            try!(os.write_uint64(3, v));
+            try!(os.write_uint64(2, v));
         };
         if let Some(v) = self.origin_name.as_ref() {
-            try!(os.write_string(4, &v));
+            try!(os.write_string(3, &v));
         };
         if let Some(v) = self.owner_id {
-            try!(os.write_uint64(5, v));
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(6, v.value()));
+        };
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(7, &v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -3988,7 +7528,7 @@ impl ::protobuf::Message for OriginInvitationCreate {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationCreate>()
+        ::std::any::TypeId::of::<OriginToken>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4000,12 +7540,12 @@ impl ::protobuf::Message for OriginInvitationCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationCreate {
-    fn new() -> OriginInvitationCreate {
-        OriginInvitationCreate::new()
+impl ::protobuf::MessageStatic for OriginToken {
+    fn new() -> OriginToken {
+        OriginToken::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4014,32 +7554,42 @@ impl ::protobuf::MessageStatic for OriginInvitationCreate {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    OriginInvitationCreate::has_account_id,
-                    OriginInvitationCreate::get_account_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "account_name",
-                    OriginInvitationCreate::has_account_name,
-                    OriginInvitationCreate::get_account_name,
+                    "id",
+                    OriginToken::has_id,
+                    OriginToken::get_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginInvitationCreate::has_origin_id,
-                    OriginInvitationCreate::get_origin_id,
+                    OriginToken::has_origin_id,
+                    OriginToken::get_origin_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
                     "origin_name",
-                    OriginInvitationCreate::has_origin_name,
-                    OriginInvitationCreate::get_origin_name,
+                    OriginToken::has_origin_name,
+                    OriginToken::get_origin_name,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "owner_id",
-                    OriginInvitationCreate::has_owner_id,
-                    OriginInvitationCreate::get_owner_id,
+                    OriginToken::has_owner_id,
+                    OriginToken::get_owner_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationCreate>(
-                    "OriginInvitationCreate",
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginToken::has_name,
+                    OriginToken::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginToken::has_role,
+                    OriginToken::get_role,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    OriginToken::has_token,
+                    OriginToken::get_token,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginToken>(
+                    "OriginToken",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4048,64 +7598,72 @@ impl ::protobuf::MessageStatic for OriginInvitationCreate {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationCreate {
+impl ::protobuf::Clear for OriginToken {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_account_name();
+        self.clear_id();
         self.clear_origin_id();
         self.clear_origin_name();
         self.clear_owner_id();
+        self.clear_name();
+        self.clear_role();
+        self.clear_token();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationCreate {
-    fn eq(&self, other: &OriginInvitationCreate) -> bool {
-        self.account_id == other.account_id &&
-        self.account_name == other.account_name &&
+impl ::std::cmp::PartialEq for OriginToken {
+    fn eq(&self, other: &OriginToken) -> bool {
+        self.id == other.id &&
         self.origin_id == other.origin_id &&
         self.origin_name == other.origin_name &&
         self.owner_id == other.owner_id &&
+        self.name == other.name &&
+        self.role == other.role &&
+        self.token == other.token &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationCreate {
+impl ::std::fmt::Debug for OriginToken {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationAcceptRequest {
+pub struct OriginTokenCreate {
     // message fields
-    account_accepting_request: ::std::option::Option<u64>,
-    invite_id: ::std::option::Option<u64>,
-    ignore: ::std::option::Option<bool>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    role: ::std::option::Option<OriginRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationAcceptRequest {}
+unsafe impl ::std::marker::Sync for OriginTokenCreate {}
 
-impl OriginInvitationAcceptRequest {
-    pub fn new() -> OriginInvitationAcceptRequest {
+impl OriginTokenCreate {
+    pub fn new() -> OriginTokenCreate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationAcceptRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginTokenCreate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginTokenCreate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationAcceptRequest,
+            ptr: 0 as *const OriginTokenCreate,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationAcceptRequest {
-                    account_accepting_request: ::std::option::Option::None,
-                    invite_id: ::std::option::Option::None,
-                    ignore: ::std::option::Option::None,
+                OriginTokenCreate {
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4113,73 +7671,151 @@ impl OriginInvitationAcceptRequest {
         }
     }
 
-    // required uint64 account_accepting_request = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_account_accepting_request(&mut self) {
-        self.account_accepting_request = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_accepting_request(&self) -> bool {
-        self.account_accepting_request.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_accepting_request(&mut self, v: u64) {
-        self.account_accepting_request = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_accepting_request(&self) -> u64 {
-        self.account_accepting_request.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    // required uint64 invite_id = 2;
+    // required string origin_name = 2;
 
-    pub fn clear_invite_id(&mut self) {
-        self.invite_id = ::std::option::Option::None;
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
     }
 
-    pub fn has_invite_id(&self) -> bool {
-        self.invite_id.is_some()
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_invite_id(&mut self, v: u64) {
-        self.invite_id = ::std::option::Option::Some(v);
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_invite_id(&self) -> u64 {
-        self.invite_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 3;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string name = 4;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    // required bool ignore = 3;
+    // required .vault.OriginRole role = 5;
 
-    pub fn clear_ignore(&mut self) {
-        self.ignore = ::std::option::Option::None;
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
     }
 
-    pub fn has_ignore(&self) -> bool {
-        self.ignore.is_some()
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_ignore(&mut self, v: bool) {
-        self.ignore = ::std::option::Option::Some(v);
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
     }
 
-    pub fn get_ignore(&self) -> bool {
-        self.ignore.unwrap_or(false)
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::ReadOnly)
     }
 }
 
-impl ::protobuf::Message for OriginInvitationAcceptRequest {
+impl ::protobuf::Message for OriginTokenCreate {
     fn is_initialized(&self) -> bool {
-        if self.account_accepting_request.is_none() {
+        if self.origin_id.is_none() {
             return false;
         };
-        if self.invite_id.is_none() {
+        if self.origin_name.is_none() {
             return false;
         };
-        if self.ignore.is_none() {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
             return false;
         };
         true
@@ -4194,21 +7830,27 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_accepting_request = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.invite_id = ::std::option::Option::Some(tmp);
+                    self.owner_id = ::std::option::Option::Some(tmp);
                 },
-                3 => {
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_bool());
-                    self.ignore = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -4222,14 +7864,20 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_accepting_request.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.invite_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
         };
-        if self.ignore.is_some() {
-            my_size += 2;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(5, *value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4237,14 +7885,20 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_accepting_request {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.invite_id {
-            try!(os.write_uint64(2, v));
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(2, &v));
         };
-        if let Some(v) = self.ignore {
-            try!(os.write_bool(3, v));
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(5, v.value()));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -4263,7 +7917,7 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationAcceptRequest>()
+        ::std::any::TypeId::of::<OriginTokenCreate>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4275,12 +7929,12 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
-    fn new() -> OriginInvitationAcceptRequest {
-        OriginInvitationAcceptRequest::new()
+impl ::protobuf::MessageStatic for OriginTokenCreate {
+    fn new() -> OriginTokenCreate {
+        OriginTokenCreate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginTokenCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4289,22 +7943,32 @@ impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_accepting_request",
-                    OriginInvitationAcceptRequest::has_account_accepting_request,
-                    OriginInvitationAcceptRequest::get_account_accepting_request,
+                    "origin_id",
+                    OriginTokenCreate::has_origin_id,
+                    OriginTokenCreate::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginTokenCreate::has_origin_name,
+                    OriginTokenCreate::get_origin_name,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "invite_id",
-                    OriginInvitationAcceptRequest::has_invite_id,
-                    OriginInvitationAcceptRequest::get_invite_id,
+                    "owner_id",
+                    OriginTokenCreate::has_owner_id,
+                    OriginTokenCreate::get_owner_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
-                    "ignore",
-                    OriginInvitationAcceptRequest::has_ignore,
-                    OriginInvitationAcceptRequest::get_ignore,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginTokenCreate::has_name,
+                    OriginTokenCreate::get_name,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptRequest>(
-                    "OriginInvitationAcceptRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginTokenCreate::has_role,
+                    OriginTokenCreate::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginTokenCreate>(
+                    "OriginTokenCreate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4313,63 +7977,109 @@ impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationAcceptRequest {
+impl ::protobuf::Clear for OriginTokenCreate {
     fn clear(&mut self) {
-        self.clear_account_accepting_request();
-        self.clear_invite_id();
-        self.clear_ignore();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_owner_id();
+        self.clear_name();
+        self.clear_role();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationAcceptRequest {
-    fn eq(&self, other: &OriginInvitationAcceptRequest) -> bool {
-        self.account_accepting_request == other.account_accepting_request &&
-        self.invite_id == other.invite_id &&
-        self.ignore == other.ignore &&
+impl ::std::cmp::PartialEq for OriginTokenCreate {
+    fn eq(&self, other: &OriginTokenCreate) -> bool {
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.owner_id == other.owner_id &&
+        self.name == other.name &&
+        self.role == other.role &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationAcceptRequest {
+impl ::std::fmt::Debug for OriginTokenCreate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationAcceptResponse {
+pub struct OriginTokenGet {
+    // message fields
+    token: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationAcceptResponse {}
+unsafe impl ::std::marker::Sync for OriginTokenGet {}
 
-impl OriginInvitationAcceptResponse {
-    pub fn new() -> OriginInvitationAcceptResponse {
+impl OriginTokenGet {
+    pub fn new() -> OriginTokenGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationAcceptResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginTokenGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginTokenGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationAcceptResponse,
+            ptr: 0 as *const OriginTokenGet,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationAcceptResponse {
+                OriginTokenGet {
+                    token: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
             })
         }
     }
+
+    // required string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
 }
 
-impl ::protobuf::Message for OriginInvitationAcceptResponse {
+impl ::protobuf::Message for OriginTokenGet {
     fn is_initialized(&self) -> bool {
+        if self.token.is_none() {
+            return false;
+        };
         true
     }
 
@@ -4377,6 +8087,9 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
         while !try!(is.eof()) {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -4389,12 +8102,18 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(1, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -4412,7 +8131,7 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationAcceptResponse>()
+        ::std::any::TypeId::of::<OriginTokenGet>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4424,21 +8143,26 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationAcceptResponse {
-    fn new() -> OriginInvitationAcceptResponse {
-        OriginInvitationAcceptResponse::new()
+impl ::protobuf::MessageStatic for OriginTokenGet {
+    fn new() -> OriginTokenGet {
+        OriginTokenGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginTokenGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptResponse>(
-                    "OriginInvitationAcceptResponse",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    OriginTokenGet::has_token,
+                    OriginTokenGet::get_token,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginTokenGet>(
+                    "OriginTokenGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4447,60 +8171,52 @@ impl ::protobuf::MessageStatic for OriginInvitationAcceptResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationAcceptResponse {
+impl ::protobuf::Clear for OriginTokenGet {
     fn clear(&mut self) {
+        self.clear_token();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationAcceptResponse {
-    fn eq(&self, other: &OriginInvitationAcceptResponse) -> bool {
+impl ::std::cmp::PartialEq for OriginTokenGet {
+    fn eq(&self, other: &OriginTokenGet) -> bool {
+        self.token == other.token &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationAcceptResponse {
+impl ::std::fmt::Debug for OriginTokenGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginSecretKey {
+pub struct OriginTokenListRequest {
     // message fields
-    id: ::std::option::Option<u64>,
     origin_id: ::std::option::Option<u64>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    revision: ::protobuf::SingularField<::std::string::String>,
-    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    owner_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginSecretKey {}
+unsafe impl ::std::marker::Sync for OriginTokenListRequest {}
 
-impl OriginSecretKey {
-    pub fn new() -> OriginSecretKey {
+impl OriginTokenListRequest {
+    pub fn new() -> OriginTokenListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginSecretKey {
-        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKey> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginTokenListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginTokenListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginSecretKey,
+            ptr: 0 as *const OriginTokenListRequest,
         };
         unsafe {
             instance.get(|| {
-                OriginSecretKey {
-                    id: ::std::option::Option::None,
+                OriginTokenListRequest {
                     origin_id: ::std::option::Option::None,
-                    name: ::protobuf::SingularField::none(),
-                    revision: ::protobuf::SingularField::none(),
-                    body: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4508,192 +8224,231 @@ impl OriginSecretKey {
         }
     }
 
-    // required uint64 id = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
+}
 
-    // required uint64 origin_id = 2;
+impl ::protobuf::Message for OriginTokenListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // required string name = 3;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginTokenListRequest>()
     }
 
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+impl ::protobuf::MessageStatic for OriginTokenListRequest {
+    fn new() -> OriginTokenListRequest {
+        OriginTokenListRequest::new()
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
+    fn descriptor_static(_: ::std::option::Option<OriginTokenListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginTokenListRequest::has_origin_id,
+                    OriginTokenListRequest::get_origin_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginTokenListRequest>(
+                    "OriginTokenListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
     }
+}
 
-    // required string revision = 4;
-
-    pub fn clear_revision(&mut self) {
-        self.revision.clear();
+impl ::protobuf::Clear for OriginTokenListRequest {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn has_revision(&self) -> bool {
-        self.revision.is_some()
+impl ::std::cmp::PartialEq for OriginTokenListRequest {
+    fn eq(&self, other: &OriginTokenListRequest) -> bool {
+        self.origin_id == other.origin_id &&
+        self.unknown_fields == other.unknown_fields
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_revision(&mut self, v: ::std::string::String) {
-        self.revision = ::protobuf::SingularField::some(v);
+impl ::std::fmt::Debug for OriginTokenListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
-        if self.revision.is_none() {
-            self.revision.set_default();
-        };
-        self.revision.as_mut().unwrap()
-    }
+#[derive(Clone,Default)]
+pub struct OriginTokenListResponse {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    tokens: ::protobuf::RepeatedField<OriginToken>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
 
-    // Take field
-    pub fn take_revision(&mut self) -> ::std::string::String {
-        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginTokenListResponse {}
+
+impl OriginTokenListResponse {
+    pub fn new() -> OriginTokenListResponse {
+        ::std::default::Default::default()
     }
 
-    pub fn get_revision(&self) -> &str {
-        match self.revision.as_ref() {
-            Some(v) => &v,
-            None => "",
+    pub fn default_instance() -> &'static OriginTokenListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginTokenListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginTokenListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginTokenListResponse {
+                    origin_id: ::std::option::Option::None,
+                    tokens: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
         }
     }
 
-    // required bytes body = 5;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_body(&mut self) {
-        self.body.clear();
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_body(&self) -> bool {
-        self.body.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
-        self.body = ::protobuf::SingularField::some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.body.is_none() {
-            self.body.set_default();
-        };
-        self.body.as_mut().unwrap()
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
-        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
-    }
+    // repeated .vault.OriginToken tokens = 2;
 
-    pub fn get_body(&self) -> &[u8] {
-        match self.body.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+    pub fn clear_tokens(&mut self) {
+        self.tokens.clear();
     }
 
-    // required uint64 owner_id = 6;
-
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    // Param is passed by value, moved
+    pub fn set_tokens(&mut self, v: ::protobuf::RepeatedField<OriginToken>) {
+        self.tokens = v;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    // Mutable pointer to the field.
+    pub fn mut_tokens(&mut self) -> &mut ::protobuf::RepeatedField<OriginToken> {
+        &mut self.tokens
     }
 
-    // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_tokens(&mut self) -> ::protobuf::RepeatedField<OriginToken> {
+        ::std::mem::replace(&mut self.tokens, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_tokens(&self) -> &[OriginToken] {
+        &self.tokens
     }
 }
 
-impl ::protobuf::Message for OriginSecretKey {
+impl ::protobuf::Message for OriginTokenListResponse {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
-            return false;
-        };
         if self.origin_id.is_none() {
             return false;
         };
-        if self.name.is_none() {
-            return false;
-        };
-        if self.revision.is_none() {
-            return false;
-        };
-        if self.body.is_none() {
-            return false;
-        };
-        if self.owner_id.is_none() {
-            return false;
-        };
         true
     }
 
@@ -4702,34 +8457,14 @@ impl ::protobuf::Message for OriginSecretKey {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
-                3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
-                },
-                4 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
-                },
-                5 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tokens));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -4743,23 +8478,12 @@ impl ::protobuf::Message for OriginSecretKey {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
         for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        for value in self.revision.iter() {
-            my_size += ::protobuf::rt::string_size(4, &value);
-        };
-        for value in self.body.iter() {
-            my_size += ::protobuf::rt::bytes_size(5, &value);
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.tokens.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4767,23 +8491,13 @@ impl ::protobuf::Message for OriginSecretKey {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
-            try!(os.write_uint64(1, v));
-        };
         if let Some(v) = self.origin_id {
-            try!(os.write_uint64(2, v));
-        };
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(3, &v));
-        };
-        if let Some(v) = self.revision.as_ref() {
-            try!(os.write_string(4, &v));
-        };
-        if let Some(v) = self.body.as_ref() {
-            try!(os.write_bytes(5, &v));
+            try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.owner_id {
-            try!(os.write_uint64(6, v));
+        for v in self.tokens.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -4802,7 +8516,7 @@ impl ::protobuf::Message for OriginSecretKey {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginSecretKey>()
+        ::std::any::TypeId::of::<OriginTokenListResponse>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4814,51 +8528,30 @@ impl ::protobuf::Message for OriginSecretKey {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginSecretKey {
-    fn new() -> OriginSecretKey {
-        OriginSecretKey::new()
+impl ::protobuf::MessageStatic for OriginTokenListResponse {
+    fn new() -> OriginTokenListResponse {
+        OriginTokenListResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginSecretKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "id",
-                    OriginSecretKey::has_id,
-                    OriginSecretKey::get_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "origin_id",
-                    OriginSecretKey::has_origin_id,
-                    OriginSecretKey::get_origin_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    OriginSecretKey::has_name,
-                    OriginSecretKey::get_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "revision",
-                    OriginSecretKey::has_revision,
-                    OriginSecretKey::get_revision,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "body",
-                    OriginSecretKey::has_body,
-                    OriginSecretKey::get_body,
-                ));
+    fn descriptor_static(_: ::std::option::Option<OriginTokenListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    OriginSecretKey::has_owner_id,
-                    OriginSecretKey::get_owner_id,
+                    "origin_id",
+                    OriginTokenListResponse::has_origin_id,
+                    OriginTokenListResponse::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKey>(
-                    "OriginSecretKey",
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "tokens",
+                    OriginTokenListResponse::get_tokens,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginTokenListResponse>(
+                    "OriginTokenListResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4867,70 +8560,58 @@ impl ::protobuf::MessageStatic for OriginSecretKey {
     }
 }
 
-impl ::protobuf::Clear for OriginSecretKey {
+impl ::protobuf::Clear for OriginTokenListResponse {
     fn clear(&mut self) {
-        self.clear_id();
         self.clear_origin_id();
-        self.clear_name();
-        self.clear_revision();
-        self.clear_body();
-        self.clear_owner_id();
+        self.clear_tokens();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginSecretKey {
-    fn eq(&self, other: &OriginSecretKey) -> bool {
-        self.id == other.id &&
+impl ::std::cmp::PartialEq for OriginTokenListResponse {
+    fn eq(&self, other: &OriginTokenListResponse) -> bool {
         self.origin_id == other.origin_id &&
-        self.name == other.name &&
-        self.revision == other.revision &&
-        self.body == other.body &&
-        self.owner_id == other.owner_id &&
+        self.tokens == other.tokens &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginSecretKey {
+impl ::std::fmt::Debug for OriginTokenListResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginSecretKeyCreate {
+pub struct OriginTokenRevoke {
     // message fields
     origin_id: ::std::option::Option<u64>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    revision: ::protobuf::SingularField<::std::string::String>,
-    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    owner_id: ::std::option::Option<u64>,
+    token_id: ::std::option::Option<u64>,
+    requester_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginSecretKeyCreate {}
+unsafe impl ::std::marker::Sync for OriginTokenRevoke {}
 
-impl OriginSecretKeyCreate {
-    pub fn new() -> OriginSecretKeyCreate {
+impl OriginTokenRevoke {
+    pub fn new() -> OriginTokenRevoke {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginSecretKeyCreate {
-        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKeyCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginTokenRevoke {
+        static mut instance: ::protobuf::lazy::Lazy<OriginTokenRevoke> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginSecretKeyCreate,
+            ptr: 0 as *const OriginTokenRevoke,
         };
         unsafe {
             instance.get(|| {
-                OriginSecretKeyCreate {
+                OriginTokenRevoke {
                     origin_id: ::std::option::Option::None,
-                    name: ::protobuf::SingularField::none(),
-                    revision: ::protobuf::SingularField::none(),
-                    body: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
+                    token_id: ::std::option::Option::None,
+                    requester_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4957,149 +8638,54 @@ impl OriginSecretKeyCreate {
         self.origin_id.unwrap_or(0)
     }
 
-    // required string name = 2;
-
-    pub fn clear_name(&mut self) {
-        self.name.clear();
-    }
-
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    // required string revision = 3;
-
-    pub fn clear_revision(&mut self) {
-        self.revision.clear();
-    }
-
-    pub fn has_revision(&self) -> bool {
-        self.revision.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_revision(&mut self, v: ::std::string::String) {
-        self.revision = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
-        if self.revision.is_none() {
-            self.revision.set_default();
-        };
-        self.revision.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_revision(&mut self) -> ::std::string::String {
-        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_revision(&self) -> &str {
-        match self.revision.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
+    // required uint64 token_id = 2;
 
-    // required bytes body = 4;
-
-    pub fn clear_body(&mut self) {
-        self.body.clear();
+    pub fn clear_token_id(&mut self) {
+        self.token_id = ::std::option::Option::None;
     }
 
-    pub fn has_body(&self) -> bool {
-        self.body.is_some()
+    pub fn has_token_id(&self) -> bool {
+        self.token_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
-        self.body = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.body.is_none() {
-            self.body.set_default();
-        };
-        self.body.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
-        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn set_token_id(&mut self, v: u64) {
+        self.token_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_body(&self) -> &[u8] {
-        match self.body.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+    pub fn get_token_id(&self) -> u64 {
+        self.token_id.unwrap_or(0)
     }
 
-    // required uint64 owner_id = 5;
+    // required uint64 requester_id = 3;
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for OriginSecretKeyCreate {
+impl ::protobuf::Message for OriginTokenRevoke {
     fn is_initialized(&self) -> bool {
         if self.origin_id.is_none() {
             return false;
         };
-        if self.name.is_none() {
-            return false;
-        };
-        if self.revision.is_none() {
-            return false;
-        };
-        if self.body.is_none() {
+        if self.token_id.is_none() {
             return false;
         };
-        if self.owner_id.is_none() {
+        if self.requester_id.is_none() {
             return false;
         };
         true
@@ -5117,20 +8703,18 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.token_id = ::std::option::Option::Some(tmp);
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
-                },
-                4 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
-                },
-                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                    self.requester_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -5147,17 +8731,11 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
         for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        for value in self.revision.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        for value in self.body.iter() {
-            my_size += ::protobuf::rt::bytes_size(4, &value);
+        for value in self.token_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -5168,17 +8746,11 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
         if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(2, &v));
-        };
-        if let Some(v) = self.revision.as_ref() {
-            try!(os.write_string(3, &v));
-        };
-        if let Some(v) = self.body.as_ref() {
-            try!(os.write_bytes(4, &v));
+        if let Some(v) = self.token_id {
+            try!(os.write_uint64(2, v));
         };
-        if let Some(v) = self.owner_id {
-            try!(os.write_uint64(5, v));
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(3, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -5197,7 +8769,7 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginSecretKeyCreate>()
+        ::std::any::TypeId::of::<OriginTokenRevoke>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -5209,12 +8781,12 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
-    fn new() -> OriginSecretKeyCreate {
-        OriginSecretKeyCreate::new()
+impl ::protobuf::MessageStatic for OriginTokenRevoke {
+    fn new() -> OriginTokenRevoke {
+        OriginTokenRevoke::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginSecretKeyCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginTokenRevoke>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -5224,31 +8796,21 @@ impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginSecretKeyCreate::has_origin_id,
-                    OriginSecretKeyCreate::get_origin_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    OriginSecretKeyCreate::has_name,
-                    OriginSecretKeyCreate::get_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "revision",
-                    OriginSecretKeyCreate::has_revision,
-                    OriginSecretKeyCreate::get_revision,
+                    OriginTokenRevoke::has_origin_id,
+                    OriginTokenRevoke::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "body",
-                    OriginSecretKeyCreate::has_body,
-                    OriginSecretKeyCreate::get_body,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "token_id",
+                    OriginTokenRevoke::has_token_id,
+                    OriginTokenRevoke::get_token_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    OriginSecretKeyCreate::has_owner_id,
-                    OriginSecretKeyCreate::get_owner_id,
+                    "requester_id",
+                    OriginTokenRevoke::has_requester_id,
+                    OriginTokenRevoke::get_requester_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKeyCreate>(
-                    "OriginSecretKeyCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginTokenRevoke>(
+                    "OriginTokenRevoke",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5257,439 +8819,884 @@ impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
     }
 }
 
-impl ::protobuf::Clear for OriginSecretKeyCreate {
+impl ::protobuf::Clear for OriginTokenRevoke {
     fn clear(&mut self) {
         self.clear_origin_id();
-        self.clear_name();
-        self.clear_revision();
-        self.clear_body();
-        self.clear_owner_id();
+        self.clear_token_id();
+        self.clear_requester_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginSecretKeyCreate {
-    fn eq(&self, other: &OriginSecretKeyCreate) -> bool {
+impl ::std::cmp::PartialEq for OriginTokenRevoke {
+    fn eq(&self, other: &OriginTokenRevoke) -> bool {
         self.origin_id == other.origin_id &&
-        self.name == other.name &&
-        self.revision == other.revision &&
-        self.body == other.body &&
-        self.owner_id == other.owner_id &&
+        self.token_id == other.token_id &&
+        self.requester_id == other.requester_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginSecretKeyCreate {
+impl ::std::fmt::Debug for OriginTokenRevoke {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum OriginRole {
+    ReadOnly = 1,
+    Member = 2,
+    Maintainer = 3,
+    Owner = 4,
+}
+
+impl ::protobuf::ProtobufEnum for OriginRole {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<OriginRole> {
+        match value {
+            1 => ::std::option::Option::Some(OriginRole::ReadOnly),
+            2 => ::std::option::Option::Some(OriginRole::Member),
+            3 => ::std::option::Option::Some(OriginRole::Maintainer),
+            4 => ::std::option::Option::Some(OriginRole::Owner),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [OriginRole] = &[
+            OriginRole::ReadOnly,
+            OriginRole::Member,
+            OriginRole::Maintainer,
+            OriginRole::Owner,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<OriginRole>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("OriginRole", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for OriginRole {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x15, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x76, 0x61, 0x75, 0x6c,
-    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x22, 0x34,
+    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x22, 0x45,
     0x0a, 0x06, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01,
     0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02,
     0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03,
-    0x20, 0x02, 0x28, 0x04, 0x22, 0x42, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x43, 0x72,
-    0x65, 0x61, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02,
-    0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x6e, 0x61,
-    0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x1c, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x19, 0x0a, 0x09, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28,
-    0x09, 0x22, 0x38, 0x0a, 0x12, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65,
-    0x72, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x75, 0x73,
-    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x2c, 0x0a, 0x17, 0x4f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x52,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x3e, 0x0a, 0x18, 0x4f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73,
-    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
-    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x6d, 0x65, 0x6d, 0x62,
-    0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x22, 0x2e, 0x0a, 0x18, 0x41, 0x63, 0x63,
-    0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
-    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x40, 0x0a, 0x19, 0x41, 0x63, 0x63,
-    0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x70, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x08, 0x22, 0x53, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x43,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18,
+    0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x6e,
+    0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x70, 0x72, 0x69,
+    0x76, 0x61, 0x74, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x22, 0x48, 0x0a, 0x0c, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a,
+    0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x70, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x08, 0x22, 0x32, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x44, 0x65,
+    0x6c, 0x65, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x72, 0x5f,
+    0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x19, 0x0a, 0x09, 0x4f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x22, 0x64, 0x0a, 0x12, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07,
+    0x75, 0x73, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a,
+    0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x22, 0x63, 0x0a, 0x0e, 0x4f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x11, 0x0a, 0x09, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14,
+    0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f,
+    0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2c,
+    0x0a, 0x17, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69,
+    0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x62, 0x0a, 0x18,
+    0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x6d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x12, 0x22, 0x0a, 0x05,
+    0x72, 0x6f, 0x6c, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x76, 0x61,
+    0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x22, 0x6c, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69,
+    0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x1f, 0x0a,
+    0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x76, 0x61,
+    0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x22, 0x8c,
+    0x01, 0x0a, 0x16, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52,
+    0x6f, 0x6c, 0x65, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x1f, 0x0a, 0x04,
+    0x72, 0x6f, 0x6c, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x76, 0x61, 0x75,
+    0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x22, 0x2e, 0x0a,
+    0x18, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69,
+    0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x40, 0x0a,
+    0x19, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69,
+    0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f,
+    0x0a, 0x07, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x22,
+    0xbd, 0x01, 0x0a, 0x18, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x41,
+    0x63, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x0a,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04,
+    0x48, 0x00, 0x12, 0x16, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61,
+    0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x12, 0x13, 0x0a, 0x09, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x01, 0x12,
+    0x15, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x12, 0x28, 0x0a, 0x0d, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0x5f, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x11, 0x2e,
+    0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65,
+    0x42, 0x0e, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x66, 0x6f,
+    0x42, 0x0d, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x22,
+    0x50, 0x0a, 0x19, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x41, 0x63,
+    0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a,
+    0x68, 0x61, 0x73, 0x5f, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x08,
+    0x12, 0x1f, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x11,
+    0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c,
+    0x65, 0x22, 0x32, 0x0a, 0x1c, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x61, 0x0a, 0x1d, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
     0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x6f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x22, 0x93, 0x01, 0x0a, 0x18,
-    0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x73,
-    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f,
-    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x12, 0x16,
+    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69, 0x6e,
+    0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e,
+    0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x30, 0x0a, 0x1b, 0x4f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5f, 0x0a, 0x1c, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69,
+    0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a,
+    0x0b, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x82, 0x01, 0x0a, 0x10,
+    0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10,
+    0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04,
+    0x22, 0x7c, 0x0a, 0x16, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14,
     0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x12, 0x13, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x01, 0x12, 0x15, 0x0a, 0x0b, 0x6f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
-    0x48, 0x01, 0x42, 0x0e, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x6e,
-    0x66, 0x6f, 0x42, 0x0d, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x6e, 0x66,
-    0x6f, 0x22, 0x2f, 0x0a, 0x19, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12,
-    0x0a, 0x0a, 0x68, 0x61, 0x73, 0x5f, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x08, 0x22, 0x32, 0x0a, 0x1c, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e, 0x76,
-    0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x61, 0x0a, 0x1d, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75,
-    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69,
-    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
-    0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49,
-    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x30, 0x0a, 0x1b, 0x4f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73,
-    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5f, 0x0a, 0x1c, 0x4f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c,
-    0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c,
-    0x0a, 0x0b, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20,
-    0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x82, 0x01, 0x0a,
-    0x10, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f,
-    0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a,
-    0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28,
-    0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d,
-    0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x09, 0x12,
-    0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28,
-    0x04, 0x22, 0x7c, 0x0a, 0x16, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61,
-    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18,
-    0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
-    0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a,
-    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22,
-    0x65, 0x0a, 0x1d, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x12, 0x21, 0x0a, 0x19, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x61, 0x63, 0x63, 0x65,
-    0x70, 0x74, 0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x5f, 0x69, 0x64,
-    0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
-    0x18, 0x03, 0x20, 0x02, 0x28, 0x08, 0x22, 0x20, 0x0a, 0x1e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74,
-    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x70, 0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x12, 0x0a, 0x0a, 0x02, 0x69,
-    0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61,
-    0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69,
-    0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f,
-    0x64, 0x79, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65,
-    0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x22, 0x6a, 0x0a, 0x15, 0x4f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x43, 0x72, 0x65,
-    0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02,
-    0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e,
-    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x04,
-    0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64,
-    0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x4a, 0xf6, 0x25, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x93,
-    0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0d, 0x0a, 0x1b, 0x0a, 0x02,
-    0x04, 0x00, 0x12, 0x04, 0x03, 0x00, 0x07, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65,
-    0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01,
-    0x12, 0x03, 0x03, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04,
-    0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x04, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04,
-    0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x05, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x05, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x02, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x06, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x0d, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x0a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x0a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x19,
-    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x1f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x0b, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03,
-    0x0c, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x0c, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x02, 0x12, 0x04, 0x0f, 0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03,
-    0x0f, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
-    0x13, 0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x13, 0x08, 0x11,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x17, 0x00, 0x1a,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x17, 0x08, 0x1a, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x18, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x18, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x18, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x18, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x19, 0x04, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x19, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x19, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x19, 0x1e, 0x1f, 0x0a, 0x2b, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x1d,
-    0x00, 0x1f, 0x01, 0x1a, 0x1f, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6d,
-    0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x1f,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x1e, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x1e, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x21, 0x00, 0x24,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x21, 0x08, 0x20, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x22, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x22, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x22, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x22, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x23, 0x04, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x23, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x23, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x23, 0x1e, 0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x27,
-    0x00, 0x29, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x27, 0x08, 0x20, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x28, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x28, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x28, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x28, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x2b, 0x00, 0x2e, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x2b, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x2c, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x2c, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x2c, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x2c, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2c, 0x21,
-    0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x2d, 0x04, 0x20, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x2d, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x35, 0x00,
-    0x3e, 0x01, 0x1a, 0x61, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a,
-    0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x6f, 0x6e, 0x6c,
-    0x79, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x61, 0x6e, 0x64,
-    0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x72, 0x65,
-    0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x21, 0x21,
-    0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54,
-    0x45, 0x21, 0x21, 0x21, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x35, 0x08,
-    0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x08, 0x00, 0x12, 0x04, 0x36, 0x04, 0x39, 0x05, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00, 0x01, 0x12, 0x03, 0x36, 0x0a, 0x16, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x37, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x37, 0x0f, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x37, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x38, 0x08,
-    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x38, 0x08, 0x0e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x38, 0x0f, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x38, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x09, 0x08, 0x01, 0x12, 0x04, 0x3a, 0x04, 0x3d, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08,
-    0x01, 0x01, 0x12, 0x03, 0x3a, 0x0a, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12,
-    0x03, 0x3b, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3b,
-    0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x0f, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3b, 0x1b, 0x1c, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x3c, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3c, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x03, 0x01, 0x12, 0x03, 0x3c, 0x0f, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03,
-    0x12, 0x03, 0x3c, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x40, 0x00, 0x42,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x40, 0x08, 0x21, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x41, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x41, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x41, 0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x41, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x41,
-    0x1f, 0x20, 0x0a, 0x3e, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x45, 0x00, 0x47, 0x01, 0x1a, 0x32,
-    0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e,
-    0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x45, 0x08, 0x24, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x46, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x46, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x46, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x46, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x49, 0x00, 0x4c, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x49, 0x08, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x0c, 0x02, 0x00, 0x12, 0x03, 0x4a, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x4a, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x4a, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4a,
-    0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4a, 0x21, 0x22,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x03, 0x4b, 0x04, 0x2e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0c, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4b, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x4b, 0x1e, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x4b, 0x2c, 0x2d, 0x0a, 0x3d, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x4f, 0x00, 0x51,
-    0x01, 0x1a, 0x31, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e,
-    0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73,
-    0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x4f, 0x08, 0x23,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x50, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x03, 0x50, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x50, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x50, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x50, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x53, 0x00, 0x56,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x53, 0x08, 0x24, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03, 0x54, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x54, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x54, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x54, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x03, 0x54,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x03, 0x55, 0x04, 0x2e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x04, 0x12, 0x03, 0x55, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12, 0x03, 0x55, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0e, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55, 0x1e, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x55, 0x2c, 0x2d, 0x0a, 0x1b, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x59,
-    0x00, 0x66, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74,
-    0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x59, 0x08, 0x18,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x5a, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x5a, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x5a, 0x17, 0x18, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x03, 0x5d,
-    0x02, 0x21, 0x1a, 0x20, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x2f, 0x61, 0x63,
-    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69,
-    0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5d,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5d, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x1c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5d, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0f, 0x02, 0x02, 0x12, 0x03, 0x5e, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x5e, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5e, 0x21,
-    0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x20, 0x1a, 0x33,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x74,
-    0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x69,
-    0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20,
-    0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x04, 0x12, 0x03, 0x61, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x01, 0x12, 0x03, 0x61, 0x12, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12, 0x03, 0x61, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x0f, 0x02, 0x04, 0x12, 0x03, 0x62, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x05, 0x12,
-    0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x01, 0x12, 0x03, 0x62,
-    0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x03, 0x12, 0x03, 0x62, 0x20, 0x21,
-    0x0a, 0x33, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x05, 0x12, 0x03, 0x65, 0x02, 0x1f, 0x1a, 0x26, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72,
-    0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x04, 0x12, 0x03,
-    0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x05, 0x12, 0x03, 0x65, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x01, 0x12, 0x03, 0x65, 0x12, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x03, 0x12, 0x03, 0x65, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x10, 0x12, 0x04, 0x68, 0x00, 0x73, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x10, 0x01,
-    0x12, 0x03, 0x68, 0x08, 0x1e, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x03, 0x6a,
-    0x02, 0x21, 0x1a, 0x18, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x62, 0x65,
-    0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x6a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x6a, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x6a, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02,
-    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6b, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6b, 0x21, 0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x10, 0x02,
-    0x02, 0x12, 0x03, 0x6e, 0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69,
+    0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22, 0x65,
+    0x0a, 0x1d, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x21, 0x0a, 0x19, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x61, 0x63, 0x63, 0x65, 0x70,
+    0x74, 0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18,
+    0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x18,
+    0x03, 0x20, 0x02, 0x28, 0x08, 0x22, 0x20, 0x0a, 0x1e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49,
+    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x55, 0x0a, 0x17, 0x4f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x63, 0x69,
+    0x6e, 0x64, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x5f,
+    0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22, 0x70,
+    0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65,
+    0x79, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a,
+    0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10,
+    0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10,
+    0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04,
+    0x22, 0x6a, 0x0a, 0x15, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74,
+    0x4b, 0x65, 0x79, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04,
+    0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65,
+    0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04,
+    0x62, 0x6f, 0x64, 0x79, 0x18, 0x04, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22, 0x91, 0x01, 0x0a,
+    0x0b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x0a, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x1f, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x06, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11,
+    0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c,
+    0x65, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09,
+    0x22, 0x7c, 0x0a, 0x11, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x43,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a,
+    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x1f, 0x0a,
+    0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x76, 0x61,
+    0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x22, 0x1f,
+    0x0a, 0x0e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x47, 0x65, 0x74,
+    0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22,
+    0x2b, 0x0a, 0x16, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4c, 0x69,
+    0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x50, 0x0a, 0x17,
+    0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x22, 0x0a, 0x06, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x76, 0x61, 0x75,
+    0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x4e,
+    0x0a, 0x11, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x76,
+    0x6f, 0x6b, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x2a, 0x41,
+    0x0a, 0x0a, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x0c, 0x0a, 0x08,
+    0x52, 0x65, 0x61, 0x64, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x4d, 0x65,
+    0x6d, 0x62, 0x65, 0x72, 0x10, 0x02, 0x12, 0x0e, 0x0a, 0x0a, 0x4d, 0x61, 0x69, 0x6e, 0x74, 0x61,
+    0x69, 0x6e, 0x65, 0x72, 0x10, 0x03, 0x12, 0x09, 0x0a, 0x05, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x10,
+    0x04, 0x4a, 0xcf, 0x4d, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x80, 0x02, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0d, 0x0a, 0x5f, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x03,
+    0x00, 0x08, 0x01, 0x1a, 0x53, 0x20, 0x72, 0x6f, 0x6c, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x2c, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x6c,
+    0x65, 0x61, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x70, 0x72, 0x69,
+    0x76, 0x69, 0x6c, 0x65, 0x67, 0x65, 0x64, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12,
+    0x03, 0x03, 0x05, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04, 0x02,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x04, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x05, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01,
+    0x02, 0x12, 0x03, 0x05, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03,
+    0x06, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x06, 0x02,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x06, 0x0f, 0x10, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x07, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x07, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x03, 0x02, 0x12, 0x03, 0x07, 0x0a, 0x0b, 0x0a, 0x1b, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04,
+    0x0b, 0x00, 0x11, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e,
+    0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x08,
+    0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x0c, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03,
+    0x0d, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x0e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x0e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e,
+    0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0e, 0x1d, 0x1e,
+    0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x10, 0x02, 0x1c, 0x1a, 0x3e, 0x20,
+    0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x70, 0x72,
+    0x69, 0x76, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x61, 0x72, 0x65,
+    0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f,
+    0x20, 0x69, 0x74, 0x73, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x10, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03,
+    0x12, 0x03, 0x10, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x13, 0x00, 0x18,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x13, 0x08, 0x14, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14,
+    0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x15, 0x02, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x15, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x15, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x15, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x15, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12,
+    0x03, 0x16, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x16,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x16, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x16, 0x12, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x16, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x17, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x03, 0x04, 0x12, 0x03, 0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x17, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x17, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x17, 0x1a,
+    0x1b, 0x0a, 0x50, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x1b, 0x00, 0x20, 0x01, 0x1a, 0x44, 0x20,
+    0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x69,
+    0x6e, 0x67, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x70, 0x65, 0x72, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x73, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x14, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x1c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x1c, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x1c, 0x1e, 0x1f, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02,
+    0x23, 0x1a, 0x1f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x6d, 0x61, 0x6b, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67,
+    0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1e, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x02, 0x12, 0x03, 0x1f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x1f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x1f, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1f, 0x10,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1f, 0x1a, 0x1b, 0x0a,
+    0x75, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x24, 0x00, 0x28, 0x01, 0x1a, 0x69, 0x20, 0x64, 0x65,
+    0x6c, 0x65, 0x74, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x20,
+    0x6f, 0x72, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x2c,
+    0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x70, 0x65, 0x72, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x24,
+    0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x25, 0x02, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x25, 0x19, 0x1a, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
+    0x03, 0x27, 0x02, 0x23, 0x1a, 0x1f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x20, 0x6d, 0x61, 0x6b, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68,
+    0x61, 0x6e, 0x67, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03,
+    0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x27, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x27, 0x21, 0x22, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x04, 0x12, 0x04, 0x2a, 0x00, 0x2c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01,
+    0x12, 0x03, 0x2a, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x2b,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2b, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2b, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2b, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2b, 0x19, 0x1a, 0x0a, 0x6b, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x04, 0x2f, 0x00, 0x35, 0x01, 0x1a, 0x5f, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x20,
+    0x61, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x6e,
+    0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x2c, 0x20, 0x70, 0x65, 0x72, 0x6d, 0x69, 0x74, 0x74,
+    0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x73, 0x20, 0x6c, 0x65, 0x61, 0x76, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x20,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03,
+    0x2f, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x30, 0x04, 0x22,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x30, 0x04, 0x0c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x30, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x30, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x30, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01,
+    0x12, 0x03, 0x31, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03,
+    0x31, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x31, 0x0d,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x31, 0x14, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x31, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x32, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x02, 0x04, 0x12, 0x03, 0x32, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x32, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x32, 0x14, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x32,
+    0x23, 0x24, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x34, 0x04, 0x25, 0x1a,
+    0x1f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x61,
+    0x6b, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03, 0x34, 0x04, 0x0c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x34, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x34, 0x14, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x34, 0x23, 0x24, 0x0a, 0x64, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04,
+    0x38, 0x00, 0x3e, 0x01, 0x1a, 0x58, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x20, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x73, 0x68, 0x69, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73,
+    0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x70,
+    0x65, 0x72, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x38, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x00, 0x12, 0x03, 0x39, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x39, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x39, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39, 0x14,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x39, 0x20, 0x21, 0x0a,
+    0x2c, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x04, 0x25, 0x1a, 0x1f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x6b, 0x69, 0x6e,
+    0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3b, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x3b, 0x14, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x3b, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x3c,
+    0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3c, 0x04, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3c, 0x0d, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3c, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3c, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x03, 0x12, 0x03, 0x3d, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x04,
+    0x12, 0x03, 0x3d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x3d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3d, 0x14,
+    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3d, 0x23, 0x24, 0x0a,
+    0x2b, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x41, 0x00, 0x43, 0x01, 0x1a, 0x1f, 0x20, 0x6c, 0x69,
+    0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x07, 0x01, 0x12, 0x03, 0x41, 0x08, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00,
+    0x12, 0x03, 0x42, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x42, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x42, 0x0d,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x42, 0x14, 0x1d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x42, 0x20, 0x21, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x08, 0x12, 0x04, 0x45, 0x00, 0x49, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01,
+    0x12, 0x03, 0x45, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x46,
+    0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46, 0x04, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x0d, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x01, 0x12, 0x03, 0x47, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x47, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x47, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x47, 0x14,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x47, 0x1e, 0x1f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x48, 0x04, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03, 0x48, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x02, 0x06, 0x12, 0x03, 0x48, 0x0d, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x48, 0x1a, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x48, 0x22, 0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x4b, 0x00, 0x50, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x4b, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x4c, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x4c, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x4c, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x4c, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4c, 0x20,
+    0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x04, 0x23, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x4d, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x4d, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03,
+    0x4e, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x04, 0x12, 0x03, 0x4e, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x4e, 0x0d, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x14, 0x20, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4e, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x03, 0x12, 0x03, 0x4f, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03,
+    0x04, 0x12, 0x03, 0x4f, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x06, 0x12,
+    0x03, 0x4f, 0x0d, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4f,
+    0x18, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4f, 0x1f, 0x20,
+    0x0a, 0x5c, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x53, 0x00, 0x5a, 0x01, 0x1a, 0x50, 0x20, 0x63,
+    0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x6c, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x78, 0x69, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x2c, 0x20, 0x6f, 0x6e, 0x6c,
+    0x79, 0x20, 0x70, 0x65, 0x72, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x53, 0x08, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a,
+    0x02, 0x00, 0x12, 0x03, 0x54, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x54, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x54, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x54, 0x14,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x54, 0x20, 0x21, 0x0a,
+    0x2c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x56, 0x04, 0x25, 0x1a, 0x1f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x6b, 0x69, 0x6e,
+    0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x56, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x56, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x56, 0x14, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x56, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x57,
+    0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x57, 0x04, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x57, 0x0d, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x57, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x57, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a,
+    0x02, 0x03, 0x12, 0x03, 0x58, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x04,
+    0x12, 0x03, 0x58, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x58, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x58, 0x14,
+    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x58, 0x23, 0x24, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x03, 0x59, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x03, 0x59, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x04, 0x06, 0x12, 0x03, 0x59, 0x0d, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04,
+    0x01, 0x12, 0x03, 0x59, 0x18, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03, 0x12,
+    0x03, 0x59, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x5d, 0x00, 0x5f, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x5e, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x5e, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x5e, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5e, 0x21,
+    0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x61, 0x00, 0x64, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x61, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02,
+    0x00, 0x12, 0x03, 0x62, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x62, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x62,
+    0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x62, 0x14, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x62, 0x21, 0x22, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x03, 0x63, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x01, 0x04, 0x12, 0x03, 0x63, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x63, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x63, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x63, 0x1e, 0x1f, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x6b, 0x00, 0x76, 0x01, 0x1a,
+    0x61, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x21, 0x21,
+    0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x69, 0x6d,
+    0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f,
+    0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21,
+    0x21, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x6b, 0x08, 0x20, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0d, 0x08, 0x00, 0x12, 0x04, 0x6c, 0x04, 0x6f, 0x05, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0d, 0x08, 0x00, 0x01, 0x12, 0x03, 0x6c, 0x0a, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d,
+    0x02, 0x00, 0x12, 0x03, 0x6d, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x6d, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x6d, 0x0f, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6d, 0x1c,
+    0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x03, 0x6e, 0x08, 0x20, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6e, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6e, 0x0f, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x6e, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x08, 0x01,
+    0x12, 0x04, 0x70, 0x04, 0x73, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x08, 0x01, 0x01, 0x12,
+    0x03, 0x70, 0x0a, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x03, 0x71, 0x08,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05, 0x12, 0x03, 0x71, 0x08, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x03, 0x71, 0x0f, 0x18, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x03, 0x71, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x03, 0x12, 0x03, 0x72, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x72, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x72, 0x0f, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12, 0x03, 0x72,
+    0x1d, 0x1e, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x04, 0x12, 0x03, 0x75, 0x04, 0x2a, 0x1a,
+    0x45, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x69, 0x76,
+    0x69, 0x6c, 0x65, 0x67, 0x65, 0x64, 0x20, 0x72, 0x6f, 0x6c, 0x65, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x67, 0x72, 0x61, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x2c,
+    0x20, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x52, 0x65, 0x61,
+    0x64, 0x4f, 0x6e, 0x6c, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x04, 0x12,
+    0x03, 0x75, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x06, 0x12, 0x03, 0x75,
+    0x0d, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x01, 0x12, 0x03, 0x75, 0x18, 0x25,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x03, 0x12, 0x03, 0x75, 0x28, 0x29, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x78, 0x00, 0x7b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e,
+    0x01, 0x12, 0x03, 0x78, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03,
+    0x79, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x03, 0x79, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x03, 0x79, 0x0d, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x03, 0x79, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x03, 0x79, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0e, 0x02, 0x01, 0x12, 0x03, 0x7a, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x7a, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x7a, 0x0d, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7a,
+    0x18, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7a, 0x1f, 0x20,
+    0x0a, 0x3f, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x05, 0x7e, 0x00, 0x80, 0x01, 0x01, 0x1a, 0x32, 0x20,
+    0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x7e, 0x08, 0x24, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x7f, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x7f, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x7f, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x7f, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7f,
+    0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0x82, 0x01, 0x00, 0x85, 0x01, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0x82, 0x01, 0x08, 0x25, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0x83, 0x01, 0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0x83, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x83, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x83, 0x01, 0x14, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x83, 0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12,
+    0x04, 0x84, 0x01, 0x04, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0x84, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x06, 0x12, 0x04, 0x84,
+    0x01, 0x0d, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01,
+    0x1e, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x01, 0x2c,
+    0x2d, 0x0a, 0x3f, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0x88, 0x01, 0x00, 0x8a, 0x01, 0x01, 0x1a,
+    0x31, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69,
+    0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0x88, 0x01, 0x08, 0x23, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0x89, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x00, 0x04, 0x12, 0x04, 0x89, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0x89, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0x89, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0x89, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12,
+    0x06, 0x8c, 0x01, 0x00, 0x8f, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04,
+    0x8c, 0x01, 0x08, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0x8d, 0x01,
+    0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8d, 0x01, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8d, 0x01, 0x0d, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x14, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x20, 0x21, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x2e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x01, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x01, 0x06, 0x12, 0x04, 0x8e, 0x01, 0x0d, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x1e, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x2c, 0x2d, 0x0a, 0x1d, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06,
+    0x92, 0x01, 0x00, 0x9f, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20,
+    0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04,
+    0x92, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0x93, 0x01,
+    0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0x93, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0x93, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0x93, 0x01, 0x12, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0x93, 0x01, 0x17, 0x18, 0x0a, 0x2e,
+    0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0x96, 0x01, 0x02, 0x21, 0x1a, 0x20, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x2f, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x04, 0x12, 0x04, 0x96, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04, 0x96, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0x96, 0x01, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0x96, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13,
+    0x02, 0x02, 0x12, 0x04, 0x97, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0x97, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0x97, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0x97, 0x01, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0x97, 0x01, 0x21, 0x22, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x03, 0x12, 0x04, 0x9a, 0x01,
+    0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20,
+    0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f,
+    0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69,
+    0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x04,
+    0x12, 0x04, 0x9a, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x05, 0x12,
+    0x04, 0x9a, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0x9a, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12, 0x04, 0x9a,
+    0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0x9b, 0x01, 0x02,
+    0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x04, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x05, 0x12, 0x04, 0x9b, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x12, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03, 0x12, 0x04, 0x9b, 0x01, 0x20, 0x21, 0x0a, 0x34, 0x0a,
+    0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x1f, 0x1a, 0x26, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x04, 0x12, 0x04, 0x9e, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x05, 0x12, 0x04, 0x9e, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x12, 0x1a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0x9e, 0x01, 0x1d, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0xa1, 0x01, 0x00, 0xac, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x08, 0x1e, 0x0a, 0x26, 0x0a, 0x04, 0x04, 0x14,
+    0x02, 0x00, 0x12, 0x04, 0xa3, 0x01, 0x02, 0x21, 0x1a, 0x18, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75,
+    0x73, 0x65, 0x72, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65,
+    0x64, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa3, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa3, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x12, 0x1c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa3, 0x01, 0x1f, 0x20, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xa4, 0x01, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xa4, 0x01, 0x21, 0x22, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02,
+    0x12, 0x04, 0xa7, 0x01, 0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69,
     0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f,
     0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20,
-    0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x6e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x6e, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x6e, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12, 0x03, 0x6f, 0x02, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x03, 0x6f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x6f, 0x20, 0x21, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x04,
-    0x12, 0x03, 0x72, 0x02, 0x1f, 0x1a, 0x26, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72,
-    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x10, 0x02, 0x04, 0x04, 0x12, 0x03, 0x72, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x04, 0x05, 0x12, 0x03, 0x72, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x72, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x72, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x04, 0x75, 0x00, 0x7a,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x03, 0x75, 0x08, 0x25, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x03, 0x76, 0x02, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x76, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x76, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x76, 0x12, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x03, 0x76,
-    0x2e, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x03, 0x77, 0x02, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x03, 0x77, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x03, 0x77, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x11, 0x02, 0x01, 0x01, 0x12, 0x03, 0x77, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x77, 0x1e, 0x1f, 0x0a, 0x45, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12,
-    0x03, 0x79, 0x02, 0x1b, 0x1a, 0x38, 0x20, 0x69, 0x66, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
-    0x20, 0x3d, 0x3d, 0x20, 0x74, 0x72, 0x75, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x74,
-    0x68, 0x65, 0x79, 0x27, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6a, 0x6f, 0x69, 0x6e, 0x69,
-    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x03, 0x79, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x10, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x79, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x04, 0x7c, 0x00,
-    0x7d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x26, 0x0a, 0x1d,
-    0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0x80, 0x01, 0x00, 0x8b, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73,
-    0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a,
-    0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0x80, 0x01, 0x08, 0x17, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x13,
-    0x02, 0x00, 0x12, 0x04, 0x82, 0x01, 0x04, 0x1b, 0x1a, 0x17, 0x20, 0x70, 0x6b, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0x82, 0x01, 0x04, 0x0c,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0x82, 0x01, 0x0d, 0x13, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0x82, 0x01, 0x14, 0x16, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0x82, 0x01, 0x19, 0x1a, 0x0a, 0x21, 0x0a,
-    0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x04, 0x22, 0x1a, 0x13, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x04, 0x12, 0x04, 0x84, 0x01, 0x04, 0x0c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04, 0x84, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x01, 0x20, 0x21, 0x0a, 0x18, 0x0a, 0x04,
-    0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0x86, 0x01, 0x04, 0x1d, 0x1a, 0x0a, 0x20, 0x6b, 0x65, 0x79,
-    0x20, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x04, 0x12,
-    0x04, 0x86, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x05, 0x12, 0x04,
-    0x86, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86,
-    0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x01,
-    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x03, 0x12, 0x04, 0x87, 0x01, 0x04, 0x21,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x04, 0x12, 0x04, 0x87, 0x01, 0x04, 0x0c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x05, 0x12, 0x04, 0x87, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01, 0x12, 0x04, 0x87, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12, 0x04, 0x87, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
-    0x02, 0x04, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
-    0x04, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04,
-    0x01, 0x12, 0x04, 0x88, 0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x88, 0x01, 0x1a, 0x1b, 0x0a, 0x2e, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04,
-    0x8a, 0x01, 0x04, 0x21, 0x1a, 0x20, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69,
-    0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x04, 0x12,
-    0x04, 0x8a, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x05, 0x12, 0x04,
-    0x8a, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8a,
-    0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8a, 0x01,
-    0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0x8d, 0x01, 0x00, 0x93, 0x01, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x08, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
-    0x02, 0x00, 0x05, 0x12, 0x04, 0x8e, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0x8e, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12,
-    0x04, 0x8f, 0x01, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x04, 0x12, 0x04,
-    0x8f, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8f,
-    0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x01,
-    0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x1b,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0x90, 0x01, 0x04, 0x21, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x04, 0x12, 0x04, 0x90, 0x01, 0x04, 0x0c, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05, 0x12, 0x04, 0x90, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12, 0x04, 0x90, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04, 0x90, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x14, 0x02, 0x03, 0x12, 0x04, 0x91, 0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
-    0x03, 0x04, 0x12, 0x04, 0x91, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03,
-    0x05, 0x12, 0x04, 0x91, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0x91, 0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12,
-    0x04, 0x91, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0x92,
-    0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x04, 0x12, 0x04, 0x92, 0x01,
-    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0x92, 0x01, 0x0d,
-    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0x92, 0x01, 0x14, 0x1c,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0x92, 0x01, 0x1f, 0x20,
+    0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xa7, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xa7, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x03, 0x12,
+    0x04, 0xa8, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x04, 0x12, 0x04,
+    0xa8, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x05, 0x12, 0x04, 0xa8,
+    0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa8, 0x01,
+    0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa8, 0x01, 0x20,
+    0x21, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0xab, 0x01, 0x02, 0x1f, 0x1a,
+    0x26, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x04,
+    0x12, 0x04, 0xab, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12,
+    0x04, 0xab, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04,
+    0xab, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0xab,
+    0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x15, 0x12, 0x06, 0xae, 0x01, 0x00, 0xb3, 0x01,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01, 0x12, 0x04, 0xae, 0x01, 0x08, 0x25, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0xaf, 0x01, 0x02, 0x30, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x15, 0x02, 0x00, 0x04, 0x12, 0x04, 0xaf, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x00, 0x05, 0x12, 0x04, 0xaf, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xaf, 0x01, 0x12, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xaf, 0x01, 0x2e, 0x2f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01,
+    0x12, 0x04, 0xb0, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x04, 0x12,
+    0x04, 0xb0, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0xb0, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb0,
+    0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb0, 0x01,
+    0x1e, 0x1f, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x02, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x1b,
+    0x1a, 0x38, 0x20, 0x69, 0x66, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x20, 0x3d, 0x3d, 0x20,
+    0x74, 0x72, 0x75, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x79, 0x27,
+    0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6a, 0x6f, 0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x02, 0x04, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xb2, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xb2, 0x01, 0x10, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xb2, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06, 0xb5, 0x01,
+    0x00, 0xb6, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xb5, 0x01, 0x08,
+    0x26, 0x0a, 0x67, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xb9, 0x01, 0x00, 0xbe, 0x01, 0x01, 0x1a,
+    0x59, 0x20, 0x77, 0x69, 0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x20, 0x61, 0x20, 0x70, 0x65, 0x6e,
+    0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c,
+    0x20, 0x70, 0x65, 0x72, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17,
+    0x01, 0x12, 0x04, 0xb9, 0x01, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12,
+    0x04, 0xba, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0xba, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x05, 0x12, 0x04, 0xba,
+    0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xba, 0x01,
+    0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xba, 0x01, 0x1e,
+    0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x20, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x04, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x05, 0x12, 0x04, 0xbb, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x01, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x01, 0x03, 0x12, 0x04, 0xbb, 0x01, 0x1e, 0x1f, 0x0a, 0x2d, 0x0a, 0x04, 0x04,
+    0x17, 0x02, 0x02, 0x12, 0x04, 0xbd, 0x01, 0x02, 0x23, 0x1a, 0x1f, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x6b, 0x69, 0x6e, 0x67, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x02, 0x04, 0x12, 0x04, 0xbd, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xbd, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xbd, 0x01, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xbd, 0x01, 0x21, 0x22, 0x0a, 0x1d, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xc1, 0x01,
+    0x00, 0xcc, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e,
+    0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12, 0x04, 0xc1, 0x01,
+    0x08, 0x17, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x00, 0x12, 0x04, 0xc3, 0x01, 0x04, 0x1b,
+    0x1a, 0x17, 0x20, 0x70, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75,
+    0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xc3, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xc3, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xc3, 0x01, 0x14, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xc3, 0x01, 0x19, 0x1a, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x01, 0x12, 0x04, 0xc5,
+    0x01, 0x04, 0x22, 0x1a, 0x13, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74,
+    0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0xc5, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xc5, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xc5, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xc5, 0x01, 0x20, 0x21, 0x0a, 0x18, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x02, 0x12, 0x04, 0xc7, 0x01,
+    0x04, 0x1d, 0x1a, 0x0a, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x04, 0x12, 0x04, 0xc7, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x18, 0x02, 0x02, 0x05, 0x12, 0x04, 0xc7, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc7, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18,
+    0x02, 0x03, 0x12, 0x04, 0xc8, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03,
+    0x04, 0x12, 0x04, 0xc8, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x05,
+    0x12, 0x04, 0xc8, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xc8, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x03, 0x12, 0x04,
+    0xc8, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x04, 0x12, 0x04, 0xc9, 0x01,
+    0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x04, 0x04, 0x12, 0x04, 0xc9, 0x01, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x04, 0x05, 0x12, 0x04, 0xc9, 0x01, 0x0d, 0x12,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc9, 0x01, 0x13, 0x17, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc9, 0x01, 0x1a, 0x1b, 0x0a, 0x2e,
+    0x0a, 0x04, 0x04, 0x18, 0x02, 0x05, 0x12, 0x04, 0xcb, 0x01, 0x04, 0x21, 0x1a, 0x20, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73,
+    0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x18, 0x02, 0x05, 0x04, 0x12, 0x04, 0xcb, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x18, 0x02, 0x05, 0x05, 0x12, 0x04, 0xcb, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x05, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x05, 0x03, 0x12, 0x04, 0xcb, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19,
+    0x12, 0x06, 0xce, 0x01, 0x00, 0xd4, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12,
+    0x04, 0xce, 0x01, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xcf,
+    0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x04, 0x12, 0x04, 0xcf, 0x01,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x05, 0x12, 0x04, 0xcf, 0x01, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x01, 0x12, 0x04, 0xcf, 0x01, 0x14, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03, 0x12, 0x04, 0xcf, 0x01, 0x20, 0x21, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x04, 0x1d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x19, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd0, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd0, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xd0, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02,
+    0x02, 0x12, 0x04, 0xd1, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x04,
+    0x12, 0x04, 0xd1, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0xd1, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0xd1, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x03, 0x12, 0x04, 0xd1,
+    0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x03, 0x12, 0x04, 0xd2, 0x01, 0x04,
+    0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x04, 0x12, 0x04, 0xd2, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x05, 0x12, 0x04, 0xd2, 0x01, 0x0d, 0x12, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x01, 0x12, 0x04, 0xd2, 0x01, 0x13, 0x17, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x03, 0x12, 0x04, 0xd2, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x19, 0x02, 0x04, 0x12, 0x04, 0xd3, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x04, 0x04, 0x12, 0x04, 0xd3, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x04, 0x05, 0x12, 0x04, 0xd3, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0xd3, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0xd3, 0x01, 0x1f, 0x20, 0x0a, 0x1d, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xd8,
+    0x01, 0x00, 0xe3, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65,
+    0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xd8,
+    0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0xd9, 0x01, 0x04,
+    0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd9, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd9, 0x01, 0x0d, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd9, 0x01, 0x14, 0x16, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd9, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1a, 0x02, 0x01, 0x12, 0x04, 0xda, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x01, 0x04, 0x12, 0x04, 0xda, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xda, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xda, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xda, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x02, 0x12,
+    0x04, 0xdb, 0x01, 0x04, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x04, 0x12, 0x04,
+    0xdb, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x05, 0x12, 0x04, 0xdb,
+    0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x01, 0x12, 0x04, 0xdb, 0x01,
+    0x14, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x03, 0x12, 0x04, 0xdb, 0x01, 0x22,
+    0x23, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x03, 0x12, 0x04, 0xdd, 0x01, 0x04, 0x21, 0x1a,
+    0x23, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x04, 0x12, 0x04, 0xdd,
+    0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x05, 0x12, 0x04, 0xdd, 0x01,
+    0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x01, 0x12, 0x04, 0xdd, 0x01, 0x14,
+    0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x03, 0x12, 0x04, 0xdd, 0x01, 0x1f, 0x20,
+    0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x04, 0x12, 0x04, 0xdf, 0x01, 0x04, 0x1d, 0x1a, 0x3c,
+    0x20, 0x61, 0x20, 0x68, 0x75, 0x6d, 0x61, 0x6e, 0x20, 0x72, 0x65, 0x61, 0x64, 0x61, 0x62, 0x6c,
+    0x65, 0x20, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66,
+    0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x69, 0x73, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x04, 0x04, 0x12, 0x04, 0xdf, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x04, 0x05, 0x12, 0x04, 0xdf, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0xdf, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x05,
+    0x12, 0x04, 0xe0, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x05, 0x04, 0x12,
+    0x04, 0xe0, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x05, 0x06, 0x12, 0x04,
+    0xe0, 0x01, 0x0d, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x05, 0x01, 0x12, 0x04, 0xe0,
+    0x01, 0x18, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x05, 0x03, 0x12, 0x04, 0xe0, 0x01,
+    0x1f, 0x20, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x06, 0x12, 0x04, 0xe2, 0x01, 0x04, 0x1e,
+    0x1a, 0x3c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x65, 0x61, 0x72, 0x65, 0x72, 0x20, 0x73, 0x65,
+    0x63, 0x72, 0x65, 0x74, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72,
+    0x6e, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1a, 0x02, 0x06, 0x04, 0x12, 0x04, 0xe2, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1a, 0x02, 0x06, 0x05, 0x12, 0x04, 0xe2, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x06, 0x01, 0x12, 0x04, 0xe2, 0x01, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x06, 0x03, 0x12, 0x04, 0xe2, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1b,
+    0x12, 0x06, 0xe5, 0x01, 0x00, 0xeb, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12,
+    0x04, 0xe5, 0x01, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0xe6,
+    0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe6, 0x01,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe6, 0x01, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x14, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe6, 0x01, 0x20, 0x21, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x04, 0x24, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1b, 0x02, 0x01, 0x04, 0x12, 0x04, 0xe7, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe7, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x14, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x22, 0x23, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02,
+    0x02, 0x12, 0x04, 0xe8, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x04,
+    0x12, 0x04, 0xe8, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0xe8, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0xe8, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe8,
+    0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x04,
+    0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x04, 0x12, 0x04, 0xe9, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x05, 0x12, 0x04, 0xe9, 0x01, 0x0d, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x14, 0x18, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1b, 0x02, 0x04, 0x12, 0x04, 0xea, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x04, 0x04, 0x12, 0x04, 0xea, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x04, 0x06, 0x12, 0x04, 0xea, 0x01, 0x0d, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0xea, 0x01, 0x18, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0xea, 0x01, 0x1f, 0x20, 0x0a, 0x34, 0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0xee,
+    0x01, 0x00, 0xf0, 0x01, 0x01, 0x1a, 0x26, 0x20, 0x6c, 0x6f, 0x6f, 0x6b, 0x20, 0x75, 0x70, 0x20,
+    0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x62, 0x79, 0x20, 0x69, 0x74, 0x73, 0x20, 0x62,
+    0x65, 0x61, 0x72, 0x65, 0x72, 0x20, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0xee, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c,
+    0x02, 0x00, 0x12, 0x04, 0xef, 0x01, 0x04, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xef, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xef, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xef, 0x01, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xef, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x06, 0xf2, 0x01, 0x00, 0xf4,
+    0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x08, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0xf3, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1d, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf3, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1d, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf3, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xf3, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1e, 0x12,
+    0x06, 0xf6, 0x01, 0x00, 0xf9, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04,
+    0xf6, 0x01, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0xf7, 0x01,
+    0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf7, 0x01, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf7, 0x01, 0x0d, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf7, 0x01, 0x14, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf7, 0x01, 0x20, 0x21, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1e, 0x02, 0x01, 0x12, 0x04, 0xf8, 0x01, 0x04, 0x24, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1e, 0x02, 0x01, 0x04, 0x12, 0x04, 0xf8, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1e, 0x02, 0x01, 0x06, 0x12, 0x04, 0xf8, 0x01, 0x0d, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xf8, 0x01, 0x19, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xf8, 0x01, 0x22, 0x23, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1f, 0x12, 0x06,
+    0xfb, 0x01, 0x00, 0x80, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1f, 0x01, 0x12, 0x04, 0xfb,
+    0x01, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x00, 0x12, 0x04, 0xfc, 0x01, 0x04,
+    0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x04, 0x12, 0x04, 0xfc, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x05, 0x12, 0x04, 0xfc, 0x01, 0x0d, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfc, 0x01, 0x14, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfc, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1f, 0x02, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1f, 0x02, 0x01, 0x04, 0x12, 0x04, 0xfd, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xfd, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xfd, 0x01, 0x1f, 0x20, 0x0a, 0x2e, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x02, 0x12,
+    0x04, 0xff, 0x01, 0x04, 0x25, 0x1a, 0x20, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x20, 0x72, 0x65, 0x76, 0x6f, 0x6b, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x02, 0x04,
+    0x12, 0x04, 0xff, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0xff, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0xff, 0x01, 0x14, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x02, 0x03, 0x12, 0x04, 0xff,
+    0x01, 0x23, 0x24,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
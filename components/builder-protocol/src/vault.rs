@@ -5,14 +5,178 @@
 // is made available under an open source license such as the Apache 2.0 License.
 
 use std::collections::BTreeMap;
+use std::fmt;
+use std::result;
+use std::str::FromStr;
 
+use protobuf::ProtobufEnum;
+use rustc_serialize::{Decoder, Decodable};
 use rustc_serialize::json::{Json, ToJson};
 
 use message::{Persistable, Routable};
+use net::{self, ErrCode, NetError};
 use sharding::InstaId;
 
 pub use message::vault::*;
 
+/// Prefix of every origin token's bearer secret. Used to tell origin tokens apart from GitHub
+/// access tokens presented in the same `Authorization` header.
+pub const ORIGIN_TOKEN_PREFIX: &'static str = "hab-origin-";
+/// Number of random bytes in an origin token's bearer secret.
+pub const ORIGIN_TOKEN_BYTES: usize = 32;
+
+/// Role assumed for members who joined an origin before roles were recorded. These members were
+/// previously able to upload and promote packages so they keep that ability.
+pub const LEGACY_MEMBER_ROLE: OriginRole = OriginRole::Maintainer;
+
+impl OriginRole {
+    /// Returns true if this role carries at least the privileges of the given role.
+    pub fn satisfies(&self, required: OriginRole) -> bool {
+        self.value() >= required.value()
+    }
+}
+
+impl OriginToken {
+    /// Returns the role the token grants given the current role of the account which created it,
+    /// or `None` if that account is no longer a member of the origin. A token never grants more
+    /// than its creator currently holds, so demoting or removing a member also limits the tokens
+    /// they created.
+    pub fn effective_role(&self, owner_role: Option<OriginRole>) -> Option<OriginRole> {
+        match owner_role {
+            Some(role) if role.satisfies(self.get_role()) => Some(self.get_role()),
+            Some(role) => Some(role),
+            None => None,
+        }
+    }
+}
+
+impl Origin {
+    /// Returns the role of a member of this origin given its role record. Members who joined
+    /// before roles were recorded have none, and are the owner if they own the origin and hold
+    /// `LEGACY_MEMBER_ROLE` otherwise.
+    pub fn role_of(&self, account_id: u64, record: Option<&OriginMember>) -> OriginRole {
+        match record {
+            Some(member) => member.get_role(),
+            None if account_id == self.get_owner_id() => OriginRole::Owner,
+            None => LEGACY_MEMBER_ROLE,
+        }
+    }
+
+    /// Checks that the requester may delete this origin. Only the owner of record may, and only
+    /// once every other member has been removed, `members` being the number of members left.
+    pub fn check_delete(&self, requester_id: u64, members: usize) -> result::Result<(), NetError> {
+        if requester_id != self.get_owner_id() {
+            return Err(net::err(ErrCode::ACCESS_DENIED, "vt:origin-delete:1"));
+        }
+        if members > 1 {
+            return Err(net::err(ErrCode::ENTITY_CONFLICT, "vt:origin-delete:2"));
+        }
+        Ok(())
+    }
+
+    /// Checks that the requester may hand this origin over to the given account, which holds
+    /// `account_role` in it if it is a member. Only the owner of record may transfer an origin,
+    /// and only to another member.
+    pub fn check_transfer(&self,
+                          requester_id: u64,
+                          account_id: u64,
+                          account_role: Option<OriginRole>)
+                          -> result::Result<(), NetError> {
+        if requester_id != self.get_owner_id() {
+            return Err(net::err(ErrCode::ACCESS_DENIED, "vt:origin-transfer:1"));
+        }
+        if account_id == self.get_owner_id() {
+            return Err(net::err(ErrCode::ENTITY_CONFLICT, "vt:origin-transfer:2"));
+        }
+        if account_role.is_none() {
+            return Err(net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-transfer:3"));
+        }
+        Ok(())
+    }
+
+    /// Makes `new_owner` the owner of record of this origin. Returns the role record of the
+    /// previous owner demoted to a maintainer, or `None` if the previous owner created the origin
+    /// before roles were recorded and has no record to update.
+    pub fn transfer_to(&mut self,
+                       new_owner: &OriginMember,
+                       previous_owner: Option<OriginMember>)
+                       -> Option<OriginMember> {
+        self.set_owner_id(new_owner.get_account_id());
+        previous_owner.map(|mut member| {
+            member.set_role(OriginRole::Maintainer);
+            member
+        })
+    }
+
+    /// Checks that the requester, who holds `requester_role` in this origin if a member, may
+    /// remove the given account, which holds `account_role` if a member. Members may always
+    /// leave and only owners may remove others, but the owner of record can only leave after
+    /// transferring the origin.
+    pub fn check_member_remove(&self,
+                               requester_id: u64,
+                               requester_role: Option<OriginRole>,
+                               account_id: u64,
+                               account_role: Option<OriginRole>)
+                               -> result::Result<(), NetError> {
+        if requester_id != account_id && requester_role != Some(OriginRole::Owner) {
+            return Err(net::err(ErrCode::ACCESS_DENIED, "vt:origin-member-remove:1"));
+        }
+        if account_id == self.get_owner_id() {
+            return Err(net::err(ErrCode::ENTITY_CONFLICT, "vt:origin-member-remove:2"));
+        }
+        if account_role.is_none() {
+            return Err(net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-remove:3"));
+        }
+        Ok(())
+    }
+}
+
+impl OriginInvitation {
+    /// Checks that the requester, who holds `requester_role` in the invitation's origin if a
+    /// member, may rescind this invitation. Its creator and the origin's owners may.
+    pub fn check_rescind(&self,
+                         requester_id: u64,
+                         requester_role: Option<OriginRole>)
+                         -> result::Result<(), NetError> {
+        if requester_id != self.get_owner_id() && requester_role != Some(OriginRole::Owner) {
+            return Err(net::err(ErrCode::ACCESS_DENIED, "vt:origin-invite-rescind:1"));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for OriginRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let role = match *self {
+            OriginRole::ReadOnly => "read-only",
+            OriginRole::Member => "member",
+            OriginRole::Maintainer => "maintainer",
+            OriginRole::Owner => "owner",
+        };
+        write!(f, "{}", role)
+    }
+}
+
+impl FromStr for OriginRole {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "read-only" | "readonly" => Ok(OriginRole::ReadOnly),
+            "member" => Ok(OriginRole::Member),
+            "maintainer" => Ok(OriginRole::Maintainer),
+            "owner" => Ok(OriginRole::Owner),
+            _ => Err(value.to_string()),
+        }
+    }
+}
+
+impl ToJson for OriginRole {
+    fn to_json(&self) -> Json {
+        self.to_string().to_json()
+    }
+}
+
 impl Persistable for Origin {
     type Key = u64;
 
@@ -51,10 +215,28 @@ impl ToJson for Origin {
         m.insert("name".to_string(), self.get_name().to_json());
         m.insert("owner_id".to_string(),
                  self.get_owner_id().to_string().to_json());
+        m.insert("private".to_string(), self.get_private().to_json());
         Json::Object(m)
     }
 }
 
+impl Routable for OriginUpdate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        // see the note on OriginGet
+        Some(self.get_name().to_string())
+    }
+}
+
 impl Routable for OriginMemberRemove {
     type H = InstaId;
 
@@ -63,6 +245,14 @@ impl Routable for OriginMemberRemove {
     }
 }
 
+impl Routable for OriginTransfer {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl Routable for OriginMemberListRequest {
     type H = InstaId;
 
@@ -133,6 +323,30 @@ impl ToJson for OriginInvitation {
     }
 }
 
+impl Decodable for OriginInvitation {
+    fn decode<D: Decoder>(d: &mut D) -> result::Result<Self, D::Error> {
+        // ids are encoded as strings, see the ToJson implementation
+        fn id<D: Decoder>(d: &mut D, name: &str, idx: usize) -> result::Result<u64, D::Error> {
+            let value: String = try!(d.read_struct_field(name, idx, |d| Decodable::decode(d)));
+            value.parse().map_err(|_| d.error(&format!("invalid {}: {}", name, value)))
+        }
+        d.read_struct("OriginInvitation", 6, |d| {
+            let mut invite = OriginInvitation::new();
+            invite.set_id(try!(id(d, "id", 0)));
+            invite.set_account_id(try!(id(d, "account_id", 1)));
+            invite.set_account_name(try!(d.read_struct_field("account_name",
+                                                             2,
+                                                             |d| Decodable::decode(d))));
+            invite.set_origin_id(try!(id(d, "origin_id", 3)));
+            invite.set_origin_name(try!(d.read_struct_field("origin_name",
+                                                            4,
+                                                            |d| Decodable::decode(d))));
+            invite.set_owner_id(try!(id(d, "owner_id", 5)));
+            Ok(invite)
+        })
+    }
+}
+
 
 impl Routable for AccountInvitationListRequest {
     type H = u64;
@@ -193,6 +407,14 @@ impl ToJson for OriginInvitationListResponse {
 }
 
 
+impl Routable for OriginInvitationRescind {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl Routable for OriginInvitationAcceptRequest {
     type H = u64;
 
@@ -209,10 +431,33 @@ impl ToJson for OriginMemberListResponse {
         m.insert("origin_id".to_string(),
                  self.get_origin_id().to_string().to_json());
         m.insert("members".to_string(), self.get_members().to_json());
+        m.insert("roles".to_string(), self.get_roles().to_json());
         Json::Object(m)
     }
 }
 
+impl ToJson for OriginMember {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("account_id".to_string(),
+                 self.get_account_id().to_string().to_json());
+        m.insert("account_name".to_string(),
+                 self.get_account_name().to_json());
+        m.insert("role".to_string(), self.get_role().to_json());
+        Json::Object(m)
+    }
+}
+
+impl Routable for OriginMemberRoleUpdate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl Routable for AccountOriginListRequest {
     type H = u64;
 
@@ -240,3 +485,248 @@ impl Routable for CheckOriginAccessRequest {
         Some(self.get_account_id())
     }
 }
+
+impl Persistable for OriginToken {
+    type Key = u64;
+
+    fn primary_key(&self) -> Self::Key {
+        self.get_id()
+    }
+
+    fn set_primary_key(&mut self, value: Self::Key) {
+        self.set_id(value);
+    }
+}
+
+impl ToJson for OriginToken {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_string().to_json());
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("origin_name".to_string(), self.get_origin_name().to_json());
+        m.insert("owner_id".to_string(),
+                 self.get_owner_id().to_string().to_json());
+        m.insert("name".to_string(), self.get_name().to_json());
+        m.insert("role".to_string(), self.get_role().to_json());
+        if self.has_token() {
+            m.insert("token".to_string(), self.get_token().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl Routable for OriginTokenCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginTokenGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        // Tokens are looked up by digest so any vault server can answer. Route on a hash of the
+        // secret so the secret itself never reaches the routing layer or its logs.
+        Some(token_route_hash(self.get_token()))
+    }
+}
+
+/// FNV-1a hash of an origin token's bearer secret.
+fn token_route_hash(token: &str) -> u64 {
+    token.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl Routable for OriginTokenListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl ToJson for OriginTokenListResponse {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("tokens".to_string(), self.get_tokens().to_json());
+        Json::Object(m)
+    }
+}
+
+impl Routable for OriginTokenRevoke {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use message::Routable;
+    use net::{ErrCode, NetError};
+    use super::*;
+
+    fn token(role: OriginRole) -> OriginToken {
+        let mut token = OriginToken::new();
+        token.set_role(role);
+        token
+    }
+
+    const OWNER: u64 = 1;
+    const MEMBER: u64 = 2;
+    const OUTSIDER: u64 = 3;
+
+    fn origin() -> Origin {
+        let mut origin = Origin::new();
+        origin.set_owner_id(OWNER);
+        origin
+    }
+
+    fn member(account_id: u64, role: OriginRole) -> OriginMember {
+        let mut member = OriginMember::new();
+        member.set_account_id(account_id);
+        member.set_role(role);
+        member
+    }
+
+    fn code(result: Result<(), NetError>) -> Option<ErrCode> {
+        result.err().map(|err| err.get_code())
+    }
+
+    #[test]
+    fn members_without_a_role_record_hold_a_legacy_role() {
+        let origin = origin();
+        assert_eq!(origin.role_of(OWNER, None), OriginRole::Owner);
+        assert_eq!(origin.role_of(MEMBER, None), LEGACY_MEMBER_ROLE);
+        let record = member(MEMBER, OriginRole::ReadOnly);
+        assert_eq!(origin.role_of(MEMBER, Some(&record)), OriginRole::ReadOnly);
+    }
+
+    #[test]
+    fn owner_cannot_leave_without_transferring() {
+        let origin = origin();
+        let owner = Some(OriginRole::Owner);
+        assert_eq!(code(origin.check_member_remove(OWNER, owner, OWNER, owner)),
+                   Some(ErrCode::ENTITY_CONFLICT));
+    }
+
+    #[test]
+    fn members_may_leave() {
+        let origin = origin();
+        let role = Some(OriginRole::ReadOnly);
+        assert_eq!(code(origin.check_member_remove(MEMBER, role, MEMBER, role)), None);
+    }
+
+    #[test]
+    fn only_owners_remove_others() {
+        let origin = origin();
+        let maintainer = Some(OriginRole::Maintainer);
+        assert_eq!(code(origin.check_member_remove(MEMBER, maintainer, OUTSIDER, maintainer)),
+                   Some(ErrCode::ACCESS_DENIED));
+        assert_eq!(code(origin.check_member_remove(OUTSIDER, None, MEMBER, maintainer)),
+                   Some(ErrCode::ACCESS_DENIED));
+        assert_eq!(code(origin.check_member_remove(OWNER,
+                                                   Some(OriginRole::Owner),
+                                                   MEMBER,
+                                                   maintainer)),
+                   None);
+        assert_eq!(code(origin.check_member_remove(OWNER, Some(OriginRole::Owner), OUTSIDER, None)),
+                   Some(ErrCode::ENTITY_NOT_FOUND));
+    }
+
+    #[test]
+    fn legacy_owner_removes_others() {
+        let origin = origin();
+        let role = Some(origin.role_of(OWNER, None));
+        assert_eq!(code(origin.check_member_remove(OWNER, role, MEMBER, Some(LEGACY_MEMBER_ROLE))),
+                   None);
+    }
+
+    #[test]
+    fn only_the_owner_transfers() {
+        let origin = origin();
+        let role = Some(OriginRole::Owner);
+        assert_eq!(code(origin.check_transfer(MEMBER, MEMBER, role)),
+                   Some(ErrCode::ACCESS_DENIED));
+        assert_eq!(code(origin.check_transfer(OWNER, OWNER, role)),
+                   Some(ErrCode::ENTITY_CONFLICT));
+        assert_eq!(code(origin.check_transfer(OWNER, MEMBER, Some(OriginRole::ReadOnly))), None);
+    }
+
+    #[test]
+    fn transfer_to_non_member_is_refused() {
+        assert_eq!(code(origin().check_transfer(OWNER, OUTSIDER, None)),
+                   Some(ErrCode::ENTITY_NOT_FOUND));
+    }
+
+    #[test]
+    fn transfer_demotes_the_previous_owner() {
+        let mut origin = origin();
+        let new_owner = member(MEMBER, OriginRole::Owner);
+        let previous = origin.transfer_to(&new_owner, Some(member(OWNER, OriginRole::Owner)))
+            .unwrap();
+        assert_eq!(origin.get_owner_id(), MEMBER);
+        assert_eq!(origin.role_of(OWNER, Some(&previous)), OriginRole::Maintainer);
+        assert_eq!(origin.role_of(MEMBER, Some(&new_owner)), OriginRole::Owner);
+    }
+
+    #[test]
+    fn transfer_demotes_a_legacy_owner() {
+        let mut origin = origin();
+        let new_owner = member(MEMBER, OriginRole::Owner);
+        assert!(origin.transfer_to(&new_owner, None).is_none());
+        assert_eq!(origin.role_of(OWNER, None), LEGACY_MEMBER_ROLE);
+        assert_eq!(code(origin.check_member_remove(OWNER, Some(LEGACY_MEMBER_ROLE), MEMBER, None)),
+                   Some(ErrCode::ACCESS_DENIED));
+    }
+
+    #[test]
+    fn only_the_owner_deletes_an_empty_origin() {
+        let origin = origin();
+        assert_eq!(code(origin.check_delete(MEMBER, 1)), Some(ErrCode::ACCESS_DENIED));
+        assert_eq!(code(origin.check_delete(OWNER, 2)), Some(ErrCode::ENTITY_CONFLICT));
+        assert_eq!(code(origin.check_delete(OWNER, 1)), None);
+    }
+
+    #[test]
+    fn invitations_are_rescinded_by_their_creator_or_an_owner() {
+        let mut invite = OriginInvitation::new();
+        invite.set_owner_id(MEMBER);
+        assert_eq!(code(invite.check_rescind(MEMBER, Some(OriginRole::Maintainer))), None);
+        assert_eq!(code(invite.check_rescind(OWNER, Some(OriginRole::Owner))), None);
+        assert_eq!(code(invite.check_rescind(OUTSIDER, Some(OriginRole::Maintainer))),
+                   Some(ErrCode::ACCESS_DENIED));
+        assert_eq!(code(invite.check_rescind(OUTSIDER, None)),
+                   Some(ErrCode::ACCESS_DENIED));
+    }
+
+    #[test]
+    fn token_role_is_capped_by_its_creator() {
+        let token = token(OriginRole::Maintainer);
+        assert_eq!(token.effective_role(Some(OriginRole::Owner)),
+                   Some(OriginRole::Maintainer));
+        assert_eq!(token.effective_role(Some(OriginRole::Maintainer)),
+                   Some(OriginRole::Maintainer));
+        assert_eq!(token.effective_role(Some(OriginRole::ReadOnly)),
+                   Some(OriginRole::ReadOnly));
+        assert_eq!(token.effective_role(None), None);
+    }
+
+    #[test]
+    fn token_lookup_routes_without_the_secret() {
+        let mut get = OriginTokenGet::new();
+        get.set_token("hab-origin-secret".to_string());
+        let key = get.route_key().unwrap();
+        assert!(!key.to_string().contains("secret"));
+        assert_eq!(key, get.route_key().unwrap());
+        get.set_token("hab-origin-other".to_string());
+        assert!(key != get.route_key().unwrap());
+    }
+}
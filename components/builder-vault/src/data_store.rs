@@ -12,17 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::ops::Deref;
 use std::sync::Arc;
 
 use dbcache::{self, data_store, ConnectionPool, Bucket, IndexSet, InstaSet};
-use protobuf::Message;
+use protobuf::{self, Message};
 use protocol::{vault, InstaId, Persistable};
 use redis::{self, Commands, PipelineCommands};
 
 use config::Config;
 use protocol::vault as proto;
 
+/// Hash mapping the digest of each origin token's bearer secret to the token's id.
+const ORIGIN_TOKEN_DIGEST_IDX: &'static str = "origin_token:digest:index";
+
 pub struct DataStore {
     pub pool: Arc<ConnectionPool>,
     pub origins: OriginTable,
@@ -48,6 +52,7 @@ pub struct OriginTable {
     pub origin_secret_keys: OriginSecretKeysTable,
     pub invites: OriginInvitesTable,
     pub name_idx: OriginNameIdx,
+    pub tokens: OriginTokensTable,
 }
 
 impl OriginTable {
@@ -55,16 +60,19 @@ impl OriginTable {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
         let pool3 = pool.clone();
+        let pool4 = pool.clone();
 
         let origin_secret_keys = OriginSecretKeysTable::new(pool1);
         let invites = OriginInvitesTable::new(pool2);
         let name_idx = OriginNameIdx::new(pool3);
+        let tokens = OriginTokensTable::new(pool4);
 
         OriginTable {
             pool: pool,
             origin_secret_keys: origin_secret_keys,
             invites: invites,
             name_idx: name_idx,
+            tokens: tokens,
         }
     }
 
@@ -83,25 +91,37 @@ impl OriginTable {
         //  will do for now.
         let account_origins_key = format!("account_origins:{}", &invite.get_account_id());
         let origin_members_key = format!("origin_members:{}", &invite.get_origin_id());
+        let origin_member_roles_key = self.origin_member_roles_key(&invite.get_origin_id());
         debug!("account_origins_key = {}", &account_origins_key);
         debug!("origin_members_key = {}", &origin_members_key);
 
         let conn = try!(self.pool().get());
 
         if !ignore {
-            // accept the invite: add the account to the origin and delete the
-            // invite
+            // accept the invite: add the account to the origin as a member and
+            // delete the invite
+            let member = Self::member_record(invite.get_origin_id(),
+                                             invite.get_account_id(),
+                                             invite.get_account_name(),
+                                             proto::OriginRole::Member);
             try!(redis::transaction(conn.deref(),
                                     &[account_origins_key.clone(), origin_members_key.clone()],
                                     |txn| {
                 txn.sadd(account_origins_key.clone(), invite.get_origin_name())
                     .sadd(origin_members_key.clone(), invite.get_account_name())
+                    .hset(origin_member_roles_key.clone(),
+                          member.get_account_id(),
+                          member.write_to_bytes().unwrap())
                     .del(OriginInvitesTable::key(invite.get_id()))
+                    .srem(OriginInvitesTable::account_to_invites_key(&invite.get_account_id()),
+                          invite.get_id())
+                    .srem(OriginInvitesTable::origin_to_invites_key(&invite.get_origin_id()),
+                          invite.get_id())
                     .query(conn.deref())
             }));
         } else {
             // "ignore" the invite, meaning: just delete it
-            try!(conn.del(invite.get_id()));
+            try!(self.invites.delete(invite));
         }
 
         Ok(())
@@ -115,6 +135,11 @@ impl OriginTable {
         format!("origin_members:{}", origin_id)
     }
 
+    /// origin_member_roles stores origin_id -> account_id -> encoded OriginMember
+    pub fn origin_member_roles_key(&self, origin_id: &u64) -> String {
+        format!("origin_member_roles:{}", origin_id)
+    }
+
     /// this is used to add the owner of the account to the full list of members
     /// right after an origin is created
     pub fn add_origin_member(&self,
@@ -128,16 +153,166 @@ impl OriginTable {
         let origin_id = try!(self.name_idx.find(&origin_name.to_string()));
         let account_origins_key = self.account_origins_key(&account_id);
         let origin_members_key = self.origin_members_key(&origin_id);
+        let origin_member_roles_key = self.origin_member_roles_key(&origin_id);
+        let member =
+            Self::member_record(origin_id, account_id, account_name, proto::OriginRole::Owner);
         try!(redis::transaction(conn.deref(),
                                 &[account_origins_key.clone(), origin_members_key.clone()],
                                 |txn| {
                                     txn.sadd(account_origins_key.clone(), origin_name)
                                         .sadd(origin_members_key.clone(), account_name)
+                                        .hset(origin_member_roles_key.clone(),
+                                              account_id,
+                                              member.write_to_bytes().unwrap())
+                                        .query(conn.deref())
+                                }));
+        Ok(())
+    }
+
+    /// Removes an account from an origin, dropping it from both the `account_origins` and
+    /// `origin_members` sets along with its role record.
+    pub fn remove_origin_member(&self,
+                                origin: &proto::Origin,
+                                account_id: u64,
+                                account_name: &str)
+                                -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let account_origins_key = self.account_origins_key(&account_id);
+        let origin_members_key = self.origin_members_key(&origin.get_id());
+        let origin_member_roles_key = self.origin_member_roles_key(&origin.get_id());
+        try!(redis::transaction(conn.deref(),
+                                &[account_origins_key.clone(), origin_members_key.clone()],
+                                |txn| {
+                                    txn.srem(account_origins_key.clone(), origin.get_name())
+                                        .srem(origin_members_key.clone(), account_name)
+                                        .hdel(origin_member_roles_key.clone(), account_id)
                                         .query(conn.deref())
                                 }));
         Ok(())
     }
 
+    /// Makes the given member the owner of record of an origin. The previous owner stays a member
+    /// of the origin as a maintainer.
+    pub fn transfer(&self,
+                    origin: &mut proto::Origin,
+                    new_owner: &proto::OriginMember)
+                    -> dbcache::Result<()> {
+        let previous_owner_id = origin.get_owner_id();
+        let previous_owner = try!(self.list_member_roles(origin.get_id()))
+            .remove(&previous_owner_id);
+        let previous_owner = origin.transfer_to(new_owner, previous_owner);
+        let conn = try!(self.pool().get());
+        let origin_member_roles_key = self.origin_member_roles_key(&origin.get_id());
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .set(Self::key(&origin.get_id()), origin.write_to_bytes().unwrap())
+            .ignore()
+            .hset(origin_member_roles_key.clone(),
+                  new_owner.get_account_id(),
+                  new_owner.write_to_bytes().unwrap())
+            .ignore();
+        // owners who created their origin before roles were recorded have no role record and
+        // are treated as a LEGACY_MEMBER_ROLE once they no longer own the origin
+        if let Some(previous_owner) = previous_owner {
+            pipe.hset(origin_member_roles_key,
+                      previous_owner_id,
+                      previous_owner.write_to_bytes().unwrap())
+                .ignore();
+        }
+        try!(pipe.query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    /// Deletes an origin along with its name index entry, membership sets, member roles, pending
+    /// invitations and tokens.
+    ///
+    /// Only the owner's `account_origins` set is updated, so any other members must be removed
+    /// beforehand.
+    pub fn delete(&self, origin: &proto::Origin) -> dbcache::Result<()> {
+        for invite in try!(self.invites.get_by_origin_id(origin.get_id())) {
+            try!(self.invites.delete(&invite));
+        }
+        for token in try!(self.tokens.list(origin.get_id())) {
+            try!(self.tokens.revoke(&token));
+        }
+        let conn = try!(self.pool().get());
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(Self::key(&origin.get_id()))
+            .ignore()
+            .hdel(OriginNameIdx::prefix(), origin.get_name())
+            .ignore()
+            .srem(self.account_origins_key(&origin.get_owner_id()), origin.get_name())
+            .ignore()
+            .del(self.origin_members_key(&origin.get_id()))
+            .ignore()
+            .del(self.origin_member_roles_key(&origin.get_id()))
+            .ignore()
+            .del(OriginInvitesTable::origin_to_invites_key(&origin.get_id()))
+            .ignore();
+        try!(pipe.query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    /// Returns the role the given account holds within an origin, or `None` if the account is not
+    /// a member of the origin.
+    ///
+    /// Members who joined before roles were recorded have no role record, see `Origin::role_of`.
+    pub fn member_role(&self,
+                       origin: &proto::Origin,
+                       account_id: u64)
+                       -> dbcache::Result<Option<proto::OriginRole>> {
+        if !try!(self.is_origin_member(account_id, origin.get_name())) {
+            return Ok(None);
+        }
+        let conn = try!(self.pool().get());
+        let key = self.origin_member_roles_key(&origin.get_id());
+        let bytes = try!(conn.hget::<String, u64, Vec<u8>>(key, account_id));
+        let record: Option<proto::OriginMember> = if bytes.is_empty() {
+            None
+        } else {
+            Some(protobuf::parse_from_bytes(&bytes).unwrap())
+        };
+        Ok(Some(origin.role_of(account_id, record.as_ref())))
+    }
+
+    /// Records the role of an existing origin member.
+    pub fn set_member_role(&self, member: &proto::OriginMember) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.hset(self.origin_member_roles_key(&member.get_origin_id()),
+                       member.get_account_id(),
+                       member.write_to_bytes().unwrap()));
+        Ok(())
+    }
+
+    /// Returns every recorded member role of an origin, keyed by account id.
+    pub fn list_member_roles(&self,
+                             origin_id: u64)
+                             -> dbcache::Result<HashMap<u64, proto::OriginMember>> {
+        let conn = try!(self.pool().get());
+        let key = self.origin_member_roles_key(&origin_id);
+        let values = try!(conn.hgetall::<String, HashMap<u64, Vec<u8>>>(key));
+        let mut members = HashMap::new();
+        for (account_id, bytes) in values.into_iter() {
+            let member: proto::OriginMember = protobuf::parse_from_bytes(&bytes).unwrap();
+            members.insert(account_id, member);
+        }
+        Ok(members)
+    }
+
+    fn member_record(origin_id: u64,
+                     account_id: u64,
+                     account_name: &str,
+                     role: proto::OriginRole)
+                     -> proto::OriginMember {
+        let mut member = proto::OriginMember::new();
+        member.set_origin_id(origin_id);
+        member.set_account_id(account_id);
+        member.set_account_name(account_name.to_string());
+        member.set_role(role);
+        member
+    }
+
     pub fn list_origin_members(&self, origin_id: u64) -> dbcache::Result<Vec<String>> {
         let origin_members_key = self.origin_members_key(&origin_id);
         let conn = try!(self.pool().get());
@@ -284,12 +459,39 @@ impl OriginInvitesTable {
         OriginInvitesTable { pool: pool }
     }
 
+    /// account_to_invites stores account_id -> invite ids
+    pub fn account_to_invites_key(account_id: &u64) -> String {
+        format!("account_to_invites:{}", account_id)
+    }
+
+    /// origin_to_invites stores origin_id -> invite ids
+    pub fn origin_to_invites_key(origin_id: &u64) -> String {
+        format!("origin_to_invites:{}", origin_id)
+    }
+
+    /// Removes a pending invitation from the account and origin invitation sets and deletes it.
+    pub fn delete(&self, invite: &proto::OriginInvitation) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(Self::key(invite.get_id()))
+            .ignore()
+            .srem(Self::account_to_invites_key(&invite.get_account_id()),
+                  invite.get_id())
+            .ignore()
+            .srem(Self::origin_to_invites_key(&invite.get_origin_id()),
+                  invite.get_id())
+            .ignore();
+        try!(pipe.query::<()>(conn.deref()));
+        Ok(())
+    }
+
     /// return a Vec of invite_id's for a given account
     pub fn get_by_account_id(&self,
                              account_id: u64)
                              -> dbcache::Result<Vec<proto::OriginInvitation>> {
         let conn = self.pool().get().unwrap();
-        let account_to_invites_key = format!("account_to_invites:{}", &account_id);
+        let account_to_invites_key = Self::account_to_invites_key(&account_id);
         match conn.smembers::<String, Vec<u64>>(account_to_invites_key) {
             Ok(invite_ids) => {
                 let account_invites = invite_ids.iter().fold(Vec::new(),
@@ -315,7 +517,7 @@ impl OriginInvitesTable {
                             origin_id: u64)
                             -> dbcache::Result<Vec<proto::OriginInvitation>> {
         let conn = self.pool().get().unwrap();
-        let origin_to_invites_key = format!("origin_to_invites:{}", &origin_id);
+        let origin_to_invites_key = Self::origin_to_invites_key(&origin_id);
         match conn.smembers::<String, Vec<u64>>(origin_to_invites_key) {
             Ok(invite_ids) => {
                 let origin_invites = invite_ids.iter().fold(Vec::new(), |mut acc, ref invite_id| {
@@ -362,8 +564,8 @@ impl InstaSet for OriginInvitesTable {
             };
             let insta_id = InstaId::generate(sequence_id);
             record.set_primary_key(*insta_id);
-            let account_to_invites_key = format!("account_to_invites:{}", record.get_account_id());
-            let origin_to_invites_key = format!("origin_to_invites:{}", record.get_origin_id());
+            let account_to_invites_key = Self::account_to_invites_key(&record.get_account_id());
+            let origin_to_invites_key = Self::origin_to_invites_key(&record.get_origin_id());
             debug!("origin invite = {:?}", &record);
             txn.set(Self::seq_id(), record.primary_key())
                 .ignore()
@@ -380,3 +582,110 @@ impl InstaSet for OriginInvitesTable {
         Ok(())
     }
 }
+
+pub struct OriginTokensTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl OriginTokensTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        OriginTokensTable { pool: pool }
+    }
+
+    /// origin_tokens stores origin_id -> token id -> digest of the token's bearer secret
+    pub fn origin_tokens_key(origin_id: &u64) -> String {
+        format!("origin_tokens:{}", origin_id)
+    }
+
+    /// Returns the token whose bearer secret hashes to the given digest.
+    pub fn find_by_digest(&self, digest: &str) -> dbcache::Result<proto::OriginToken> {
+        let conn = try!(self.pool().get());
+        match try!(conn.hget::<&'static str, &str, Option<u64>>(ORIGIN_TOKEN_DIGEST_IDX, digest)) {
+            Some(id) => self.find(&id),
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    /// Returns every token of the given origin. Bearer secrets are never stored so none of the
+    /// returned tokens carry one.
+    pub fn list(&self, origin_id: u64) -> dbcache::Result<Vec<proto::OriginToken>> {
+        let conn = try!(self.pool().get());
+        let ids = try!(conn.hkeys::<String, Vec<u64>>(Self::origin_tokens_key(&origin_id)));
+        let mut tokens = vec![];
+        for id in ids.iter() {
+            match self.find(id) {
+                Ok(token) => tokens.push(token),
+                Err(e) => debug!("Can't find origin token for token_id {}:{}", id, e),
+            }
+        }
+        tokens.sort_by(|a, b| a.get_id().cmp(&b.get_id()));
+        Ok(tokens)
+    }
+
+    /// Write a new token, indexed by the digest of its bearer secret. The secret itself is
+    /// cleared from the stored record.
+    pub fn create(&self, record: &mut proto::OriginToken, digest: &str) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let secret = record.take_token();
+        try!(redis::transaction(conn.deref(), &[Self::seq_id()], |txn| {
+            let sequence_id: u64 = match conn.get::<&'static str, u64>(Self::seq_id()) {
+                Ok(value) => value + 1,
+                _ => 0,
+            };
+            let insta_id = InstaId::generate(sequence_id);
+            record.set_primary_key(*insta_id);
+            txn.set(Self::seq_id(), record.primary_key())
+                .ignore()
+                .set(Self::key(&record.primary_key()),
+                     record.write_to_bytes().unwrap())
+                .ignore()
+                .hset(ORIGIN_TOKEN_DIGEST_IDX, digest, record.primary_key())
+                .ignore()
+                .hset(Self::origin_tokens_key(&record.get_origin_id()),
+                      record.primary_key(),
+                      digest)
+                .ignore()
+                .query(conn.deref())
+        }));
+        record.set_token(secret);
+        Ok(())
+    }
+
+    /// Removes a token and its index entries. Requests bearing the token are rejected from then
+    /// on.
+    pub fn revoke(&self, record: &proto::OriginToken) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let origin_tokens_key = Self::origin_tokens_key(&record.get_origin_id());
+        let digest = try!(conn.hget::<String, u64, Option<String>>(origin_tokens_key.clone(),
+                                                                   record.primary_key()));
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(Self::key(&record.primary_key()))
+            .ignore()
+            .hdel(origin_tokens_key, record.primary_key())
+            .ignore();
+        if let Some(digest) = digest {
+            pipe.hdel(ORIGIN_TOKEN_DIGEST_IDX, digest).ignore();
+        }
+        try!(pipe.query::<()>(conn.deref()));
+        Ok(())
+    }
+}
+
+impl Bucket for OriginTokensTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "origin_token"
+    }
+}
+
+impl InstaSet for OriginTokensTable {
+    type Record = vault::OriginToken;
+
+    fn seq_id() -> &'static str {
+        "origin_token_seq"
+    }
+}
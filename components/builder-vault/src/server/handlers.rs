@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use dbcache::{self, ExpiringSet, IndexSet, InstaSet};
+use hab_core::crypto;
+use hab_core::crypto::hash;
 use hab_net::server::Envelope;
 use protobuf::RepeatedField;
 use protocol::net::{self, ErrCode};
@@ -51,11 +53,22 @@ pub fn origin_check_access(req: &mut Envelope,
     // !!!NOTE!!!
     // !!!NOTE!!!
     let msg: proto::CheckOriginAccessRequest = try!(req.parse_msg());
-    let is_member = try!(state.datastore
-        .origins
-        .is_origin_member(msg.get_account_id(), msg.get_origin_name()));
     let mut resp = proto::CheckOriginAccessResponse::new();
-    resp.set_has_access(is_member);
+    resp.set_has_access(false);
+    let origin = match state.datastore.origins.name_idx.find(&msg.get_origin_name().to_string()) {
+        Ok(origin_id) => try!(state.datastore.origins.find(&origin_id)),
+        Err(e) => {
+            debug!("Can't find origin {} to check access: {}",
+                   msg.get_origin_name(),
+                   e);
+            try!(req.reply_complete(sock, &resp));
+            return Ok(());
+        }
+    };
+    if let Some(role) = try!(state.datastore.origins.member_role(&origin, msg.get_account_id())) {
+        resp.set_has_access(role.satisfies(msg.get_required_role()));
+        resp.set_role(role);
+    }
     try!(req.reply_complete(sock, &resp));
     Ok(())
 }
@@ -68,6 +81,7 @@ pub fn origin_create(req: &mut Envelope,
     let mut origin = proto::Origin::new();
     origin.set_name(msg.get_name().to_string());
     origin.set_owner_id(msg.get_owner_id());
+    origin.set_private(msg.get_private());
 
     if let Ok(_origin) = state.datastore
         .origins
@@ -89,6 +103,31 @@ pub fn origin_create(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_delete(req: &mut Envelope,
+                     sock: &mut zmq::Socket,
+                     state: &mut ServerState)
+                     -> Result<()> {
+    let msg: proto::OriginDelete = try!(req.parse_msg());
+    let origin = match state.datastore.origins.name_idx.find(&msg.get_name().to_string()) {
+        Ok(origin_id) => try!(state.datastore.origins.find(&origin_id)),
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-delete:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    // every other member has to be removed first so no account keeps a stale membership
+    let members = try!(state.datastore.origins.list_origin_members(origin.get_id())).len();
+    if let Err(err) = origin.check_delete(msg.get_requester_id(), members) {
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    try!(state.datastore.origins.delete(&origin));
+    try!(req.reply_complete(sock, &origin));
+    Ok(())
+}
+
 pub fn origin_get(req: &mut Envelope,
                   sock: &mut zmq::Socket,
                   state: &mut ServerState)
@@ -112,6 +151,68 @@ pub fn origin_get(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_update(req: &mut Envelope,
+                     sock: &mut zmq::Socket,
+                     state: &mut ServerState)
+                     -> Result<()> {
+    let msg: proto::OriginUpdate = try!(req.parse_msg());
+    let mut origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-update:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    match try!(state.datastore.origins.member_role(&origin, msg.get_requester_id())) {
+        Some(proto::OriginRole::Owner) => (),
+        _ => {
+            let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-update:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+    if msg.has_private() {
+        origin.set_private(msg.get_private());
+    }
+    try!(state.datastore.origins.update(&origin));
+    try!(req.reply_complete(sock, &origin));
+    Ok(())
+}
+
+pub fn origin_transfer(req: &mut Envelope,
+                       sock: &mut zmq::Socket,
+                       state: &mut ServerState)
+                       -> Result<()> {
+    let msg: proto::OriginTransfer = try!(req.parse_msg());
+    let mut origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-transfer:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let account_role = try!(state.datastore.origins.member_role(&origin, msg.get_account_id()));
+    if let Err(err) = origin.check_transfer(msg.get_requester_id(),
+                                            msg.get_account_id(),
+                                            account_role) {
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+
+    let mut owner = proto::OriginMember::new();
+    owner.set_origin_id(origin.get_id());
+    owner.set_account_id(msg.get_account_id());
+    owner.set_account_name(msg.get_account_name().to_string());
+    owner.set_role(proto::OriginRole::Owner);
+    try!(state.datastore.origins.transfer(&mut origin, &owner));
+    try!(req.reply_complete(sock, &origin));
+    Ok(())
+}
+
 pub fn origin_invitation_accept(req: &mut Envelope,
                                 sock: &mut zmq::Socket,
                                 state: &mut ServerState)
@@ -206,6 +307,38 @@ pub fn origin_invitation_list(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_invitation_rescind(req: &mut Envelope,
+                                 sock: &mut zmq::Socket,
+                                 state: &mut ServerState)
+                                 -> Result<()> {
+    let msg: proto::OriginInvitationRescind = try!(req.parse_msg());
+    let invite = match state.datastore.origins.invites.find(&msg.get_invite_id()) {
+        Ok(ref invite) if invite.get_origin_id() != msg.get_origin_id() => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-invite-rescind:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Ok(invite) => invite,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-invite-rescind:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let origin = try!(state.datastore.origins.find(&invite.get_origin_id()));
+    let requester_role = try!(state.datastore
+        .origins
+        .member_role(&origin, msg.get_requester_id()));
+    if let Err(err) = invite.check_rescind(msg.get_requester_id(), requester_role) {
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    try!(state.datastore.origins.invites.delete(&invite));
+    try!(req.reply_complete(sock, &invite));
+    Ok(())
+}
+
 pub fn origin_list(req: &mut Envelope,
                    sock: &mut zmq::Socket,
                    _state: &mut ServerState)
@@ -233,13 +366,110 @@ pub fn origin_member_list(req: &mut Envelope,
     for member in members {
         r_members.push(member);
     }
+    let mut roles: Vec<proto::OriginMember> = try!(state.datastore
+            .origins
+            .list_member_roles(msg.get_origin_id()))
+        .into_iter()
+        .map(|(_, member)| member)
+        .collect();
+    roles.sort_by(|a, b| a.get_account_name().cmp(b.get_account_name()));
     let mut resp = proto::OriginMemberListResponse::new();
     resp.set_origin_id(msg.get_origin_id());
     resp.set_members(r_members);
+    resp.set_roles(RepeatedField::from_vec(roles));
     try!(req.reply_complete(sock, &resp));
     Ok(())
 }
 
+pub fn origin_member_remove(req: &mut Envelope,
+                            sock: &mut zmq::Socket,
+                            state: &mut ServerState)
+                            -> Result<()> {
+    let msg: proto::OriginMemberRemove = try!(req.parse_msg());
+    let origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-remove:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let requester_role = try!(state.datastore
+        .origins
+        .member_role(&origin, msg.get_requester_id()));
+    let role = try!(state.datastore.origins.member_role(&origin, msg.get_user_id()));
+    if let Err(err) = origin.check_member_remove(msg.get_requester_id(),
+                                                 requester_role,
+                                                 msg.get_user_id(),
+                                                 role) {
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    let role = role.unwrap();
+
+    try!(state.datastore
+        .origins
+        .remove_origin_member(&origin, msg.get_user_id(), msg.get_account_name()));
+    // tokens act on behalf of their creator and must not outlive their membership
+    for token in try!(state.datastore.origins.tokens.list(origin.get_id())) {
+        if token.get_owner_id() == msg.get_user_id() {
+            try!(state.datastore.origins.tokens.revoke(&token));
+        }
+    }
+    let mut member = proto::OriginMember::new();
+    member.set_origin_id(origin.get_id());
+    member.set_account_id(msg.get_user_id());
+    member.set_account_name(msg.get_account_name().to_string());
+    member.set_role(role);
+    try!(req.reply_complete(sock, &member));
+    Ok(())
+}
+
+pub fn origin_member_role_update(req: &mut Envelope,
+                                 sock: &mut zmq::Socket,
+                                 state: &mut ServerState)
+                                 -> Result<()> {
+    let msg: proto::OriginMemberRoleUpdate = try!(req.parse_msg());
+    let origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-role-update:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    match try!(state.datastore.origins.member_role(&origin, msg.get_requester_id())) {
+        Some(proto::OriginRole::Owner) => (),
+        _ => {
+            let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-member-role-update:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+    // the owner of record can only give up ownership by transferring the origin
+    if msg.get_account_id() == origin.get_owner_id() {
+        let err = net::err(ErrCode::ENTITY_CONFLICT, "vt:origin-member-role-update:2");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    if try!(state.datastore.origins.member_role(&origin, msg.get_account_id())).is_none() {
+        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-role-update:3");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+
+    let mut member = proto::OriginMember::new();
+    member.set_origin_id(origin.get_id());
+    member.set_account_id(msg.get_account_id());
+    member.set_account_name(msg.get_account_name().to_string());
+    member.set_role(msg.get_role());
+    try!(state.datastore.origins.set_member_role(&member));
+    try!(req.reply_complete(sock, &member));
+    Ok(())
+}
+
 pub fn account_origin_list(req: &mut Envelope,
                            sock: &mut zmq::Socket,
                            state: &mut ServerState)
@@ -273,3 +503,130 @@ pub fn origin_secret_key_create(req: &mut Envelope,
     try!(req.reply_complete(sock, &pk));
     Ok(())
 }
+
+pub fn origin_token_create(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    let msg: proto::OriginTokenCreate = try!(req.parse_msg());
+    let origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-token-create:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    // a token can never carry more privileges than the account which created it
+    match try!(state.datastore.origins.member_role(&origin, msg.get_owner_id())) {
+        Some(role) if role.satisfies(proto::OriginRole::Maintainer) &&
+                      role.satisfies(msg.get_role()) => (),
+        _ => {
+            let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-token-create:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+
+    let secret = format!("{}{}",
+                         proto::ORIGIN_TOKEN_PREFIX,
+                         crypto::generate_token(proto::ORIGIN_TOKEN_BYTES));
+    let digest = try!(hash::hash_string(&secret));
+    let mut token = proto::OriginToken::new();
+    token.set_origin_id(origin.get_id());
+    token.set_origin_name(origin.get_name().to_string());
+    token.set_owner_id(msg.get_owner_id());
+    token.set_name(msg.get_name().to_string());
+    token.set_role(msg.get_role());
+    token.set_token(secret);
+    try!(state.datastore.origins.tokens.create(&mut token, &digest));
+    try!(req.reply_complete(sock, &token));
+    Ok(())
+}
+
+pub fn origin_token_get(req: &mut Envelope,
+                        sock: &mut zmq::Socket,
+                        state: &mut ServerState)
+                        -> Result<()> {
+    let msg: proto::OriginTokenGet = try!(req.parse_msg());
+    let digest = try!(hash::hash_string(msg.get_token()));
+    match state.datastore.origins.tokens.find_by_digest(&digest) {
+        Ok(mut token) => {
+            // the token is only as good as the current membership of the account which created it
+            let origin = try!(state.datastore.origins.find(&token.get_origin_id()));
+            let owner_role = try!(state.datastore
+                .origins
+                .member_role(&origin, token.get_owner_id()));
+            match token.effective_role(owner_role) {
+                Some(role) => {
+                    token.set_role(role);
+                    try!(req.reply_complete(sock, &token));
+                }
+                None => {
+                    let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-token-get:2");
+                    try!(req.reply_complete(sock, &err));
+                }
+            }
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-token-get:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("OriginTokenGet, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "vt:origin-token-get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_token_list(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::OriginTokenListRequest = try!(req.parse_msg());
+    let tokens = try!(state.datastore.origins.tokens.list(msg.get_origin_id()));
+    let mut resp = proto::OriginTokenListResponse::new();
+    resp.set_origin_id(msg.get_origin_id());
+    resp.set_tokens(RepeatedField::from_vec(tokens));
+    try!(req.reply_complete(sock, &resp));
+    Ok(())
+}
+
+pub fn origin_token_revoke(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    let msg: proto::OriginTokenRevoke = try!(req.parse_msg());
+    let token = match state.datastore.origins.tokens.find(&msg.get_token_id()) {
+        Ok(ref token) if token.get_origin_id() != msg.get_origin_id() => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-token-revoke:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Ok(token) => token,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-token-revoke:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let origin = try!(state.datastore.origins.find(&token.get_origin_id()));
+    // owners may revoke any token of their origin, everyone else only the tokens they created
+    let allowed = match try!(state.datastore.origins.member_role(&origin, msg.get_requester_id())) {
+        Some(proto::OriginRole::Owner) => true,
+        Some(_) => token.get_owner_id() == msg.get_requester_id(),
+        None => false,
+    };
+    if !allowed {
+        let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-token-revoke:1");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    try!(state.datastore.origins.tokens.revoke(&token));
+    try!(req.reply_complete(sock, &token));
+    Ok(())
+}
@@ -75,16 +75,26 @@ impl Dispatcher for Worker {
             }
             "CheckOriginAccessRequest" => handlers::origin_check_access(message, sock, state),
             "OriginCreate" => handlers::origin_create(message, sock, state),
+            "OriginDelete" => handlers::origin_delete(message, sock, state),
             "OriginGet" => handlers::origin_get(message, sock, state),
+            "OriginTransfer" => handlers::origin_transfer(message, sock, state),
+            "OriginUpdate" => handlers::origin_update(message, sock, state),
             "OriginInvitationAcceptRequest" => {
                 handlers::origin_invitation_accept(message, sock, state)
             }
             "OriginInvitationCreate" => handlers::origin_invitation_create(message, sock, state),
             "OriginInvitationListRequest" => handlers::origin_invitation_list(message, sock, state),
+            "OriginInvitationRescind" => handlers::origin_invitation_rescind(message, sock, state),
             "OriginList" => handlers::origin_list(message, sock, state),
             "OriginMemberListRequest" => handlers::origin_member_list(message, sock, state),
+            "OriginMemberRemove" => handlers::origin_member_remove(message, sock, state),
+            "OriginMemberRoleUpdate" => handlers::origin_member_role_update(message, sock, state),
             "AccountOriginListRequest" => handlers::account_origin_list(message, sock, state),
             "OriginSecretKeyCreate" => handlers::origin_secret_key_create(message, sock, state),
+            "OriginTokenCreate" => handlers::origin_token_create(message, sock, state),
+            "OriginTokenGet" => handlers::origin_token_get(message, sock, state),
+            "OriginTokenListRequest" => handlers::origin_token_list(message, sock, state),
+            "OriginTokenRevoke" => handlers::origin_token_revoke(message, sock, state),
             _ => panic!("unhandled message"),
         }
     }
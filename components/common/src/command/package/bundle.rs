@@ -0,0 +1,298 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bundles a Habitat package for installing it where no [depot](../depot) can be reached.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle core/redis
+//! ```
+//!
+//! Will write `core-redis-3.0.7-20160614231131-bundle.tar`, which is installed with:
+//!
+//! ```bash
+//! $ hab pkg install core-redis-3.0.7-20160614231131-bundle.tar
+//! ```
+//!
+//! # Internals
+//!
+//! A bundle is a tarball holding the artifacts of the package and of all its dependencies, the
+//! public origin keys which signed them, and an `IDENT` file naming the bundled package:
+//!
+//! ```text
+//! IDENT
+//! artifacts/core-redis-3.0.7-20160614231131-x86_64-linux.hart
+//! keys/core-20160423193745.pub
+//! ```
+//!
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ansi_term::Colour::{Blue, Green, Yellow};
+use depot_client::Client;
+use hcore::crypto::{artifact, SigKeyPair};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::package::{Identifiable, Lockfile, PackageArchive, PackageIdent};
+use tar;
+
+use command::package::install;
+use error::{Error, Result};
+
+const IDENT_FILE: &'static str = "IDENT";
+const ARTIFACTS_DIR: &'static str = "artifacts";
+const KEYS_DIR: &'static str = "keys";
+
+/// Writes a bundle of the latest release of a package which satisfies `ident` to `dst`, or to
+/// a file named after the package in the current directory. The artifacts and keys are taken
+/// from the caches, and downloaded from the depot when they are missing.
+///
+/// # Failures
+///
+/// * Fails if the package, one of its dependencies or a signing key cannot be downloaded
+/// * Fails if the bundle cannot be written
+pub fn start(url: &str,
+             token: Option<&str>,
+             ident: &PackageIdent,
+             dst: Option<&Path>,
+             fs_root_path: &Path,
+             cache_artifact_path: &Path,
+             cache_key_path: &Path)
+             -> Result<PathBuf> {
+    println!("{}",
+             Yellow.bold().paint(format!("» Bundling {}", ident)));
+    let depot_client = try!(Client::new(url, Some(fs_root_path)));
+    let pkg_data = try!(depot_client.show_package(ident.clone(), token));
+    let pkg_ident: PackageIdent = pkg_data.get_ident().clone().into();
+    let mut idents: Vec<PackageIdent> =
+        pkg_data.get_tdeps().iter().map(|dep| (*dep).clone().into()).collect();
+    idents.push(pkg_ident.clone());
+    // The checksum of the package is on hand already, so it isn't looked up again to download it
+    let mut pinned = Lockfile::default();
+    pinned.push(pkg_ident.clone(), pkg_data.get_checksum().to_string());
+    let archives = try!(install::download_all(url,
+                                              token,
+                                              &idents,
+                                              Some(&pinned),
+                                              fs_root_path,
+                                              cache_artifact_path));
+
+    let mut signers = BTreeSet::new();
+    for archive in archives.iter() {
+        for nwr in try!(artifact::artifact_signers(&archive.path)) {
+            signers.insert(nwr);
+        }
+    }
+    for nwr in signers.iter() {
+        if let Err(_) = SigKeyPair::get_public_key_path(nwr, cache_key_path) {
+            println!("{} {} public origin key",
+                     Green.bold().paint("↓ Downloading"),
+                     nwr);
+            let (name, rev) = try!(parse_name_with_rev(nwr));
+            try!(depot_client.fetch_origin_key(&name, &rev, cache_key_path, None));
+        }
+    }
+
+    let dst = match dst {
+        Some(dst) => dst.to_path_buf(),
+        None => {
+            PathBuf::from(format!("{}-{}-{}-{}-bundle.tar",
+                                  pkg_ident.origin(),
+                                  pkg_ident.name(),
+                                  pkg_ident.version().unwrap(),
+                                  pkg_ident.release().unwrap()))
+        }
+    };
+    let mut keyfiles = Vec::new();
+    for nwr in signers.iter() {
+        keyfiles.push(try!(SigKeyPair::get_public_key_path(nwr, cache_key_path)));
+    }
+    try!(write(&dst, &pkg_ident, &archives, &keyfiles));
+    println!("{}",
+             Blue.paint(format!("★ Bundled {} with {} packages into {}",
+                                &pkg_ident,
+                                archives.len(),
+                                dst.display())));
+    Ok(dst)
+}
+
+/// Writes a bundle of the package `ident` holding the given artifacts and public key files.
+fn write(dst: &Path,
+         ident: &PackageIdent,
+         archives: &[PackageArchive],
+         keyfiles: &[PathBuf])
+         -> Result<()> {
+    let mut builder = tar::Builder::new(try!(File::create(dst)));
+    let content = format!("{}\n", ident);
+    let mut header = tar::Header::new_gnu();
+    try!(header.set_path(IDENT_FILE));
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    try!(builder.append(&header, content.as_bytes()));
+    for archive in archives.iter() {
+        let mut file = try!(File::open(&archive.path));
+        try!(builder.append_file(Path::new(ARTIFACTS_DIR).join(archive.file_name()), &mut file));
+    }
+    for keyfile in keyfiles.iter() {
+        let mut file = try!(File::open(keyfile));
+        try!(builder.append_file(Path::new(KEYS_DIR).join(keyfile.file_name().unwrap()),
+                                 &mut file));
+    }
+    try!(builder.finish());
+    Ok(())
+}
+
+/// Unpacks the artifacts and public keys of a bundle into the artifact and key caches, and
+/// returns the identifier of the bundled package. Keys which are cached already are kept.
+///
+/// # Failures
+///
+/// * Fails if the bundle cannot be read or has no `IDENT` file
+/// * Fails if an entry of the bundle is not a regular file or directory
+/// * Fails if the caches cannot be written to
+pub fn unpack(bundle: &Path,
+              cache_artifact_path: &Path,
+              cache_key_path: &Path)
+              -> Result<PackageIdent> {
+    try!(fs::create_dir_all(cache_artifact_path));
+    try!(fs::create_dir_all(cache_key_path));
+    let mut archive = tar::Archive::new(try!(File::open(bundle)));
+    let mut ident = None;
+    for entry in try!(archive.entries()) {
+        let mut entry = try!(entry);
+        let path = try!(entry.path()).into_owned();
+        // Only regular files are unpacked, so an entry can't link to a path outside the caches
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_dir() {
+            continue;
+        }
+        if !entry_type.is_file() {
+            return Err(Error::InvalidBundleEntry(bundle.display().to_string(),
+                                                 path.display().to_string()));
+        }
+        if path == Path::new(IDENT_FILE) {
+            let mut content = String::new();
+            try!(entry.read_to_string(&mut content));
+            ident = Some(try!(PackageIdent::from_str(content.trim())));
+            continue;
+        }
+        let (dir, file_name) = match (path.parent(), path.file_name()) {
+            (Some(dir), Some(file_name)) => (dir, file_name),
+            _ => continue,
+        };
+        let dst = if dir == Path::new(ARTIFACTS_DIR) {
+            cache_artifact_path.join(file_name)
+        } else if dir == Path::new(KEYS_DIR) {
+            let dst = cache_key_path.join(file_name);
+            if dst.is_file() {
+                continue;
+            }
+            dst
+        } else {
+            continue;
+        };
+        debug!("Unpacking {} to {}", path.display(), dst.display());
+        try!(entry.unpack(&dst));
+    }
+    match ident {
+        Some(ident) => Ok(ident),
+        None => Err(Error::InvalidBundle(bundle.display().to_string())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use hcore::package::{PackageArchive, PackageIdent};
+    use tar;
+    use tempdir::TempDir;
+
+    use error::Error;
+    use super::{unpack, write};
+
+    const ARCHIVE: &'static str = "core-redis-3.0.7-20160614231131-x86_64-linux.hart";
+    const KEY: &'static str = "core-20160423193745.pub";
+
+    fn write_file(path: &Path, content: &str) -> PathBuf {
+        File::create(path).unwrap().write_all(content.as_bytes()).unwrap();
+        path.to_path_buf()
+    }
+
+    fn read_file(path: &Path) -> String {
+        let mut content = String::new();
+        File::open(path).unwrap().read_to_string(&mut content).unwrap();
+        content
+    }
+
+    #[test]
+    fn write_and_unpack() {
+        let src = TempDir::new("src").unwrap();
+        let artifacts = TempDir::new("artifact_cache").unwrap();
+        let keys = TempDir::new("key_cache").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.0.7/20160614231131").unwrap();
+        let archive = PackageArchive::new(write_file(&src.path().join(ARCHIVE), "artifact"));
+        let keyfile = write_file(&src.path().join(KEY), "bundled key");
+        let bundle = src.path().join("bundle.tar");
+        write(&bundle, &ident, &[archive], &[keyfile]).unwrap();
+        write_file(&keys.path().join(KEY), "cached key");
+
+        assert_eq!(unpack(&bundle, artifacts.path(), keys.path()).unwrap(), ident);
+        assert_eq!(read_file(&artifacts.path().join(ARCHIVE)), "artifact");
+        assert_eq!(read_file(&keys.path().join(KEY)), "cached key");
+    }
+
+    #[test]
+    fn unpack_refuses_links() {
+        let src = TempDir::new("src").unwrap();
+        let artifacts = TempDir::new("artifact_cache").unwrap();
+        let keys = TempDir::new("key_cache").unwrap();
+        let bundle = src.path().join("bundle.tar");
+        {
+            let mut builder = tar::Builder::new(File::create(&bundle).unwrap());
+            let content = "core/redis/3.0.7/20160614231131\n";
+            let mut header = tar::Header::new_gnu();
+            header.set_path("IDENT").unwrap();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append(&header, content.as_bytes()).unwrap();
+            let mut header = tar::Header::new_gnu();
+            header.set_path(format!("artifacts/{}", ARCHIVE)).unwrap();
+            header.set_link_name("/etc/passwd").unwrap();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            header.set_mode(0o777);
+            header.set_cksum();
+            builder.append(&header, &[][..]).unwrap();
+            builder.finish().unwrap();
+        }
+
+        match unpack(&bundle, artifacts.path(), keys.path()) {
+            Err(Error::InvalidBundleEntry(_, entry)) => {
+                assert_eq!(entry, format!("artifacts/{}", ARCHIVE))
+            }
+            _ => panic!("Expected a bundle with a symlink to be refused"),
+        }
+        assert!(!artifacts.path().join(ARCHIVE).exists());
+    }
+}
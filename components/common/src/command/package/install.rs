@@ -30,63 +30,124 @@
 //!
 //! # Internals
 //!
-//! * Download the artifact
-//! * Verify it is un-altered
-//! * Unpack it
+//! * Download the artifacts of the package and its dependencies which are not installed yet, a
+//!   few at a time, resuming partial downloads left in the artifact cache
+//! * Check each artifact against the checksum the depot has on record for it
+//! * Fetch the public keys of its signers
+//! * Unpack it while verifying it is un-altered, in a single pass over the artifact
+//!
+//! # Offline mode
+//!
+//! With `--offline` the package and all of its dependencies are installed from the artifact
+//! cache, and the public keys of their signers must be in the key cache already, so the depot
+//! is never contacted. A bundle made with `hab pkg bundle` is unpacked into both caches before
+//! it is installed offline.
+//!
+//! # Lockfiles
+//!
+//! A lockfile written by `hab pkg lock` lists the exact releases to install along with the
+//! checksums of their artifacts. Installing from it installs just those releases, and every
+//! artifact must match its locked checksum rather than the one the depot has on record.
+//!
+//! # Strict mode
+//!
+//! When a `TrustStore` is given, every artifact must be signed only by origin keys pinned in the
+//! trust store. Missing public keys are still downloaded from the depot, but a downloaded key is
+//! only accepted if it matches its pinned fingerprint.
 //!
 
+use std::cmp;
+use std::collections::HashSet;
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
 use ansi_term::Colour::{Blue, Green, Yellow};
-use depot_client::Client;
-use hcore::crypto::{artifact, SigKeyPair};
-use hcore::crypto::keys::parse_name_with_rev;
+use depot_client::{Client, DisplayProgress};
+use hcore::crypto::{artifact, hash, KeyRevocation, SigKeyPair, TrustStore};
+use hcore::crypto::artifact::VerifyPolicy;
+use hcore::crypto::keys::{parse_name_with_rev, revocation};
 use hcore::fs::cache_artifact_path;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, PackageInstall};
+use hcore::package::{Identifiable, Lockfile, PackageArchive, PackageIdent, PackageInstall};
 use protocol::depotsrv;
 
 use command::ProgressBar;
-use error::Result;
+use command::package::bundle;
+use error::{Error, Result};
+
+/// The number of artifacts which are downloaded at the same time.
+const DOWNLOAD_CONCURRENCY: usize = 4;
 
 pub fn start<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
+                                                 token: Option<&str>,
                                                  ident_or_archive: &str,
                                                  fs_root_path: &P1,
                                                  cache_artifact_path: &P2,
-                                                 cache_key_path: &P3)
-                                                 -> Result<()>
+                                                 cache_key_path: &P3,
+                                                 trust_store: Option<&TrustStore>,
+                                                 offline: bool)
+                                                 -> Result<PackageIdent>
     where P1: AsRef<Path>,
           P2: AsRef<Path>,
           P3: AsRef<Path>
 {
-    if Path::new(ident_or_archive).is_file() {
-        try!(from_archive(url,
-                          &ident_or_archive,
-                          fs_root_path,
-                          cache_artifact_path,
-                          cache_key_path));
+    let path = Path::new(ident_or_archive);
+    if path.is_file() && path.extension().map_or(false, |ext| ext == "tar") {
+        let ident = try!(bundle::unpack(path,
+                                        cache_artifact_path.as_ref(),
+                                        cache_key_path.as_ref()));
+        from_cache(&ident,
+                   fs_root_path,
+                   cache_artifact_path,
+                   cache_key_path,
+                   trust_store)
+    } else if path.is_file() {
+        from_archive(url,
+                     token,
+                     &ident_or_archive,
+                     fs_root_path,
+                     cache_artifact_path,
+                     cache_key_path,
+                     trust_store,
+                     offline)
+    } else if offline {
+        let ident = try!(PackageIdent::from_str(ident_or_archive));
+        from_cache(&ident,
+                   fs_root_path,
+                   cache_artifact_path,
+                   cache_key_path,
+                   trust_store)
     } else {
         let ident = try!(PackageIdent::from_str(ident_or_archive));
-        try!(from_url(url,
-                      &ident,
-                      fs_root_path,
-                      cache_artifact_path,
-                      cache_key_path));
+        let pkg_data = try!(from_url(url,
+                                     token,
+                                     &ident,
+                                     fs_root_path,
+                                     cache_artifact_path,
+                                     cache_key_path,
+                                     trust_store));
+        Ok(pkg_data.get_ident().clone().into())
     }
-    Ok(())
 }
 
 /// Given a package name and a base url, downloads the package
 /// to the cache artifact path. Returns the filename in the cache as a String
 ///
+/// The optional token is passed along to the Depot so packages of private origins can be
+/// installed. If a trust store is given, every artifact is verified in strict mode.
+///
 /// # Failures
 ///
 /// * Fails if it cannot download the package from the upstream
 pub fn from_url<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
+                                                    token: Option<&str>,
                                                     ident: &PackageIdent,
                                                     fs_root_path: &P1,
                                                     cache_artifact_path: &P2,
-                                                    cache_key_path: &P3)
+                                                    cache_key_path: &P3,
+                                                    trust_store: Option<&TrustStore>)
                                                     -> Result<depotsrv::Package>
     where P1: AsRef<Path>,
           P2: AsRef<Path>,
@@ -95,22 +156,28 @@ pub fn from_url<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
     println!("{}",
              Yellow.bold().paint(format!("» Installing {}", ident)));
     let depot_client = try!(Client::new(url, Some(fs_root_path.as_ref())));
-    let pkg_data = try!(depot_client.show_package(ident.clone()));
-    for dep in pkg_data.get_tdeps().into_iter() {
-        let d: PackageIdent = (*dep).clone().into();
-        try!(install_from_depot(url,
-                                &d,
-                                &d,
-                                fs_root_path.as_ref(),
-                                cache_artifact_path.as_ref(),
-                                cache_key_path.as_ref()));
-    }
+    let pkg_data = try!(depot_client.show_package(ident.clone(), token));
+    let mut idents: Vec<(PackageIdent, PackageIdent)> = pkg_data.get_tdeps()
+        .iter()
+        .map(|dep| {
+            let d: PackageIdent = (*dep).clone().into();
+            (d.clone(), d)
+        })
+        .collect();
+    let pkg_ident: PackageIdent = pkg_data.get_ident().clone().into();
+    idents.push((pkg_ident.clone(), ident.clone()));
+    // The checksum of the package is on hand already, so it isn't looked up again to download it
+    let mut pinned = Lockfile::default();
+    pinned.push(pkg_ident, pkg_data.get_checksum().to_string());
     try!(install_from_depot(url,
-                            &pkg_data.get_ident().clone().into(),
-                            ident,
+                            token,
+                            &idents,
+                            Some(&pinned),
                             fs_root_path.as_ref(),
                             cache_artifact_path.as_ref(),
-                            cache_key_path.as_ref()));
+                            cache_key_path.as_ref(),
+                            trust_store));
+    warn_conflicts(&pkg_data.get_ident().clone().into(), fs_root_path.as_ref());
     println!("{}",
              Blue.paint(format!("★ Install of {} complete with {} packages installed.",
                                 ident,
@@ -118,12 +185,18 @@ pub fn from_url<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
     Ok(pkg_data)
 }
 
-pub fn from_archive<P1: ?Sized, P2: ?Sized, P3: ?Sized, P4: ?Sized>(url: &str,
-                                                                    path: &P1,
-                                                                    fs_root_path: &P2,
-                                                                    cache_artifact_path: &P3,
-                                                                    cache_key_path: &P4)
-                                                                    -> Result<()>
+/// Installs a package from an artifact on disk. Its dependencies are installed from the depot,
+/// or from the artifact cache when `offline` is set.
+pub fn from_archive<P1: ?Sized, P2: ?Sized, P3: ?Sized, P4: ?Sized>
+    (url: &str,
+     token: Option<&str>,
+     path: &P1,
+     fs_root_path: &P2,
+     cache_artifact_path: &P3,
+     cache_key_path: &P4,
+     trust_store: Option<&TrustStore>,
+     offline: bool)
+     -> Result<PackageIdent>
     where P1: AsRef<Path>,
           P2: AsRef<Path>,
           P3: AsRef<Path>,
@@ -134,67 +207,482 @@ pub fn from_archive<P1: ?Sized, P2: ?Sized, P3: ?Sized, P4: ?Sized>(url: &str,
     let mut archive = PackageArchive::new(PathBuf::from(path.as_ref()));
     let ident = try!(archive.ident());
     let tdeps = try!(archive.tdeps());
-    for dep in &tdeps {
+    if offline {
+        for dep in tdeps.iter() {
+            try!(install_from_cache(dep,
+                                    fs_root_path.as_ref(),
+                                    cache_artifact_path.as_ref(),
+                                    cache_key_path.as_ref(),
+                                    trust_store));
+        }
+    } else {
+        let idents: Vec<(PackageIdent, PackageIdent)> =
+            tdeps.iter().map(|dep| (dep.clone(), dep.clone())).collect();
         try!(install_from_depot(url,
-                                &dep,
-                                dep.as_ref(),
+                                token,
+                                &idents,
+                                None,
                                 fs_root_path.as_ref(),
                                 cache_artifact_path.as_ref(),
-                                cache_key_path.as_ref()));
+                                cache_key_path.as_ref(),
+                                trust_store));
     }
+    let url = if offline { None } else { Some(url) };
     try!(install_from_archive(url,
                               archive,
                               &ident,
                               fs_root_path.as_ref(),
-                              cache_key_path.as_ref()));
+                              cache_key_path.as_ref(),
+                              trust_store));
+    warn_conflicts(&ident, fs_root_path.as_ref());
     println!("{}",
              Blue.paint(format!("★ Install of {} complete with {} packages installed.",
                                 &ident,
                                 1 + &tdeps.len())));
+    Ok(ident)
+}
+
+/// Installs a package and its dependencies from the artifact cache, without contacting a depot.
+/// The newest cached release is installed when `ident` isn't fully qualified.
+///
+/// # Failures
+///
+/// * Fails if the package or one of its dependencies has no artifact in the artifact cache
+/// * Fails if the public key of a signer is not in the key cache
+pub fn from_cache<P1: ?Sized, P2: ?Sized, P3: ?Sized>(ident: &PackageIdent,
+                                                      fs_root_path: &P1,
+                                                      cache_artifact_path: &P2,
+                                                      cache_key_path: &P3,
+                                                      trust_store: Option<&TrustStore>)
+                                                      -> Result<PackageIdent>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>,
+          P3: AsRef<Path>
+{
+    println!("{}",
+             Yellow.bold().paint(format!("» Installing {} from cache", ident)));
+    let mut archive = try!(find_cached_artifact(ident, cache_artifact_path.as_ref()));
+    let pkg_ident = try!(archive.ident());
+    let tdeps = try!(archive.tdeps());
+    for dep in tdeps.iter() {
+        try!(install_from_cache(dep,
+                                fs_root_path.as_ref(),
+                                cache_artifact_path.as_ref(),
+                                cache_key_path.as_ref(),
+                                trust_store));
+    }
+    try!(install_from_archive(None,
+                              archive,
+                              &pkg_ident,
+                              fs_root_path.as_ref(),
+                              cache_key_path.as_ref(),
+                              trust_store));
+    warn_conflicts(&pkg_ident, fs_root_path.as_ref());
+    println!("{}",
+             Blue.paint(format!("★ Install of {} complete with {} packages installed.",
+                                &pkg_ident,
+                                1 + &tdeps.len())));
+    Ok(pkg_ident)
+}
+
+/// Installs exactly the packages listed in a lockfile, in the order they are listed. Every
+/// artifact is checked against its locked checksum. With `offline` set the artifacts are taken
+/// from the artifact cache instead of the depot.
+///
+/// # Failures
+///
+/// * Fails if an artifact cannot be downloaded, or found in the cache when offline
+/// * Fails if an artifact doesn't match its locked checksum
+pub fn from_lockfile<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
+                                                         token: Option<&str>,
+                                                         lockfile: &Lockfile,
+                                                         fs_root_path: &P1,
+                                                         cache_artifact_path: &P2,
+                                                         cache_key_path: &P3,
+                                                         trust_store: Option<&TrustStore>,
+                                                         offline: bool)
+                                                         -> Result<()>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>,
+          P3: AsRef<Path>
+{
+    println!("{}",
+             Yellow.bold().paint(format!("» Installing {} locked packages",
+                                         lockfile.packages.len())));
+    if offline {
+        for package in lockfile.packages.iter() {
+            if PackageInstall::load(&package.ident, Some(fs_root_path.as_ref())).is_ok() {
+                println!("{} {}", Green.paint("→ Using"), &package.ident);
+                continue;
+            }
+            let archive = try!(find_cached_artifact(&package.ident, cache_artifact_path.as_ref()));
+            let actual = try!(archive.checksum());
+            if actual != package.checksum {
+                return Err(Error::ArtifactChecksumMismatch(package.ident.to_string(),
+                                                           package.checksum.clone(),
+                                                           actual));
+            }
+            try!(install_from_archive(None,
+                                      archive,
+                                      &package.ident,
+                                      fs_root_path.as_ref(),
+                                      cache_key_path.as_ref(),
+                                      trust_store));
+        }
+    } else {
+        let idents: Vec<(PackageIdent, PackageIdent)> = lockfile.packages
+            .iter()
+            .map(|package| (package.ident.clone(), package.ident.clone()))
+            .collect();
+        try!(install_from_depot(url,
+                                token,
+                                &idents,
+                                Some(lockfile),
+                                fs_root_path.as_ref(),
+                                cache_artifact_path.as_ref(),
+                                cache_key_path.as_ref(),
+                                trust_store));
+    }
+    for package in lockfile.packages.iter() {
+        if lockfile.requested.iter().any(|ident| package.ident.satisfies(ident)) {
+            warn_conflicts(&package.ident, fs_root_path.as_ref());
+        }
+    }
+    println!("{}",
+             Blue.paint(format!("★ Install of lockfile complete with {} packages installed.",
+                                lockfile.packages.len())));
     Ok(())
 }
 
+/// Warns about every package of which the runtime path of an installed package combines more
+/// than one release, since only the release found first on the runtime path is ever used, and
+/// about every dependency of which other installed packages need a different release.
+///
+/// The check is best effort, so a package which can't be read never fails the install.
+fn warn_conflicts(ident: &PackageIdent, fs_root_path: &Path) {
+    let install = match PackageInstall::load(ident, Some(fs_root_path)) {
+        Ok(install) => install,
+        Err(err) => {
+            debug!("Skipping the dependency conflict check of {}: {}", ident, err);
+            return;
+        }
+    };
+    match install.runtime_conflicts() {
+        Ok(conflicts) => {
+            for (_, releases) in conflicts {
+                let releases: Vec<String> = releases.iter().map(|r| r.to_string()).collect();
+                println!("{} {} has {} on its runtime path",
+                         Yellow.bold().paint("✗ Conflict"),
+                         ident,
+                         releases.join(" and "));
+            }
+        }
+        Err(err) => debug!("Skipping the runtime path conflict check of {}: {}", ident, err),
+    }
+    match installed_conflicts(&install, fs_root_path) {
+        Ok(conflicts) => {
+            for (release, other, users) in conflicts {
+                let users: Vec<String> = users.iter().map(|u| u.to_string()).collect();
+                println!("{} {} needs {} while {} needs {}",
+                         Yellow.bold().paint("! Conflict"),
+                         ident,
+                         release,
+                         users.join(", "),
+                         other);
+            }
+        }
+        Err(err) => debug!("Skipping the installed package conflict check of {}: {}", ident, err),
+    }
+}
+
+/// Returns every dependency of an installed package of which other installed packages need a
+/// different release, as the release the package needs, the other release and the packages
+/// needing it. The package's own dependencies and other releases of the package are left out,
+/// and so are packages which can't be read.
+fn installed_conflicts(install: &PackageInstall,
+                       fs_root_path: &Path)
+                       -> Result<Vec<(PackageIdent, PackageIdent, Vec<PackageIdent>)>> {
+    let ident = install.ident();
+    let tdeps = try!(install.tdeps());
+    let mut conflicts: Vec<(PackageIdent, PackageIdent, Vec<PackageIdent>)> = Vec::new();
+    for package in try!(PackageInstall::installed(None, Some(fs_root_path))) {
+        if (package.origin == ident.origin && package.name == ident.name) ||
+           tdeps.contains(&package) {
+            continue;
+        }
+        let other_tdeps = match PackageInstall::load(&package, Some(fs_root_path))
+            .and_then(|other| other.tdeps()) {
+            Ok(other_tdeps) => other_tdeps,
+            Err(err) => {
+                debug!("Skipping {} in the dependency conflict check: {}", package, err);
+                continue;
+            }
+        };
+        for other in other_tdeps {
+            let release = match tdeps.iter().find(|dep| {
+                dep.origin == other.origin && dep.name == other.name && **dep != other
+            }) {
+                Some(release) => release,
+                None => continue,
+            };
+            match conflicts.iter().position(|&(_, ref o, _)| *o == other) {
+                Some(i) => conflicts[i].2.push(package.clone()),
+                None => conflicts.push((release.clone(), other, vec![package.clone()])),
+            }
+        }
+    }
+    Ok(conflicts)
+}
+
+/// Installs packages from the depot in the given order, skipping the ones which are installed
+/// already. Each package is given as its fully qualified identifier paired with the identifier
+/// it was asked for by.
+///
+/// The missing artifacts are all downloaded before the first one is unpacked, so a failed
+/// download leaves nothing half installed. Packages listed in the `pinned` lockfile must match
+/// its checksums rather than the depot's.
 fn install_from_depot(url: &str,
-                      ident: &PackageIdent,
-                      given_ident: &PackageIdent,
+                      token: Option<&str>,
+                      idents: &[(PackageIdent, PackageIdent)],
+                      pinned: Option<&Lockfile>,
                       fs_root_path: &Path,
                       cache_artifact_path: &Path,
-                      cache_key_path: &Path)
+                      cache_key_path: &Path,
+                      trust_store: Option<&TrustStore>)
                       -> Result<()> {
-    match PackageInstall::load(ident, Some(&fs_root_path)) {
-        Ok(_) => {
-            if given_ident.fully_qualified() {
-                println!("{} {}", Green.paint("→ Using"), ident);
-            } else {
-                println!("{} {} which satisfies {}",
-                         Green.paint("→ Using"),
-                         ident.as_ref(),
-                         given_ident);
+    let mut missing = Vec::new();
+    for &(ref ident, ref given_ident) in idents {
+        match PackageInstall::load(ident, Some(&fs_root_path)) {
+            Ok(_) => {
+                if given_ident.fully_qualified() {
+                    println!("{} {}", Green.paint("→ Using"), ident);
+                } else {
+                    println!("{} {} which satisfies {}",
+                             Green.paint("→ Using"),
+                             ident.as_ref(),
+                             given_ident);
+                }
             }
+            Err(_) => missing.push(ident.clone()),
         }
-        Err(_) => {
-            println!("{} {}",
-                     Green.bold().paint("↓ Downloading"),
-                     ident.as_ref());
-            let mut progress = ProgressBar::default();
-            let depot_client = try!(Client::new(url, Some(fs_root_path)));
-            let mut archive = try!(depot_client.fetch_package((*ident).clone(),
-                                                               cache_artifact_path,
-                                                               Some(&mut progress)));
-            let ident = try!(archive.ident());
-            try!(verify(url, &archive, &ident, fs_root_path, cache_key_path));
-            try!(archive.unpack(Some(fs_root_path)));
-            println!("{} {}", Green.bold().paint("✓ Installed"), ident.as_ref());
-        }
+    }
+    let archives = try!(download_all(url,
+                                     token,
+                                     &missing,
+                                     pinned,
+                                     fs_root_path,
+                                     cache_artifact_path));
+    let depot_client = try!(Client::new(url, Some(fs_root_path)));
+    let mut synced = HashSet::new();
+    for mut archive in archives.into_iter() {
+        let ident = try!(archive.ident());
+        try!(fetch_signer_keys(Some(&depot_client),
+                               &mut synced,
+                               &archive,
+                               cache_key_path,
+                               trust_store));
+        try!(unpack_verified(&archive, &ident, fs_root_path, cache_key_path));
+        println!("{} {}", Green.bold().paint("✓ Installed"), ident.as_ref());
     }
     Ok(())
 }
 
-fn install_from_archive(url: &str,
+/// Downloads the artifacts of the given packages, up to `DOWNLOAD_CONCURRENCY` at a time, and
+/// returns them in the order of the packages. A progress bar is only shown when there is a
+/// single artifact to download. Packages listed in the `pinned` lockfile are checked against
+/// its checksums, and all others against the checksums the depot has on record.
+pub fn download_all(url: &str,
+                    token: Option<&str>,
+                    idents: &[PackageIdent],
+                    pinned: Option<&Lockfile>,
+                    fs_root_path: &Path,
+                    cache_artifact_path: &Path)
+                    -> Result<Vec<PackageArchive>> {
+    let expected = |ident: &PackageIdent| {
+        pinned.and_then(|lockfile| lockfile.checksum_for(ident)).map(|c| c.to_string())
+    };
+    let depot_client = try!(Client::new(url, Some(fs_root_path)));
+    if idents.len() == 1 {
+        let mut progress = ProgressBar::default();
+        let archive = try!(download(&depot_client,
+                                    token,
+                                    &idents[0],
+                                    expected(&idents[0]).as_ref().map(|c| c.as_str()),
+                                    cache_artifact_path,
+                                    Some(&mut progress)));
+        return Ok(vec![archive]);
+    }
+    // Workers pop from the back of the queue, so reverse it to download in order.
+    let queue: Vec<(usize, PackageIdent, Option<String>)> = idents.iter()
+        .enumerate()
+        .rev()
+        .map(|(i, ident)| (i, ident.clone(), expected(ident)))
+        .collect();
+    let queue = Arc::new(Mutex::new(queue));
+    let (tx, rx) = mpsc::channel();
+    let mut workers = Vec::new();
+    for _ in 0..cmp::min(DOWNLOAD_CONCURRENCY, idents.len()) {
+        let queue = queue.clone();
+        let tx = tx.clone();
+        // Clones share the connection pool of the client.
+        let depot_client = depot_client.clone();
+        let token = token.map(|t| t.to_string());
+        let cache_artifact_path = cache_artifact_path.to_path_buf();
+        workers.push(thread::spawn(move || {
+            loop {
+                let next = queue.lock().unwrap().pop();
+                let (i, ident, checksum) = match next {
+                    Some(next) => next,
+                    None => break,
+                };
+                let result = download(&depot_client,
+                                      token.as_ref().map(|t| t.as_str()),
+                                      &ident,
+                                      checksum.as_ref().map(|c| c.as_str()),
+                                      &cache_artifact_path,
+                                      None);
+                if tx.send((i, result)).is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+    drop(tx);
+    let mut archives: Vec<Option<PackageArchive>> = idents.iter().map(|_| None).collect();
+    let mut failure = None;
+    for (i, result) in rx {
+        match result {
+            Ok(archive) => archives[i] = Some(archive),
+            Err(e) => {
+                if failure.is_none() {
+                    // Let the downloads in flight finish, but don't start any new ones.
+                    queue.lock().unwrap().clear();
+                    failure = Some(e);
+                }
+            }
+        }
+    }
+    for worker in workers {
+        let _ = worker.join();
+    }
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(archives.into_iter().map(|archive| archive.unwrap()).collect()),
+    }
+}
+
+/// Downloads the artifact of a package into the artifact cache, unless the cache holds it
+/// already, and checks it against the `expected` checksum, or the checksum the depot has on
+/// record when none is given. An artifact which doesn't match is removed from the cache.
+fn download(depot_client: &Client,
+            token: Option<&str>,
+            ident: &PackageIdent,
+            expected: Option<&str>,
+            cache_artifact_path: &Path,
+            progress: Option<&mut DisplayProgress>)
+            -> Result<PackageArchive> {
+    let checksum = match expected {
+        Some(checksum) => checksum.to_string(),
+        None => {
+            try!(depot_client.show_package(ident.clone(), token)).get_checksum().to_string()
+        }
+    };
+    if let Some(archive) = try!(cached_archive(ident, &checksum, cache_artifact_path)) {
+        println!("{} {} from cache", Green.bold().paint("☑ Using"), ident);
+        return Ok(archive);
+    }
+    println!("{} {}", Green.bold().paint("↓ Downloading"), ident);
+    let archive = try!(depot_client.fetch_package(ident.clone(),
+                                                   token,
+                                                   cache_artifact_path,
+                                                   progress));
+    let actual = try!(archive.checksum());
+    if actual != checksum {
+        try!(fs::remove_file(&archive.path));
+        return Err(Error::ArtifactChecksumMismatch(ident.to_string(), checksum, actual));
+    }
+    println!("{} {}", Green.bold().paint("☑ Cached"), ident);
+    Ok(archive)
+}
+
+/// Returns the newest artifact in the artifact cache which satisfies `ident`.
+fn find_cached_artifact(ident: &PackageIdent,
+                        cache_artifact_path: &Path)
+                        -> Result<PackageArchive> {
+    if let Some(name) = ident.archive_name() {
+        let path = cache_artifact_path.join(name);
+        if path.is_file() {
+            return Ok(PackageArchive::new(path));
+        }
+    }
+    let mut newest: Option<(PackageIdent, PathBuf)> = None;
+    if let Ok(entries) = fs::read_dir(cache_artifact_path) {
+        for entry in entries {
+            let path = try!(entry).path();
+            if path.extension().map_or(true, |ext| ext != "hart") {
+                continue;
+            }
+            let candidate = match PackageArchive::new(path.clone()).ident() {
+                Ok(candidate) => candidate,
+                Err(_) => continue,
+            };
+            if !candidate.satisfies(ident) {
+                continue;
+            }
+            let newer = match newest {
+                Some((ref current, _)) => candidate > *current,
+                None => true,
+            };
+            if newer {
+                newest = Some((candidate, path));
+            }
+        }
+    }
+    match newest {
+        Some((_, path)) => Ok(PackageArchive::new(path)),
+        None => Err(Error::ArtifactNotCached(ident.to_string())),
+    }
+}
+
+/// Returns the artifact of a fully qualified package from the artifact cache, if it is there
+/// and has the given checksum.
+fn cached_archive(ident: &PackageIdent,
+                  checksum: &str,
+                  cache_artifact_path: &Path)
+                  -> Result<Option<PackageArchive>> {
+    if !ident.fully_qualified() {
+        return Ok(None);
+    }
+    let prefix = format!("{}-{}-{}-{}-",
+                         ident.origin(),
+                         ident.name(),
+                         ident.version().unwrap(),
+                         ident.release().unwrap());
+    let entries = match fs::read_dir(cache_artifact_path) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(None),
+    };
+    for entry in entries {
+        let path = try!(entry).path();
+        let matches = match path.file_name().and_then(|n| n.to_str()) {
+            Some(name) => name.starts_with(&prefix) && name.ends_with(".hart"),
+            None => false,
+        };
+        if matches && try!(hash::hash_file(&path)) == checksum {
+            let mut archive = PackageArchive::new(path);
+            archive.set_checksum(checksum.to_string());
+            return Ok(Some(archive));
+        }
+    }
+    Ok(None)
+}
+
+fn install_from_archive(url: Option<&str>,
                         archive: PackageArchive,
                         ident: &PackageIdent,
                         fs_root_path: &Path,
-                        cache_key_path: &Path)
+                        cache_key_path: &Path,
+                        trust_store: Option<&TrustStore>)
                         -> Result<()> {
     match PackageInstall::load(ident.as_ref(), Some(&fs_root_path)) {
         Ok(_) => {
@@ -204,37 +692,346 @@ fn install_from_archive(url: &str,
             println!("{} {} from cache",
                      Green.bold().paint("← Extracting"),
                      ident);
-            try!(verify(url, &archive, &ident, fs_root_path, cache_key_path));
-            try!(archive.unpack(Some(fs_root_path)));
+            let depot_client = match url {
+                Some(url) => Some(try!(Client::new(url, Some(fs_root_path)))),
+                None => None,
+            };
+            try!(fetch_signer_keys(depot_client.as_ref(),
+                                   &mut HashSet::new(),
+                                   &archive,
+                                   cache_key_path,
+                                   trust_store));
+            try!(unpack_verified(&archive, &ident, fs_root_path, cache_key_path));
             println!("{} {}", Green.bold().paint("✓ Installed"), ident);
         }
     }
     Ok(())
 }
 
-/// get the signer for the artifact and see if we have the key locally.
-/// If we don't, attempt to download it from the depot.
-fn verify(url: &str,
-          archive: &PackageArchive,
-          ident: &PackageIdent,
-          fs_root_path: &Path,
-          cache_key_path: &Path)
-          -> Result<()> {
-    let nwr = try!(artifact::artifact_signer(&archive.path));
-    if let Err(_) = SigKeyPair::get_public_key_path(&nwr, cache_key_path) {
-        println!("{} {} public origin key",
-                 Green.bold().paint("↓ Downloading"),
-                 &nwr);
-        let (name, rev) = try!(parse_name_with_rev(&nwr));
-        let mut progress = ProgressBar::default();
-        let depot_client = try!(Client::new(url, Some(fs_root_path)));
-        try!(depot_client.fetch_origin_key(&name, &rev, cache_key_path, Some(&mut progress)));
-        println!("{} {} public origin key",
-                 Green.bold().paint("☑ Cached"),
-                 &nwr);
+/// Installs a package from its artifact in the artifact cache, unless it is installed already.
+fn install_from_cache(ident: &PackageIdent,
+                      fs_root_path: &Path,
+                      cache_artifact_path: &Path,
+                      cache_key_path: &Path,
+                      trust_store: Option<&TrustStore>)
+                      -> Result<()> {
+    if PackageInstall::load(ident, Some(&fs_root_path)).is_ok() {
+        println!("{} {}", Green.paint("→ Using"), ident);
+        return Ok(());
     }
+    let archive = try!(find_cached_artifact(ident, cache_artifact_path));
+    install_from_archive(None,
+                         archive,
+                         ident,
+                         fs_root_path,
+                         cache_key_path,
+                         trust_store)
+}
+
+/// get the signers for the artifact and see if we have their keys locally.
+/// If we don't, attempt to download them from the depot, unless no depot client is given for
+/// an offline install. The key revocations of each signing origin are synced from the depot the
+/// first time the origin is seen, and it is added to `synced`. With a trust store, the artifact
+/// is refused unless every signer is pinned in it.
+fn fetch_signer_keys(depot_client: Option<&Client>,
+                     synced: &mut HashSet<String>,
+                     archive: &PackageArchive,
+                     cache_key_path: &Path,
+                     trust_store: Option<&TrustStore>)
+                     -> Result<()> {
+    let signers = try!(artifact::artifact_signers(&archive.path));
+    for nwr in signers.iter() {
+        let depot_client = match depot_client {
+            Some(depot_client) => depot_client,
+            None => {
+                try!(SigKeyPair::get_public_key_path(nwr, cache_key_path));
+                if let Some(trust_store) = trust_store {
+                    try!(trust_store.check_key(nwr, cache_key_path));
+                }
+                continue;
+            }
+        };
+        let (name, rev) = try!(parse_name_with_rev(nwr));
+        if let Err(_) = SigKeyPair::get_public_key_path(nwr, cache_key_path) {
+            println!("{} {} public origin key",
+                     Green.bold().paint("↓ Downloading"),
+                     nwr);
+            let mut progress = ProgressBar::default();
+            try!(depot_client.fetch_origin_key(&name, &rev, cache_key_path, Some(&mut progress)));
+            println!("{} {} public origin key",
+                     Green.bold().paint("☑ Cached"),
+                     nwr);
+        }
+        if !synced.contains(&name) {
+            try!(sync_origin_key_revocations(depot_client, &name, cache_key_path));
+            synced.insert(name);
+        }
+        if let Some(trust_store) = trust_store {
+            try!(trust_store.check_key(nwr, cache_key_path));
+        }
+    }
+    Ok(())
+}
 
-    try!(archive.verify(&cache_key_path));
-    info!("Verified {} signed by {}", &ident, &nwr);
+/// Unpacks the artifact while verifying every signature, so the artifact is only read once.
+/// Nothing is installed unless the artifact verifies.
+fn unpack_verified(archive: &PackageArchive,
+                   ident: &PackageIdent,
+                   fs_root_path: &Path,
+                   cache_key_path: &Path)
+                   -> Result<()> {
+    let (signers, _) = try!(archive.unpack_verified(Some(fs_root_path),
+                                                    &cache_key_path,
+                                                    &VerifyPolicy::All));
+    info!("Verified {} signed by {}", &ident, signers.join(", "));
     Ok(())
 }
+
+/// Downloads any origin key revocations published by the Depot which are not yet in effect in
+/// the key cache, along with the public keys which signed them, so that artifacts signed with a
+/// revoked key fail verification.
+///
+/// A revocation is only kept in the key cache once it verifies. One which cannot be completed or
+/// does not verify is removed again, so a bad file is never mistaken for a synced revocation.
+pub fn sync_origin_key_revocations(depot_client: &Client,
+                                   origin: &str,
+                                   cache_key_path: &Path)
+                                   -> Result<()> {
+    for ident in try!(depot_client.show_origin_key_revocations(origin)) {
+        let nwr = format!("{}-{}", ident.get_origin(), ident.get_revision());
+        if try!(revocation::is_revoked(&nwr, cache_key_path)) {
+            continue;
+        }
+        let revocation = match fetch_revocation(depot_client, &ident, &nwr, cache_key_path) {
+            Ok(revocation) => revocation,
+            Err(e) => {
+                let _ = fs::remove_file(KeyRevocation::get_path(&nwr, cache_key_path));
+                return Err(e);
+            }
+        };
+        match revocation.verify(cache_key_path) {
+            Ok(()) => {
+                println!("{} {} public origin key",
+                         Yellow.bold().paint("✗ Revoked"),
+                         &nwr);
+            }
+            Err(e) => {
+                try!(fs::remove_file(KeyRevocation::get_path(&nwr, cache_key_path)));
+                println!("{} invalid revocation of {}: {}",
+                         Yellow.bold().paint("! Ignoring"),
+                         &nwr,
+                         e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Downloads the revocation of a key into the key cache, along with the public key which signed
+/// it if that is not cached yet.
+fn fetch_revocation(depot_client: &Client,
+                    ident: &depotsrv::OriginKeyIdent,
+                    nwr: &str,
+                    cache_key_path: &Path)
+                    -> Result<KeyRevocation> {
+    try!(depot_client.fetch_origin_key_revocation(ident.get_origin(),
+                                                  ident.get_revision(),
+                                                  cache_key_path));
+    let revocation = match try!(KeyRevocation::get_for(nwr, cache_key_path)) {
+        Some(revocation) => revocation,
+        None => {
+            return Err(Error::CryptoKeyError(format!("no revocation of {} was downloaded", nwr)))
+        }
+    };
+    if let Err(_) = SigKeyPair::get_public_key_path(&revocation.signer, cache_key_path) {
+        let (name, rev) = try!(parse_name_with_rev(&revocation.signer));
+        try!(depot_client.fetch_origin_key(&name, &rev, cache_key_path, None));
+    }
+    Ok(revocation)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use hcore::crypto::hash;
+    use hcore::package::{PackageIdent, PackageInstall};
+    use tempdir::TempDir;
+
+    use error::Error;
+    use super::{cached_archive, find_cached_artifact, from_cache, installed_conflicts};
+
+    const ARCHIVE: &'static str = "core-redis-3.0.7-20160614231131-x86_64-linux.hart";
+    const FIXTURE: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+    const FIXTURE_KEY: &'static str = "happyhumans-20160424223347.pub";
+
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../core/tests/fixtures")
+    }
+
+    fn cache_with_fixture() -> TempDir {
+        let cache = TempDir::new("artifact_cache").unwrap();
+        fs::copy(fixtures().join(FIXTURE), cache.path().join(FIXTURE)).unwrap();
+        cache
+    }
+
+    #[test]
+    fn find_cached_artifact_by_archive_name() {
+        let cache = cache_with_fixture();
+        let ident = PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap();
+        let archive = find_cached_artifact(&ident, cache.path()).unwrap();
+        assert_eq!(archive.path, cache.path().join(FIXTURE));
+    }
+
+    #[test]
+    fn find_cached_artifact_satisfying_partial_ident() {
+        let cache = cache_with_fixture();
+        File::create(cache.path().join("not-an-artifact.txt")).unwrap();
+        let ident = PackageIdent::from_str("happyhumans/possums").unwrap();
+        let mut archive = find_cached_artifact(&ident, cache.path()).unwrap();
+        assert_eq!(archive.ident().unwrap(),
+                   PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap());
+    }
+
+    #[test]
+    fn find_cached_artifact_not_cached() {
+        let cache = cache_with_fixture();
+        let ident = PackageIdent::from_str("happyhumans/wombats").unwrap();
+        match find_cached_artifact(&ident, cache.path()) {
+            Err(Error::ArtifactNotCached(ref name)) => assert_eq!(name, "happyhumans/wombats"),
+            _ => panic!("Expected the package not to be found in the cache"),
+        }
+    }
+
+    #[test]
+    fn from_cache_installs_cached_artifact() {
+        let cache = cache_with_fixture();
+        let keys = TempDir::new("key_cache").unwrap();
+        let root = TempDir::new("fs_root").unwrap();
+        fs::copy(fixtures().join(FIXTURE_KEY), keys.path().join(FIXTURE_KEY)).unwrap();
+        let ident = PackageIdent::from_str("happyhumans/possums").unwrap();
+
+        let installed = from_cache(&ident, root.path(), cache.path(), keys.path(), None).unwrap();
+        assert_eq!(installed,
+                   PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap());
+        assert!(root.path().join("hab/pkgs/happyhumans/possums/8.1.4/20160427165340").is_dir());
+    }
+
+    #[test]
+    fn from_cache_without_signer_key() {
+        let cache = cache_with_fixture();
+        let keys = TempDir::new("key_cache").unwrap();
+        let root = TempDir::new("fs_root").unwrap();
+        let ident = PackageIdent::from_str("happyhumans/possums").unwrap();
+
+        assert!(from_cache(&ident, root.path(), cache.path(), keys.path(), None).is_err());
+        assert!(!root.path().join("hab/pkgs/happyhumans/possums").exists());
+    }
+
+    fn cache_with_archive() -> (TempDir, String) {
+        let cache = TempDir::new("artifact_cache").unwrap();
+        File::create(cache.path().join(ARCHIVE)).unwrap().write_all(b"artifact").unwrap();
+        (cache, hash::hash_bytes(b"artifact").unwrap())
+    }
+
+    #[test]
+    fn cached_archive_with_matching_checksum() {
+        let (cache, checksum) = cache_with_archive();
+        let ident = PackageIdent::from_str("core/redis/3.0.7/20160614231131").unwrap();
+        let archive = cached_archive(&ident, &checksum, cache.path()).unwrap().unwrap();
+        assert_eq!(archive.path, cache.path().join(ARCHIVE));
+        assert_eq!(archive.checksum().unwrap(), checksum);
+    }
+
+    #[test]
+    fn cached_archive_with_other_checksum() {
+        let (cache, _) = cache_with_archive();
+        let ident = PackageIdent::from_str("core/redis/3.0.7/20160614231131").unwrap();
+        let checksum = hash::hash_bytes(b"other artifact").unwrap();
+        assert!(cached_archive(&ident, &checksum, cache.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn cached_archive_of_other_release() {
+        let (cache, checksum) = cache_with_archive();
+        let ident = PackageIdent::from_str("core/redis/3.0.7/20160614231132").unwrap();
+        assert!(cached_archive(&ident, &checksum, cache.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn cached_archive_not_fully_qualified() {
+        let (cache, checksum) = cache_with_archive();
+        let ident = PackageIdent::from_str("core/redis/3.0.7").unwrap();
+        assert!(cached_archive(&ident, &checksum, cache.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn cached_archive_without_cache() {
+        let (cache, checksum) = cache_with_archive();
+        let ident = PackageIdent::from_str("core/redis/3.0.7/20160614231131").unwrap();
+        let missing = cache.path().join("missing");
+        assert!(cached_archive(&ident, &checksum, &missing).unwrap().is_none());
+    }
+
+    fn fake_install(root: &TempDir, ident: &str, tdeps: &[&str]) {
+        let path = root.path().join("hab/pkgs").join(ident);
+        fs::create_dir_all(&path).unwrap();
+        if tdeps.is_empty() {
+            return;
+        }
+        let mut content = String::new();
+        for tdep in tdeps {
+            content.push_str(&format!("{}\n", tdep));
+        }
+        File::create(path.join("TDEPS")).unwrap().write_all(content.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn installed_conflicts_with_other_packages() {
+        let root = TempDir::new("fs_root").unwrap();
+        fake_install(&root,
+                     "acme/app/1.0.0/20161010101010",
+                     &["core/openssl/1.0.2j/20161005195021"]);
+        fake_install(&root, "core/openssl/1.0.2j/20161005195021", &[]);
+        fake_install(&root, "core/openssl/1.0.2h/20160612081125", &[]);
+        fake_install(&root,
+                     "core/curl/7.50.1/20160815192039",
+                     &["core/openssl/1.0.2h/20160612081125"]);
+        fake_install(&root,
+                     "core/wget/1.18/20160815192039",
+                     &["core/openssl/1.0.2h/20160612081125"]);
+        fake_install(&root,
+                     "acme/app/0.9.0/20160910101010",
+                     &["core/openssl/1.0.2h/20160612081125"]);
+
+        let app = PackageIdent::from_str("acme/app/1.0.0/20161010101010").unwrap();
+        let app = PackageInstall::load(&app, Some(root.path())).unwrap();
+        let conflicts = installed_conflicts(&app, root.path()).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        let (ref release, ref other, ref users) = conflicts[0];
+        assert_eq!(release.to_string(), "core/openssl/1.0.2j/20161005195021");
+        assert_eq!(other.to_string(), "core/openssl/1.0.2h/20160612081125");
+        let users: Vec<String> = users.iter().map(|u| u.to_string()).collect();
+        assert_eq!(users,
+                   vec!["core/curl/7.50.1/20160815192039", "core/wget/1.18/20160815192039"]);
+    }
+
+    #[test]
+    fn installed_conflicts_without_conflicts() {
+        let root = TempDir::new("fs_root").unwrap();
+        fake_install(&root,
+                     "acme/app/1.0.0/20161010101010",
+                     &["core/openssl/1.0.2j/20161005195021"]);
+        fake_install(&root, "core/openssl/1.0.2j/20161005195021", &[]);
+        fake_install(&root,
+                     "core/curl/7.50.1/20160815192039",
+                     &["core/openssl/1.0.2j/20161005195021"]);
+
+        let app = PackageIdent::from_str("acme/app").unwrap();
+        let app = PackageInstall::load(&app, Some(root.path())).unwrap();
+        assert!(installed_conflicts(&app, root.path()).unwrap().is_empty());
+    }
+}
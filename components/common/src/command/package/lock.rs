@@ -0,0 +1,77 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writes a lockfile of the releases a [depot](../depot) resolves a set of packages to.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg lock core/redis core/nginx
+//! ```
+//!
+//! Will write `hab.lock`, listing the latest releases of `core/redis` and `core/nginx` and of
+//! all their dependencies, so they are installed again with:
+//!
+//! ```bash
+//! $ hab pkg install --lockfile hab.lock
+//! ```
+//!
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use ansi_term::Colour::{Blue, Green};
+use depot_client::Client;
+use hcore::package::{Lockfile, PackageIdent};
+
+use error::Result;
+
+/// Resolves each package and its dependencies against the depot and writes the resulting
+/// lockfile to `dst`.
+///
+/// # Failures
+///
+/// * Fails if a package cannot be found in the depot
+/// * Fails if the lockfile cannot be written
+pub fn start(url: &str,
+             token: Option<&str>,
+             idents: &[PackageIdent],
+             dst: &Path,
+             fs_root_path: &Path)
+             -> Result<Lockfile> {
+    let depot_client = try!(Client::new(url, Some(fs_root_path)));
+    let mut lockfile = Lockfile::default();
+    for ident in idents.iter() {
+        let pkg_data = try!(depot_client.show_package(ident.clone(), token));
+        for dep in pkg_data.get_tdeps().iter() {
+            let dep: PackageIdent = (*dep).clone().into();
+            if !lockfile.contains(&dep) {
+                let dep_data = try!(depot_client.show_package(dep.clone(), token));
+                lockfile.push(dep, dep_data.get_checksum().to_string());
+            }
+        }
+        let pkg_ident: PackageIdent = pkg_data.get_ident().clone().into();
+        println!("{} {} to {}", Green.bold().paint("☑ Locked"), ident, &pkg_ident);
+        lockfile.push(pkg_ident, pkg_data.get_checksum().to_string());
+        lockfile.requested.push(ident.clone());
+    }
+    let mut file = try!(File::create(dst));
+    try!(file.write_all(lockfile.to_toml_string().as_bytes()));
+    println!("{}",
+             Blue.paint(format!("★ Locked {} packages into {}",
+                                lockfile.packages.len(),
+                                dst.display())));
+    Ok(lockfile)
+}
@@ -27,12 +27,20 @@ pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
+    /// Occurs when a downloaded artifact doesn't match the checksum the depot has on record.
+    ArtifactChecksumMismatch(String, String, String),
+    /// Occurs when an offline install finds no artifact of a package in the artifact cache.
+    ArtifactNotCached(String),
     CantUploadGossipToml,
     CryptoKeyError(String),
     GossipFileRelativePath(String),
     DepotClient(depot_client::Error),
     FileNameError,
     HabitatCore(hcore::Error),
+    /// Occurs when a tarball given as a bundle doesn't say which package it bundles.
+    InvalidBundle(String),
+    /// Occurs when an entry of a bundle is not a regular file, such as a symlink or a hardlink.
+    InvalidBundleEntry(String, String),
     InvalidTomlError(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
@@ -46,6 +54,15 @@ pub enum Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::ArtifactChecksumMismatch(ref ident, ref expected, ref actual) => {
+                format!("Artifact of {} has checksum {}, but the depot expected {}",
+                        ident,
+                        actual,
+                        expected)
+            }
+            Error::ArtifactNotCached(ref ident) => {
+                format!("No artifact of {} found in the artifact cache", ident)
+            }
             Error::CantUploadGossipToml => {
                 format!("Can't upload gossip.toml, it's a reserved file name")
             }
@@ -57,6 +74,14 @@ impl fmt::Display for Error {
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::InvalidBundle(ref path) => {
+                format!("{} is not a Habitat bundle, it has no IDENT file", path)
+            }
+            Error::InvalidBundleEntry(ref path, ref entry) => {
+                format!("Bundle {} has an entry {} which is not a regular file",
+                        path,
+                        entry)
+            }
             Error::InvalidTomlError(ref e) => format!("Invalid TOML: {}", e),
             Error::IO(ref err) => format!("{}", err),
             Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
@@ -72,6 +97,10 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::ArtifactChecksumMismatch(_, _, _) => {
+                "Downloaded artifact doesn't match the checksum of the depot"
+            }
+            Error::ArtifactNotCached(_) => "No artifact of the package in the artifact cache",
             Error::CantUploadGossipToml => "Can't upload gossip.toml, it's a reserved filename",
             Error::CryptoKeyError(_) => "Missing or invalid key",
             Error::GossipFileRelativePath(_) => {
@@ -80,6 +109,8 @@ impl error::Error for Error {
             Error::DepotClient(ref err) => err.description(),
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
+            Error::InvalidBundle(_) => "Tarball is not a Habitat bundle",
+            Error::InvalidBundleEntry(_, _) => "Bundle entry is not a regular file",
             Error::InvalidTomlError(_) => "Invalid TOML",
             Error::IO(ref err) => err.description(),
             Error::JsonDecode(_) => "JSON decoding error: {:?}",
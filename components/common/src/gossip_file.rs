@@ -20,7 +20,7 @@ use std::io::prelude::*;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use hcore::crypto::{BoxKeyPair, default_cache_key_path};
+use hcore::crypto::{BoxKeyPair, SymKey, default_cache_key_path, SECRET_SYM_KEY_SUFFIX};
 use hcore::fs;
 use hcore::service::ServiceGroup;
 use openssl::crypto::hash as openssl_hash;
@@ -176,8 +176,17 @@ impl GossipFile {
         }
     }
 
+    /// Returns true if this is an encrypted ring key, which is distributed with
+    /// `hab ring key rotate`. A ring key is imported into the key cache rather than written to the
+    /// service's files directory.
+    pub fn is_ring_key(&self) -> bool {
+        self.encrypted && self.file_name.ends_with(&format!(".{}", SECRET_SYM_KEY_SUFFIX))
+    }
+
     pub fn on_disk_path(&self) -> PathBuf {
-        if &self.file_name == GOSSIP_TOML {
+        if self.is_ring_key() {
+            default_cache_key_path(None).join(&self.file_name)
+        } else if &self.file_name == GOSSIP_TOML {
             fs::svc_path(&self.service_group.service).join(&self.file_name)
         } else {
             fs::svc_files_path(&self.service_group.service).join(&self.file_name)
@@ -214,7 +223,6 @@ impl GossipFile {
             let new_filename = format!("{}.write", filename.to_string_lossy());
             {
                 if self.encrypted {
-                    let mut new_file = try!(File::create(&new_filename));
                     // I'm the recipient, because GossipFileList::write()
                     // checks before calling this function.
                     // However, if decrypt() can't find user/service keys,
@@ -223,6 +231,10 @@ impl GossipFile {
                     let decrypted_bytes = try!(BoxKeyPair::decrypt(&self.body,
                                                                    &default_cache_key_path(None)));
                     println!("Successfully decrypted {}", &self.file_name);
+                    if self.is_ring_key() {
+                        return self.import_ring_key(decrypted_bytes).map(|_| true);
+                    }
+                    let mut new_file = try!(File::create(&new_filename));
                     try!(new_file.write_all(&decrypted_bytes));
                 } else {
                     let mut new_file = try!(File::create(&new_filename));
@@ -233,6 +245,22 @@ impl GossipFile {
             Ok(true)
         }
     }
+
+    /// Imports a decrypted ring key into the key cache. The key must be the one named by this
+    /// file, otherwise the file would never match its on disk checksum.
+    fn import_ring_key(&self, decrypted_bytes: Vec<u8>) -> Result<()> {
+        let content = try!(String::from_utf8(decrypted_bytes));
+        let name_with_rev = content.lines().nth(1).unwrap_or("");
+        if self.file_name != format!("{}.{}", name_with_rev, SECRET_SYM_KEY_SUFFIX) {
+            return Err(Error::CryptoKeyError(format!("Ring key {} does not match file {}",
+                                                     name_with_rev,
+                                                     &self.file_name)));
+        }
+        let (pair, _) = try!(SymKey::write_file_from_str(&content,
+                                                          &default_cache_key_path(None)));
+        println!("Imported ring key {}", pair.name_with_rev());
+        Ok(())
+    }
 }
 
 impl fmt::Display for GossipFile {
@@ -361,10 +389,12 @@ impl GossipFileList {
     /// One result of that is that this function returns a tuple of boolean values. The first is
     /// `needs_file_updated`, which means a file has changed, and we run the file_updated hook. The
     /// second is `needs_reconfigure`, which means the `gossip.toml` has changed, and we need to
-    /// reconfigure the daemon and the `service_config`.
-    pub fn write(&mut self) -> Result<(bool, bool)> {
+    /// reconfigure the daemon and the `service_config`. The third is `needs_ring_reload`, which
+    /// means a new ring key was imported and the ring keys need to be reloaded.
+    pub fn write(&mut self) -> Result<(bool, bool, bool)> {
         let mut needs_file_updated = false;
         let mut needs_reconfigure = false;
+        let mut needs_ring_reload = false;
         for (&(ref sg, _), ref gf) in self.gossip_files.iter() {
             // Don't write this file if it's not my_service_group.
             // Take note, this applies to encrypted payloads as well.
@@ -427,6 +457,12 @@ impl GossipFileList {
                 }
             };
 
+            if gf.is_ring_key() {
+                if written {
+                    needs_ring_reload = true;
+                }
+                continue;
+            }
             if needs_file_updated == false && written == true {
                 needs_file_updated = true;
             }
@@ -434,7 +470,7 @@ impl GossipFileList {
                 needs_reconfigure = true;
             }
         }
-        Ok((needs_file_updated, needs_reconfigure))
+        Ok((needs_file_updated, needs_reconfigure, needs_ring_reload))
     }
 
     pub fn process(&mut self, remote_gf: GossipFile) -> bool {
@@ -466,7 +502,7 @@ mod test {
     use tempdir::TempDir;
     use time::SteadyTime;
 
-    use hcore::crypto::BoxKeyPair;
+    use hcore::crypto::{BoxKeyPair, SymKey};
     use hcore::service::ServiceGroup;
     use gossip_file::{GossipFile, FileWriteRetry, GOSSIP_TOML};
 
@@ -539,6 +575,29 @@ mod test {
         assert!(decrypted == s);
     }
 
+    #[test]
+    fn new_from_file_encrypt_ring_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let user_pair = BoxKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let service_pair =
+            BoxKeyPair::generate_pair_for_service("someorg", "petty.gunslingers", cache.path())
+                .unwrap();
+        let ring_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let ring_key_path = SymKey::get_secret_key_path(&ring_key.name_with_rev(), cache.path())
+            .unwrap();
+        let gf = GossipFile::from_file_encrypt(&user_pair, &service_pair, &ring_key_path, 1)
+            .unwrap();
+        assert!(gf.is_ring_key());
+        assert_eq!(gf.file_name, format!("{}.sym.key", ring_key.name_with_rev()));
+
+        let gf = GossipFile::from_file_encrypt(&user_pair,
+                                               &service_pair,
+                                               fixture("foo.toml").as_path(),
+                                               1)
+            .unwrap();
+        assert!(!gf.is_ring_key());
+    }
+
     #[test]
     fn new_from_body() {
         let cf = GossipFile::from_body(ServiceGroup::from_str("chromeo.footwork").unwrap(),
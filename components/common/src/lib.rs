@@ -22,6 +22,7 @@ extern crate openssl;
 extern crate pbr;
 extern crate regex;
 extern crate rustc_serialize;
+extern crate tar;
 #[cfg(test)]
 extern crate tempdir;
 extern crate time;
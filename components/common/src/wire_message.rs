@@ -198,6 +198,51 @@ impl WireMessage {
     /// * If the wire message is malformed, that is, missing fields required for encrypted messages
     /// * If a required key with revision is not present for decrypting
     pub fn msg<T: Decodable>(&self, sym_key: Option<&SymKey>) -> Result<T> {
+        match sym_key {
+            Some(key) => self.msg_with_keys(&[key]),
+            None => self.msg_with_keys(&[]),
+        }
+    }
+
+    /// Decodes the message, decrypting it with whichever of the given keys has the name with
+    /// revision the message was encrypted with. This lets a receiver accept messages from peers
+    /// which have not yet moved to the latest revision of a key.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// extern crate habitat_common;
+    /// extern crate habitat_core;
+    /// extern crate tempdir;
+    ///
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use habitat_core::crypto::SymKey;
+    /// use habitat_common::wire_message::WireMessage;
+    /// use tempdir::TempDir;
+    ///
+    /// fn main() {
+    ///     let cache = TempDir::new("key_cache").unwrap();
+    ///     let old_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+    ///     thread::sleep(Duration::from_millis(1000));
+    ///     let new_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+    ///
+    ///     let encrypted = WireMessage::encrypted(&"halo".to_string(), &old_key).unwrap();
+    ///     let result: String = encrypted.msg_with_keys(&[&new_key, &old_key]).unwrap();
+    ///
+    ///     assert_eq!(result, "halo");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * If the message can't be decoded from bytes
+    /// * If the wire message is malformed, that is, missing fields required for encrypted messages
+    /// * If none of the keys has the name with revision the message was encrypted with
+    pub fn msg_with_keys<T: Decodable>(&self, sym_keys: &[&SymKey]) -> Result<T> {
         match self.format {
             MessageFormat::Plain => {
                 let msg_str = try!(str::from_utf8(&self.msg_bytes));
@@ -219,21 +264,25 @@ impl WireMessage {
                             .to_string()))
                     }
                 };
-                let sym_key = match sym_key.as_ref() {
+                let sym_key = match sym_keys.iter()
+                    .find(|k| &k.name_with_rev() == key_name_with_rev) {
                     Some(k) => k,
-                    None => {
+                    None if sym_keys.is_empty() => {
                         let msg = format!("Message is encrypted with key {} but \
                                           no key is loaded to decrypt.",
                                           key_name_with_rev);
                         return Err(Error::WireDecode(msg));
                     }
+                    None => {
+                        let loaded: Vec<String> =
+                            sym_keys.iter().map(|k| k.name_with_rev()).collect();
+                        let msg = format!("Loaded keys {} do not match message encrypted with \
+                                           key {}",
+                                          loaded.join(", "),
+                                          key_name_with_rev);
+                        return Err(Error::WireDecode(msg));
+                    }
                 };
-                if key_name_with_rev != &sym_key.name_with_rev() {
-                    let msg = format!("Loaded key {} does not match message encrypted with key {}",
-                                      &sym_key.name_with_rev(),
-                                      key_name_with_rev);
-                    return Err(Error::WireDecode(msg));
-                }
                 let msg = try!(sym_key.decrypt(&nonce[..], &self.msg_bytes[..]));
                 let msg_str = try!(str::from_utf8(&msg));
                 let decoded: T = try!(json::decode(msg_str));
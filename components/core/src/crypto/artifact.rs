@@ -12,34 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
 use std::io::{BufReader, BufWriter};
 use std::path::Path;
+use std::result;
+use std::str::FromStr;
 
 use rustc_serialize::base64::{STANDARD, ToBase64, FromBase64};
 use sodiumoxide::crypto::sign;
 
 use error::{Error, Result};
-use super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+use super::{HART_FORMAT_VERSION, HART_MULTISIG_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
 use super::keys::parse_name_with_rev;
+use super::keys::revocation;
+use super::signer::Signer;
 
-/// Generate and sign a package
-pub fn sign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Result<()>
+/// Generate and sign a package. The `signer` is usually a `SigKeyPair`, but can be any `Signer`
+/// such as one which keeps the secret origin key in another process.
+pub fn sign<P1: ?Sized, P2: ?Sized, S: ?Sized>(src: &P1, dst: &P2, signer: &S) -> Result<()>
     where P1: AsRef<Path>,
-          P2: AsRef<Path>
+          P2: AsRef<Path>,
+          S: Signer
 {
     let hash = try!(super::hash::hash_file(&src));
     debug!("File hash for {} = {}", src.as_ref().display(), &hash);
 
-    let signature = sign::sign(&hash.as_bytes(), try!(pair.secret()));
+    let signature = try!(signer.sign_hash(&hash));
     let output_file = try!(File::create(dst));
     let mut writer = BufWriter::new(&output_file);
     let () = try!(write!(writer,
                          "{}\n{}\n{}\n{}\n\n",
                          HART_FORMAT_VERSION,
-                         pair.name_with_rev(),
+                         signer.key_name_with_rev(),
                          SIG_HASH_TYPE,
                          signature.to_base64(STANDARD)));
     let mut file = try!(File::open(src));
@@ -47,39 +54,81 @@ pub fn sign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Re
     Ok(())
 }
 
+/// Adds a signature to an already signed Habitat artifact, writing a `HART-2` artifact to `dst`
+/// which carries the existing signatures of `src` followed by a signature from `signer`.
+///
+/// The new signature covers the hash of the payload, so the existing signatures are not required
+/// to be verifiable by the countersigner.
+pub fn countersign<P1: ?Sized, P2: ?Sized, S: ?Sized>(src: &P1,
+                                                      dst: &P2,
+                                                      signer: &S)
+                                                      -> Result<()>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>,
+          S: Signer
+{
+    let key_name = signer.key_name_with_rev();
+    let header = try!(get_artifact_header(&src.as_ref()));
+    if header.signatures.iter().any(|s| s.key_name == key_name) {
+        let msg = format!("Artifact is already signed with {}", key_name);
+        return Err(Error::CryptoError(msg));
+    }
+    let hash = {
+        let mut reader = try!(get_archive_reader(&src.as_ref()));
+        try!(super::hash::hash_reader(&mut reader))
+    };
+    debug!("Payload hash for {} = {}", src.as_ref().display(), &hash);
+
+    let signature = try!(signer.sign_hash(&hash));
+    let output_file = try!(File::create(dst));
+    let mut writer = BufWriter::new(&output_file);
+    try!(write!(writer, "{}\n{}\n", HART_MULTISIG_FORMAT_VERSION, SIG_HASH_TYPE));
+    for existing in header.signatures.iter() {
+        try!(write!(writer, "{} {}\n", existing.key_name, existing.signature_raw));
+    }
+    try!(write!(writer,
+                "{} {}\n\n",
+                key_name,
+                signature.to_base64(STANDARD)));
+    let mut reader = try!(get_archive_reader(&src.as_ref()));
+    try!(io::copy(&mut reader, &mut writer));
+    Ok(())
+}
+
 /// return a BufReader to the .tar bytestream, skipping the signed header
 pub fn get_archive_reader<P: AsRef<Path>>(src: &P) -> Result<BufReader<File>> {
-    let f = try!(File::open(src));
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
+    let (_, reader) = try!(get_header_and_archive_reader(src));
+    Ok(reader)
+}
 
+/// return the signed header along with a BufReader to the .tar bytestream which follows it, so
+/// the payload can be hashed while it is consumed and verified afterwards with `verify_header`
+pub fn get_header_and_archive_reader<P: AsRef<Path>>(src: &P)
+                                                     -> Result<(ArtifactHeader, BufReader<File>)> {
+    let f = try!(File::open(src));
     let mut reader = BufReader::new(f);
-    if try!(reader.read_line(&mut your_format_version)) <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
-    }
-    if try!(reader.read_line(&mut your_key_name)) <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if try!(reader.read_line(&mut your_hash_type)) <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
-    }
-    if try!(reader.read_line(&mut your_signature_raw)) <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
-    }
-    if try!(reader.read_line(&mut empty_line)) <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
-    }
-    Ok(reader)
+    let header = try!(read_header(&mut reader));
+    Ok((header, reader))
+}
+
+/// A single origin key signature of an artifact's payload hash.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArtifactSignature {
+    /// The name with revision of the origin key which made the signature
+    pub key_name: String,
+    /// The Base64 encoded signature
+    pub signature_raw: String,
 }
 
 pub struct ArtifactHeader {
     pub format_version: String,
+    /// The name with revision of the first signing key
     pub key_name: String,
     pub hash_type: String,
+    /// The first signature, kept for readers of single signature `HART-1` artifacts
     pub signature_raw: String,
+    /// Every signature in the header, in the order they were added
+    pub signatures: Vec<ArtifactSignature>,
 }
 
 impl ArtifactHeader {
@@ -88,11 +137,16 @@ impl ArtifactHeader {
                hash_type: String,
                signature_raw: String)
                -> ArtifactHeader {
+        let signatures = vec![ArtifactSignature {
+                                  key_name: key_name.clone(),
+                                  signature_raw: signature_raw.clone(),
+                              }];
         ArtifactHeader {
             format_version: format_version,
             key_name: key_name,
             hash_type: hash_type,
             signature_raw: signature_raw,
+            signatures: signatures,
         }
     }
 }
@@ -102,112 +156,225 @@ impl ArtifactHeader {
 /// stripped before returning in an `ArtifactHeader` struct
 pub fn get_artifact_header<P: AsRef<Path>>(src: &P) -> Result<ArtifactHeader> {
     let f = try!(File::open(src));
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
-
     let mut reader = BufReader::new(f);
-    if try!(reader.read_line(&mut your_format_version)) <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
-    }
-    if try!(reader.read_line(&mut your_key_name)) <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if try!(reader.read_line(&mut your_hash_type)) <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
+    read_header(&mut reader)
+}
+
+/// Determines which signatures of an artifact must verify for the artifact to be trusted.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerifyPolicy {
+    /// At least one signature must verify
+    Any,
+    /// Every signature must verify
+    All,
+    /// Signatures made by at least `k` distinct named keys must verify. A name can either be a
+    /// key name, ex: "core", or a key name with revision, ex: "core-20160405144945". Signatures
+    /// made by several revisions of one key count once.
+    Threshold(usize, Vec<String>),
+}
+
+impl VerifyPolicy {
+    fn names_key(names: &[String], name_with_rev: &str) -> bool {
+        names.iter().any(|n| {
+            n == name_with_rev ||
+            parse_name_with_rev(name_with_rev).map(|(name, _)| &name == n).unwrap_or(false)
+        })
     }
-    if try!(reader.read_line(&mut your_signature_raw)) <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
+}
+
+impl Default for VerifyPolicy {
+    fn default() -> Self {
+        VerifyPolicy::All
     }
-    if try!(reader.read_line(&mut empty_line)) <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
+}
+
+impl fmt::Display for VerifyPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VerifyPolicy::Any => write!(f, "any"),
+            VerifyPolicy::All => write!(f, "all"),
+            VerifyPolicy::Threshold(k, ref names) => write!(f, "{}-of:{}", k, names.join(",")),
+        }
     }
-    let your_format_version = your_format_version.trim().to_string();
-    let your_key_name = your_key_name.trim().to_string();
-    let your_hash_type = your_hash_type.trim().to_string();
-    let your_signature_raw = your_signature_raw.trim().to_string();
+}
+
+impl FromStr for VerifyPolicy {
+    type Err = Error;
 
-    Ok(ArtifactHeader::new(your_format_version,
-                           your_key_name,
-                           your_hash_type,
-                           your_signature_raw))
+    /// Parses `any`, `all`, or `<k>-of:<name>,<name>,...`
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "any" => return Ok(VerifyPolicy::Any),
+            "all" => return Ok(VerifyPolicy::All),
+            _ => (),
+        }
+        let invalid = || {
+            Error::CryptoError(format!("Invalid verify policy {}, expected 'any', 'all' or \
+                                        '<k>-of:<key>,<key>,...'",
+                                       value))
+        };
+        let mut parts = value.splitn(2, "-of:");
+        let k = match parts.next().and_then(|k| k.parse::<usize>().ok()) {
+            Some(k) if k > 0 => k,
+            _ => return Err(invalid()),
+        };
+        let names: Vec<String> = match parts.next() {
+            Some(names) => {
+                names.split(',').filter(|n| !n.is_empty()).map(|n| n.to_string()).collect()
+            }
+            None => return Err(invalid()),
+        };
+        if names.len() < k {
+            return Err(invalid());
+        }
+        Ok(VerifyPolicy::Threshold(k, names))
+    }
 }
 
 /// verify the crypto signature of a .hart file
+///
+/// Every signature of the artifact must verify. The return is a tuple of the name with revision
+/// of the first signing key and the artifact's payload hash.
 pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(String, String)>
     where P1: AsRef<Path>,
           P2: AsRef<Path>
+{
+    let (mut signers, hash) = try!(verify_with_policy(src, cache_key_path, &VerifyPolicy::All));
+    Ok((signers.remove(0), hash))
+}
+
+/// verify the crypto signatures of a .hart file against a `VerifyPolicy`
+///
+/// The return is a tuple of the names with revision of every key whose signature verified and
+/// the artifact's payload hash.
+pub fn verify_with_policy<P1: ?Sized, P2: ?Sized>(src: &P1,
+                                                  cache_key_path: &P2,
+                                                  policy: &VerifyPolicy)
+                                                  -> Result<(Vec<String>, String)>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
 {
     let f = try!(File::open(src));
     let mut reader = BufReader::new(f);
+    let header = try!(read_header(&mut reader));
+    let computed_hash = try!(super::hash::hash_reader(&mut reader));
+    debug!("My hash {}", computed_hash);
+    let verified = try!(verify_header(&header, &computed_hash, cache_key_path.as_ref(), policy));
+    Ok((verified, computed_hash))
+}
 
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read format version"
-                    .to_string()))
-            }
-            Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
-                    let msg = format!("Unsupported format version: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
-                }
-            }
-            Err(e) => return Err(Error::from(e)),
+/// Splits a signed string, such as a signed package metafile, into its header and payload
+/// without verifying any of its signatures.
+pub fn read_signed_str(content: &str) -> Result<(ArtifactHeader, &str)> {
+    let mut reader = content.as_bytes();
+    let header = try!(read_header(&mut reader));
+    let offset = content.len() - reader.len();
+    Ok((header, &content[offset..]))
+}
 
-        };
-        buffer.trim().to_string()
-    };
-    let pair = {
-        let mut buffer = String::new();
-        if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
-                .to_string()));
-        }
-        try!(SigKeyPair::get_pair_for(buffer.trim(), cache_key_path))
-    };
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read hash type".to_string()))
-            }
-            Ok(_) => {
-                if buffer.trim() != SIG_HASH_TYPE {
-                    let msg = format!("Unsupported signature type: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
+/// verify the crypto signatures of a signed string against a `VerifyPolicy`
+///
+/// The return is a tuple of the names with revision of every key whose signature verified and
+/// the verified payload.
+pub fn verify_str<'a, P: ?Sized>(content: &'a str,
+                                 cache_key_path: &P,
+                                 policy: &VerifyPolicy)
+                                 -> Result<(Vec<String>, &'a str)>
+    where P: AsRef<Path>
+{
+    let (header, payload) = try!(read_signed_str(content));
+    let computed_hash = try!(super::hash::hash_string(payload));
+    debug!("My hash {}", computed_hash);
+    let verified = try!(verify_header(&header, &computed_hash, cache_key_path.as_ref(), policy));
+    Ok((verified, payload))
+}
+
+/// verify the signatures of an artifact header against the hash of its payload, computed by the
+/// caller, and a `VerifyPolicy`
+///
+/// The return is the names with revision of every key whose signature verified.
+pub fn verify_header(header: &ArtifactHeader,
+                     computed_hash: &str,
+                     cache_key_path: &Path,
+                     policy: &VerifyPolicy)
+                     -> Result<Vec<String>> {
+    let mut verified = Vec::new();
+    let mut first_err = None;
+    for signature in header.signatures.iter() {
+        match verify_signature(signature, computed_hash, cache_key_path) {
+            Ok(()) => verified.push(signature.key_name.clone()),
+            Err(e) => {
+                debug!("Signature by {} did not verify: {}", signature.key_name, e);
+                if first_err.is_none() {
+                    first_err = Some(e);
                 }
             }
-            Err(e) => return Err(Error::from(e)),
-        };
-    };
-    let signature = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read signature".to_string()))
+        }
+    }
+
+    match *policy {
+        VerifyPolicy::All => {
+            if let Some(e) = first_err {
+                return Err(e);
             }
-            Ok(_) => {
-                match buffer.trim().as_bytes().from_base64() {
-                    Ok(sig) => sig,
-                    Err(e) => {
-                        return Err(Error::CryptoError(format!("Can't decode signature: {}", e)));
-                    }
-                }
+        }
+        VerifyPolicy::Any => {
+            if verified.is_empty() {
+                return Err(first_err.unwrap_or(Error::CryptoError("Verification failed"
+                    .to_string())));
             }
-            Err(e) => return Err(Error::from(e)),
         }
-    };
-    let _ = {
-        let mut buffer = String::new();
-        if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't find end of header".to_string()));
+        VerifyPolicy::Threshold(k, ref names) => {
+            let mut keys: Vec<String> = verified.iter()
+                .filter(|v| VerifyPolicy::names_key(names, v))
+                .map(|v| parse_name_with_rev(v).map(|(name, _)| name).unwrap_or(v.to_string()))
+                .collect();
+            keys.sort();
+            keys.dedup();
+            let count = keys.len();
+            if count < k {
+                let msg = format!("Verify policy {} not satisfied, {} of the required \
+                                   signatures verified",
+                                  policy,
+                                  count);
+                return Err(Error::CryptoError(msg));
+            }
         }
+    }
+    Ok(verified)
+}
+
+/// Returns the name with revision of the first key which signed the artifact.
+pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
+    let mut signers = try!(artifact_signers(src));
+    Ok(signers.remove(0))
+}
+
+/// Returns the names with revision of every key which signed the artifact.
+pub fn artifact_signers<P: AsRef<Path>>(src: &P) -> Result<Vec<String>> {
+    let header = try!(get_artifact_header(src));
+    Ok(header.signatures.into_iter().map(|s| s.key_name).collect())
+}
+
+fn verify_signature(signature: &ArtifactSignature,
+                    computed_hash: &str,
+                    cache_key_path: &Path)
+                    -> Result<()> {
+    let pair = try!(SigKeyPair::get_pair_for(&signature.key_name, cache_key_path));
+    if try!(revocation::is_revoked(&pair.name_with_rev(), cache_key_path)) {
+        let msg = format!("Origin key {} has been revoked", pair.name_with_rev());
+        return Err(Error::CryptoError(msg));
+    }
+    if pair.is_expired() {
+        warn!("Origin key {} expired at {}, the artifact should be signed with a newer key",
+              pair.name_with_rev(),
+              pair.expires.as_ref().unwrap());
+    }
+    let signature_bytes = match signature.signature_raw.as_bytes().from_base64() {
+        Ok(sig) => sig,
+        Err(e) => return Err(Error::CryptoError(format!("Can't decode signature: {}", e))),
     };
-    let expected_hash = match sign::verify(signature.as_slice(), try!(pair.public())) {
+    let expected_hash = match sign::verify(signature_bytes.as_slice(), try!(pair.public())) {
         Ok(signed_data) => {
             match String::from_utf8(signed_data) {
                 Ok(hash) => hash,
@@ -219,13 +386,9 @@ pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(
         Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
     };
     debug!("VERIFIED, checking signed hash against mine");
-
-    let computed_hash = try!(super::hash::hash_reader(&mut reader));
-
     debug!("Expected hash {}", expected_hash);
-    debug!("My hash {}", computed_hash);
     if computed_hash == expected_hash {
-        Ok((pair.name_with_rev(), expected_hash))
+        Ok(())
     } else {
         let msg = format!("Habitat artifact is invalid, \
                           hashes don't match (expected: {}, computed: {})",
@@ -235,11 +398,10 @@ pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(
     }
 }
 
-pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
-    let f = try!(File::open(src));
-    let mut reader = BufReader::new(f);
-
-    let _ = {
+/// Reads a `HART-1` or `HART-2` header, leaving the reader positioned at the start of the
+/// payload.
+fn read_header<R: BufRead>(reader: &mut R) -> Result<ArtifactHeader> {
+    let format_version = {
         let mut buffer = String::new();
         match reader.read_line(&mut buffer) {
             Ok(0) => {
@@ -247,37 +409,127 @@ pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
                     .to_string()))
             }
             Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
-                    let msg = format!("Unsupported format version: {}", &buffer.trim());
+                let version = buffer.trim();
+                if version != HART_FORMAT_VERSION && version != HART_MULTISIG_FORMAT_VERSION {
+                    let msg = format!("Unsupported format version: {}", version);
                     return Err(Error::CryptoError(msg));
                 }
             }
             Err(e) => return Err(Error::from(e)),
-
         };
         buffer.trim().to_string()
     };
-    let name_with_rev = {
+    if format_version == HART_FORMAT_VERSION {
+        let key_name = {
+            let mut buffer = String::new();
+            if try!(reader.read_line(&mut buffer)) <= 0 {
+                return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
+                    .to_string()));
+            }
+            try!(parse_name_with_rev(buffer.trim()));
+            buffer.trim().to_string()
+        };
+        let hash_type = try!(read_hash_type(reader));
+        let signature_raw = {
+            let mut buffer = String::new();
+            match reader.read_line(&mut buffer) {
+                Ok(0) => {
+                    return Err(Error::CryptoError("Corrupt payload, can't read signature"
+                        .to_string()))
+                }
+                Ok(_) => try!(check_signature_raw(buffer.trim())),
+                Err(e) => return Err(Error::from(e)),
+            }
+        };
+        let _ = {
+            let mut buffer = String::new();
+            if try!(reader.read_line(&mut buffer)) <= 0 {
+                return Err(Error::CryptoError("Corrupt payload, can't find end of header"
+                    .to_string()));
+            }
+        };
+        return Ok(ArtifactHeader::new(format_version, key_name, hash_type, signature_raw));
+    }
+
+    let hash_type = try!(read_hash_type(reader));
+    let mut signatures = Vec::new();
+    loop {
         let mut buffer = String::new();
         if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
+            return Err(Error::CryptoError("Corrupt payload, can't find end of header"
                 .to_string()));
         }
-        try!(parse_name_with_rev(buffer.trim()));
-        buffer.trim().to_string()
-    };
-    Ok(name_with_rev)
+        let line = buffer.trim();
+        if line.is_empty() {
+            break;
+        }
+        let mut parts = line.splitn(2, ' ');
+        let key_name = parts.next().unwrap_or("");
+        try!(parse_name_with_rev(key_name));
+        if signatures.iter().any(|s: &ArtifactSignature| s.key_name == key_name) {
+            let msg = format!("Corrupt payload, duplicate signature by {}", key_name);
+            return Err(Error::CryptoError(msg));
+        }
+        let signature_raw = match parts.next() {
+            Some(raw) => try!(check_signature_raw(raw.trim())),
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read signature"
+                    .to_string()))
+            }
+        };
+        signatures.push(ArtifactSignature {
+            key_name: key_name.to_string(),
+            signature_raw: signature_raw,
+        });
+    }
+    if signatures.is_empty() {
+        return Err(Error::CryptoError("Corrupt payload, can't read signature".to_string()));
+    }
+    let first = signatures[0].clone();
+    Ok(ArtifactHeader {
+        format_version: format_version,
+        key_name: first.key_name,
+        hash_type: hash_type,
+        signature_raw: first.signature_raw,
+        signatures: signatures,
+    })
+}
+
+fn read_hash_type<R: BufRead>(reader: &mut R) -> Result<String> {
+    let mut buffer = String::new();
+    match reader.read_line(&mut buffer) {
+        Ok(0) => Err(Error::CryptoError("Corrupt payload, can't read hash type".to_string())),
+        Ok(_) => {
+            if buffer.trim() != SIG_HASH_TYPE {
+                let msg = format!("Unsupported signature type: {}", &buffer.trim());
+                return Err(Error::CryptoError(msg));
+            }
+            Ok(buffer.trim().to_string())
+        }
+        Err(e) => Err(Error::from(e)),
+    }
+}
+
+fn check_signature_raw(raw: &str) -> Result<String> {
+    match raw.as_bytes().from_base64() {
+        Ok(_) => Ok(raw.to_string()),
+        Err(e) => Err(Error::CryptoError(format!("Can't decode signature: {}", e))),
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::fs::{self, File};
     use std::io::{BufRead, BufReader, Read, Write};
+    use std::str::FromStr;
+    use std::thread;
+    use std::time::Duration;
 
     use tempdir::TempDir;
 
     use super::*;
-    use super::super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+    use super::super::{HART_FORMAT_VERSION, HART_MULTISIG_FORMAT_VERSION, SIG_HASH_TYPE,
+                       KeyRevocation, SigKeyPair};
     use super::super::test_support::*;
     use super::super::keys::parse_name_with_rev;
 
@@ -292,6 +544,182 @@ mod test {
         assert!(true);
     }
 
+    #[test]
+    #[should_panic(expected = "has been revoked")]
+    fn verify_revoked_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        KeyRevocation::create(&pair.name_with_rev(), &pair)
+            .unwrap()
+            .write_file(cache.path())
+            .unwrap();
+
+        verify(&dst, cache.path()).unwrap();
+    }
+
+    #[test]
+    fn countersign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let release = SigKeyPair::generate_pair_for_origin("release", cache.path()).unwrap();
+        let signed = cache.path().join("signed.dat");
+        let dst = cache.path().join("countersigned.dat");
+
+        sign(&fixture("signme.dat"), &signed, &pair).unwrap();
+        countersign(&signed, &dst, &release).unwrap();
+
+        let header = get_artifact_header(&dst).unwrap();
+        assert_eq!(HART_MULTISIG_FORMAT_VERSION, header.format_version);
+        assert_eq!(pair.name_with_rev(), header.key_name);
+        assert_eq!(2, header.signatures.len());
+        let (signers, _) = verify_with_policy(&dst, cache.path(), &VerifyPolicy::All).unwrap();
+        assert_eq!(vec![pair.name_with_rev(), release.name_with_rev()], signers);
+
+        let mut buffer = String::new();
+        get_archive_reader(&dst).unwrap().read_to_string(&mut buffer).unwrap();
+        let mut expected = String::new();
+        File::open(fixture("signme.dat")).unwrap().read_to_string(&mut expected).unwrap();
+        assert_eq!(expected, buffer);
+    }
+
+    #[test]
+    fn sign_and_verify_str() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        let mut content = String::new();
+        File::open(&dst).unwrap().read_to_string(&mut content).unwrap();
+
+        let (signers, payload) = verify_str(&content, cache.path(), &VerifyPolicy::All).unwrap();
+        assert_eq!(vec![pair.name_with_rev()], signers);
+        assert_eq!(fixture_as_string("signme.dat"), payload);
+        let (header, unverified) = read_signed_str(&content).unwrap();
+        assert_eq!(pair.name_with_rev(), header.key_name);
+        assert_eq!(payload, unverified);
+    }
+
+    #[test]
+    #[should_panic(expected = "hashes don't match")]
+    fn verify_str_tampered_payload() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        let mut content = String::new();
+        File::open(&dst).unwrap().read_to_string(&mut content).unwrap();
+        content.push_str("tampered");
+
+        verify_str(&content, cache.path(), &VerifyPolicy::All).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Artifact is already signed with")]
+    fn countersign_twice_with_same_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signed = cache.path().join("signed.dat");
+        let dst = cache.path().join("countersigned.dat");
+
+        sign(&fixture("signme.dat"), &signed, &pair).unwrap();
+        countersign(&signed, &dst, &pair).unwrap();
+    }
+
+    #[test]
+    fn verify_policies_with_missing_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let other = TempDir::new("other_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let release = SigKeyPair::generate_pair_for_origin("release", other.path()).unwrap();
+        let signed = cache.path().join("signed.dat");
+        let dst = cache.path().join("countersigned.dat");
+        sign(&fixture("signme.dat"), &signed, &pair).unwrap();
+        countersign(&signed, &dst, &release).unwrap();
+
+        assert!(verify_with_policy(&dst, cache.path(), &VerifyPolicy::All).is_err());
+        let (signers, _) = verify_with_policy(&dst, cache.path(), &VerifyPolicy::Any).unwrap();
+        assert_eq!(vec![pair.name_with_rev()], signers);
+        let one_of = VerifyPolicy::from_str("1-of:unicorn,release").unwrap();
+        assert!(verify_with_policy(&dst, cache.path(), &one_of).is_ok());
+        let two_of = VerifyPolicy::from_str("2-of:unicorn,release").unwrap();
+        assert!(verify_with_policy(&dst, cache.path(), &two_of).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate signature")]
+    fn verify_duplicate_signature_lines() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let release = SigKeyPair::generate_pair_for_origin("release", cache.path()).unwrap();
+        let signed = cache.path().join("signed.dat");
+        let countersigned = cache.path().join("countersigned.dat");
+        let dst = cache.path().join("duplicated.dat");
+        sign(&fixture("signme.dat"), &signed, &pair).unwrap();
+        countersign(&signed, &countersigned, &release).unwrap();
+
+        let header = get_artifact_header(&countersigned).unwrap();
+        let release_line = format!("{} {}\n",
+                                   header.signatures[1].key_name,
+                                   header.signatures[1].signature_raw);
+        let mut payload = Vec::new();
+        get_archive_reader(&countersigned).unwrap().read_to_end(&mut payload).unwrap();
+        let mut f = File::create(&dst).unwrap();
+        write!(f,
+               "{}\n{}\n{} {}\n{}{}\n",
+               HART_MULTISIG_FORMAT_VERSION,
+               SIG_HASH_TYPE,
+               header.signatures[0].key_name,
+               header.signatures[0].signature_raw,
+               release_line,
+               release_line)
+            .unwrap();
+        f.write_all(&payload).unwrap();
+
+        let two_of = VerifyPolicy::from_str("2-of:unicorn,release").unwrap();
+        verify_with_policy(&dst, cache.path(), &two_of).unwrap();
+    }
+
+    #[test]
+    fn verify_threshold_counts_distinct_keys() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        // key revisions have a resolution of one second
+        thread::sleep(Duration::from_millis(1100));
+        let rotated = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        assert!(pair.name_with_rev() != rotated.name_with_rev());
+        let signed = cache.path().join("signed.dat");
+        let dst = cache.path().join("countersigned.dat");
+        sign(&fixture("signme.dat"), &signed, &pair).unwrap();
+        countersign(&signed, &dst, &rotated).unwrap();
+
+        let (signers, _) = verify_with_policy(&dst, cache.path(), &VerifyPolicy::All).unwrap();
+        assert_eq!(2, signers.len());
+        let one_of = VerifyPolicy::from_str("1-of:unicorn,release").unwrap();
+        assert!(verify_with_policy(&dst, cache.path(), &one_of).is_ok());
+        let two_of = VerifyPolicy::from_str("2-of:unicorn,release").unwrap();
+        assert!(verify_with_policy(&dst, cache.path(), &two_of).is_err());
+        let both_revisions = VerifyPolicy::Threshold(2,
+                                                     vec![pair.name_with_rev(),
+                                                          rotated.name_with_rev()]);
+        assert!(verify_with_policy(&dst, cache.path(), &both_revisions).is_err());
+    }
+
+    #[test]
+    fn verify_policy_from_str() {
+        assert_eq!(VerifyPolicy::Any, VerifyPolicy::from_str("any").unwrap());
+        assert_eq!(VerifyPolicy::All, VerifyPolicy::from_str("all").unwrap());
+        assert_eq!(VerifyPolicy::Threshold(2,
+                                           vec!["core".to_string(),
+                                                "release-20160405144945".to_string(),
+                                                "ops".to_string()]),
+                   VerifyPolicy::from_str("2-of:core,release-20160405144945,ops").unwrap());
+        assert!(VerifyPolicy::from_str("3-of:core,ops").is_err());
+        assert!(VerifyPolicy::from_str("0-of:core").is_err());
+        assert!(VerifyPolicy::from_str("some").is_err());
+    }
+
     #[test]
     #[should_panic(expected = "Secret key is required but not present for")]
     fn sign_missing_private_key() {
@@ -12,16 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::RefCell;
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{self, BufReader, Read};
 use std::mem;
 use std::path::Path;
 use std::ptr;
+use std::rc::Rc;
 
 use libsodium_sys;
 use rustc_serialize::hex::ToHex;
 
-use error::Result;
+use error::{Error, Result};
 
 const BUF_SIZE: usize = 1024;
 
@@ -35,45 +37,136 @@ pub fn hash_file<P: AsRef<Path>>(filename: &P) -> Result<String> {
 }
 
 pub fn hash_string(data: &str) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
-    let pst = unsafe {
-        mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
-    };
-    unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
-        libsodium_sys::crypto_generichash_update(pst, data[..].as_ptr(), data.len() as u64);
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
-    }
-    Ok(out.to_hex())
+    hash_bytes(data.as_bytes())
 }
 
-pub fn hash_reader(reader: &mut BufReader<File>) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
-    let pst = unsafe {
-        mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
-    };
-
-    unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
-    }
+/// Calculate the BLAKE2b hash of a byte slice, return as a hex string
+pub fn hash_bytes(data: &[u8]) -> Result<String> {
+    let mut hasher = Hasher::new();
+    hasher.update(data);
+    Ok(hasher.finish())
+}
 
+/// Calculate the BLAKE2b hash of everything left in a reader, return as a hex string
+pub fn hash_reader<R: Read>(reader: &mut R) -> Result<String> {
+    let mut hasher = Hasher::new();
     let mut buf = [0u8; BUF_SIZE];
     loop {
         let bytes_read = try!(reader.read(&mut buf));
         if bytes_read == 0 {
             break;
         }
-        let chunk = &buf[0..bytes_read];
+        hasher.update(&buf[0..bytes_read]);
+    }
+    Ok(hasher.finish())
+}
+
+/// An incremental BLAKE2b hasher, for hashing data which is streamed elsewhere as it is read.
+/// The result is the same as hashing all of the data at once with `hash_bytes`.
+pub struct Hasher {
+    state: Vec<u8>,
+}
+
+impl Hasher {
+    pub fn new() -> Self {
+        let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
+        unsafe {
+            libsodium_sys::crypto_generichash_init(Self::state_ptr(&mut st),
+                                                   ptr::null_mut(),
+                                                   0,
+                                                   libsodium_sys::crypto_generichash_BYTES);
+        }
+        Hasher { state: st }
+    }
+
+    /// Adds a chunk of data to the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        unsafe {
+            libsodium_sys::crypto_generichash_update(Self::state_ptr(&mut self.state),
+                                                     data.as_ptr(),
+                                                     data.len() as u64);
+        }
+    }
+
+    /// Returns the hex encoded hash of all the data added.
+    pub fn finish(mut self) -> String {
+        let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
+        unsafe {
+            libsodium_sys::crypto_generichash_final(Self::state_ptr(&mut self.state),
+                                                    out.as_mut_ptr(),
+                                                    out.len());
+        }
+        out.to_hex()
+    }
+
+    fn state_ptr(st: &mut [u8]) -> *mut libsodium_sys::crypto_generichash_state {
         unsafe {
-            libsodium_sys::crypto_generichash_update(pst, chunk.as_ptr(), chunk.len() as u64);
+            mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
+        }
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+/// A reader which hashes the bytes passing through it.
+///
+/// Clones share the underlying reader and hash, so a clone can be handed to a consumer which
+/// takes ownership of its reader, such as an archive extractor, while the original is kept to
+/// `finish` the hash once the consumer is done.
+pub struct HashingReader<R: Read> {
+    inner: Rc<RefCell<Option<(R, Hasher)>>>,
+}
+
+impl<R: Read> HashingReader<R> {
+    pub fn new(reader: R) -> Self {
+        HashingReader { inner: Rc::new(RefCell::new(Some((reader, Hasher::new())))) }
+    }
+
+    /// Hashes whatever the consumer left unread and returns the hex encoded hash of everything
+    /// read from the underlying reader.
+    ///
+    /// # Failures
+    ///
+    /// * The rest of the underlying reader can't be read
+    /// * The hash was already finished by a clone of this reader
+    pub fn finish(&self) -> Result<String> {
+        let (mut reader, mut hasher) = match self.inner.borrow_mut().take() {
+            Some(inner) => inner,
+            None => return Err(Error::CryptoError("Hash already finished".to_string())),
+        };
+        let mut buf = [0u8; BUF_SIZE];
+        loop {
+            let bytes_read = try!(reader.read(&mut buf));
+            if bytes_read == 0 {
+                break;
+            }
+            hasher.update(&buf[0..bytes_read]);
         }
+        Ok(hasher.finish())
+    }
+}
+
+impl<R: Read> Clone for HashingReader<R> {
+    fn clone(&self) -> Self {
+        HashingReader { inner: self.inner.clone() }
     }
-    unsafe {
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match *self.inner.borrow_mut() {
+            Some((ref mut reader, ref mut hasher)) => {
+                let bytes_read = try!(reader.read(buf));
+                hasher.update(&buf[0..bytes_read]);
+                Ok(bytes_read)
+            }
+            None => Ok(0),
+        }
     }
-    Ok(out.to_hex())
 }
 
 #[cfg(test)]
@@ -82,7 +175,7 @@ mod test {
     #[allow(unused_imports)]
     use std::fs::{self, File};
     #[allow(unused_imports)]
-    use std::io;
+    use std::io::{self, Read};
     use std::path::PathBuf;
 
     #[cfg(feature = "functional")]
@@ -128,6 +221,29 @@ mod test {
         assert_eq!(computed, expected);
     }
 
+    #[test]
+    fn hasher_matches_hash_file() {
+        let mut data = Vec::new();
+        File::open(fixture("signme.dat")).unwrap().read_to_end(&mut data).unwrap();
+        let mut hasher = Hasher::new();
+        for chunk in data.chunks(7) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finish(), hash_file(&fixture("signme.dat")).unwrap());
+    }
+
+    #[test]
+    fn hashing_reader_hashes_unread_bytes_on_finish() {
+        let reader = HashingReader::new(File::open(fixture("signme.dat")).unwrap());
+        let mut consumer = reader.clone();
+        let mut buf = [0u8; 10];
+        consumer.read_exact(&mut buf).unwrap();
+        drop(consumer);
+        assert_eq!(reader.finish().unwrap(),
+                   hash_file(&fixture("signme.dat")).unwrap());
+        assert!(reader.finish().is_err());
+    }
+
     #[test]
     #[cfg(feature = "functional")]
     fn hash_file_large_binary() {
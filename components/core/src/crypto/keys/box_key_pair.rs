@@ -23,8 +23,9 @@ use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::SecretKey as BoxSecre
 use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::{Nonce, gen_nonce};
 
 use error::{Error, Result};
-use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_with_rev,
-            read_key_bytes, write_keypair_files, KeyPair, KeyType};
+use super::{get_key_revisions, key_expiry, mk_key_filename, mk_revision_string,
+            parse_name_with_rev, read_key_bytes, read_key_expires, read_secret_key_bytes,
+            secret_key_content, write_keypair_files, KeyPair, KeyType};
 use super::super::{BOX_FORMAT_VERSION, PUBLIC_KEY_SUFFIX, SECRET_BOX_KEY_SUFFIX};
 
 pub type BoxKeyPair = KeyPair<BoxPublicKey, BoxSecretKey>;
@@ -37,10 +38,14 @@ impl BoxKeyPair {
         let revision = try!(mk_revision_string());
         let keyname = Self::mk_key_name_for_service(org, service_group, &revision);
         debug!("new service box key name = {}", &keyname);
+        let expires = try!(key_expiry());
         let (public_key, secret_key) = try!(Self::generate_pair_files(&keyname,
+                                                                      expires.as_ref(),
                                                                       cache_key_path.as_ref()));
         let (name, _) = try!(parse_name_with_rev(&keyname));
-        Ok(Self::new(name, revision, Some(public_key), Some(secret_key)))
+        let mut pair = Self::new(name, revision, Some(public_key), Some(secret_key));
+        pair.expires = expires;
+        Ok(pair)
     }
 
     pub fn generate_pair_for_user<P: AsRef<Path> + ?Sized>(user: &str,
@@ -49,10 +54,14 @@ impl BoxKeyPair {
         let revision = try!(mk_revision_string());
         let keyname = Self::mk_key_name_for_user(user, &revision);
         debug!("new user sig key name = {}", &keyname);
+        let expires = try!(key_expiry());
         let (public_key, secret_key) = try!(Self::generate_pair_files(&keyname,
+                                                                      expires.as_ref(),
                                                                       cache_key_path.as_ref()));
         let (name, _) = try!(parse_name_with_rev(&keyname));
-        Ok(Self::new(name, revision, Some(public_key), Some(secret_key)))
+        let mut pair = Self::new(name, revision, Some(public_key), Some(secret_key));
+        pair.expires = expires;
+        Ok(pair)
     }
 
     pub fn get_pairs_for<P: AsRef<Path> + ?Sized>(name: &str,
@@ -99,7 +108,15 @@ impl BoxKeyPair {
                               name_with_rev);
             return Err(Error::CryptoError(msg));
         }
-        Ok(Self::new(name, rev, pk, sk))
+        let mut pair = Self::new(name, rev, pk, sk);
+        let cache_key_path = cache_key_path.as_ref();
+        pair.expires = try!(read_key_expires(&[mk_key_filename(cache_key_path,
+                                                               name_with_rev,
+                                                               PUBLIC_KEY_SUFFIX),
+                                               mk_key_filename(cache_key_path,
+                                                               name_with_rev,
+                                                               SECRET_BOX_KEY_SUFFIX)]));
+        Ok(pair)
     }
 
     pub fn get_latest_pair_for<P: AsRef<Path> + ?Sized>(name: &str,
@@ -137,7 +154,10 @@ impl BoxKeyPair {
 
     /// A user can encrypt data with a service as the recipient.
     /// Key names and nonce are embedded in the payload.
+    /// Neither the sender's nor the receiver's key may have expired.
     pub fn encrypt(&self, data: &[u8], receiver: &Self) -> Result<Vec<u8>> {
+        try!(self.check_not_expired());
+        try!(receiver.check_not_expired());
         let nonce = gen_nonce();
         let ciphertext = box_::seal(data, &nonce, try!(receiver.public()), try!(self.secret()));
 
@@ -228,7 +248,43 @@ impl BoxKeyPair {
         }
     }
 
+    /// Returns the names with revision of the sender and receiver keys of an encrypted payload
+    /// without decrypting it, so the caller can tell which secret key is needed to decrypt it.
+    pub fn payload_key_names(payload: &[u8]) -> Result<(String, String)> {
+        let mut lines = try!(str::from_utf8(payload)).lines();
+        match lines.next() {
+            Some(val) if val == BOX_FORMAT_VERSION => (),
+            Some(val) => return Err(Error::CryptoError(format!("Unsupported version: {}", val))),
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read file version"
+                    .to_string()))
+            }
+        }
+        let sender = match lines.next() {
+            Some(val) => {
+                try!(parse_name_with_rev(val));
+                val.to_string()
+            }
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read sender key name"
+                    .to_string()))
+            }
+        };
+        let receiver = match lines.next() {
+            Some(val) => {
+                try!(parse_name_with_rev(val));
+                val.to_string()
+            }
+            None => {
+                return Err(Error::CryptoError("Corrupt payload, can't read receiver key name"
+                    .to_string()))
+            }
+        };
+        Ok((sender, receiver))
+    }
+
     fn generate_pair_files(name_with_rev: &str,
+                           expires: Option<&String>,
                            cache_key_path: &Path)
                            -> Result<(BoxPublicKey, BoxSecretKey)> {
         let (pk, sk) = box_::gen_keypair();
@@ -238,12 +294,15 @@ impl BoxKeyPair {
         debug!("public box keyfile = {}", public_keyfile.display());
         debug!("secret box keyfile = {}", secret_keyfile.display());
 
+        let (secret_content, encrypted) = try!(secret_key_content(&sk[..]));
         try!(write_keypair_files(KeyType::Box,
                                  &name_with_rev,
+                                 expires,
                                  Some(&public_keyfile),
                                  Some(&pk[..].to_base64(STANDARD).into_bytes()),
                                  Some(&secret_keyfile),
-                                 Some(&sk[..].to_base64(STANDARD).into_bytes())));
+                                 Some(&secret_content),
+                                 encrypted));
         Ok((pk, sk))
     }
 
@@ -261,7 +320,7 @@ impl BoxKeyPair {
 
     fn get_secret_key(key_with_rev: &str, cache_key_path: &Path) -> Result<BoxSecretKey> {
         let secret_keyfile = mk_key_filename(cache_key_path, key_with_rev, SECRET_BOX_KEY_SUFFIX);
-        let bytes = try!(read_key_bytes(&secret_keyfile));
+        let bytes = try!(read_secret_key_bytes(&secret_keyfile));
         match BoxSecretKey::from_slice(&bytes) {
             Some(sk) => Ok(sk),
             None => {
@@ -481,6 +540,18 @@ mod test {
         assert_eq!(message, "I wish to buy more rockets".as_bytes());
     }
 
+    #[test]
+    #[should_panic(expected = "expired")]
+    fn encrypt_with_expired_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let service = BoxKeyPair::generate_pair_for_service("acme", "tnt.default", cache.path())
+            .unwrap();
+        let mut user = BoxKeyPair::generate_pair_for_user("wecoyote", cache.path()).unwrap();
+        user.expires = Some("20160101000000".to_string());
+
+        user.encrypt("I wish to buy more rockets".as_bytes(), &service).unwrap();
+    }
+
     #[test]
     fn encrypt_and_decrypt_from_service_to_user() {
         let cache = TempDir::new("key_cache").unwrap();
@@ -493,6 +564,25 @@ mod test {
         assert_eq!(message, "Out of rockets".as_bytes());
     }
 
+    #[test]
+    fn encrypt_and_decrypt_from_user_to_user() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let sender = BoxKeyPair::generate_pair_for_user("wecoyote", cache.path()).unwrap();
+        let receiver = BoxKeyPair::generate_pair_for_user("roadrunner", cache.path()).unwrap();
+
+        let ciphertext = sender.encrypt("Meep meep".as_bytes(), &receiver).unwrap();
+        assert_eq!(BoxKeyPair::payload_key_names(&ciphertext).unwrap(),
+                   (sender.name_with_rev(), receiver.name_with_rev()));
+        let message = BoxKeyPair::decrypt(&ciphertext, cache.path()).unwrap();
+        assert_eq!(message, "Meep meep".as_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported version")]
+    fn payload_key_names_invalid_version() {
+        BoxKeyPair::payload_key_names("SIG-PUB-1\nnope-20160509181736\n".as_bytes()).unwrap();
+    }
+
     #[test]
     fn encrypt_and_decrypt_minimal_keys() {
         let full_cache = TempDir::new("full_cache").unwrap();
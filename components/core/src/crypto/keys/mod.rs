@@ -23,26 +23,41 @@ use std::result;
 use std::str::FromStr;
 
 use regex::Regex;
-use rustc_serialize::base64::FromBase64;
+use rustc_serialize::base64::{STANDARD, FromBase64, ToBase64};
+use sodiumoxide::crypto::pwhash::scryptsalsa208sha256 as pwhash;
+use sodiumoxide::crypto::secretbox;
 use time;
 
+use env as henv;
 use error::{Error, Result};
 use util::perm;
 
-use super::{PUBLIC_BOX_KEY_VERSION, PUBLIC_KEY_PERMISSIONS, PUBLIC_KEY_SUFFIX,
-            PUBLIC_SIG_KEY_VERSION, SECRET_BOX_KEY_SUFFIX, SECRET_BOX_KEY_VERSION,
-            SECRET_KEY_PERMISSIONS, SECRET_SIG_KEY_SUFFIX, SECRET_SIG_KEY_VERSION,
-            SECRET_SYM_KEY_SUFFIX, SECRET_SYM_KEY_VERSION};
+use super::{KEY_EXPIRE_DAYS_ENV_VAR, KEY_PASSPHRASE_ENV_VAR, KEY_PASSPHRASE_FILE_ENV_VAR,
+            PUBLIC_BOX_KEY_VERSION, PUBLIC_KEY_PERMISSIONS, PUBLIC_KEY_SUFFIX,
+            PUBLIC_SIG_KEY_VERSION,
+            SECRET_BOX_KEY_ENCRYPTED_VERSION, SECRET_BOX_KEY_SUFFIX, SECRET_BOX_KEY_VERSION,
+            SECRET_KEY_PERMISSIONS, SECRET_SIG_KEY_ENCRYPTED_VERSION, SECRET_SIG_KEY_SUFFIX,
+            SECRET_SIG_KEY_VERSION, SECRET_SYM_KEY_ENCRYPTED_VERSION, SECRET_SYM_KEY_SUFFIX,
+            SECRET_SYM_KEY_VERSION};
 
 lazy_static! {
     static ref NAME_WITH_REV_RE: Regex = Regex::new(r"\A(?P<name>.+)-(?P<rev>\d{14})\z").unwrap();
     static ref KEYFILE_RE: Regex = Regex::new(r"\A(?P<name>.+)-(?P<rev>\d{14})\.(?P<suffix>[a-z]+(\.[a-z]+)?)\z").unwrap();
     static ref ORIGIN_NAME_RE: Regex = Regex::new(r"\A[a-z0-9][a-z0-9_-]*\z").unwrap();
+    static ref EXPIRES_RE: Regex = Regex::new(r"\AExpires: (?P<expires>\d{14})\z").unwrap();
 }
 
 pub mod box_key_pair;
+pub mod revocation;
 pub mod sym_key;
 pub mod sig_key_pair;
+pub mod trust;
+
+/// The suffix of the format version of a key file without headers, ex: `SIG-PUB-1`
+const BASE_VERSION_SUFFIX: &'static str = "-1";
+
+/// The suffix of the format version of a key file with an `Expires:` header, ex: `SIG-PUB-2`
+const EXPIRING_VERSION_SUFFIX: &'static str = "-2";
 
 enum KeyType {
     Sig,
@@ -108,6 +123,8 @@ pub struct KeyPair<P, S> {
     pub public: Option<P>,
     /// The private key component, if relevant
     pub secret: Option<S>,
+    /// The time the key expires, in the same format as the revision, if the key expires
+    pub expires: Option<String>,
 }
 
 impl<P, S> KeyPair<P, S> {
@@ -118,6 +135,7 @@ impl<P, S> KeyPair<P, S> {
             rev: rev,
             public: p,
             secret: s,
+            expires: None,
         }
     }
 
@@ -137,6 +155,31 @@ impl<P, S> KeyPair<P, S> {
         }
     }
 
+    /// Returns true if the key has an expiry which has passed.
+    pub fn is_expired(&self) -> bool {
+        match self.expires {
+            Some(ref expires) => {
+                match mk_revision_string() {
+                    Ok(now) => now >= *expires,
+                    Err(_) => false,
+                }
+            }
+            None => false,
+        }
+    }
+
+    /// Returns an error if the key has expired. Keys are checked before they are used to sign or
+    /// encrypt.
+    pub fn check_not_expired(&self) -> Result<()> {
+        if self.is_expired() {
+            let msg = format!("Key {} expired at {}",
+                              self.name_with_rev(),
+                              self.expires.as_ref().unwrap());
+            return Err(Error::CryptoError(msg));
+        }
+        Ok(())
+    }
+
     pub fn secret(&self) -> Result<&S> {
         match self.secret.as_ref() {
             Some(s) => Ok(s),
@@ -202,7 +245,7 @@ fn check_filename(keyname: &str, filename: String, candidates: &mut HashSet<Stri
 
 /// Take a key name (ex "habitat"), and find all revisions of that
 /// keyname in the default_cache_key_path().
-fn get_key_revisions(keyname: &str, cache_key_path: &Path) -> Result<Vec<String>> {
+pub fn get_key_revisions(keyname: &str, cache_key_path: &Path) -> Result<Vec<String>> {
     // accumulator for files that match
     let mut candidates = HashSet::new();
     let paths = match fs::read_dir(cache_key_path) {
@@ -266,7 +309,10 @@ fn mk_key_filename(path: &Path, keyname: &str, suffix: &str) -> PathBuf {
 /// `{year}{month}{day}{hour24}{minute}{second}`
 /// Timestamps are in UTC time.
 fn mk_revision_string() -> Result<String> {
-    let now = time::now_utc();
+    mk_timestamp(time::now_utc())
+}
+
+fn mk_timestamp(now: time::Tm) -> Result<String> {
     // https://github.com/rust-lang-deprecated/time/blob/master/src/display.rs
     // http://man7.org/linux/man-pages/man3/strftime.3.html
     match now.strftime("%Y%m%d%H%M%S") {
@@ -310,11 +356,38 @@ pub fn is_valid_origin_name(name: &str) -> bool {
 
 /// Read a file into a Vec<u8>
 fn read_key_bytes(keyfile: &Path) -> Result<Vec<u8>> {
+    let s = try!(read_key_str(keyfile));
+    decode_key_body(&s, keyfile)
+}
+
+/// Read a secret key file into a Vec<u8>, decrypting the key if it is stored encrypted with a
+/// passphrase.
+fn read_secret_key_bytes(keyfile: &Path) -> Result<Vec<u8>> {
+    let s = try!(read_key_str(keyfile));
+    let bytes = try!(decode_key_body(&s, keyfile));
+    if !is_encrypted_secret_key(&s) {
+        return Ok(bytes);
+    }
+    match key_passphrase() {
+        Some(passphrase) => open_key_bytes(&bytes, &passphrase, keyfile),
+        None => {
+            Err(Error::CryptoError(format!("Secret key {} is encrypted, set {} to its passphrase",
+                                           keyfile.display(),
+                                           KEY_PASSPHRASE_ENV_VAR)))
+        }
+    }
+}
+
+fn read_key_str(keyfile: &Path) -> Result<String> {
     let mut f = try!(File::open(keyfile));
     let mut s = String::new();
     if try!(f.read_to_string(&mut s)) <= 0 {
         return Err(Error::CryptoError("Can't read key bytes".to_string()));
     }
+    Ok(s)
+}
+
+fn decode_key_body(s: &str, keyfile: &Path) -> Result<Vec<u8>> {
     let start_index = match s.find("\n\n") {
         Some(i) => i + 1,
         None => {
@@ -333,12 +406,235 @@ fn read_key_bytes(keyfile: &Path) -> Result<Vec<u8>> {
     }
 }
 
+/// Returns the expiry recorded in the headers of a key file's content, if the key expires. The
+/// headers are the lines between the key name with revision and the empty line.
+///
+/// # Failures
+///
+/// * A header line is not a well formed expiry
+/// * A version 2 key file has no expiry, or a version 1 key file has headers
+pub fn parse_key_expires(content: &str) -> Result<Option<String>> {
+    let mut expires = None;
+    for line in content.lines().skip(2).take_while(|l| !l.is_empty()) {
+        match EXPIRES_RE.captures(line).and_then(|c| c.name("expires")) {
+            Some(e) => expires = Some(e.to_string()),
+            None => {
+                return Err(Error::CryptoError(format!("Malformed key header: {}", line)));
+            }
+        }
+    }
+    let version = content.lines().next().unwrap_or("");
+    match (is_expiring_version(version), expires.is_some()) {
+        (true, false) => {
+            Err(Error::CryptoError(format!("Key format version {} requires an expiry", version)))
+        }
+        (false, true) => {
+            Err(Error::CryptoError(format!("Key format version {} can't have an expiry",
+                                           version)))
+        }
+        _ => Ok(expires),
+    }
+}
+
+/// Is a key format version one whose key files carry an expiry?
+fn is_expiring_version(version: &str) -> bool {
+    version.ends_with(EXPIRING_VERSION_SUFFIX)
+}
+
+/// Returns the format version of a key file's first line with the expiry version bump undone,
+/// ex: `SIG-PUB-1` for `SIG-PUB-2`, so it can be compared with the key version constants.
+pub fn base_key_version(version: &str) -> String {
+    if is_expiring_version(version) {
+        format!("{}{}",
+                &version[..version.len() - EXPIRING_VERSION_SUFFIX.len()],
+                BASE_VERSION_SUFFIX)
+    } else {
+        version.to_string()
+    }
+}
+
+/// Returns the format version a key file with an expiry is written with, ex: `SIG-PUB-2` for
+/// `SIG-PUB-1`.
+fn expiring_version(version: &str) -> String {
+    format!("{}{}",
+            version.trim_right_matches(BASE_VERSION_SUFFIX),
+            EXPIRING_VERSION_SUFFIX)
+}
+
+/// Returns the key body of a key file's content, given an iterator over its lines which has
+/// consumed the version and the key name with revision. Any header lines are skipped.
+fn next_key_body<'a, I: Iterator<Item = &'a str>>(lines: &mut I) -> Option<&'a str> {
+    lines.skip_while(|l| !l.is_empty()).nth(1)
+}
+
+/// Returns the expiry of a key from the first of its key files which exists.
+fn read_key_expires(keyfiles: &[PathBuf]) -> Result<Option<String>> {
+    match keyfiles.iter().find(|k| k.is_file()) {
+        Some(keyfile) => parse_key_expires(&try!(read_key_str(keyfile))),
+        None => Ok(None),
+    }
+}
+
+/// Returns the expiry for newly generated keys, if a number of days is set in the environment.
+///
+/// # Failures
+///
+/// * The number of days is not a positive number
+fn key_expiry() -> Result<Option<String>> {
+    let days = match henv::var(KEY_EXPIRE_DAYS_ENV_VAR) {
+        Ok(ref val) if !val.trim().is_empty() => val.trim().to_string(),
+        _ => return Ok(None),
+    };
+    match days.parse::<u32>() {
+        Ok(days) if days > 0 => {
+            let expires = time::now_utc() + time::Duration::days(days as i64);
+            Ok(Some(try!(mk_timestamp(expires))))
+        }
+        _ => {
+            Err(Error::CryptoError(format!("{} must be a positive number of days, not {}",
+                                           KEY_EXPIRE_DAYS_ENV_VAR,
+                                           days)))
+        }
+    }
+}
+
+/// Returns the passphrase for encrypted secret keys, if one is set in the environment, either
+/// directly or as the path to a file holding it. A trailing newline in the file is ignored.
+pub fn key_passphrase() -> Option<String> {
+    match henv::var(KEY_PASSPHRASE_ENV_VAR) {
+        Ok(ref val) if !val.is_empty() => return Some(val.to_string()),
+        _ => (),
+    }
+    let path = match henv::var(KEY_PASSPHRASE_FILE_ENV_VAR) {
+        Ok(ref val) if !val.is_empty() => PathBuf::from(val),
+        _ => return None,
+    };
+    let mut content = String::new();
+    match File::open(&path).and_then(|mut f| f.read_to_string(&mut content)) {
+        Ok(_) => {
+            let passphrase = content.trim_right_matches(|c: char| c == '\n' || c == '\r');
+            if passphrase.is_empty() {
+                None
+            } else {
+                Some(passphrase.to_string())
+            }
+        }
+        Err(e) => {
+            debug!("Could not read key passphrase file {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Returns the path to the secret key file of the latest revision of a key, if that revision has
+/// a secret key with the given suffix in the key cache.
+pub fn latest_secret_keyfile(keyname: &str,
+                             suffix: &str,
+                             cache_key_path: &Path)
+                             -> Result<Option<PathBuf>> {
+    let revisions = try!(get_key_revisions(keyname, cache_key_path));
+    match revisions.first() {
+        Some(name_with_rev) => {
+            let keyfile = mk_key_filename(cache_key_path, name_with_rev, suffix);
+            if keyfile.is_file() {
+                Ok(Some(keyfile))
+            } else {
+                Ok(None)
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// Is the content of a secret key file encrypted with a passphrase?
+pub fn is_encrypted_secret_key(content: &str) -> bool {
+    match &*base_key_version(content.lines().next().unwrap_or("")) {
+        SECRET_SIG_KEY_ENCRYPTED_VERSION |
+        SECRET_BOX_KEY_ENCRYPTED_VERSION |
+        SECRET_SYM_KEY_ENCRYPTED_VERSION => true,
+        _ => false,
+    }
+}
+
+/// Checks that a passphrase decrypts the content of an encrypted secret key file.
+///
+/// # Failures
+///
+/// * The content is not a well formed secret key
+/// * The passphrase does not decrypt the secret key
+pub fn check_key_passphrase(content: &str, passphrase: &str) -> Result<()> {
+    let source = Path::new(content.lines().nth(1).unwrap_or(""));
+    let bytes = try!(decode_key_body(content, source));
+    if is_encrypted_secret_key(content) {
+        try!(open_key_bytes(&bytes, passphrase, source));
+    }
+    Ok(())
+}
+
+/// Returns the Base64 content of a newly generated secret key and whether it is encrypted. The
+/// key is encrypted when a passphrase is set in the environment.
+fn secret_key_content(raw: &[u8]) -> Result<(Vec<u8>, bool)> {
+    match key_passphrase() {
+        Some(passphrase) => {
+            let sealed = try!(seal_key_bytes(raw, &passphrase));
+            Ok((sealed.to_base64(STANDARD).into_bytes(), true))
+        }
+        None => Ok((raw.to_base64(STANDARD).into_bytes(), false)),
+    }
+}
+
+fn passphrase_key(passphrase: &str, salt: &pwhash::Salt) -> Result<secretbox::Key> {
+    let mut key = [0u8; secretbox::KEYBYTES];
+    if pwhash::derive_key(&mut key,
+                          passphrase.as_bytes(),
+                          salt,
+                          pwhash::OPSLIMIT_INTERACTIVE,
+                          pwhash::MEMLIMIT_INTERACTIVE)
+        .is_err() {
+        return Err(Error::CryptoError("Can't derive a key from the passphrase".to_string()));
+    }
+    Ok(secretbox::Key(key))
+}
+
+/// Seals the raw bytes of a secret key with a key derived from a passphrase. The return is the
+/// salt, nonce and ciphertext, in that order.
+fn seal_key_bytes(raw: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let salt = pwhash::gen_salt();
+    let nonce = secretbox::gen_nonce();
+    let key = try!(passphrase_key(passphrase, &salt));
+    let mut sealed = Vec::new();
+    sealed.extend_from_slice(&salt[..]);
+    sealed.extend_from_slice(&nonce[..]);
+    sealed.extend_from_slice(&secretbox::seal(raw, &nonce, &key));
+    Ok(sealed)
+}
+
+fn open_key_bytes(sealed: &[u8], passphrase: &str, keyfile: &Path) -> Result<Vec<u8>> {
+    let header_len = pwhash::SALTBYTES + secretbox::NONCEBYTES;
+    if sealed.len() <= header_len {
+        return Err(Error::CryptoError(format!("Malformed encrypted key contents for: {}",
+                                              keyfile.display())));
+    }
+    let salt = pwhash::Salt::from_slice(&sealed[..pwhash::SALTBYTES]).unwrap();
+    let nonce = secretbox::Nonce::from_slice(&sealed[pwhash::SALTBYTES..header_len]).unwrap();
+    let key = try!(passphrase_key(passphrase, &salt));
+    match secretbox::open(&sealed[header_len..], &nonce, &key) {
+        Ok(raw) => Ok(raw),
+        Err(_) => {
+            Err(Error::CryptoError(format!("Incorrect passphrase for secret key {}",
+                                           keyfile.display())))
+        }
+    }
+}
+
 fn write_keypair_files(key_type: KeyType,
                        keyname: &str,
+                       expires: Option<&String>,
                        public_keyfile: Option<&Path>,
                        public_content: Option<&Vec<u8>>,
                        secret_keyfile: Option<&Path>,
-                       secret_content: Option<&Vec<u8>>)
+                       secret_content: Option<&Vec<u8>>,
+                       secret_encrypted: bool)
                        -> Result<()> {
     if let Some(public_keyfile) = public_keyfile {
         let public_version = match key_type {
@@ -364,16 +660,19 @@ fn write_keypair_files(key_type: KeyType,
         }
         let public_file = try!(File::create(public_keyfile));
         let mut public_writer = BufWriter::new(&public_file);
-        try!(write!(public_writer, "{}\n{}\n\n", public_version, keyname));
+        try!(write_key_header(&mut public_writer, public_version, keyname, expires));
         try!(public_writer.write_all(public_content));
         try!(perm::set_permissions(public_keyfile, PUBLIC_KEY_PERMISSIONS));
     }
 
     if let Some(secret_keyfile) = secret_keyfile {
-        let secret_version = match key_type {
-            KeyType::Sig => SECRET_SIG_KEY_VERSION,
-            KeyType::Box => SECRET_BOX_KEY_VERSION,
-            KeyType::Sym => SECRET_SYM_KEY_VERSION,
+        let secret_version = match (key_type, secret_encrypted) {
+            (KeyType::Sig, false) => SECRET_SIG_KEY_VERSION,
+            (KeyType::Box, false) => SECRET_BOX_KEY_VERSION,
+            (KeyType::Sym, false) => SECRET_SYM_KEY_VERSION,
+            (KeyType::Sig, true) => SECRET_SIG_KEY_ENCRYPTED_VERSION,
+            (KeyType::Box, true) => SECRET_BOX_KEY_ENCRYPTED_VERSION,
+            (KeyType::Sym, true) => SECRET_SYM_KEY_ENCRYPTED_VERSION,
         };
 
         let secret_content = match secret_content {
@@ -393,7 +692,7 @@ fn write_keypair_files(key_type: KeyType,
         }
         let secret_file = try!(File::create(secret_keyfile));
         let mut secret_writer = BufWriter::new(&secret_file);
-        try!(write!(secret_writer, "{}\n{}\n\n", secret_version, keyname));
+        try!(write_key_header(&mut secret_writer, secret_version, keyname, expires));
         try!(secret_writer.write_all(secret_content));
         try!(perm::set_permissions(secret_keyfile, SECRET_KEY_PERMISSIONS));
     }
@@ -401,12 +700,30 @@ fn write_keypair_files(key_type: KeyType,
     Ok(())
 }
 
+fn write_key_header<W: Write>(writer: &mut W,
+                              version: &str,
+                              keyname: &str,
+                              expires: Option<&String>)
+                              -> Result<()> {
+    match expires {
+        Some(expires) => {
+            try!(write!(writer, "{}\n{}\n", expiring_version(version), keyname));
+            try!(write!(writer, "Expires: {}\n", expires));
+        }
+        None => try!(write!(writer, "{}\n{}\n", version, keyname)),
+    }
+    try!(write!(writer, "\n"));
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashSet;
     use std::fs::{self, File};
     use std::io::Write;
+    use std::path::Path;
     use tempdir::TempDir;
+    use rustc_serialize::base64::{STANDARD, ToBase64};
     use rustc_serialize::hex::ToHex;
 
 
@@ -420,6 +737,86 @@ mod test {
     static VALID_KEY: &'static str = "ring-key-valid-20160504220722.sym.key";
     static VALID_KEY_AS_HEX: &'static str = "44215a3bce23e351a6af359d77131db17a46767de2b88cbb330df162b8cf2ec1";
 
+    #[test]
+    fn parse_key_expires() {
+        let content = "SIG-PUB-2\nunicorn-20160517220007\nExpires: 20170517220007\n\nbody";
+        assert_eq!(super::parse_key_expires(content).unwrap(),
+                   Some("20170517220007".to_string()));
+        let content = "SIG-PUB-1\nunicorn-20160517220007\n\nbody";
+        assert_eq!(super::parse_key_expires(content).unwrap(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed key header")]
+    fn parse_key_expires_malformed() {
+        let content = "SIG-PUB-2\nunicorn-20160517220007\nExpires: tomorrow\n\nbody";
+        super::parse_key_expires(content).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "can't have an expiry")]
+    fn parse_key_expires_in_version_1() {
+        let content = "SIG-PUB-1\nunicorn-20160517220007\nExpires: 20170517220007\n\nbody";
+        super::parse_key_expires(content).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "requires an expiry")]
+    fn parse_key_expires_missing_in_version_2() {
+        let content = "SIG-PUB-2\nunicorn-20160517220007\n\nbody";
+        super::parse_key_expires(content).unwrap();
+    }
+
+    #[test]
+    fn key_versions_with_expiry() {
+        assert_eq!(super::expiring_version("SIG-PUB-1"), "SIG-PUB-2");
+        assert_eq!(super::expiring_version("SYM-SEC-ENC-1"), "SYM-SEC-ENC-2");
+        assert_eq!(super::base_key_version("SIG-PUB-2"), "SIG-PUB-1");
+        assert_eq!(super::base_key_version("SYM-SEC-ENC-2"), "SYM-SEC-ENC-1");
+        assert_eq!(super::base_key_version("BOX-SEC-1"), "BOX-SEC-1");
+        assert!(super::is_encrypted_secret_key("BOX-SEC-ENC-2\nunicorn-20160517220007\n"));
+    }
+
+    #[test]
+    fn key_pair_expiry() {
+        let mut pair = SigKeyPair::new("unicorn".to_string(),
+                                       "20160517220007".to_string(),
+                                       None,
+                                       None);
+        assert!(!pair.is_expired());
+        pair.expires = Some("99991231235959".to_string());
+        assert!(!pair.is_expired());
+        pair.check_not_expired().unwrap();
+        pair.expires = Some("20160517220007".to_string());
+        assert!(pair.is_expired());
+        assert!(pair.check_not_expired().is_err());
+    }
+
+    #[test]
+    fn seal_and_open_key_bytes() {
+        let sealed = super::seal_key_bytes(b"sekrit", "passphrase").unwrap();
+        let opened = super::open_key_bytes(&sealed, "passphrase", Path::new("mykey")).unwrap();
+        assert_eq!(b"sekrit".to_vec(), opened);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incorrect passphrase")]
+    fn open_key_bytes_wrong_passphrase() {
+        let sealed = super::seal_key_bytes(b"sekrit", "passphrase").unwrap();
+        super::open_key_bytes(&sealed, "wrong", Path::new("mykey")).unwrap();
+    }
+
+    #[test]
+    fn check_encrypted_key_passphrase() {
+        let sealed = super::seal_key_bytes(b"sekrit", "passphrase").unwrap();
+        let content = format!("SIG-SEC-ENC-1\nunicorn-20160517220007\n\n{}",
+                              sealed.to_base64(STANDARD));
+        assert!(super::is_encrypted_secret_key(&content));
+        assert!(super::check_key_passphrase(&content, "passphrase").is_ok());
+        assert!(super::check_key_passphrase(&content, "wrong").is_err());
+        assert!(!super::is_encrypted_secret_key("SIG-SEC-1\nunicorn-20160517220007\n\nabc="));
+    }
+
     #[test]
     fn tmp_keyfile_delete_on_drop() {
         let cache = TempDir::new("key_cache").unwrap();
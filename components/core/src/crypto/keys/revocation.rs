@@ -0,0 +1,328 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::result;
+use std::str::FromStr;
+
+use rustc_serialize::base64::{STANDARD, ToBase64, FromBase64};
+use rustc_serialize::hex::ToHex;
+use sodiumoxide::crypto::sign;
+use sodiumoxide::crypto::sign::ed25519::PublicKey as SigPublicKey;
+use sodiumoxide::randombytes::randombytes;
+
+use error::{Error, Result};
+use super::{mk_key_filename, parse_name_with_rev, TmpKeyfile};
+use super::sig_key_pair::SigKeyPair;
+use super::super::{KEY_REVOCATION_SUFFIX, KEY_REVOCATION_VERSION, PUBLIC_KEY_PERMISSIONS};
+use util::perm;
+
+/// A signed statement that an origin key revision must no longer be trusted.
+///
+/// A revocation is signed by a key of the same origin whose revision is the same as, or newer
+/// than, the revoked key. This allows an origin to revoke a key with the key itself, or with the
+/// key that replaces it during a rotation, but never lets an older key revoke a newer one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyRevocation {
+    /// The name with revision of the revoked key, ex: "habitat-20160405144945"
+    pub revoked: String,
+    /// The name with revision of the key which signed this revocation
+    pub signer: String,
+    signature: Vec<u8>,
+}
+
+impl KeyRevocation {
+    /// Creates a revocation of `revoked`, signed with the secret key of `signer`.
+    ///
+    /// # Errors
+    ///
+    /// * If `revoked` is not a valid key name with revision
+    /// * If the signer does not belong to the same origin as the revoked key
+    /// * If the signer is older than the revoked key
+    /// * If the signer's secret key is not present
+    pub fn create(revoked: &str, signer: &SigKeyPair) -> Result<Self> {
+        try!(check_signer(revoked, &signer.name_with_rev()));
+        let signature = sign::sign(revoked.as_bytes(), try!(signer.secret()));
+        Ok(KeyRevocation {
+            revoked: revoked.to_string(),
+            signer: signer.name_with_rev(),
+            signature: signature,
+        })
+    }
+
+    /// Returns the path of the revocation file for `revoked` in the key cache.
+    pub fn get_path<P: AsRef<Path> + ?Sized>(revoked: &str, cache_key_path: &P) -> PathBuf {
+        mk_key_filename(cache_key_path.as_ref(), revoked, KEY_REVOCATION_SUFFIX)
+    }
+
+    /// Reads the revocation for `revoked` from the key cache, if one is present.
+    pub fn get_for<P: AsRef<Path> + ?Sized>(revoked: &str,
+                                            cache_key_path: &P)
+                                            -> Result<Option<Self>> {
+        let path = Self::get_path(revoked, cache_key_path);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let mut f = try!(File::open(&path));
+        let mut content = String::new();
+        try!(f.read_to_string(&mut content));
+        let revocation = try!(Self::from_str(&content));
+        if revocation.revoked != revoked {
+            let msg = format!("Revocation file {} is for {}, not {}",
+                              path.display(),
+                              revocation.revoked,
+                              revoked);
+            return Err(Error::CryptoError(msg));
+        }
+        Ok(Some(revocation))
+    }
+
+    /// Verifies the signature of this revocation against the signer's public key.
+    pub fn verify_with(&self, public_key: &SigPublicKey) -> Result<()> {
+        try!(check_signer(&self.revoked, &self.signer));
+        match sign::verify(self.signature.as_slice(), public_key) {
+            Ok(ref signed_data) if signed_data.as_slice() == self.revoked.as_bytes() => Ok(()),
+            Ok(_) => {
+                Err(Error::CryptoError(format!("Revocation signature does not match {}",
+                                               self.revoked)))
+            }
+            Err(_) => Err(Error::CryptoError("Revocation verification failed".to_string())),
+        }
+    }
+
+    /// Verifies this revocation using the signer's public key from the key cache.
+    ///
+    /// When the revocation was signed by a different key than the one being revoked, the signer
+    /// must not itself be revoked.
+    pub fn verify<P: AsRef<Path> + ?Sized>(&self, cache_key_path: &P) -> Result<()> {
+        let pair = try!(SigKeyPair::get_pair_for(&self.signer, cache_key_path));
+        try!(self.verify_with(try!(pair.public())));
+        if self.signer != self.revoked && try!(is_revoked(&self.signer, cache_key_path)) {
+            return Err(Error::CryptoError(format!("Revocation signer {} has been revoked",
+                                                  self.signer)));
+        }
+        Ok(())
+    }
+
+    /// Writes this revocation to the key cache, returning the path of the written file.
+    ///
+    /// # Errors
+    ///
+    /// * If a different revocation for the same key is already present in the key cache
+    /// * If the file cannot be written to disk
+    pub fn write_file<P: AsRef<Path> + ?Sized>(&self, cache_key_path: &P) -> Result<PathBuf> {
+        let content = self.to_string();
+        let path = Self::get_path(&self.revoked, cache_key_path);
+        if path.is_file() {
+            let mut f = try!(File::open(&path));
+            let mut existing = String::new();
+            try!(f.read_to_string(&mut existing));
+            if existing == content {
+                return Ok(path);
+            }
+            let msg = format!("Existing revocation file {} found with different content, \
+                               failing to write new file over existing.",
+                              path.display());
+            return Err(Error::CryptoError(msg));
+        }
+        try!(fs::create_dir_all(cache_key_path.as_ref()));
+        let tmpfile = TmpKeyfile {
+            path: path.with_file_name(format!("{}.{}.{}",
+                                              &self.revoked,
+                                              KEY_REVOCATION_SUFFIX,
+                                              &randombytes(6).as_slice().to_hex())),
+        };
+        {
+            let mut f = try!(File::create(&tmpfile.path));
+            try!(f.write_all(content.as_bytes()));
+        }
+        try!(perm::set_permissions(&tmpfile.path, PUBLIC_KEY_PERMISSIONS));
+        debug!("Moving {} to {}", tmpfile.path.display(), path.display());
+        try!(fs::rename(&tmpfile.path, &path));
+        Ok(path)
+    }
+
+    /// Parses a revocation from a string slice and writes it to the key cache.
+    pub fn write_file_from_str<P: AsRef<Path> + ?Sized>(content: &str,
+                                                        cache_key_path: &P)
+                                                        -> Result<Self> {
+        let revocation = try!(Self::from_str(content));
+        try!(revocation.write_file(cache_key_path));
+        Ok(revocation)
+    }
+}
+
+impl fmt::Display for KeyRevocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "{}\n{}\n{}\n\n{}",
+               KEY_REVOCATION_VERSION,
+               self.revoked,
+               self.signer,
+               self.signature.to_base64(STANDARD))
+    }
+}
+
+impl FromStr for KeyRevocation {
+    type Err = Error;
+
+    fn from_str(content: &str) -> result::Result<Self, Self::Err> {
+        let mut lines = content.lines();
+        match lines.next() {
+            Some(val) if val == KEY_REVOCATION_VERSION => (),
+            Some(val) => {
+                return Err(Error::CryptoError(format!("Unsupported revocation version: {}",
+                                                      val)))
+            }
+            None => return Err(Error::CryptoError("Malformed revocation string".to_string())),
+        }
+        let revoked = match lines.next() {
+            Some(val) => val.to_string(),
+            None => return Err(Error::CryptoError("Malformed revocation string".to_string())),
+        };
+        try!(parse_name_with_rev(&revoked));
+        let signer = match lines.next() {
+            Some(val) => val.to_string(),
+            None => return Err(Error::CryptoError("Malformed revocation string".to_string())),
+        };
+        try!(parse_name_with_rev(&signer));
+        let signature = match lines.nth(1) {
+            Some(val) => {
+                match val.trim().as_bytes().from_base64() {
+                    Ok(sig) => sig,
+                    Err(e) => {
+                        return Err(Error::CryptoError(format!("Can't decode revocation \
+                                                               signature: {}",
+                                                              e)))
+                    }
+                }
+            }
+            None => return Err(Error::CryptoError("Malformed revocation string".to_string())),
+        };
+        Ok(KeyRevocation {
+            revoked: revoked,
+            signer: signer,
+            signature: signature,
+        })
+    }
+}
+
+/// Returns `true` if the key cache holds a valid revocation for the given key name with revision.
+///
+/// Revocation files which fail to verify are ignored, as anyone can drop a file in the cache but
+/// only an origin key holder can produce a valid signature.
+pub fn is_revoked<P: AsRef<Path> + ?Sized>(name_with_rev: &str,
+                                           cache_key_path: &P)
+                                           -> Result<bool> {
+    let revocation = match KeyRevocation::get_for(name_with_rev, cache_key_path) {
+        Ok(Some(r)) => r,
+        Ok(None) => return Ok(false),
+        Err(e) => {
+            debug!("Ignoring unreadable revocation for {}: {}", name_with_rev, e);
+            return Ok(false);
+        }
+    };
+    match revocation.verify(cache_key_path) {
+        Ok(()) => Ok(true),
+        Err(e) => {
+            debug!("Ignoring invalid revocation for {}: {}", name_with_rev, e);
+            Ok(false)
+        }
+    }
+}
+
+fn check_signer(revoked: &str, signer: &str) -> Result<()> {
+    let (revoked_name, revoked_rev) = try!(parse_name_with_rev(revoked));
+    let (signer_name, signer_rev) = try!(parse_name_with_rev(signer));
+    if revoked_name != signer_name {
+        return Err(Error::CryptoError(format!("Revocation of {} cannot be signed by {}",
+                                              revoked,
+                                              signer)));
+    }
+    if signer_rev < revoked_rev {
+        return Err(Error::CryptoError(format!("Revocation of {} cannot be signed by older \
+                                               key {}",
+                                              revoked,
+                                              signer)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tempdir::TempDir;
+
+    use super::{KeyRevocation, is_revoked};
+    use super::super::sig_key_pair::SigKeyPair;
+
+    #[test]
+    fn create_and_verify_self_revocation() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let revocation = KeyRevocation::create(&pair.name_with_rev(), &pair).unwrap();
+
+        assert!(!is_revoked(&pair.name_with_rev(), cache.path()).unwrap());
+        revocation.write_file(cache.path()).unwrap();
+        assert!(is_revoked(&pair.name_with_rev(), cache.path()).unwrap());
+    }
+
+    #[test]
+    fn round_trip_string() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let revocation = KeyRevocation::create(&pair.name_with_rev(), &pair).unwrap();
+        let parsed = KeyRevocation::from_str(&revocation.to_string()).unwrap();
+
+        assert_eq!(revocation, parsed);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be signed by")]
+    fn create_with_other_origin() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        KeyRevocation::create("dragon-20160517220007", &pair).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be signed by older key")]
+    fn create_with_older_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        KeyRevocation::create("unicorn-99990101000000", &pair).unwrap();
+    }
+
+    #[test]
+    fn invalid_signature_is_ignored() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let other = TempDir::new("other_cache").unwrap();
+        let imposter = SigKeyPair::new(pair.name.clone(),
+                                       pair.rev.clone(),
+                                       None,
+                                       SigKeyPair::generate_pair_for_origin("unicorn",
+                                                                            other.path())
+                                           .unwrap()
+                                           .secret);
+        let revocation = KeyRevocation::create(&pair.name_with_rev(), &imposter).unwrap();
+        revocation.write_file(cache.path()).unwrap();
+
+        assert!(!is_revoked(&pair.name_with_rev(), cache.path()).unwrap());
+    }
+}
@@ -15,7 +15,7 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use rustc_serialize::base64::{STANDARD, ToBase64};
+use rustc_serialize::base64::{STANDARD, FromBase64, ToBase64};
 use rustc_serialize::hex::ToHex;
 use sodiumoxide::crypto::sign;
 use sodiumoxide::crypto::sign::ed25519::SecretKey as SigSecretKey;
@@ -23,10 +23,12 @@ use sodiumoxide::crypto::sign::ed25519::PublicKey as SigPublicKey;
 use sodiumoxide::randombytes::randombytes;
 
 use error::{Error, Result};
-use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_with_rev,
-            read_key_bytes, write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
-use super::super::{PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_SUFFIX,
-                   SECRET_SIG_KEY_VERSION, hash};
+use super::{base_key_version, get_key_revisions, is_encrypted_secret_key, key_expiry,
+            mk_key_filename, mk_revision_string, next_key_body, parse_key_expires,
+            parse_name_with_rev, read_key_bytes, read_key_expires, read_secret_key_bytes,
+            secret_key_content, write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
+use super::super::{PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_ENCRYPTED_VERSION,
+                   SECRET_SIG_KEY_SUFFIX, SECRET_SIG_KEY_VERSION, hash};
 
 pub type SigKeyPair = KeyPair<SigPublicKey, SigSecretKey>;
 
@@ -37,12 +39,16 @@ impl SigKeyPair {
         let revision = try!(mk_revision_string());
         let keyname = Self::mk_key_name(name, &revision);
         debug!("new sig key name = {}", &keyname);
+        let expires = try!(key_expiry());
         let (public_key, secret_key) = try!(Self::generate_pair_files(&keyname,
+                                                                      expires.as_ref(),
                                                                       cache_key_path.as_ref()));
-        Ok(Self::new(name.to_string(),
-                     revision,
-                     Some(public_key),
-                     Some(secret_key)))
+        let mut pair = Self::new(name.to_string(),
+                                 revision,
+                                 Some(public_key),
+                                 Some(secret_key));
+        pair.expires = expires;
+        Ok(pair)
     }
 
     fn mk_key_name(name: &str, revision: &str) -> String {
@@ -50,6 +56,7 @@ impl SigKeyPair {
     }
 
     fn generate_pair_files(name_with_rev: &str,
+                           expires: Option<&String>,
                            cache_key_path: &Path)
                            -> Result<(SigPublicKey, SigSecretKey)> {
         let (pk, sk) = sign::gen_keypair();
@@ -59,12 +66,15 @@ impl SigKeyPair {
         debug!("public sig keyfile = {}", public_keyfile.display());
         debug!("secret sig keyfile = {}", secret_keyfile.display());
 
+        let (secret_content, encrypted) = try!(secret_key_content(&sk[..]));
         try!(write_keypair_files(KeyType::Sig,
                                  &name_with_rev,
+                                 expires,
                                  Some(&public_keyfile),
                                  Some(&pk[..].to_base64(STANDARD).into_bytes()),
                                  Some(&secret_keyfile),
-                                 Some(&sk[..].to_base64(STANDARD).into_bytes())));
+                                 Some(&secret_content),
+                                 encrypted));
         Ok((pk, sk))
     }
 
@@ -116,7 +126,15 @@ impl SigKeyPair {
                               name_with_rev);
             return Err(Error::CryptoError(msg));
         }
-        Ok(SigKeyPair::new(name, rev, pk, sk))
+        let mut pair = SigKeyPair::new(name, rev, pk, sk);
+        let cache_key_path = cache_key_path.as_ref();
+        pair.expires = try!(read_key_expires(&[mk_key_filename(cache_key_path,
+                                                               name_with_rev,
+                                                               PUBLIC_KEY_SUFFIX),
+                                               mk_key_filename(cache_key_path,
+                                                               name_with_rev,
+                                                               SECRET_SIG_KEY_SUFFIX)]));
+        Ok(pair)
     }
 
     pub fn get_latest_pair_for<P: AsRef<Path> + ?Sized>(name: &str,
@@ -132,6 +150,12 @@ impl SigKeyPair {
         }
     }
 
+    /// Returns the fingerprint of the public key, which is the hex encoded BLAKE2b hash of its
+    /// raw bytes. A fingerprint identifies a public key regardless of where it was downloaded from.
+    pub fn fingerprint(&self) -> Result<String> {
+        hash::hash_bytes(&try!(self.public())[..])
+    }
+
     pub fn get_public_key_path<P: AsRef<Path> + ?Sized>(key_with_rev: &str,
                                                         cache_key_path: &P)
                                                         -> Result<PathBuf> {
@@ -233,6 +257,7 @@ impl SigKeyPair {
                                                         cache_key_path: &P)
                                                         -> Result<(Self, PairType)> {
         let (pair_type, name_with_rev, key_body) = try!(Self::parse_key_str(content));
+        let expires = try!(parse_key_expires(content));
         let name_with_rev = &name_with_rev;
         let key_body = &key_body;
 
@@ -254,18 +279,22 @@ impl SigKeyPair {
             PairType::Public => {
                 try!(write_keypair_files(KeyType::Sig,
                                          &name_with_rev,
+                                         expires.as_ref(),
                                          Some(&tmpfile.path),
                                          Some(&key_body.as_bytes().to_vec()),
                                          None,
-                                         None));
+                                         None,
+                                         false));
             }
             PairType::Secret => {
                 try!(write_keypair_files(KeyType::Sig,
                                          &name_with_rev,
+                                         expires.as_ref(),
                                          None,
                                          None,
                                          Some(&tmpfile.path),
-                                         Some(&key_body.as_bytes().to_vec())));
+                                         Some(&key_body.as_bytes().to_vec()),
+                                         is_encrypted_secret_key(content)));
             }
         }
 
@@ -356,9 +385,10 @@ impl SigKeyPair {
         let mut lines = content.lines();
         let pair_type = match lines.next() {
             Some(val) => {
-                match val {
+                match &*base_key_version(val) {
                     PUBLIC_SIG_KEY_VERSION => PairType::Public,
-                    SECRET_SIG_KEY_VERSION => PairType::Secret,
+                    SECRET_SIG_KEY_VERSION |
+                    SECRET_SIG_KEY_ENCRYPTED_VERSION => PairType::Secret,
                     _ => {
                         return Err(Error::CryptoError(format!("Unsupported key version: {}", val)))
                     }
@@ -378,7 +408,7 @@ impl SigKeyPair {
                 return Err(Error::CryptoError(msg));
             }
         };
-        let key_body = match lines.nth(1) {
+        let key_body = match next_key_body(&mut lines) {
             Some(val) => val,
             None => {
                 let msg = format!("write_sig_key_from_str:3 Malformed sig key string:\n({})",
@@ -389,6 +419,31 @@ impl SigKeyPair {
         Ok((pair_type, name_with_rev.to_string(), key_body.to_string()))
     }
 
+    /// Parses the public key out of the contents of a public sig key file, without requiring the
+    /// key to be present in a key cache.
+    pub fn public_key_from_str(content: &str) -> Result<SigPublicKey> {
+        let (pair_type, name_with_rev, key_body) = try!(Self::parse_key_str(content));
+        if pair_type != PairType::Public {
+            return Err(Error::CryptoError(format!("Expected a public sig key for {}",
+                                                  name_with_rev)));
+        }
+        let bytes = match key_body.as_bytes().from_base64() {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return Err(Error::CryptoError(format!("Can't read raw key for {}: {}",
+                                                      name_with_rev,
+                                                      e)))
+            }
+        };
+        match SigPublicKey::from_slice(&bytes) {
+            Some(pk) => Ok(pk),
+            None => {
+                Err(Error::CryptoError(format!("Can't read sig public key for {}",
+                                               name_with_rev)))
+            }
+        }
+    }
+
     fn get_public_key(key_with_rev: &str, cache_key_path: &Path) -> Result<SigPublicKey> {
         let public_keyfile = mk_key_filename(cache_key_path, key_with_rev, PUBLIC_KEY_SUFFIX);
         let bytes = try!(read_key_bytes(&public_keyfile));
@@ -403,7 +458,7 @@ impl SigKeyPair {
 
     fn get_secret_key(key_with_rev: &str, cache_key_path: &Path) -> Result<SigSecretKey> {
         let secret_keyfile = mk_key_filename(cache_key_path, key_with_rev, SECRET_SIG_KEY_SUFFIX);
-        let bytes = try!(read_key_bytes(&secret_keyfile));
+        let bytes = try!(read_secret_key_bytes(&secret_keyfile));
         match SigSecretKey::from_slice(&bytes) {
             Some(sk) => Ok(sk),
             None => {
@@ -467,6 +522,18 @@ mod test {
         assert!(cache.path().join(format!("{}.sig.key", pair.name_with_rev())).exists());
     }
 
+    #[test]
+    fn fingerprint() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let other = SigKeyPair::generate_pair_for_origin("narwhal", cache.path()).unwrap();
+        let loaded = SigKeyPair::get_pair_for(&pair.name_with_rev(), cache.path()).unwrap();
+
+        assert_eq!(pair.fingerprint().unwrap().len(), 64);
+        assert_eq!(pair.fingerprint().unwrap(), loaded.fingerprint().unwrap());
+        assert!(pair.fingerprint().unwrap() != other.fingerprint().unwrap());
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
@@ -606,6 +673,25 @@ mod test {
         assert_eq!(new_content, content);
     }
 
+    #[test]
+    fn write_file_from_str_public_with_expiry() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let content = fixture_as_string(&format!("keys/{}", VALID_PUB));
+        let content = content.replace("SIG-PUB-1\n", "SIG-PUB-2\n")
+            .replace("\n\n", "\nExpires: 99991231235959\n\n");
+
+        let (pair, _) = SigKeyPair::write_file_from_str(&content, cache.path()).unwrap();
+        assert_eq!(pair.expires, Some("99991231235959".to_string()));
+        assert!(pair.public().is_ok());
+        let new_content = {
+            let mut new_content_file = File::open(cache.path().join(VALID_PUB)).unwrap();
+            let mut new_content = String::new();
+            new_content_file.read_to_string(&mut new_content).unwrap();
+            new_content
+        };
+        assert_eq!(new_content, content);
+    }
+
     #[test]
     fn write_file_from_str_public() {
         let cache = TempDir::new("key_cache").unwrap();
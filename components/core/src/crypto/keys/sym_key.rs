@@ -15,16 +15,18 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use rustc_serialize::base64::{STANDARD, ToBase64};
 use rustc_serialize::hex::ToHex;
 use sodiumoxide::crypto::secretbox;
 use sodiumoxide::crypto::secretbox::Key as SymSecretKey;
 use sodiumoxide::randombytes::randombytes;
 
 use error::{Error, Result};
-use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_with_rev,
-            read_key_bytes, write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
-use super::super::{SECRET_SYM_KEY_SUFFIX, SECRET_SYM_KEY_VERSION, hash};
+use super::{base_key_version, get_key_revisions, is_encrypted_secret_key, key_expiry,
+            mk_key_filename, mk_revision_string, next_key_body, parse_key_expires,
+            parse_name_with_rev, read_key_expires, read_secret_key_bytes, secret_key_content,
+            write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
+use super::super::{SECRET_SYM_KEY_ENCRYPTED_VERSION, SECRET_SYM_KEY_SUFFIX,
+                   SECRET_SYM_KEY_VERSION, hash};
 
 pub type SymKey = KeyPair<(), SymSecretKey>;
 
@@ -35,12 +37,16 @@ impl SymKey {
         let revision = try!(mk_revision_string());
         let keyname = Self::mk_key_name_for_ring(name, &revision);
         debug!("new ring key name = {}", &keyname);
+        let expires = try!(key_expiry());
         let (public_key, secret_key) = try!(Self::generate_pair_files(&keyname,
+                                                                      expires.as_ref(),
                                                                       cache_key_path.as_ref()));
-        Ok(Self::new(name.to_string(),
-                     revision,
-                     Some(public_key),
-                     Some(secret_key)))
+        let mut pair = Self::new(name.to_string(),
+                                 revision,
+                                 Some(public_key),
+                                 Some(secret_key));
+        pair.expires = expires;
+        Ok(pair)
     }
 
     pub fn get_pairs_for<P: AsRef<Path> + ?Sized>(name: &str,
@@ -87,7 +93,11 @@ impl SymKey {
                               name_with_rev);
             return Err(Error::CryptoError(msg));
         }
-        Ok(Self::new(name, rev, pk, sk))
+        let mut pair = Self::new(name, rev, pk, sk);
+        pair.expires = try!(read_key_expires(&[mk_key_filename(cache_key_path.as_ref(),
+                                                               name_with_rev,
+                                                               SECRET_SYM_KEY_SUFFIX)]));
+        Ok(pair)
     }
 
     pub fn get_latest_pair_for<P: AsRef<Path> + ?Sized>(name: &str,
@@ -170,10 +180,18 @@ impl SymKey {
     /// }
     /// ```
     ///
+    /// An expired `SymKey` still encrypts, with a warning, as a ring whose key expires would
+    /// otherwise stop gossiping until a new revision reaches every member.
+    ///
     /// # Errors
     ///
     /// * If the secret key component of the `SymKey` is not present
     pub fn encrypt(&self, data: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+        if self.is_expired() {
+            warn!("Ring key {} expired at {}, rotate it with 'hab ring key rotate'",
+                  self.name_with_rev(),
+                  self.expires.as_ref().unwrap());
+        }
         let key = try!(self.secret());
         let nonce = secretbox::gen_nonce();
         Ok((nonce.as_ref().to_vec(), secretbox::seal(data, &nonce, &key)))
@@ -230,7 +248,7 @@ impl SymKey {
 
     fn get_secret_key(key_with_rev: &str, cache_key_path: &Path) -> Result<SymSecretKey> {
         let secret_keyfile = mk_key_filename(cache_key_path, key_with_rev, SECRET_SYM_KEY_SUFFIX);
-        let bytes = try!(read_key_bytes(&secret_keyfile));
+        let bytes = try!(read_secret_key_bytes(&secret_keyfile));
         match SymSecretKey::from_slice(&bytes) {
             Some(sk) => Ok(sk),
             None => {
@@ -285,7 +303,9 @@ impl SymKey {
         let mut lines = content.lines();
         let _ = match lines.next() {
             Some(val) => {
-                if val != SECRET_SYM_KEY_VERSION {
+                let version = base_key_version(val);
+                if version != SECRET_SYM_KEY_VERSION &&
+                   version != SECRET_SYM_KEY_ENCRYPTED_VERSION {
                     return Err(Error::CryptoError(format!("Unsupported key version: {}", val)));
                 }
                 ()
@@ -304,7 +324,7 @@ impl SymKey {
                 return Err(Error::CryptoError(msg));
             }
         };
-        let sk = match lines.nth(1) {
+        let sk = match next_key_body(&mut lines) {
             Some(val) => val,
             None => {
                 let msg = format!("write_sym_key_from_str:3 Malformed sym key string:\n({})",
@@ -312,6 +332,7 @@ impl SymKey {
                 return Err(Error::CryptoError(msg));
             }
         };
+        let expires = try!(parse_key_expires(content));
         let secret_keyfile = mk_key_filename(cache_key_path.as_ref(),
                                              &name_with_rev,
                                              SECRET_SYM_KEY_SUFFIX);
@@ -326,10 +347,12 @@ impl SymKey {
         debug!("Writing temp key file {}", tmpfile.path.display());
         try!(write_keypair_files(KeyType::Sym,
                                  &name_with_rev,
+                                 expires.as_ref(),
                                  None,
                                  None,
                                  Some(&tmpfile.path),
-                                 Some(&sk.as_bytes().to_vec())));
+                                 Some(&sk.as_bytes().to_vec()),
+                                 is_encrypted_secret_key(content)));
 
         if Path::new(&secret_keyfile).is_file() {
             let existing_hash = try!(hash::hash_file(&secret_keyfile));
@@ -367,6 +390,7 @@ impl SymKey {
     }
 
     fn generate_pair_files(name_with_rev: &str,
+                           expires: Option<&String>,
                            cache_key_path: &Path)
                            -> Result<((), SymSecretKey)> {
         let pk = ();
@@ -375,12 +399,15 @@ impl SymKey {
         let secret_keyfile = mk_key_filename(cache_key_path, name_with_rev, SECRET_SYM_KEY_SUFFIX);
         debug!("secret ring keyfile = {}", secret_keyfile.display());
 
+        let (secret_content, encrypted) = try!(secret_key_content(&sk[..]));
         try!(write_keypair_files(KeyType::Sym,
                                  &name_with_rev,
+                                 expires,
                                  None,
                                  None,
                                  Some(&secret_keyfile),
-                                 Some(&sk[..].to_base64(STANDARD).into_bytes())));
+                                 Some(&secret_content),
+                                 encrypted));
         Ok((pk, sk))
     }
 }
@@ -549,6 +576,17 @@ mod test {
         assert_eq!(message, "Ringonit".to_string().into_bytes());
     }
 
+    #[test]
+    fn encrypt_with_expired_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let mut pair = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        pair.expires = Some("20160101000000".to_string());
+
+        let (nonce, ciphertext) = pair.encrypt("Ringonit".as_bytes()).unwrap();
+        let message = pair.decrypt(&nonce, &ciphertext).unwrap();
+        assert_eq!(message, "Ringonit".to_string().into_bytes());
+    }
+
     #[test]
     #[should_panic(expected = "Secret key is required but not present for")]
     fn encrypt_missing_secret_key() {
@@ -0,0 +1,221 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use toml;
+
+use config::ConfigFile;
+use crypto::artifact::{self, VerifyPolicy};
+use error::{Error, Result};
+use super::sig_key_pair::SigKeyPair;
+
+/// A pinned set of trusted origin keys.
+///
+/// Normally any public origin key in the key cache is trusted, and a missing key is downloaded
+/// from the Depot on first use. A trust store instead lists every origin key which may sign
+/// artifacts along with the fingerprint of its public key, as returned by
+/// `SigKeyPair::fingerprint`. In strict mode, an artifact is refused unless every key which
+/// signed it is listed and the public key in the key cache matches its pinned fingerprint.
+///
+/// The trust store is a TOML file with a `keys` table of key names with revision and their
+/// fingerprints:
+///
+/// ```toml
+/// [keys]
+/// core-20160810182414 = "2b0f5ca4c1f2bc4c5a4e2bd4f0d6e3b1e5c0b3a4f5e6d7c8b9a0f1e2d3c4b5a6"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrustStore {
+    keys: BTreeMap<String, String>,
+}
+
+impl TrustStore {
+    /// Returns the pinned fingerprint of an origin key, if the key is trusted.
+    pub fn fingerprint_for(&self, name_with_rev: &str) -> Option<&str> {
+        self.keys.get(name_with_rev).map(|f| f.as_str())
+    }
+
+    /// Checks that an origin key is trusted and that its public key in the key cache matches its
+    /// pinned fingerprint.
+    ///
+    /// # Failures
+    ///
+    /// * The key is not listed in the trust store
+    /// * The public key is not present in the key cache
+    /// * The public key does not match its pinned fingerprint
+    /// * The key has expired
+    pub fn check_key<P: AsRef<Path> + ?Sized>(&self,
+                                              name_with_rev: &str,
+                                              cache_key_path: &P)
+                                              -> Result<()> {
+        let pinned = match self.fingerprint_for(name_with_rev) {
+            Some(pinned) => pinned,
+            None => {
+                return Err(Error::CryptoError(format!("Origin key {} is not in the trust store",
+                                                      name_with_rev)))
+            }
+        };
+        let pair = try!(SigKeyPair::get_pair_for(name_with_rev, cache_key_path));
+        let fingerprint = try!(pair.fingerprint());
+        if fingerprint != pinned {
+            return Err(Error::CryptoError(format!("Public key {} has fingerprint {} which does \
+                                                   not match its pinned fingerprint {}",
+                                                  name_with_rev,
+                                                  fingerprint,
+                                                  pinned)));
+        }
+        pair.check_not_expired()
+    }
+
+    /// Checks that every key which signed an artifact is trusted, without verifying the
+    /// signatures themselves. This lets the signatures be verified later, for example while the
+    /// artifact is unpacked.
+    ///
+    /// # Failures
+    ///
+    /// * A signing key is not trusted or does not match its pinned fingerprint
+    pub fn check_signers<P1: ?Sized, P2: ?Sized>(&self,
+                                                 src: &P1,
+                                                 cache_key_path: &P2)
+                                                 -> Result<()>
+        where P1: AsRef<Path>,
+              P2: AsRef<Path>
+    {
+        for signer in try!(artifact::artifact_signers(&src.as_ref())) {
+            try!(self.check_key(&signer, cache_key_path));
+        }
+        Ok(())
+    }
+
+    /// Verifies an artifact in strict mode: every key which signed the artifact must be trusted
+    /// and every signature must verify. The return is a tuple of the names with revision of the
+    /// signing keys and the artifact's payload hash.
+    ///
+    /// # Failures
+    ///
+    /// * A signing key is not trusted or does not match its pinned fingerprint
+    /// * A signature does not verify
+    pub fn verify_artifact<P1: ?Sized, P2: ?Sized>(&self,
+                                                   src: &P1,
+                                                   cache_key_path: &P2)
+                                                   -> Result<(Vec<String>, String)>
+        where P1: AsRef<Path>,
+              P2: AsRef<Path>
+    {
+        try!(self.check_signers(src, cache_key_path));
+        artifact::verify_with_policy(src, cache_key_path, &VerifyPolicy::All)
+    }
+}
+
+impl ConfigFile for TrustStore {
+    type Error = Error;
+
+    fn from_toml(toml: toml::Value) -> Result<Self> {
+        let mut keys = BTreeMap::new();
+        if let Some(table) = toml.lookup("keys") {
+            let table = match table.as_table() {
+                Some(table) => table,
+                None => return Err(Error::ConfigInvalidString("keys")),
+            };
+            for (name_with_rev, fingerprint) in table.iter() {
+                match fingerprint.as_str() {
+                    Some(fingerprint) => {
+                        keys.insert(name_with_rev.clone(), fingerprint.trim().to_lowercase());
+                    }
+                    None => return Err(Error::ConfigInvalidString("keys")),
+                }
+            }
+        }
+        Ok(TrustStore { keys: keys })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+    use toml;
+
+    use super::TrustStore;
+    use config::ConfigFile;
+    use crypto::{artifact, SigKeyPair};
+
+    fn trust_store(pairs: &[&SigKeyPair]) -> TrustStore {
+        let mut content = String::from("[keys]\n");
+        for pair in pairs {
+            content.push_str(&format!("{} = \"{}\"\n",
+                                      pair.name_with_rev(),
+                                      pair.fingerprint().unwrap()));
+        }
+        TrustStore::from_toml(content.parse::<toml::Value>().unwrap()).unwrap()
+    }
+
+    fn signed_artifact(cache: &TempDir, pair: &SigKeyPair) -> String {
+        let src = cache.path().join("payload.tar");
+        let dst = cache.path().join("signed.hart");
+        File::create(&src).unwrap().write_all(b"they're all the same").unwrap();
+        artifact::sign(&src, &dst, pair).unwrap();
+        dst.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn check_trusted_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let store = trust_store(&[&pair]);
+        assert_eq!(store.fingerprint_for(&pair.name_with_rev()),
+                   Some(pair.fingerprint().unwrap().as_str()));
+        store.check_key(&pair.name_with_rev(), cache.path()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "is not in the trust store")]
+    fn check_untrusted_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        TrustStore::default().check_key(&pair.name_with_rev(), cache.path()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match its pinned fingerprint")]
+    fn check_key_with_wrong_fingerprint() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let content = format!("[keys]\n{} = \"{}\"\n", pair.name_with_rev(), "00");
+        let store = TrustStore::from_toml(content.parse::<toml::Value>().unwrap()).unwrap();
+        store.check_key(&pair.name_with_rev(), cache.path()).unwrap();
+    }
+
+    #[test]
+    fn verify_artifact_signed_by_trusted_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = signed_artifact(&cache, &pair);
+        let (signers, _) = trust_store(&[&pair]).verify_artifact(&dst, cache.path()).unwrap();
+        assert_eq!(signers, vec![pair.name_with_rev()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not in the trust store")]
+    fn verify_artifact_signed_by_untrusted_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = signed_artifact(&cache, &pair);
+        TrustStore::default().verify_artifact(&dst, cache.path()).unwrap();
+    }
+}
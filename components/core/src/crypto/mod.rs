@@ -112,6 +112,12 @@
 //! <ring_name>-<revision>.sym.key
 //! ```
 //!
+//! ## Origin key revocation
+//!
+//! ```text
+//! <origin_name>-<revision>.revoked
+//! ```
+//!
 //! Example Ring keys:
 //!
 //! ```text
@@ -189,6 +195,32 @@
 //! is **not** a supported workflow for working with Habitat artifacts--they are signed for very
 //! important reasons.
 //!
+//! ## Countersigned Habitat artifacts
+//!
+//! A Habitat artifact may carry more than one signature, for example the signature of the origin
+//! key of the build followed by the signature of a release manager's key. Such an artifact uses
+//! the `HART-2` format, which has a variable number of header lines:
+//!
+//! 1. The artifact format version, `HART-2`
+//! 1. The hashing algorithm used, which at present is only `BLAKE2b`
+//! 1. One line per signature, in the order the signatures were added, each holding the name with
+//!    revision of the signing key and the Base64 *signed* value of the payload hash, separated by
+//!    a single space
+//! 1. An empty line, which ends the header
+//!
+//! ```text
+//! HART-2
+//! BLAKE2b
+//! habitat-20160405144945 signed BLAKE2b signature
+//! release-20160601101112 signed BLAKE2b signature
+//!
+//! <binary-blob>
+//! ```
+//!
+//! Every signature covers the same payload hash, so signatures can be added to an existing
+//! `HART-1` or `HART-2` artifact without re-signing it. A `VerifyPolicy` decides which of the
+//! signatures have to verify: `any`, `all` (the default), or `k` of a list of named keys.
+//!
 //! ## Encrypted payloads
 //!
 //! The first 4 lines of an encrypted payload are as follows:
@@ -208,6 +240,27 @@
 //! <ciphertext_base64>
 //! ```
 //!
+//! ## Origin key revocations
+//!
+//! A revocation marks an origin key revision as no longer trusted. Artifacts signed with a revoked
+//! key fail verification. The revocation is signed by a key of the same origin whose revision is
+//! the same as or newer than the revoked key, so an origin can revoke a leaked key either with the
+//! key itself or with the key that replaces it.
+//!
+//! 1. The revocation format version
+//! 1. The name with revision of the revoked origin key
+//! 1. The name with revision of the origin key which signed the revocation
+//! 1. The last line is left empty
+//! 1. A Base64 *signed* value of the revoked key's name with revision
+//!
+//! ```text
+//! SIG-REV-1
+//! habitat-20160405144945
+//! habitat-20160601101112
+//!
+//! <signature_base64>
+//! ```
+//!
 //! ## Ring keys
 //!
 //! There are 3 lines, that is 3 parts that are separtated by a newline character `\n`. They are as
@@ -223,13 +276,62 @@
 //!
 //! <symkey_base64>
 //! ```
+//!
+//! ## Encrypted secret keys
+//!
+//! Secret sig, box and sym keys can be stored encrypted at rest with a passphrase. When the
+//! `HAB_KEY_PASSPHRASE` environment variable is set at the time a secret key is generated, the key
+//! is written in the encrypted format. The format version is the same as the plaintext version
+//! with an `-ENC` marker (`SIG-SEC-ENC-1`, `BOX-SEC-ENC-1` or `SYM-SEC-ENC-1`) and the Base64 body
+//! holds the salt, nonce and ciphertext:
+//!
+//! 1. A 32 byte salt used to derive a key from the passphrase with
+//!    `pwhash::scryptsalsa208sha256`
+//! 1. A 24 byte nonce
+//! 1. The raw secret key, sealed with `secretbox` using the derived key
+//!
+//! ```text
+//! SIG-SEC-ENC-1
+//! habitat-20160405144945
+//!
+//! <salt_nonce_ciphertext_base64>
+//! ```
+//!
+//! Reading an encrypted secret key requires the same passphrase in `HAB_KEY_PASSPHRASE`, or in the
+//! file named by `HAB_KEY_PASSPHRASE_FILE` when `HAB_KEY_PASSPHRASE` is not set.
+//! Without it, a key pair is loaded with its public key only.
+//!
+//! ## Key expiry
+//!
+//! A key may carry an expiry, recorded as an `Expires:` header line between the key name with
+//! revision and the empty line. The expiry is a timestamp in the same UTC format as a revision.
+//! When the `HAB_KEY_EXPIRE_DAYS` environment variable is set at the time a key is generated, the
+//! public and secret key files both carry an expiry that many days from now.
+//!
+//! A key file with an expiry is written with version 2 of its format (`SIG-PUB-2`, `SIG-SEC-2`,
+//! `SIG-SEC-ENC-2` and so on for box and sym keys), so readers which predate key expiry reject it
+//! rather than misread its header. A version 1 key file never has headers.
+//!
+//! ```text
+//! SIG-PUB-2
+//! habitat-20160405144945
+//! Expires: 20170405144945
+//!
+//! <public_key_base64>
+//! ```
+//!
+//! An expired key can't sign or encrypt, except for ring keys, which only warn so a ring keeps
+//! gossiping until it is rotated. Verifying an artifact signed with an expired key only
+//! warns, unless the artifact is verified in strict mode with a trust store.
 
 use std::path::{Path, PathBuf};
 
+use rustc_serialize::hex::ToHex;
 use sodiumoxide::init as nacl_init;
+use sodiumoxide::randombytes::randombytes;
 
 use env as henv;
-use fs::cache_key_path;
+use fs::{cache_key_path, FS_ROOT_PATH};
 
 /// The suffix on the end of a public sig/box file
 pub static PUBLIC_KEY_SUFFIX: &'static str = "pub";
@@ -243,6 +345,9 @@ pub static SECRET_BOX_KEY_SUFFIX: &'static str = "box.key";
 /// The suffix on the end of a secret symmetric key file
 pub static SECRET_SYM_KEY_SUFFIX: &'static str = "sym.key";
 
+/// The suffix on the end of an origin key revocation file
+pub static KEY_REVOCATION_SUFFIX: &'static str = "revoked";
+
 /// The hashing function we're using during sign/verify
 /// See also: https://download.libsodium.org/doc/hashing/generic_hashing.html
 pub static SIG_HASH_TYPE: &'static str = "BLAKE2b";
@@ -251,11 +356,32 @@ pub static SIG_HASH_TYPE: &'static str = "BLAKE2b";
 /// at runtime. This is useful for testing.
 pub static CACHE_KEY_PATH_ENV_VAR: &'static str = "HAB_CACHE_KEY_PATH";
 
+/// This environment variable holds the passphrase used to encrypt newly generated secret keys
+/// and to decrypt encrypted secret keys.
+pub static KEY_PASSPHRASE_ENV_VAR: &'static str = "HAB_KEY_PASSPHRASE";
+
+/// This environment variable holds the path to a file containing the passphrase for secret keys.
+/// It is only consulted when `HAB_KEY_PASSPHRASE` is not set, and lets a passphrase be handed to
+/// a process without it appearing on a command line.
+pub static KEY_PASSPHRASE_FILE_ENV_VAR: &'static str = "HAB_KEY_PASSPHRASE_FILE";
+
+/// This environment variable holds the number of days newly generated keys are valid for. Keys
+/// generated without it never expire.
+pub static KEY_EXPIRE_DAYS_ENV_VAR: &'static str = "HAB_KEY_EXPIRE_DAYS";
+
+/// This environment variable allows you to override the path of the trust store used to verify
+/// artifacts in strict mode.
+pub static TRUST_STORE_ENV_VAR: &'static str = "HAB_TRUST_STORE";
+
+/// The default path of the trust store, relative to the filesystem root
+pub static TRUST_STORE_PATH: &'static str = "hab/etc/trust.toml";
+
 /// Create secret key files with these permissions
 static PUBLIC_KEY_PERMISSIONS: &'static str = "0400";
 static SECRET_KEY_PERMISSIONS: &'static str = "0400";
 
 pub static HART_FORMAT_VERSION: &'static str = "HART-1";
+pub static HART_MULTISIG_FORMAT_VERSION: &'static str = "HART-2";
 pub static BOX_FORMAT_VERSION: &'static str = "BOX-1";
 
 pub const PUBLIC_SIG_KEY_VERSION: &'static str = "SIG-PUB-1";
@@ -263,14 +389,22 @@ pub const SECRET_SIG_KEY_VERSION: &'static str = "SIG-SEC-1";
 pub const PUBLIC_BOX_KEY_VERSION: &'static str = "BOX-PUB-1";
 pub const SECRET_BOX_KEY_VERSION: &'static str = "BOX-SEC-1";
 pub const SECRET_SYM_KEY_VERSION: &'static str = "SYM-SEC-1";
+pub const SECRET_SIG_KEY_ENCRYPTED_VERSION: &'static str = "SIG-SEC-ENC-1";
+pub const SECRET_BOX_KEY_ENCRYPTED_VERSION: &'static str = "BOX-SEC-ENC-1";
+pub const SECRET_SYM_KEY_ENCRYPTED_VERSION: &'static str = "SYM-SEC-ENC-1";
+pub const KEY_REVOCATION_VERSION: &'static str = "SIG-REV-1";
 
 pub use self::keys::box_key_pair::BoxKeyPair;
+pub use self::keys::revocation::KeyRevocation;
 pub use self::keys::sym_key::SymKey;
 pub use self::keys::sig_key_pair::SigKeyPair;
+pub use self::keys::trust::TrustStore;
+pub use self::signer::{CommandSigner, Signer};
 
 pub mod artifact;
 pub mod hash;
 pub mod keys;
+pub mod signer;
 
 pub fn default_cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
     match henv::var(CACHE_KEY_PATH_ENV_VAR) {
@@ -279,10 +413,26 @@ pub fn default_cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
     }
 }
 
+/// Returns the path to the trust store, optionally taking a custom filesystem root.
+pub fn default_trust_store_path(fs_root_path: Option<&Path>) -> PathBuf {
+    match henv::var(TRUST_STORE_ENV_VAR) {
+        Ok(val) => PathBuf::from(val),
+        Err(_) => {
+            Path::new(fs_root_path.unwrap_or(Path::new(FS_ROOT_PATH))).join(TRUST_STORE_PATH)
+        }
+    }
+}
+
 pub fn init() {
     nacl_init();
 }
 
+/// Generates a hex encoded random token from `len` bytes of libsodium's CSPRNG output, suitable
+/// for use as a bearer secret.
+pub fn generate_token(len: usize) -> String {
+    randombytes(len).as_slice().to_hex()
+}
+
 #[cfg(test)]
 pub mod test_support {
     use std::env;
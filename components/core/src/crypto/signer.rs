@@ -0,0 +1,274 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Signers of Habitat artifacts.
+//!
+//! An artifact is signed by signing the hash of its payload with a secret origin key. A `Signer`
+//! abstracts over where that secret key lives, so the secret key doesn't have to be present on
+//! the machine building or signing an artifact.
+//!
+//! There are 2 signers:
+//!
+//! * A `SigKeyPair`, which signs with a secret origin key from the key cache
+//! * A `CommandSigner`, which asks an external program to sign on its behalf
+//!
+//! # External signer protocol
+//!
+//! The program named by the `HAB_ORIGIN_SIGNER` environment variable is run once to find the key
+//! it signs with and once per signature:
+//!
+//! * `<program> key <origin>` prints the name with revision of the origin key it signs with for
+//!   `origin`, ex: `core-20160810182414`
+//! * `<program> sign <name_with_rev>` reads the hex encoded payload hash on standard input and
+//!   prints the Base64 encoded signed hash, as produced by libsodium's `crypto_sign`
+//!
+//! A non-zero exit status fails the signing operation. The program is free to hand the request to
+//! another process, for example over a local socket to a signing agent. Every signature it returns
+//! is verified against the public origin key in the key cache before it is used.
+
+use std::ffi::OsStr;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use rustc_serialize::base64::FromBase64;
+use sodiumoxide::crypto::sign;
+use sodiumoxide::crypto::sign::ed25519::PublicKey as SigPublicKey;
+
+use env as henv;
+use error::{Error, Result};
+use super::SigKeyPair;
+use super::keys::parse_name_with_rev;
+
+/// This environment variable names a program which signs artifacts with secret origin keys held
+/// outside of the key cache.
+pub static ORIGIN_SIGNER_ENV_VAR: &'static str = "HAB_ORIGIN_SIGNER";
+
+/// Something which can sign the payload hash of an artifact with a secret origin key.
+pub trait Signer {
+    /// Returns the name with revision of the origin key which signs.
+    fn key_name_with_rev(&self) -> String;
+
+    /// Signs a hex encoded payload hash. The return is the signed hash, that is the signature
+    /// followed by the hash, as produced by libsodium's `crypto_sign`.
+    fn sign_hash(&self, hash: &str) -> Result<Vec<u8>>;
+}
+
+impl Signer for SigKeyPair {
+    fn key_name_with_rev(&self) -> String {
+        self.name_with_rev()
+    }
+
+    fn sign_hash(&self, hash: &str) -> Result<Vec<u8>> {
+        try!(self.check_not_expired());
+        Ok(sign::sign(hash.as_bytes(), try!(self.secret())))
+    }
+}
+
+/// A signer which runs an external program to sign with an origin key, following the protocol
+/// described in the module documentation.
+pub struct CommandSigner {
+    program: PathBuf,
+    name_with_rev: String,
+    public: SigPublicKey,
+}
+
+impl CommandSigner {
+    /// Creates a signer for `origin` which runs `program`. The program is asked for the key it
+    /// signs with, whose public key must be present in the key cache.
+    ///
+    /// # Failures
+    ///
+    /// * The program can't be run or exits with a non-zero status
+    /// * The program signs with a key for a different origin
+    /// * The public key of the signing key is not present in the key cache
+    /// * The signing key has expired
+    pub fn for_origin<P1: ?Sized, P2: ?Sized>(program: &P1,
+                                              origin: &str,
+                                              cache_key_path: &P2)
+                                              -> Result<Self>
+        where P1: AsRef<Path>,
+              P2: AsRef<Path>
+    {
+        let output = try!(run(program.as_ref(), &["key", origin], None));
+        let name_with_rev = output.trim().to_string();
+        let (name, _) = try!(parse_name_with_rev(&name_with_rev));
+        if name != origin {
+            return Err(Error::CryptoError(format!("Signer {} signs with {}, not an origin key \
+                                                   for {}",
+                                                  program.as_ref().display(),
+                                                  name_with_rev,
+                                                  origin)));
+        }
+        let pair = try!(SigKeyPair::get_pair_for(&name_with_rev, cache_key_path));
+        try!(pair.check_not_expired());
+        let public = try!(pair.public()).clone();
+        Ok(CommandSigner {
+            program: program.as_ref().to_path_buf(),
+            name_with_rev: name_with_rev,
+            public: public,
+        })
+    }
+}
+
+impl Signer for CommandSigner {
+    fn key_name_with_rev(&self) -> String {
+        self.name_with_rev.clone()
+    }
+
+    fn sign_hash(&self, hash: &str) -> Result<Vec<u8>> {
+        let output = try!(run(&self.program, &["sign", &self.name_with_rev], Some(hash)));
+        let signed = match output.trim().from_base64() {
+            Ok(signed) => signed,
+            Err(e) => {
+                return Err(Error::CryptoError(format!("Signer {} returned a malformed \
+                                                       signature: {}",
+                                                      self.program.display(),
+                                                      e)))
+            }
+        };
+        match sign::verify(&signed, &self.public) {
+            Ok(ref signed_hash) if signed_hash.as_slice() == hash.as_bytes() => Ok(signed),
+            _ => {
+                Err(Error::CryptoError(format!("Signer {} returned a signature which does not \
+                                                verify with {}",
+                                               self.program.display(),
+                                               self.name_with_rev)))
+            }
+        }
+    }
+}
+
+/// Returns the program named by `HAB_ORIGIN_SIGNER`, if it is set.
+pub fn origin_signer_program() -> Option<String> {
+    match henv::var(ORIGIN_SIGNER_ENV_VAR) {
+        Ok(ref val) if !val.trim().is_empty() => Some(val.trim().to_string()),
+        _ => None,
+    }
+}
+
+/// Runs a signer program, writing `input` to its standard input, and returns its standard
+/// output.
+fn run<S: AsRef<OsStr>>(program: &Path, args: &[S], input: Option<&str>) -> Result<String> {
+    let mut child = match Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            return Err(Error::CryptoError(format!("Can't run signer {}: {}",
+                                                  program.display(),
+                                                  e)))
+        }
+    };
+    if let Some(input) = input {
+        if let Some(ref mut stdin) = child.stdin {
+            try!(stdin.write_all(input.as_bytes()));
+        }
+    }
+    // Close standard input so the program sees the end of the hash
+    drop(child.stdin.take());
+    let output = try!(child.wait_with_output());
+    if !output.status.success() {
+        return Err(Error::CryptoError(format!("Signer {} failed ({}): {}",
+                                              program.display(),
+                                              output.status,
+                                              String::from_utf8_lossy(&output.stderr).trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    use rustc_serialize::base64::{STANDARD, ToBase64};
+    use tempdir::TempDir;
+
+    use super::*;
+    use crypto::SigKeyPair;
+
+    static HASH: &'static str = "3d57b2e6ec7a03d18b5e3c4bf9c5b6c3a7cb1b52ae0f1e0bd0c4bba6ad8f9d12";
+
+    /// Writes a stand-in signer program which answers with canned output.
+    fn stand_in(dir: &TempDir, key_output: &str, sign_output: &str) -> PathBuf {
+        let program = dir.path().join("signer");
+        let mut file = File::create(&program).unwrap();
+        write!(file,
+               "#!/bin/sh\ncase \"$1\" in\n  key) echo '{}' ;;\n  sign) cat >/dev/null; echo \
+                '{}' ;;\n  *) exit 1 ;;\nesac\n",
+               key_output,
+               sign_output)
+            .unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+        program
+    }
+
+    #[test]
+    fn key_pair_signs_hash() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signed = pair.sign_hash(HASH).unwrap();
+        let verified = sign::verify(&signed, pair.public().unwrap()).unwrap();
+        assert_eq!(verified.as_slice(), HASH.as_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "expired")]
+    fn key_pair_rejects_expired_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let mut pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        pair.expires = Some("20160101000000".to_string());
+        pair.sign_hash(HASH).unwrap();
+    }
+
+    #[test]
+    fn command_signer_signs_hash() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signed = pair.sign_hash(HASH).unwrap().to_base64(STANDARD);
+        let program = stand_in(&cache, &pair.name_with_rev(), &signed);
+
+        let signer = CommandSigner::for_origin(&program, "unicorn", cache.path()).unwrap();
+        assert_eq!(signer.key_name_with_rev(), pair.name_with_rev());
+        assert_eq!(signer.sign_hash(HASH).unwrap().to_base64(STANDARD), signed);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not verify")]
+    fn command_signer_rejects_bad_signature() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signed = pair.sign_hash("nope").unwrap().to_base64(STANDARD);
+        let program = stand_in(&cache, &pair.name_with_rev(), &signed);
+
+        let signer = CommandSigner::for_origin(&program, "unicorn", cache.path()).unwrap();
+        signer.sign_hash(HASH).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "not an origin key")]
+    fn command_signer_rejects_other_origin() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let program = stand_in(&cache, &pair.name_with_rev(), "");
+
+        CommandSigner::for_origin(&program, "narwhal", cache.path()).unwrap();
+    }
+}
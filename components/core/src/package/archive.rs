@@ -12,19 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::error;
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::{self, FromStr};
+use std::sync::Mutex;
+use std::time::SystemTime;
 
 use libarchive::writer;
 use libarchive::reader::{self, Reader};
-use libarchive::archive::{Entry, ReadFilter, ReadFormat};
+use libarchive::archive::{Entry, ExtractOption, ExtractOptions, FileType, ReadFilter,
+                          ReadFormat};
 use regex::Regex;
 
 use error::{Error, Result};
-use crypto::{artifact, hash};
+use crypto::{artifact, hash, TrustStore};
+use crypto::artifact::VerifyPolicy;
+use crypto::hash::HashingReader;
+use fs::ROOT_PATH;
 use package::{Identifiable, PackageIdent, MetaFile};
 
 lazy_static! {
@@ -40,16 +47,33 @@ lazy_static! {
         map.insert(MetaFile::LdFlags, Regex::new(&format!(r"^hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$", MetaFile::LdFlags)).unwrap());
         map.insert(MetaFile::Manifest, Regex::new(&format!(r"^hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$", MetaFile::Manifest)).unwrap());
         map.insert(MetaFile::Path, Regex::new(&format!(r"^hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$", MetaFile::Path)).unwrap());
+        map.insert(MetaFile::Provenance, Regex::new(&format!(r"^hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$", MetaFile::Provenance)).unwrap());
         map
     };
+
+    /// Metadata read from archives during the life of the process, so that opening the same
+    /// archive again doesn't re-scan its tarball.
+    static ref METADATA_CACHE: Mutex<HashMap<PathBuf, CachedMetadata>> = Mutex::new(HashMap::new());
 }
 
+/// The most archives whose metadata is kept in the metadata cache
+const METADATA_CACHE_SIZE: usize = 64;
+
 type Metadata = HashMap<MetaFile, String>;
 
+/// An entry of the metadata cache, which is only valid while the archive's size and modification
+/// time are unchanged.
+struct CachedMetadata {
+    len: u64,
+    modified: SystemTime,
+    metadata: Metadata,
+}
+
 #[derive(Debug)]
 pub struct PackageArchive {
     pub path: PathBuf,
     metadata: Option<Metadata>,
+    checksum: Option<String>,
 }
 
 impl PackageArchive {
@@ -57,6 +81,7 @@ impl PackageArchive {
         PackageArchive {
             path: path.into(),
             metadata: None,
+            checksum: None,
         }
     }
 
@@ -66,7 +91,16 @@ impl PackageArchive {
     ///
     /// * If the archive cannot be read
     pub fn checksum(&self) -> Result<String> {
-        hash::hash_file(&self.path)
+        match self.checksum {
+            Some(ref checksum) => Ok(checksum.clone()),
+            None => hash::hash_file(&self.path),
+        }
+    }
+
+    /// Sets the checksum of the package archive, for callers which hashed the archive while
+    /// writing it, so `checksum` doesn't read the archive again.
+    pub fn set_checksum(&mut self, checksum: String) {
+        self.checksum = Some(checksum);
     }
 
     pub fn cflags(&mut self) -> Result<Option<String>> {
@@ -157,6 +191,68 @@ impl PackageArchive {
         }
     }
 
+    /// Returns the signed contents of the package's provenance metafile, if the package was
+    /// built with one. See `PackageProvenance` for reading the contents.
+    pub fn provenance(&mut self) -> Result<Option<String>> {
+        match self.read_metadata(MetaFile::Provenance) {
+            Ok(data) => Ok(data.cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the paths of the files in the package, relative to the package's installed path,
+    /// in sorted order. Directories are not listed.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read
+    pub fn files(&self) -> Result<Vec<String>> {
+        let mut files = Vec::new();
+        let mut reader = try!(self.tar_reader());
+        while let Some(entry) = reader.next_header() {
+            if let FileType::Directory = entry.filetype() {
+                continue;
+            }
+            if let Some(path) = package_relative_path(entry.pathname()) {
+                files.push(path.to_string());
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Returns the contents of the package's hooks, keyed by the name of the hook.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read
+    pub fn hooks(&self) -> Result<BTreeMap<String, String>> {
+        let mut hooks = BTreeMap::new();
+        let mut reader = try!(self.tar_reader());
+        loop {
+            let name = match reader.next_header() {
+                Some(entry) => {
+                    if let FileType::Directory = entry.filetype() {
+                        continue;
+                    }
+                    match package_relative_path(entry.pathname()) {
+                        Some(path) if path.starts_with("hooks/") => {
+                            path["hooks/".len()..].to_string()
+                        }
+                        _ => continue,
+                    }
+                }
+                None => break,
+            };
+            let mut content = Vec::new();
+            while let Some(bytes) = try!(reader.read_block()) {
+                content.extend_from_slice(bytes);
+            }
+            hooks.insert(name, String::from_utf8_lossy(&content).into_owned());
+        }
+        Ok(hooks)
+    }
+
     /// A plain string representation of the archive's file name.
     pub fn file_name(&self) -> String {
         self.path.file_name().unwrap().to_string_lossy().into_owned()
@@ -172,6 +268,78 @@ impl PackageArchive {
         artifact::verify(&self.path, cache_key_path)
     }
 
+    /// Verify the archive's signatures in strict mode, refusing it unless every key which signed
+    /// it is pinned in the trust store.
+    ///
+    /// # Failures
+    ///
+    /// * Fails if a signing key is not trusted or a signature does not verify
+    pub fn verify_trusted<P: AsRef<Path>>(&self,
+                                          trust_store: &TrustStore,
+                                          cache_key_path: &P)
+                                          -> Result<(Vec<String>, String)> {
+        trust_store.verify_artifact(&self.path, cache_key_path.as_ref())
+    }
+
+    /// Verify the archive's signatures against `policy` while unpacking it, reading the archive
+    /// only once.
+    ///
+    /// The payload is extracted into a staging directory under the filesystem root as it is
+    /// hashed. Entries with absolute paths, `..` components or paths through symlinks are
+    /// refused, so an archive can't write outside the staging directory before it is verified.
+    /// Only once every signature required by the policy has verified are the staged files moved
+    /// into place, so nothing from an unverified archive is ever installed. The
+    /// return is a tuple of the names with revision of the keys whose signatures verified and
+    /// the archive's payload hash.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read or unpacked
+    /// * If the archive's signatures don't satisfy `policy`
+    pub fn unpack_verified<P: AsRef<Path>>(&self,
+                                           fs_root_path: Option<&Path>,
+                                           cache_key_path: &P,
+                                           policy: &VerifyPolicy)
+                                           -> Result<(Vec<String>, String)> {
+        let root = fs_root_path.unwrap_or(Path::new("/"));
+        let staging = root.join(ROOT_PATH).join(format!(".unpack-{}", self.file_name()));
+        if staging.exists() {
+            try!(fs::remove_dir_all(&staging));
+        }
+        try!(fs::create_dir_all(&staging));
+        let result = self.unpack_verified_into(&staging, root, cache_key_path.as_ref(), policy);
+        if let Err(e) = fs::remove_dir_all(&staging) {
+            debug!("Failed to remove staging directory {}: {}",
+                   staging.display(),
+                   e);
+        }
+        result
+    }
+
+    fn unpack_verified_into(&self,
+                            staging: &Path,
+                            root: &Path,
+                            cache_key_path: &Path,
+                            policy: &VerifyPolicy)
+                            -> Result<(Vec<String>, String)> {
+        let (header, tar_reader) = try!(artifact::get_header_and_archive_reader(&self.path));
+        let hashing_reader = HashingReader::new(tar_reader);
+        {
+            let mut builder = reader::Builder::new();
+            try!(builder.support_format(ReadFormat::Gnutar));
+            try!(builder.support_filter(ReadFilter::Xz));
+            let mut reader = try!(builder.open_stream(hashing_reader.clone()));
+            let writer = try!(disk_writer());
+            try!(writer.write(&mut reader, Some(staging.to_string_lossy().as_ref())));
+            try!(writer.close());
+        }
+        let computed_hash = try!(hashing_reader.finish());
+        let verified =
+            try!(artifact::verify_header(&header, &computed_hash, cache_key_path, policy));
+        try!(move_tree(staging, root));
+        Ok((verified, computed_hash))
+    }
+
     /// Given a package name and a path to a file as an `&str`, unpack
     /// the package.
     ///
@@ -180,16 +348,20 @@ impl PackageArchive {
     /// * If the package cannot be unpacked
     pub fn unpack(&self, fs_root_path: Option<&Path>) -> Result<()> {
         let root = fs_root_path.unwrap_or(Path::new("/"));
+        let mut reader = try!(self.tar_reader());
+        let writer = try!(disk_writer());
+        try!(writer.write(&mut reader, Some(root.to_string_lossy().as_ref())));
+        try!(writer.close());
+        Ok(())
+    }
+
+    /// Opens the tarball of the archive for reading, past the artifact's signed header.
+    fn tar_reader(&self) -> Result<reader::StreamReader> {
         let tar_reader = try!(artifact::get_archive_reader(&self.path));
         let mut builder = reader::Builder::new();
         try!(builder.support_format(ReadFormat::Gnutar));
         try!(builder.support_filter(ReadFilter::Xz));
-        let mut reader = try!(builder.open_stream(tar_reader));
-        let writer = writer::Disk::new();
-        try!(writer.set_standard_lookup());
-        try!(writer.write(&mut reader, Some(root.to_string_lossy().as_ref())));
-        try!(writer.close());
-        Ok(())
+        Ok(try!(builder.open_stream(tar_reader)))
     }
 
     fn read_deps(&mut self, file: MetaFile) -> Result<Vec<PackageIdent>> {
@@ -216,6 +388,9 @@ impl PackageArchive {
     }
 
     fn read_metadata(&mut self, file: MetaFile) -> Result<Option<&String>> {
+        if self.metadata.is_none() {
+            self.metadata = self.cached_metadata();
+        }
         if let Some(ref files) = self.metadata {
             return Ok(files.get(&file));
         }
@@ -261,9 +436,112 @@ impl PackageArchive {
                 break;
             }
         }
+        self.cache_metadata(&metadata);
         self.metadata = Some(metadata);
         Ok(self.metadata.as_ref().unwrap().get(&file))
     }
+
+    /// Returns the metadata of this archive from the metadata cache, if it was read before and
+    /// the archive hasn't changed since.
+    fn cached_metadata(&self) -> Option<Metadata> {
+        let (len, modified) = match file_stamp(&self.path) {
+            Some(stamp) => stamp,
+            None => return None,
+        };
+        let cache = METADATA_CACHE.lock().unwrap();
+        match cache.get(&self.path) {
+            Some(entry) if entry.len == len && entry.modified == modified => {
+                Some(entry.metadata.clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn cache_metadata(&self, metadata: &Metadata) {
+        let (len, modified) = match file_stamp(&self.path) {
+            Some(stamp) => stamp,
+            None => return,
+        };
+        let mut cache = METADATA_CACHE.lock().unwrap();
+        if cache.len() >= METADATA_CACHE_SIZE && !cache.contains_key(&self.path) {
+            cache.clear();
+        }
+        cache.insert(self.path.clone(),
+                     CachedMetadata {
+                         len: len,
+                         modified: modified,
+                         metadata: metadata.clone(),
+                     });
+    }
+}
+
+/// Returns the path of an entry of an artifact's tarball relative to the installed path of its
+/// package, or `None` for entries outside of it, such as the installed path itself.
+fn package_relative_path(pathname: &str) -> Option<&str> {
+    let parts: Vec<&str> = pathname.splitn(7, '/').collect();
+    if parts.len() == 7 && parts[0] == "hab" && parts[1] == "pkgs" {
+        let path = parts[6].trim_right_matches('/');
+        if !path.is_empty() {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Returns the size and modification time of a file, which identify a version of its contents.
+fn file_stamp(path: &Path) -> Option<(u64, SystemTime)> {
+    match fs::metadata(path) {
+        Ok(md) => md.modified().ok().map(|modified| (md.len(), modified)),
+        Err(_) => None,
+    }
+}
+
+/// Returns a writer which extracts archive entries to disk, refusing any entry which would be
+/// written outside of the extraction root.
+fn disk_writer() -> Result<writer::Disk> {
+    let writer = writer::Disk::new();
+    let mut options = ExtractOptions::new();
+    options.add(ExtractOption::SecureSymlinks)
+        .add(ExtractOption::SecureNoDotDot)
+        .add(ExtractOption::SecureNoAbsolutePaths);
+    try!(writer.set_options(&options));
+    try!(writer.set_standard_lookup());
+    Ok(writer)
+}
+
+/// Moves every file and directory under `src` to the same relative path under `dst`, merging
+/// into directories which already exist. If a move fails, the entries already moved are moved
+/// back under `src`, so a partially moved tree is never left under `dst`.
+fn move_tree(src: &Path, dst: &Path) -> Result<()> {
+    let mut moved = Vec::new();
+    let result = move_entries(src, dst, &mut moved);
+    if result.is_err() {
+        for &(ref from, ref to) in moved.iter().rev() {
+            if let Err(e) = fs::rename(to, from) {
+                debug!("Failed to move {} back to {}: {}",
+                       to.display(),
+                       from.display(),
+                       e);
+            }
+        }
+    }
+    result
+}
+
+/// Moves the entries under `src` to `dst`, recording the source and target of every rename.
+fn move_entries(src: &Path, dst: &Path, moved: &mut Vec<(PathBuf, PathBuf)>) -> Result<()> {
+    for entry in try!(fs::read_dir(src)) {
+        let entry = try!(entry);
+        let target = dst.join(entry.file_name());
+        let is_dir = try!(entry.file_type()).is_dir();
+        if is_dir && target.is_dir() {
+            try!(move_entries(&entry.path(), &target, moved));
+        } else {
+            try!(fs::rename(entry.path(), &target));
+            moved.push((entry.path(), target));
+        }
+    }
+    Ok(())
 }
 
 pub trait FromArchive: Sized {
@@ -275,8 +553,14 @@ pub trait FromArchive: Sized {
 #[cfg(test)]
 mod test {
     use std::env;
+    use std::fs::{self, File};
     use std::path::PathBuf;
+
+    use tempdir::TempDir;
+
     use super::*;
+    use crypto::SigKeyPair;
+    use crypto::artifact::{self, VerifyPolicy};
 
     #[test]
     fn reading_artifact_metadata() {
@@ -289,6 +573,97 @@ mod test {
         assert_eq!(ident.release, Some("20160427165340".to_string()));
     }
 
+    #[test]
+    fn reading_artifact_metadata_from_cache() {
+        let path = fixtures().join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart");
+        let mut hart = PackageArchive::new(path.clone());
+        hart.ident().unwrap();
+
+        let cold = PackageArchive::new(path);
+        assert_eq!(cold.cached_metadata().unwrap().get(&MetaFile::Ident),
+                   hart.metadata.as_ref().unwrap().get(&MetaFile::Ident));
+    }
+
+    #[test]
+    fn unpack_verified_installs_verified_archive() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let root = TempDir::new("fs_root").unwrap();
+        fs::copy(fixtures().join("happyhumans-20160424223347.pub"),
+                 cache.path().join("happyhumans-20160424223347.pub"))
+            .unwrap();
+        let hart = PackageArchive::new(fixtures()
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart"));
+
+        let verified = hart.unpack_verified(Some(root.path()), &cache.path(), &VerifyPolicy::All)
+            .unwrap();
+        assert_eq!(verified.0, vec!["happyhumans-20160424223347".to_string()]);
+        assert_eq!(verified,
+                   artifact::verify_with_policy(&hart.path, cache.path(), &VerifyPolicy::All)
+                       .unwrap());
+        assert!(root.path().join("hab/pkgs/happyhumans/possums/8.1.4/20160427165340").is_dir());
+        assert_eq!(fs::read_dir(root.path().join("hab")).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn unpack_verified_installs_nothing_when_unverified() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let root = TempDir::new("fs_root").unwrap();
+        let hart = PackageArchive::new(fixtures()
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart"));
+
+        assert!(hart.unpack_verified(Some(root.path()), &cache.path(), &VerifyPolicy::All)
+            .is_err());
+        assert_eq!(fs::read_dir(root.path().join("hab")).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn unpack_verified_refuses_entries_outside_the_staging_directory() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let root = TempDir::new("fs_root").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let path = root.path().join("unicorn-escape.hart");
+        artifact::sign(&fixtures().join("escape.tar.xz"), &path, &pair).unwrap();
+        let hart = PackageArchive::new(path);
+
+        assert!(hart.unpack_verified(Some(root.path()), &cache.path(), &VerifyPolicy::All)
+            .is_err());
+        assert!(!root.path().join("dotdot-escape").exists());
+        assert!(!root.path().join("symlink-escape").exists());
+        assert_eq!(fs::read_dir(root.path().join("hab")).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn move_tree_moves_entries_back_when_a_move_fails() {
+        let src = TempDir::new("src").unwrap();
+        let dst = TempDir::new("dst").unwrap();
+        File::create(src.path().join("moved")).unwrap();
+        File::create(src.path().join("blocked")).unwrap();
+        fs::create_dir_all(dst.path().join("blocked/occupied")).unwrap();
+
+        assert!(move_tree(src.path(), dst.path()).is_err());
+        assert!(src.path().join("moved").is_file());
+        assert!(!dst.path().join("moved").exists());
+    }
+
+    #[test]
+    fn listing_artifact_files() {
+        let hart = PackageArchive::new(fixtures()
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart"));
+        assert_eq!(hart.files().unwrap(),
+                   vec!["IDENT", "MANIFEST", "TARGET", "share/signme.dat"]);
+        assert!(hart.hooks().unwrap().is_empty());
+    }
+
+    #[test]
+    fn package_relative_paths() {
+        assert_eq!(package_relative_path("hab/pkgs/core/redis/3.0.7/20160614231131/bin/redis"),
+                   Some("bin/redis"));
+        assert_eq!(package_relative_path("hab/pkgs/core/redis/3.0.7/20160614231131/hooks/"),
+                   Some("hooks"));
+        assert_eq!(package_relative_path("hab/pkgs/core/redis/3.0.7/20160614231131"), None);
+        assert_eq!(package_relative_path("hab/pkgs/core/redis/3.0.7/20160614231131/"), None);
+    }
+
     pub fn exe_path() -> PathBuf {
         env::current_exe().unwrap()
     }
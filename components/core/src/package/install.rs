@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::cmp::{Ordering, PartialOrd};
 use std::env;
 use std::fs::{DirEntry, File};
@@ -90,6 +90,33 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the identifiers of every installed package which satisfies `ident`, or of every
+    /// installed package if no identifier is given. The packages are sorted by origin and name,
+    /// and then from oldest to newest.
+    ///
+    /// An optional `fs_root` path may be provided to list packages on a filesystem not currently
+    /// rooted at `/`.
+    pub fn installed(ident: Option<&PackageIdent>,
+                     fs_root_path: Option<&Path>)
+                     -> Result<Vec<PackageIdent>> {
+        let package_root_path = fs_root_path.unwrap_or(Path::new("/")).join(PKG_PATH);
+        if !package_root_path.exists() {
+            return Ok(Vec::new());
+        }
+        let mut packages: Vec<PackageIdent> = try!(Self::package_list(&package_root_path))
+            .into_iter()
+            .filter(|p| ident.map_or(true, |i| p.satisfies(i)))
+            .collect();
+        packages.sort_by(|a, b| {
+            match (a.origin.cmp(&b.origin), a.name.cmp(&b.name)) {
+                (Ordering::Equal, Ordering::Equal) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+                (Ordering::Equal, by_name) => by_name,
+                (by_origin, _) => by_origin,
+            }
+        });
+        Ok(packages)
+    }
+
     pub fn new_from_parts(ident: PackageIdent,
                           fs_root_path: PathBuf,
                           package_root_path: PathBuf,
@@ -151,6 +178,77 @@ impl PackageInstall {
         }
     }
 
+    /// Return the contents of the package's manifest metafile
+    ///
+    /// # Failures
+    ///
+    /// * The package does not contain a Manifest metafile or it could not be read
+    pub fn manifest(&self) -> Result<String> {
+        self.read_metafile(MetaFile::Manifest)
+    }
+
+    /// Return the contents of the package's default configuration, if it has one
+    ///
+    /// # Failures
+    ///
+    /// * The package contains a default configuration but it could not be read
+    pub fn config(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Config) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Config)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the paths of the files in the package, relative to its installed path, in sorted
+    /// order. Directories are not listed.
+    ///
+    /// # Failures
+    ///
+    /// * The installed path of the package could not be read
+    pub fn files(&self) -> Result<Vec<String>> {
+        let mut files = Vec::new();
+        try!(walk_files(&self.installed_path, &self.installed_path, &mut files));
+        files.sort();
+        Ok(files)
+    }
+
+    /// Returns the contents of the package's hooks, keyed by the name of the hook.
+    ///
+    /// # Failures
+    ///
+    /// * A hook could not be read
+    pub fn hooks(&self) -> Result<BTreeMap<String, String>> {
+        let mut hooks = BTreeMap::new();
+        let hooks_path = self.installed_path.join("hooks");
+        if !hooks_path.is_dir() {
+            return Ok(hooks);
+        }
+        for entry in try!(std::fs::read_dir(&hooks_path)) {
+            let path = try!(entry).path();
+            if !path.is_file() {
+                continue;
+            }
+            let mut content = String::new();
+            try!(try!(File::open(&path)).read_to_string(&mut content));
+            hooks.insert(path.file_name().unwrap().to_string_lossy().into_owned(), content);
+        }
+        Ok(hooks)
+    }
+
+    /// Return the signed contents of the package's provenance metafile, if it exists
+    ///
+    /// # Failures
+    ///
+    /// * The package contains a Provenance metafile but it could not be read
+    pub fn provenance(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Provenance) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Provenance)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Returns a `String` with the full run path for this package. The `PATH` string will be
     /// constructed by add all `PATH` metadata entries from the *direct* dependencies first (in
     /// declared order) and then from any remaining transitive dependencies last (in lexically
@@ -184,10 +282,44 @@ impl PackageInstall {
         Ok(run_path)
     }
 
+    /// Returns the packages of which `runtime_path` would combine more than one release, keyed
+    /// by `origin/name`. Besides the dependencies of this package, the transitive dependencies
+    /// of each installed dependency are checked, since a dependency which was built against
+    /// another release of a package may not work with the release found on the runtime path.
+    pub fn runtime_conflicts(&self) -> Result<BTreeMap<String, Vec<PackageIdent>>> {
+        let mut idents = vec![self.ident.clone()];
+        idents.extend(try!(self.deps()));
+        let tdeps = try!(self.tdeps());
+        for dep in tdeps.iter() {
+            if let Ok(dep_install) = Self::load(dep, Some(&self.fs_root_path)) {
+                idents.extend(try!(dep_install.tdeps()));
+            }
+        }
+        idents.extend(tdeps);
+        Ok(release_conflicts(idents.iter()))
+    }
+
     pub fn installed_path(&self) -> &PathBuf {
         &self.installed_path
     }
 
+    /// Removes the installed package from disk, along with any version, name and origin
+    /// directories it leaves empty.
+    ///
+    /// Note that this does not check whether other installed packages depend on this package.
+    pub fn uninstall(&self) -> Result<()> {
+        try!(std::fs::remove_dir_all(&self.installed_path));
+        let mut parent = self.installed_path.parent();
+        while let Some(dir) = parent {
+            if dir == self.package_root_path.as_path() || try!(std::fs::read_dir(dir)).count() > 0 {
+                break;
+            }
+            try!(std::fs::remove_dir(dir));
+            parent = dir.parent();
+        }
+        Ok(())
+    }
+
     /// Returns the root path for service configuration, files, and data.
     pub fn svc_path(&self) -> PathBuf {
         fs::svc_path(&self.ident.name)
@@ -390,3 +522,202 @@ impl PackageInstall {
         Ok(())
     }
 }
+
+/// Groups package identifiers by `origin/name` and returns the groups holding more than one
+/// release, each sorted oldest release first.
+pub fn release_conflicts<'a, I>(idents: I) -> BTreeMap<String, Vec<PackageIdent>>
+    where I: IntoIterator<Item = &'a PackageIdent>
+{
+    let mut releases: BTreeMap<String, Vec<PackageIdent>> = BTreeMap::new();
+    for ident in idents {
+        let group = releases.entry(format!("{}/{}", ident.origin, ident.name)).or_insert(vec![]);
+        if !group.contains(ident) {
+            group.push(ident.clone());
+        }
+    }
+    releases.into_iter()
+        .filter(|&(_, ref group)| group.len() > 1)
+        .map(|(name, mut group)| {
+            group.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            (name, group)
+        })
+        .collect()
+}
+
+/// Adds the paths relative to `root` of every file below `dir` to `files`. Symbolic links are
+/// listed as files and not followed.
+fn walk_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<()> {
+    for entry in try!(std::fs::read_dir(dir)) {
+        let path = try!(entry).path();
+        if try!(std::fs::symlink_metadata(&path)).is_dir() {
+            try!(walk_files(root, &path, files));
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.push(relative.to_string_lossy().into_owned());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::str::FromStr;
+
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use super::{release_conflicts, PackageInstall};
+    use fs::PKG_PATH;
+    use package::PackageIdent;
+
+    fn fake_install(root: &TempDir, ident: &str) {
+        let ident = PackageIdent::from_str(ident).unwrap();
+        fs::create_dir_all(root.path()
+                .join(PKG_PATH)
+                .join(&ident.origin)
+                .join(&ident.name)
+                .join(ident.version.as_ref().unwrap())
+                .join(ident.release.as_ref().unwrap()))
+            .unwrap();
+    }
+
+    fn fake_install_with_tdeps(root: &TempDir, ident: &str, tdeps: &[&str]) {
+        fake_install(root, ident);
+        let mut content = String::new();
+        for tdep in tdeps {
+            content.push_str(&format!("{}\n", tdep));
+        }
+        File::create(root.path().join(PKG_PATH).join(ident).join("TDEPS"))
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+    }
+
+    fn idents(packages: Vec<PackageIdent>) -> Vec<String> {
+        packages.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn installed_lists_sorted_packages() {
+        let root = TempDir::new("fs_root").unwrap();
+        fake_install(&root, "core/redis/3.0.7/20160614231131");
+        fake_install(&root, "core/redis/3.0.1/20160512012734");
+        fake_install(&root, "acme/redis/3.0.7/20160614231131");
+        fake_install(&root, "core/glibc/2.22/20160427193532");
+
+        let all = PackageInstall::installed(None, Some(root.path())).unwrap();
+        assert_eq!(idents(all),
+                   vec!["acme/redis/3.0.7/20160614231131",
+                        "core/glibc/2.22/20160427193532",
+                        "core/redis/3.0.1/20160512012734",
+                        "core/redis/3.0.7/20160614231131"]);
+
+        let filter = PackageIdent::from_str("core/redis").unwrap();
+        let redis = PackageInstall::installed(Some(&filter), Some(root.path())).unwrap();
+        assert_eq!(idents(redis),
+                   vec!["core/redis/3.0.1/20160512012734", "core/redis/3.0.7/20160614231131"]);
+    }
+
+    #[test]
+    fn installed_without_package_root() {
+        let root = TempDir::new("fs_root").unwrap();
+        assert!(PackageInstall::installed(None, Some(root.path())).unwrap().is_empty());
+    }
+
+    #[test]
+    fn release_conflicts_groups_releases() {
+        let all: Vec<PackageIdent> = vec!["core/openssl/1.0.2j/20161005195021",
+                                          "core/glibc/2.22/20160427193532",
+                                          "core/openssl/1.0.2h/20160612081125",
+                                          "core/openssl/1.0.2j/20161005195021"]
+            .iter()
+            .map(|i| PackageIdent::from_str(i).unwrap())
+            .collect();
+        let conflicts = release_conflicts(all.iter());
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(idents(conflicts["core/openssl"].clone()),
+                   vec!["core/openssl/1.0.2h/20160612081125",
+                        "core/openssl/1.0.2j/20161005195021"]);
+    }
+
+    #[test]
+    fn runtime_conflicts_through_dependencies() {
+        let root = TempDir::new("fs_root").unwrap();
+        fake_install_with_tdeps(&root,
+                                "core/curl/7.50.1/20160815192039",
+                                &["core/openssl/1.0.2h/20160612081125"]);
+        fake_install(&root, "core/openssl/1.0.2h/20160612081125");
+        fake_install(&root, "core/openssl/1.0.2j/20161005195021");
+        fake_install_with_tdeps(&root,
+                                "acme/app/1.0.0/20161010101010",
+                                &["core/curl/7.50.1/20160815192039",
+                                  "core/openssl/1.0.2j/20161005195021"]);
+
+        let curl = PackageIdent::from_str("core/curl/7.50.1/20160815192039").unwrap();
+        let curl = PackageInstall::load(&curl, Some(root.path())).unwrap();
+        assert!(curl.runtime_conflicts().unwrap().is_empty());
+
+        let app = PackageIdent::from_str("acme/app/1.0.0/20161010101010").unwrap();
+        let app = PackageInstall::load(&app, Some(root.path())).unwrap();
+        let conflicts = app.runtime_conflicts().unwrap();
+        assert_eq!(conflicts.keys().collect::<Vec<_>>(), vec!["core/openssl"]);
+    }
+
+    #[test]
+    fn config_reads_default_toml() {
+        let root = TempDir::new("fs_root").unwrap();
+        fake_install(&root, "core/redis/3.0.7/20160614231131");
+        fake_install(&root, "core/glibc/2.22/20160427193532");
+        let redis_path = root.path().join(PKG_PATH).join("core/redis/3.0.7/20160614231131");
+        File::create(redis_path.join("default.toml"))
+            .unwrap()
+            .write_all(b"port = 6379\n")
+            .unwrap();
+
+        let redis = PackageIdent::from_str("core/redis").unwrap();
+        let redis = PackageInstall::load(&redis, Some(root.path())).unwrap();
+        assert_eq!(redis.config().unwrap(), Some("port = 6379".to_string()));
+        let glibc = PackageIdent::from_str("core/glibc").unwrap();
+        let glibc = PackageInstall::load(&glibc, Some(root.path())).unwrap();
+        assert_eq!(glibc.config().unwrap(), None);
+    }
+
+    #[test]
+    fn files_and_hooks() {
+        let root = TempDir::new("fs_root").unwrap();
+        fake_install(&root, "core/redis/3.0.7/20160614231131");
+        let redis_path = root.path().join(PKG_PATH).join("core/redis/3.0.7/20160614231131");
+        fs::create_dir_all(redis_path.join("bin")).unwrap();
+        fs::create_dir_all(redis_path.join("hooks")).unwrap();
+        for file in &["IDENT", "bin/redis-server", "hooks/run"] {
+            File::create(redis_path.join(file)).unwrap().write_all(b"content").unwrap();
+        }
+
+        let redis = PackageIdent::from_str("core/redis").unwrap();
+        let redis = PackageInstall::load(&redis, Some(root.path())).unwrap();
+        assert_eq!(redis.files().unwrap(), vec!["IDENT", "bin/redis-server", "hooks/run"]);
+        let hooks = redis.hooks().unwrap();
+        assert_eq!(hooks.keys().collect::<Vec<_>>(), vec!["run"]);
+        assert_eq!(hooks["run"], "content");
+    }
+
+    #[test]
+    fn uninstall_removes_empty_parents() {
+        let root = TempDir::new("fs_root").unwrap();
+        fake_install(&root, "core/redis/3.0.7/20160614231131");
+        fake_install(&root, "core/redis/3.0.1/20160512012734");
+
+        let old = PackageIdent::from_str("core/redis/3.0.1/20160512012734").unwrap();
+        PackageInstall::load(&old, Some(root.path())).unwrap().uninstall().unwrap();
+        let pkgs = root.path().join(PKG_PATH);
+        assert!(!pkgs.join("core/redis/3.0.1").exists());
+        assert!(pkgs.join("core/redis/3.0.7/20160614231131").is_dir());
+
+        let new = PackageIdent::from_str("core/redis/3.0.7/20160614231131").unwrap();
+        PackageInstall::load(&new, Some(root.path())).unwrap().uninstall().unwrap();
+        assert!(!pkgs.join("core").exists());
+        assert!(pkgs.is_dir());
+    }
+}
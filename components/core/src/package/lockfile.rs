@@ -0,0 +1,179 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use toml;
+
+use config::ConfigFile;
+use error::{Error, Result};
+use super::{Identifiable, PackageIdent};
+
+/// The name of the lockfile `hab pkg lock` writes when no other path is given.
+pub const DEFAULT_LOCKFILE: &'static str = "hab.lock";
+
+/// A fully qualified package along with the checksum of its artifact.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockedPackage {
+    pub ident: PackageIdent,
+    pub checksum: String,
+}
+
+/// An exact set of packages to install, so that every install from the same lockfile gets the
+/// same bits.
+///
+/// The lockfile is a TOML file listing the packages which were asked for, and every package
+/// needed to install them along with the checksum of its artifact. The packages are listed in
+/// the order they are installed in, with dependencies before the packages which need them:
+///
+/// ```toml
+/// requested = ["core/redis"]
+///
+/// [[package]]
+/// ident = "core/glibc/2.22/20160612063629"
+/// checksum = "4d1b1c4c26ab7d4b7ae3b1d1a1e4d5f3c2c1e1f5b6d7e8f9a0b1c2d3e4f5a6b7"
+///
+/// [[package]]
+/// ident = "core/redis/3.0.7/20160614231131"
+/// checksum = "c7a7c2ac1e6fc1bd2d6e6b1d4ac4c3b1f2b1d5e4f3a2b1c0d9e8f7a6b5c4d3e2"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lockfile {
+    pub requested: Vec<PackageIdent>,
+    pub packages: Vec<LockedPackage>,
+}
+
+impl Lockfile {
+    /// Adds a package to the end of the lockfile, unless it is listed already.
+    pub fn push(&mut self, ident: PackageIdent, checksum: String) {
+        if !self.contains(&ident) {
+            self.packages.push(LockedPackage {
+                ident: ident,
+                checksum: checksum,
+            });
+        }
+    }
+
+    /// Returns true if the lockfile lists the package.
+    pub fn contains(&self, ident: &PackageIdent) -> bool {
+        self.checksum_for(ident).is_some()
+    }
+
+    /// Returns the locked checksum of a package's artifact, if the lockfile lists the package.
+    pub fn checksum_for(&self, ident: &PackageIdent) -> Option<&str> {
+        self.packages
+            .iter()
+            .find(|p| p.ident == *ident)
+            .map(|p| p.checksum.as_str())
+    }
+
+    /// Returns the lockfile in its TOML form.
+    pub fn to_toml_string(&self) -> String {
+        let requested: Vec<String> =
+            self.requested.iter().map(|ident| format!("\"{}\"", ident)).collect();
+        let mut content = format!("requested = [{}]\n", requested.join(", "));
+        for package in self.packages.iter() {
+            content.push_str(&format!("\n[[package]]\nident = \"{}\"\nchecksum = \"{}\"\n",
+                                      package.ident,
+                                      package.checksum));
+        }
+        content
+    }
+}
+
+impl ConfigFile for Lockfile {
+    type Error = Error;
+
+    fn from_toml(toml: toml::Value) -> Result<Self> {
+        let mut lockfile = Lockfile::default();
+        if let Some(requested) = toml.lookup("requested") {
+            let requested = match requested.as_slice() {
+                Some(requested) => requested,
+                None => return Err(Error::ConfigInvalidArray("requested")),
+            };
+            for ident in requested.iter() {
+                match ident.as_str() {
+                    Some(ident) => lockfile.requested.push(try!(PackageIdent::from_str(ident))),
+                    None => return Err(Error::ConfigInvalidString("requested")),
+                }
+            }
+        }
+        if let Some(packages) = toml.lookup("package") {
+            let packages = match packages.as_slice() {
+                Some(packages) => packages,
+                None => return Err(Error::ConfigInvalidArray("package")),
+            };
+            for package in packages.iter() {
+                let ident = match package.lookup("ident").and_then(|i| i.as_str()) {
+                    Some(ident) => try!(PackageIdent::from_str(ident)),
+                    None => return Err(Error::ConfigInvalidString("package.ident")),
+                };
+                if !ident.fully_qualified() {
+                    return Err(Error::InvalidPackageIdent(ident.to_string()));
+                }
+                let checksum = match package.lookup("checksum").and_then(|c| c.as_str()) {
+                    Some(checksum) => checksum.trim().to_lowercase(),
+                    None => return Err(Error::ConfigInvalidString("package.checksum")),
+                };
+                lockfile.push(ident, checksum);
+            }
+        }
+        Ok(lockfile)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use toml;
+
+    use super::Lockfile;
+    use config::ConfigFile;
+    use package::PackageIdent;
+
+    fn ident(s: &str) -> PackageIdent {
+        PackageIdent::from_str(s).unwrap()
+    }
+
+    fn parse(content: &str) -> Lockfile {
+        Lockfile::from_toml(content.parse::<toml::Value>().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut lockfile = Lockfile::default();
+        lockfile.requested.push(ident("core/redis"));
+        lockfile.push(ident("core/glibc/2.22/20160612063629"), "aa".to_string());
+        lockfile.push(ident("core/redis/3.0.7/20160614231131"), "bb".to_string());
+        assert_eq!(parse(&lockfile.to_toml_string()), lockfile);
+    }
+
+    #[test]
+    fn push_skips_listed_packages() {
+        let mut lockfile = Lockfile::default();
+        lockfile.push(ident("core/glibc/2.22/20160612063629"), "aa".to_string());
+        lockfile.push(ident("core/glibc/2.22/20160612063629"), "bb".to_string());
+        assert_eq!(lockfile.packages.len(), 1);
+        assert_eq!(lockfile.checksum_for(&ident("core/glibc/2.22/20160612063629")),
+                   Some("aa"));
+        assert_eq!(lockfile.checksum_for(&ident("core/redis/3.0.7/20160614231131")), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidPackageIdent")]
+    fn package_must_be_fully_qualified() {
+        parse("[[package]]\nident = \"core/redis\"\nchecksum = \"aa\"\n");
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Value written by `hab-plan-build` into the `Upstream URL` field when a plan does not set
+/// `pkg_upstream_url`.
+const UNDEFINED_UPSTREAM_URL: &'static str = "upstream project's website or home page is not \
+                                              defined";
+
+/// A structured view over the fields of a package's `MANIFEST` metafile.
+///
+/// The `MANIFEST` is a Markdown document generated by `hab-plan-build`. It starts with a title
+/// line of `# origin / name`, followed by the plan's description and a bulleted list of
+/// `* __Field__: value` entries. Any field which is missing or left undefined by the plan is
+/// represented here as `None` (or an empty `Vec`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PackageManifest {
+    pub description: Option<String>,
+    pub maintainer: Option<String>,
+    pub licenses: Vec<String>,
+    pub upstream_url: Option<String>,
+}
+
+impl PackageManifest {
+    /// Parses the contents of a `MANIFEST` metafile.
+    ///
+    /// Parsing is lenient: unknown fields are skipped and a document without any recognizable
+    /// fields yields an empty manifest.
+    pub fn parse(content: &str) -> Self {
+        let mut manifest = PackageManifest::default();
+        let mut description: Vec<&str> = vec![];
+        let mut in_header = false;
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with("# ") && !in_header && manifest == PackageManifest::default() &&
+               description.is_empty() {
+                in_header = true;
+                continue;
+            }
+            if let Some((field, value)) = parse_field(line) {
+                in_header = false;
+                match field {
+                    "Maintainer" => manifest.maintainer = non_empty(value),
+                    "License" => {
+                        manifest.licenses = value.split_whitespace()
+                            .map(|l| l.to_string())
+                            .collect()
+                    }
+                    "Upstream URL" => manifest.upstream_url = parse_upstream_url(value),
+                    _ => (),
+                }
+                continue;
+            }
+            if line.starts_with("#") {
+                // The plan, build flags and plan source follow the field list and are not
+                // indexed.
+                break;
+            }
+            if in_header && !line.is_empty() {
+                description.push(line);
+            }
+        }
+        if !description.is_empty() {
+            manifest.description = Some(description.join(" "));
+        }
+        manifest
+    }
+}
+
+/// Splits a `* __Field__: value` line into its field name and value.
+fn parse_field(line: &str) -> Option<(&str, &str)> {
+    if !line.starts_with("* __") {
+        return None;
+    }
+    let rest = &line[4..];
+    match rest.find("__:") {
+        Some(idx) => Some((&rest[..idx], rest[idx + 3..].trim())),
+        None => None,
+    }
+}
+
+/// Extracts a bare URL from either a Markdown link (`[url](url)`) or a plain value.
+fn parse_upstream_url(value: &str) -> Option<String> {
+    if value == UNDEFINED_UPSTREAM_URL {
+        return None;
+    }
+    if value.starts_with("[") {
+        if let (Some(start), Some(end)) = (value.find("]("), value.rfind(")")) {
+            if start + 2 < end {
+                return non_empty(&value[start + 2..end]);
+            }
+        }
+    }
+    non_empty(value)
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static MANIFEST: &'static str = "# core / redis
+Persistent key-value database, with built-in net interface
+
+* __Maintainer__: The Habitat Maintainers <humans@habitat.sh>
+* __Version__: 3.0.7
+* __Release__: 20160614231131
+* __Architecture__: x86_64
+* __System__: linux
+* __Target__: x86_64-linux
+* __Upstream URL__: [http://redis.io](http://redis.io)
+* __License__: BSD MIT
+* __Source__: [http://download.redis.io/releases/redis-3.0.7.tar.gz](http://download.redis.io/releases/redis-3.0.7.tar.gz)
+* __SHA__: `b2a791c4ea3bb7268795c45c6321ea5abcc24457178373e6a6e3be6372737f23`
+* __Path__: `/hab/pkgs/core/redis/3.0.7/20160614231131`
+
+# Plan
+
+## Plan Source
+
+```bash
+pkg_name=redis
+```
+";
+
+    #[test]
+    fn parse_manifest_fields() {
+        let manifest = PackageManifest::parse(MANIFEST);
+        assert_eq!(manifest.description,
+                   Some("Persistent key-value database, with built-in net interface".to_string()));
+        assert_eq!(manifest.maintainer,
+                   Some("The Habitat Maintainers <humans@habitat.sh>".to_string()));
+        assert_eq!(manifest.licenses, vec!["BSD".to_string(), "MIT".to_string()]);
+        assert_eq!(manifest.upstream_url, Some("http://redis.io".to_string()));
+    }
+
+    #[test]
+    fn parse_manifest_undefined_upstream_url() {
+        let manifest = PackageManifest::parse("# core / foo\n\n* __Upstream URL__: upstream \
+                                               project's website or home page is not defined");
+        assert_eq!(manifest.upstream_url, None);
+        assert_eq!(manifest.description, None);
+    }
+
+    #[test]
+    fn parse_empty_manifest() {
+        assert_eq!(PackageManifest::parse(""), PackageManifest::default());
+    }
+}
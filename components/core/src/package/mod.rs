@@ -15,10 +15,16 @@
 pub mod archive;
 pub mod ident;
 pub mod install;
+pub mod lockfile;
+pub mod manifest;
+pub mod provenance;
 
 pub use self::archive::{FromArchive, PackageArchive};
 pub use self::ident::{Identifiable, PackageIdent};
 pub use self::install::PackageInstall;
+pub use self::lockfile::{Lockfile, LockedPackage};
+pub use self::manifest::PackageManifest;
+pub use self::provenance::PackageProvenance;
 
 use std::fmt;
 
@@ -34,6 +40,7 @@ pub enum MetaFile {
     LdFlags,
     Manifest,
     Path,
+    Provenance,
 }
 
 impl fmt::Display for MetaFile {
@@ -49,6 +56,7 @@ impl fmt::Display for MetaFile {
             MetaFile::LdFlags => "LDFLAGS",
             MetaFile::Manifest => "MANIFEST",
             MetaFile::Path => "PATH",
+            MetaFile::Provenance => "PROVENANCE",
         };
         write!(f, "{}", id)
     }
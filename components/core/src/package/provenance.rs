@@ -0,0 +1,179 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+
+use toml;
+
+use config::ParseInto;
+use crypto::artifact::{self, VerifyPolicy};
+use error::{Error, Result};
+use package::MetaFile;
+
+/// A structured view over the fields of a package's `PROVENANCE` metafile.
+///
+/// The `PROVENANCE` metafile is generated by `hab-plan-build` and records how a package was
+/// built. Its payload is a flat TOML table which is signed with the origin key of the package,
+/// in the same format as a `HART-1` artifact, so the metafile's contents can be verified
+/// independently of the artifact it was shipped in. Any field which was unknown at build time is
+/// represented here as `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PackageProvenance {
+    /// SHA256 checksum of the `plan.sh` the package was built from
+    pub plan_shasum: Option<String>,
+    /// Package identifier of the Studio the package was built in
+    pub studio_ident: Option<String>,
+    /// Identifier of the Builder job which built the package
+    pub builder_job_id: Option<String>,
+    /// UTC timestamp at which the package was built
+    pub build_timestamp: Option<String>,
+    /// URL of the plan's upstream source archive
+    pub source_url: Option<String>,
+    /// SHA256 checksum of the plan's upstream source archive
+    pub source_shasum: Option<String>,
+}
+
+impl PackageProvenance {
+    /// Parses the signed contents of a `PROVENANCE` metafile without verifying its signatures.
+    ///
+    /// # Failures
+    ///
+    /// * The signed header of the metafile is malformed
+    /// * The payload of the metafile is not a valid TOML table
+    pub fn from_signed(content: &str) -> Result<Self> {
+        let (_, payload) = try!(artifact::read_signed_str(content));
+        Self::from_str(payload)
+    }
+
+    /// Verifies every signature of the signed contents of a `PROVENANCE` metafile and parses its
+    /// payload. The return is a tuple of the names with revision of the signing keys and the
+    /// provenance.
+    ///
+    /// # Failures
+    ///
+    /// * A signing key is not present in the key cache or has been revoked
+    /// * A signature does not verify
+    /// * The payload of the metafile is not a valid TOML table
+    pub fn verify<P: ?Sized>(content: &str, cache_key_path: &P) -> Result<(Vec<String>, Self)>
+        where P: AsRef<Path>
+    {
+        let (signers, payload) =
+            try!(artifact::verify_str(content, cache_key_path, &VerifyPolicy::default()));
+        Ok((signers, try!(Self::from_str(payload))))
+    }
+
+    /// Returns the provenance fields in display order, paired with their names.
+    pub fn fields(&self) -> Vec<(&'static str, Option<&String>)> {
+        vec![("Plan SHA", self.plan_shasum.as_ref()),
+             ("Studio", self.studio_ident.as_ref()),
+             ("Builder Job", self.builder_job_id.as_ref()),
+             ("Build Timestamp", self.build_timestamp.as_ref()),
+             ("Source", self.source_url.as_ref()),
+             ("Source SHA", self.source_shasum.as_ref())]
+    }
+}
+
+impl FromStr for PackageProvenance {
+    type Err = Error;
+
+    /// Parses the unsigned payload of a `PROVENANCE` metafile. Unknown keys are ignored and empty
+    /// values are treated as missing.
+    fn from_str(payload: &str) -> result::Result<Self, Self::Err> {
+        let toml = match payload.parse::<toml::Value>() {
+            Ok(toml) => toml,
+            Err(_) => return Err(Error::MetaFileMalformed(MetaFile::Provenance)),
+        };
+        Ok(PackageProvenance {
+            plan_shasum: try!(parse_field(&toml, "plan_shasum")),
+            studio_ident: try!(parse_field(&toml, "studio_ident")),
+            builder_job_id: try!(parse_field(&toml, "builder_job_id")),
+            build_timestamp: try!(parse_field(&toml, "build_timestamp")),
+            source_url: try!(parse_field(&toml, "source_url")),
+            source_shasum: try!(parse_field(&toml, "source_shasum")),
+        })
+    }
+}
+
+fn parse_field(toml: &toml::Value, field: &'static str) -> Result<Option<String>> {
+    let mut value: Option<String> = None;
+    try!(toml.parse_into(field, &mut value));
+    Ok(value.and_then(|v| if v.trim().is_empty() { None } else { Some(v) }))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::str::FromStr;
+
+    use tempdir::TempDir;
+
+    use super::*;
+    use crypto::{artifact, SigKeyPair};
+
+    static PAYLOAD: &'static str = "plan_shasum = \
+                                    \"3c8b3e2ad4d67d4e4f2dbd2b0f06e0b36b5bd8f6bc1b0d8d5e8f1f3b0a2c9d1e\"
+studio_ident = \"core/hab-studio/0.7.0/20160614231131\"
+builder_job_id = \"\"
+build_timestamp = \"2016-06-14T23:11:31Z\"
+source_url = \"http://download.redis.io/releases/redis-3.0.7.tar.gz\"
+source_shasum = \"b2a791c4ea3bb7268795c45c6321ea5abcc24457178373e6a6e3be6372737f23\"";
+
+    fn sign_payload(cache: &TempDir, pair: &SigKeyPair) -> String {
+        let src = cache.path().join("provenance");
+        let dst = cache.path().join("PROVENANCE");
+        File::create(&src).unwrap().write_all(PAYLOAD.as_bytes()).unwrap();
+        artifact::sign(&src, &dst, pair).unwrap();
+        let mut content = String::new();
+        File::open(&dst).unwrap().read_to_string(&mut content).unwrap();
+        content
+    }
+
+    #[test]
+    fn parse_provenance_fields() {
+        let provenance = PackageProvenance::from_str(PAYLOAD).unwrap();
+        assert_eq!(provenance.studio_ident,
+                   Some("core/hab-studio/0.7.0/20160614231131".to_string()));
+        assert_eq!(provenance.builder_job_id, None);
+        assert_eq!(provenance.build_timestamp, Some("2016-06-14T23:11:31Z".to_string()));
+        assert_eq!(provenance.source_url,
+                   Some("http://download.redis.io/releases/redis-3.0.7.tar.gz".to_string()));
+    }
+
+    #[test]
+    fn parse_empty_provenance() {
+        assert_eq!(PackageProvenance::from_str("").unwrap(),
+                   PackageProvenance::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "MetaFileMalformed")]
+    fn parse_malformed_provenance() {
+        PackageProvenance::from_str("plan_shasum = ").unwrap();
+    }
+
+    #[test]
+    fn verify_signed_provenance() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let content = sign_payload(&cache, &pair);
+
+        let (signers, provenance) = PackageProvenance::verify(&content, cache.path()).unwrap();
+        assert_eq!(vec![pair.name_with_rev()], signers);
+        assert_eq!(PackageProvenance::from_str(PAYLOAD).unwrap(), provenance);
+        assert_eq!(PackageProvenance::from_signed(&content).unwrap(), provenance);
+    }
+}
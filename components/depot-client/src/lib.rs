@@ -29,31 +29,56 @@ pub mod error;
 
 pub use error::{Error, Result};
 
-use std::fs::{self, File};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 use broadcast::BroadcastWriter;
+use hab_core::crypto::hash::Hasher;
+use hab_core::env;
 use hab_core::package::{Identifiable, PackageArchive};
 use hab_http::new_hyper_client;
 use hyper::client::{Body, IntoUrl, Response};
 use hyper::status::StatusCode;
-use hyper::header::{Headers, Authorization, Bearer};
+use hyper::header::{Headers, Authorization, Bearer, ByteRangeSpec, Range};
 use hyper::Url;
-use protocol::depotsrv;
-use rustc_serialize::json;
+use protocol::{depotsrv, vault};
+use rustc_serialize::Decodable;
+use rustc_serialize::json::{self, Json};
 use tee::TeeReader;
 
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
 
+/// Overrides the number of times a failed read from the Depot is retried.
+pub const RETRIES_ENVVAR: &'static str = "HAB_DEPOT_RETRIES";
+
+/// The number of times a failed read from the Depot is retried, unless `HAB_DEPOT_RETRIES` says
+/// otherwise.
+pub const DEFAULT_RETRIES: u32 = 4;
+
+/// The wait before the first retry, which doubles with every further retry.
+const RETRY_BACKOFF_MS: u64 = 500;
+
 pub trait DisplayProgress: Write {
     fn size(&mut self, size: u64);
 }
 
+/// A client of a Depot's HTTP API.
+///
+/// Clones of a client share its connection pool, so a client can be cloned into every thread
+/// which talks to the same Depot. Requests which only read from the Depot, including downloads,
+/// are retried with a growing backoff when the Depot cannot be reached or answers with a server
+/// error, such as a `502 Bad Gateway` from a proxy in front of it.
+#[derive(Clone)]
 pub struct Client {
     depot_url: Url,
-    client: hyper::Client,
+    client: Arc<hyper::Client>,
+    retries: u32,
 }
 
 impl Client {
@@ -61,7 +86,8 @@ impl Client {
         let url = try!(hab_depot_url.into_url());
         Ok(Client {
             depot_url: url.clone(),
-            client: try!(new_hyper_client(Some(&url), fs_root_path)),
+            client: Arc::new(try!(new_hyper_client(Some(&url), fs_root_path))),
+            retries: retries(),
         })
     }
 
@@ -79,17 +105,16 @@ impl Client {
                                                      progress: Option<&mut DisplayProgress>)
                                                      -> Result<PathBuf> {
         let url = try!(self.url_join(&format!("origins/{}/keys/{}", origin, revision)));
-        self.download(url, dst_path.as_ref(), progress)
+        self.download(url, dst_path.as_ref(), None, progress)
     }
 
     pub fn show_origin_keys(&self, origin: &str) -> Result<Vec<depotsrv::OriginKeyIdent>> {
         let url = try!(self.url_join(&format!("origins/{}/keys", origin)));
-        debug!("GET {} with {:?}", &url, &self.client);
-        let request = self.client.get(url);
-        let mut res = try!(request.send());
-        debug!("Response: {:?}", res);
+        let mut res = try!(self.get(url, Headers::new()));
 
-        if res.status != hyper::status::StatusCode::Ok {
+        if res.status.is_server_error() {
+            return Err(Error::HTTP(res.status));
+        } else if res.status != hyper::status::StatusCode::Ok {
             return Err(Error::RemoteOriginKeyNotFound(origin.to_string()));
         };
 
@@ -100,6 +125,76 @@ impl Client {
         Ok(revisions)
     }
 
+    /// Download the revocation of an origin key from a remote Depot to the given filepath.
+    ///
+    /// # Failures
+    ///
+    /// * Revocation cannot be found
+    /// * Remote Depot is not available
+    /// * File cannot be created and written to
+    pub fn fetch_origin_key_revocation<P: AsRef<Path> + ?Sized>(&self,
+                                                                origin: &str,
+                                                                revision: &str,
+                                                                dst_path: &P)
+                                                                -> Result<PathBuf> {
+        let url = try!(self.url_join(&format!("origins/{}/keys/{}/revocation",
+                                              origin,
+                                              revision)));
+        self.download(url, dst_path.as_ref(), None, None)
+    }
+
+    /// Returns the revoked key revisions of an origin. A Depot which does not publish
+    /// revocations is treated as having none.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    pub fn show_origin_key_revocations(&self,
+                                       origin: &str)
+                                       -> Result<Vec<depotsrv::OriginKeyIdent>> {
+        let url = try!(self.url_join(&format!("origins/{}/revocations", origin)));
+        let mut res = try!(self.get(url, Headers::new()));
+
+        if res.status == hyper::status::StatusCode::NotFound {
+            return Ok(vec![]);
+        }
+        if res.status != hyper::status::StatusCode::Ok {
+            return Err(Error::HTTP(res.status));
+        };
+
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Response body: {:?}", encoded);
+        let revisions: Vec<depotsrv::OriginKeyIdent> = json::decode(&encoded).unwrap();
+        Ok(revisions)
+    }
+
+    /// Upload a signed revocation of an origin key to a remote Depot.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Revocation is not signed by a known key of the origin
+    /// * Token does not belong to the owner of the origin
+    pub fn put_origin_key_revocation(&self,
+                                     origin: &str,
+                                     revision: &str,
+                                     content: &str,
+                                     token: &str)
+                                     -> Result<()> {
+        let mut headers = Headers::new();
+        headers.set(Authorization(Bearer { token: token.to_string() }));
+        let url = try!(self.url_join(&format!("origins/{}/keys/{}/revocation",
+                                              &origin,
+                                              &revision)));
+        debug!("POST {} with {:?}", &url, &self.client);
+        match self.client.post(url).headers(headers).body(content).send() {
+            Ok(Response { status: StatusCode::Created, .. }) => Ok(()),
+            Ok(Response { status: code, .. }) => Err(Error::HTTP(code)),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     /// Upload a public origin key to a remote Depot.
     ///
     /// # Failures
@@ -189,6 +284,101 @@ impl Client {
         }
     }
 
+    /// Delete an origin. Only the owner of an origin without packages or other members may delete
+    /// it.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Origin still has packages or members other than its owner
+    /// * Token does not belong to the owner of the origin
+    pub fn delete_origin(&self, origin: &str, token: &str) -> Result<()> {
+        let url = try!(self.url_join(&format!("origins/{}", origin)));
+        self.send_delete(url, token)
+    }
+
+    /// Hand ownership of an origin to one of its members. The previous owner remains a
+    /// maintainer of the origin.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * User is not a member of the origin
+    /// * Token does not belong to the owner of the origin
+    pub fn transfer_origin(&self, origin: &str, username: &str, token: &str) -> Result<()> {
+        let mut headers = Headers::new();
+        headers.set(Authorization(Bearer { token: token.to_string() }));
+        let url = try!(self.url_join(&format!("origins/{}/transfer/{}", origin, username)));
+        debug!("POST {} with {:?}", &url, &self.client);
+        match self.client.post(url).headers(headers).send() {
+            Ok(Response { status: StatusCode::Ok, .. }) => Ok(()),
+            Ok(Response { status: code, .. }) => Err(Error::HTTP(code)),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    /// Remove a member from an origin. Owners may remove any member other than themselves while
+    /// other members may only remove themselves.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * User is not a member of the origin
+    /// * Token does not grant permission to remove the member
+    pub fn remove_origin_member(&self, origin: &str, username: &str, token: &str) -> Result<()> {
+        let url = try!(self.url_join(&format!("origins/{}/users/{}", origin, username)));
+        self.send_delete(url, token)
+    }
+
+    /// Returns the pending invitations of an origin.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Token does not belong to a member of the origin
+    pub fn list_origin_invitations(&self,
+                                   origin: &str,
+                                   token: &str)
+                                   -> Result<Vec<vault::OriginInvitation>> {
+        let mut headers = Headers::new();
+        headers.set(Authorization(Bearer { token: token.to_string() }));
+        let url = try!(self.url_join(&format!("origins/{}/invitations", origin)));
+        let mut res = try!(self.get(url, headers));
+
+        if res.status != StatusCode::Ok {
+            return Err(Error::HTTP(res.status));
+        }
+
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Body: {:?}", encoded);
+        let invitations = match Json::from_str(&encoded).unwrap() {
+            Json::Object(mut body) => body.remove("invitations").unwrap_or(Json::Array(vec![])),
+            _ => Json::Array(vec![]),
+        };
+        let invitations: Vec<vault::OriginInvitation> =
+            Decodable::decode(&mut json::Decoder::new(invitations)).unwrap();
+        Ok(invitations)
+    }
+
+    /// Withdraw a pending invitation to an origin.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Invitation cannot be found
+    /// * Token belongs to neither an origin owner nor the creator of the invitation
+    pub fn rescind_origin_invitation(&self,
+                                     origin: &str,
+                                     invitation_id: u64,
+                                     token: &str)
+                                     -> Result<()> {
+        let url = try!(self.url_join(&format!("origins/{}/invitations/{}",
+                                              origin,
+                                              invitation_id)));
+        self.send_delete(url, token)
+    }
+
     /// Download the latest release of a package.
     ///
     /// An optional version and release can be specified which, when provided, will increase
@@ -196,21 +386,43 @@ impl Client {
     /// the latest release of a given version. Specifying both a version and a release will
     /// retrieve that exact package.
     ///
+    /// An authentication token is required to download packages of a private origin.
+    ///
+    /// Downloads of a fully qualified package are written to a `.part` file in `dst_path` first,
+    /// so an interrupted download is resumed from where it stopped the next time the package is
+    /// fetched. The returned archive carries the checksum computed while it was downloaded.
+    ///
     /// # Failures
     ///
     /// * Package cannot be found
     /// * Remote Depot is not available
     /// * File cannot be created and written to
+    /// * Package belongs to a private origin the token does not grant access to
     pub fn fetch_package<P: AsRef<Path> + ?Sized, I: Identifiable>(&self,
                                                   ident: I,
+                                                  token: Option<&str>,
                                                   dst_path: &P,
                                                   progress: Option<&mut DisplayProgress>)
                                                   -> Result<PackageArchive> {
         let url = try!(self.url_join(&format!("pkgs/{}/download", ident)));
-        match self.download(url, dst_path.as_ref(), progress) {
-            Ok(file) => {
-                let path = PathBuf::from(file);
-                Ok(PackageArchive::new(path))
+        let partial = if ident.fully_qualified() {
+            Some(dst_path.as_ref().join(format!("{}-{}-{}-{}.part",
+                                                ident.origin(),
+                                                ident.name(),
+                                                ident.version().unwrap(),
+                                                ident.release().unwrap())))
+        } else {
+            None
+        };
+        match self.download_resumable(url,
+                                      dst_path.as_ref(),
+                                      partial.as_ref().map(|p| p.as_path()),
+                                      token,
+                                      progress) {
+            Ok((path, checksum)) => {
+                let mut archive = PackageArchive::new(path);
+                archive.set_checksum(checksum);
+                Ok(archive)
             }
             Err(Error::HTTP(StatusCode::NotFound)) => {
                 Err(Error::RemotePackageNotFound(ident.into()))
@@ -228,14 +440,25 @@ impl Client {
     ///
     /// * Package cannot be found
     /// * Remote Depot is not available
-    pub fn show_package<I: Identifiable>(&self, ident: I) -> Result<depotsrv::Package> {
+    /// * Package belongs to a private origin the token does not grant access to
+    pub fn show_package<I: Identifiable>(&self,
+                                         ident: I,
+                                         token: Option<&str>)
+                                         -> Result<depotsrv::Package> {
         let url = try!(self.url_show_package(&ident));
-        debug!("GET {} with {:?}", &url, &self.client);
-        let request = self.client.get(url);
-        let mut res = try!(request.send());
+        let mut headers = Headers::new();
+        if let Some(token) = token {
+            headers.set(Authorization(Bearer { token: token.to_string() }));
+        }
+        let mut res = try!(self.get(url, headers));
 
-        if res.status != hyper::status::StatusCode::Ok {
-            return Err(Error::RemotePackageNotFound(ident.into()));
+        match res.status {
+            StatusCode::Ok => (),
+            StatusCode::Unauthorized | StatusCode::Forbidden => {
+                return Err(Error::HTTP(res.status))
+            }
+            status if status.is_server_error() => return Err(Error::HTTP(status)),
+            _ => return Err(Error::RemotePackageNotFound(ident.into())),
         }
 
         let mut encoded = String::new();
@@ -245,6 +468,68 @@ impl Client {
         Ok(package)
     }
 
+    /// Search a remote Depot for packages matching the given query.
+    ///
+    /// A query is a whitespace separated list of terms which may either be plain words or
+    /// field-qualified terms such as `license:mit` or `port:6379`. Results are returned a page at
+    /// a time starting from the given zero-based offset, along with the offset of the next page
+    /// if there are more matching packages. Packages of private origins are only included when
+    /// the token grants access to them.
+    ///
+    /// # Failures
+    ///
+    /// * The query was rejected by the remote Depot
+    /// * Remote Depot is not available
+    pub fn search_package(&self,
+                          query: &str,
+                          sort: Option<&str>,
+                          order: Option<&str>,
+                          offset: usize,
+                          token: Option<&str>)
+                          -> Result<(Vec<depotsrv::PackageIdent>, Option<usize>)> {
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        let mut url = try!(self.url_join(&format!("pkgs/search/{}", terms.join("+"))));
+        if let Some(sort) = sort {
+            url.query_pairs_mut().append_pair("sort", sort);
+        }
+        if let Some(order) = order {
+            url.query_pairs_mut().append_pair("order", order);
+        }
+        let mut headers = Headers::new();
+        // The Depot treats a non-zero range as one-based
+        let range = if offset > 0 { offset + 1 } else { 0 };
+        headers.set_raw("range", vec![format!("{}", range).into_bytes()]);
+        if let Some(token) = token {
+            headers.set(Authorization(Bearer { token: token.to_string() }));
+        }
+        let mut res = try!(self.get(url, headers));
+
+        match res.status {
+            StatusCode::Ok | StatusCode::PartialContent => (),
+            code => return Err(Error::HTTP(code)),
+        }
+
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Body: {:?}", encoded);
+        let packages: Vec<depotsrv::PackageIdent> = json::decode(&encoded).unwrap();
+        // A partial response means there are more packages, starting at the one-based
+        // Next-Range
+        let next = if res.status == StatusCode::PartialContent {
+            let next = res.headers
+                .get_raw("Next-Range")
+                .and_then(|raw| raw.get(0))
+                .and_then(|raw| String::from_utf8_lossy(raw).trim().parse::<usize>().ok());
+            match next {
+                Some(next) if next > offset + 1 => Some(next - 1),
+                _ => Some(offset + packages.len()),
+            }
+        } else {
+            None
+        };
+        Ok((packages, next))
+    }
+
     /// Upload a package to a remote Depot.
     ///
     /// # Failures
@@ -302,43 +587,465 @@ impl Client {
     fn download(&self,
                 url: Url,
                 dst_path: &Path,
+                token: Option<&str>,
                 progress: Option<&mut DisplayProgress>)
                 -> Result<PathBuf> {
+        self.download_resumable(url, dst_path, None, token, progress).map(|(path, _)| path)
+    }
+
+    /// Downloads a file into `dst_path`, returning its path and its BLAKE2b checksum.
+    ///
+    /// When a `partial` file is given the download is written to it before being moved into
+    /// place. If it already holds the beginning of the file from an earlier, interrupted
+    /// download, only the remaining bytes are requested with a `Range` header. This includes a
+    /// download interrupted by a transient error, which is retried from where it stopped.
+    fn download_resumable(&self,
+                          url: Url,
+                          dst_path: &Path,
+                          partial: Option<&Path>,
+                          token: Option<&str>,
+                          mut progress: Option<&mut DisplayProgress>)
+                          -> Result<(PathBuf, String)> {
+        self.with_retries(&url, || {
+            self.download_once(url.clone(),
+                               dst_path,
+                               partial,
+                               token,
+                               progress.as_mut().map(|p| &mut **p))
+        })
+    }
+
+    fn download_once(&self,
+                     url: Url,
+                     dst_path: &Path,
+                     partial: Option<&Path>,
+                     token: Option<&str>,
+                     mut progress: Option<&mut DisplayProgress>)
+                     -> Result<(PathBuf, String)> {
         debug!("GET {} with {:?}", &url, &self.client);
-        let mut res = try!(self.client.get(url).send());
+        let mut headers = Headers::new();
+        if let Some(token) = token {
+            headers.set(Authorization(Bearer { token: token.to_string() }));
+        }
+        let offset = match partial {
+            Some(partial) => fs::metadata(partial).map(|m| m.len()).unwrap_or(0),
+            None => 0,
+        };
+        if offset > 0 {
+            headers.set(Range::Bytes(vec![ByteRangeSpec::AllFrom(offset)]));
+        }
+        let mut res = try!(self.client.get(url.clone()).headers(headers).send());
         debug!("Response: {:?}", res);
 
-        if res.status != hyper::status::StatusCode::Ok {
-            return Err(Error::HTTP(res.status));
-        }
+        let resumed = match res.status {
+            StatusCode::Ok => false,
+            StatusCode::PartialContent if offset > 0 => true,
+            StatusCode::RangeNotSatisfiable if offset > 0 => {
+                // The partial file is no prefix of the remote file, so start over.
+                try!(fs::remove_file(partial.unwrap()));
+                return self.download_once(url, dst_path, partial, token, progress);
+            }
+            status => return Err(Error::HTTP(status)),
+        };
         try!(fs::create_dir_all(&dst_path));
 
         let file_name = match res.headers.get::<XFileName>() {
             Some(filename) => format!("{}", filename),
             None => return Err(Error::NoXFilename),
         };
-        let tmp_file_path = dst_path.join(format!("{}.tmp", file_name));
+        let tmp_file_path = match partial {
+            Some(partial) => partial.to_path_buf(),
+            None => dst_path.join(format!("{}.tmp", file_name)),
+        };
         let dst_file_path = dst_path.join(file_name);
-        debug!("Writing to {}", &tmp_file_path.display());
-        let mut f = try!(File::create(&tmp_file_path));
+        let mut hasher = Hasher::new();
+        if let Some(ref mut progress) = progress {
+            // The progress covers the whole file, including the bytes of an earlier download
+            let size: u64 = res.headers.get::<hyper::header::ContentLength>().map_or(0, |v| **v);
+            progress.size(if resumed { offset + size } else { size });
+        }
+        let mut f = if resumed {
+            debug!("Resuming {} at byte {}", &tmp_file_path.display(), offset);
+            let mut existing = try!(File::open(&tmp_file_path));
+            match progress {
+                Some(ref mut progress) => try!(copy_hashing(&mut existing, progress, &mut hasher)),
+                None => try!(copy_hashing(&mut existing, &mut io::sink(), &mut hasher)),
+            };
+            try!(OpenOptions::new().append(true).open(&tmp_file_path))
+        } else {
+            debug!("Writing to {}", &tmp_file_path.display());
+            try!(File::create(&tmp_file_path))
+        };
         match progress {
             Some(progress) => {
-                let size: u64 =
-                    res.headers.get::<hyper::header::ContentLength>().map_or(0, |v| **v);
-                progress.size(size);
                 let mut writer = BroadcastWriter::new(&mut f, progress);
-                try!(io::copy(&mut res, &mut writer))
+                try!(copy_hashing(&mut res, &mut writer, &mut hasher))
             }
-            None => try!(io::copy(&mut res, &mut f)),
+            None => try!(copy_hashing(&mut res, &mut f, &mut hasher)),
         };
         debug!("Moving {} to {}",
                &tmp_file_path.display(),
                &dst_file_path.display());
         try!(fs::rename(&tmp_file_path, &dst_file_path));
-        Ok(dst_file_path)
+        Ok((dst_file_path, hasher.finish()))
+    }
+
+    /// Sends a GET request, retrying it when the Depot cannot be reached or answers with a server
+    /// error. The response to the last attempt is returned, whatever its status.
+    fn get(&self, url: Url, headers: Headers) -> Result<Response> {
+        let mut attempt = 0;
+        loop {
+            debug!("GET {} with {:?}", &url, &self.client);
+            match self.client.get(url.clone()).headers(headers.clone()).send() {
+                Ok(ref res) if attempt < self.retries && res.status.is_server_error() => {
+                    backoff(&url, &res.status, attempt);
+                }
+                Err(ref e) if attempt < self.retries && is_transient_hyper(e) => {
+                    backoff(&url, e, attempt)
+                }
+                res => {
+                    let res = try!(res);
+                    debug!("Response: {:?}", res);
+                    return Ok(res);
+                }
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Runs a request until it succeeds, fails for a reason retrying won't fix, or runs out of
+    /// retries.
+    fn with_retries<T, F>(&self, url: &Url, mut request: F) -> Result<T>
+        where F: FnMut() -> Result<T>
+    {
+        let mut attempt = 0;
+        loop {
+            match request() {
+                Err(ref e) if attempt < self.retries && is_transient(e) => {
+                    backoff(url, e, attempt)
+                }
+                res => return res,
+            }
+            attempt += 1;
+        }
+    }
+
+    fn send_delete(&self, url: Url, token: &str) -> Result<()> {
+        let mut headers = Headers::new();
+        headers.set(Authorization(Bearer { token: token.to_string() }));
+        debug!("DELETE {} with {:?}", &url, &self.client);
+        match self.client.delete(url).headers(headers).send() {
+            Ok(Response { status: StatusCode::Ok, .. }) => Ok(()),
+            Ok(Response { status: code, .. }) => Err(Error::HTTP(code)),
+            Err(e) => Err(Error::from(e)),
+        }
     }
 
     fn url_join(&self, path: &str) -> Result<Url> {
         Ok(try!(self.depot_url.join(&format!("{}/{}", self.depot_url.path(), path))))
     }
 }
+
+/// Waits before the retry following the given attempt, twice as long as before the previous one.
+fn backoff(url: &Url, reason: &fmt::Display, attempt: u32) {
+    let wait = RETRY_BACKOFF_MS * 2u64.pow(attempt);
+    warn!("Retrying {} in {}ms after: {}", url, wait, reason);
+    thread::sleep(Duration::from_millis(wait));
+}
+
+/// Returns true if an error might go away when the request is sent again: the connection failed,
+/// broke off or timed out, or the Depot answered with a server error. Local failures, such as a
+/// full disk while writing a download, are never retried.
+fn is_transient(err: &Error) -> bool {
+    match *err {
+        Error::HTTP(status) => status.is_server_error(),
+        Error::HyperError(ref err) => is_transient_hyper(err),
+        Error::IO(ref err) => is_transient_io(err),
+        _ => false,
+    }
+}
+
+fn is_transient_hyper(err: &hyper::Error) -> bool {
+    match *err {
+        hyper::Error::Io(ref err) => is_transient_io(err),
+        _ => false,
+    }
+}
+
+fn is_transient_io(err: &io::Error) -> bool {
+    match err.kind() {
+        io::ErrorKind::ConnectionRefused |
+        io::ErrorKind::ConnectionReset |
+        io::ErrorKind::ConnectionAborted |
+        io::ErrorKind::NotConnected |
+        io::ErrorKind::BrokenPipe |
+        io::ErrorKind::TimedOut |
+        io::ErrorKind::WouldBlock |
+        io::ErrorKind::UnexpectedEof |
+        io::ErrorKind::Interrupted => true,
+        _ => false,
+    }
+}
+
+/// Returns the number of times to retry a failed read, from `HAB_DEPOT_RETRIES` if it holds a
+/// number.
+fn retries() -> u32 {
+    match env::var(RETRIES_ENVVAR) {
+        Ok(val) => {
+            match val.parse::<u32>() {
+                Ok(retries) => retries,
+                Err(_) => {
+                    warn!("Ignoring {}={}, which is not a number", RETRIES_ENVVAR, &val);
+                    DEFAULT_RETRIES
+                }
+            }
+        }
+        Err(_) => DEFAULT_RETRIES,
+    }
+}
+
+/// Copies everything from `reader` into `writer`, feeding the copied bytes to `hasher` as well.
+fn copy_hashing<R: Read, W: Write>(reader: &mut R,
+                                   writer: &mut W,
+                                   hasher: &mut Hasher)
+                                   -> io::Result<u64> {
+    let mut buf = [0u8; 8192];
+    let mut written = 0;
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => return Ok(written),
+            Ok(len) => len,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        try!(writer.write_all(&buf[..len]));
+        hasher.update(&buf[..len]);
+        written += len as u64;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+    use std::env;
+    use std::io::{self, BufRead, BufReader, Cursor, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::thread;
+
+    use hab_core::crypto::hash::{self, Hasher};
+    use hyper;
+    use hyper::header::Headers;
+    use hyper::status::StatusCode;
+    use hyper::Url;
+
+    use error::Error;
+    use super::{copy_hashing, is_transient, retries, Client, DEFAULT_RETRIES, RETRIES_ENVVAR};
+
+    fn client(url: &str, retries: u32) -> Client {
+        Client {
+            depot_url: Url::parse(url).unwrap(),
+            client: Arc::new(hyper::Client::new()),
+            retries: retries,
+        }
+    }
+
+    /// Answers one request per response on a local port, closing every connection after
+    /// answering, and returns the requests it answered.
+    fn serve(responses: Vec<String>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                    request.push_str(&line);
+                    line.clear();
+                }
+                requests.push(request);
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn response(status: &str, headers: &str, body: &str) -> String {
+        format!("HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                headers,
+                body.len(),
+                body)
+    }
+
+    fn statuses(statuses: &[&str]) -> Vec<String> {
+        statuses.iter().map(|status| response(status, "", "")).collect()
+    }
+
+    fn io_error(kind: io::ErrorKind) -> Error {
+        Error::IO(io::Error::new(kind, "test"))
+    }
+
+    #[test]
+    fn copy_hashing_copies_and_hashes() {
+        let data = vec![42u8; 20000];
+        let mut copy = Vec::new();
+        let mut hasher = Hasher::new();
+        let written = copy_hashing(&mut Cursor::new(data.clone()), &mut copy, &mut hasher).unwrap();
+        assert_eq!(written, 20000);
+        assert_eq!(copy, data);
+        assert_eq!(hasher.finish(), hash::hash_bytes(&data).unwrap());
+    }
+
+    #[test]
+    fn copy_hashing_continues_a_hash() {
+        let mut hasher = Hasher::new();
+        copy_hashing(&mut Cursor::new(b"resumed ".to_vec()),
+                     &mut io::sink(),
+                     &mut hasher)
+            .unwrap();
+        copy_hashing(&mut Cursor::new(b"download".to_vec()),
+                     &mut io::sink(),
+                     &mut hasher)
+            .unwrap();
+        assert_eq!(hasher.finish(), hash::hash_string("resumed download").unwrap());
+    }
+
+    #[test]
+    fn transient_errors() {
+        assert!(is_transient(&Error::HTTP(StatusCode::BadGateway)));
+        assert!(is_transient(&Error::HTTP(StatusCode::ServiceUnavailable)));
+        assert!(is_transient(&io_error(io::ErrorKind::ConnectionReset)));
+        assert!(is_transient(&io_error(io::ErrorKind::TimedOut)));
+        assert!(is_transient(&io_error(io::ErrorKind::WouldBlock)));
+        let refused = io::Error::new(io::ErrorKind::ConnectionRefused, "test");
+        assert!(is_transient(&Error::HyperError(hyper::Error::Io(refused))));
+    }
+
+    #[test]
+    fn permanent_errors() {
+        assert!(!is_transient(&Error::HTTP(StatusCode::NotFound)));
+        assert!(!is_transient(&Error::HTTP(StatusCode::Forbidden)));
+        assert!(!is_transient(&io_error(io::ErrorKind::PermissionDenied)));
+        assert!(!is_transient(&io_error(io::ErrorKind::NotFound)));
+        assert!(!is_transient(&io_error(io::ErrorKind::Other)));
+        assert!(!is_transient(&Error::HyperError(hyper::Error::TooLarge)));
+        assert!(!is_transient(&Error::NoXFilename));
+    }
+
+    #[test]
+    fn retries_from_environment() {
+        env::remove_var(RETRIES_ENVVAR);
+        assert_eq!(retries(), DEFAULT_RETRIES);
+        env::set_var(RETRIES_ENVVAR, "0");
+        assert_eq!(retries(), 0);
+        env::set_var(RETRIES_ENVVAR, "lots");
+        assert_eq!(retries(), DEFAULT_RETRIES);
+        env::remove_var(RETRIES_ENVVAR);
+    }
+
+    #[test]
+    fn with_retries_retries_transient_errors() {
+        let client = client("http://127.0.0.1", 2);
+        let url = client.depot_url.clone();
+        let attempts = Cell::new(0);
+        let res = client.with_retries(&url, || {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() < 3 {
+                Err(Error::HTTP(StatusCode::BadGateway))
+            } else {
+                Ok(attempts.get())
+            }
+        });
+        assert_eq!(res.unwrap(), 3);
+    }
+
+    #[test]
+    fn with_retries_gives_up() {
+        let client = client("http://127.0.0.1", 1);
+        let url = client.depot_url.clone();
+        let attempts = Cell::new(0);
+        let res: super::Result<()> = client.with_retries(&url, || {
+            attempts.set(attempts.get() + 1);
+            Err(io_error(io::ErrorKind::ConnectionReset))
+        });
+        assert!(res.is_err());
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[test]
+    fn with_retries_fails_fast_on_permanent_errors() {
+        let client = client("http://127.0.0.1", 4);
+        let url = client.depot_url.clone();
+        let attempts = Cell::new(0);
+        let res: super::Result<()> = client.with_retries(&url, || {
+            attempts.set(attempts.get() + 1);
+            Err(io_error(io::ErrorKind::Other))
+        });
+        assert!(res.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn get_retries_server_errors() {
+        let (url, server) = serve(statuses(&["502 Bad Gateway", "200 OK"]));
+        let client = client(&url, 1);
+        let res = client.get(Url::parse(&url).unwrap(), Headers::new()).unwrap();
+        assert_eq!(res.status, StatusCode::Ok);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn get_returns_last_server_error() {
+        let (url, server) = serve(statuses(&["503 Service Unavailable"]));
+        let client = client(&url, 0);
+        let res = client.get(Url::parse(&url).unwrap(), Headers::new()).unwrap();
+        assert_eq!(res.status, StatusCode::ServiceUnavailable);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn get_does_not_retry_client_errors() {
+        let (url, server) = serve(statuses(&["404 Not Found"]));
+        let client = client(&url, 3);
+        let res = client.get(Url::parse(&url).unwrap(), Headers::new()).unwrap();
+        assert_eq!(res.status, StatusCode::NotFound);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn search_package_pages() {
+        let page = |from: usize, to: usize| {
+            let idents: Vec<String> = (from..to)
+                .map(|i| {
+                    format!("{{\"origin\":\"core\",\"name\":\"pkg{}\",\"version\":\"1.0.0\",\
+                             \"release\":\"20161010101010\"}}",
+                            i)
+                })
+                .collect();
+            format!("[{}]", idents.join(","))
+        };
+        let (url, server) =
+            serve(vec![response("206 Partial Content",
+                                "Next-Range: 51\r\nContent-Range: 0..50\r\n",
+                                &page(0, 50)),
+                       response("200 OK", "Content-Range: 50..100\r\n", &page(50, 60))]);
+        let client = client(&format!("{}/v1/depot", url), 0);
+
+        let (packages, next) = client.search_package("pkg", None, None, 0, None).unwrap();
+        assert_eq!(packages.len(), 50);
+        assert_eq!(next, Some(50));
+        let (packages, next) = client.search_package("pkg", None, None, 50, None).unwrap();
+        assert_eq!(packages.len(), 10);
+        assert_eq!(packages[0].get_name(), "pkg50");
+        assert_eq!(next, None);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].to_lowercase().contains("range: 0\r\n"));
+        assert!(requests[1].to_lowercase().contains("range: 51\r\n"));
+    }
+}
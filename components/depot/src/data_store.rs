@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::result;
 use std::str::FromStr;
@@ -25,12 +26,21 @@ use r2d2_redis::RedisConnectionManager;
 use redis::{self, Commands, Pipeline, PipelineCommands};
 
 use error::{Error, Result};
+use search::{self, SearchField, SearchPage, SearchQuery, SearchTerm, SortField, SortOrder};
+
+/// Version of the terms indexed for search, bumped to reindex every package on the next start.
+const SEARCH_INDEX_VERSION: u32 = 1;
+/// Seconds before the temporary sets of a search expire.
+const SEARCH_TTL: usize = 60;
+/// Number of package identifiers read at once while paging search results.
+const SEARCH_BATCH: usize = 100;
 
 pub struct DataStore {
     pub pool: Arc<ConnectionPool>,
     pub packages: PackagesTable,
     pub views: ViewsTable,
     pub origin_keys: OriginKeysTable,
+    pub origin_key_revocations: OriginKeyRevocationsTable,
 }
 
 impl DataStore {
@@ -42,14 +52,17 @@ impl DataStore {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
         let pool3 = pool.clone();
+        let pool4 = pool.clone();
         let packages = PackagesTable::new(pool1);
         let views = ViewsTable::new(pool2);
         let origin_keys = OriginKeysTable::new(pool3);
+        let origin_key_revocations = OriginKeyRevocationsTable::new(pool4);
         Ok(DataStore {
             pool: pool,
             packages: packages,
             views: views,
             origin_keys: origin_keys,
+            origin_key_revocations: origin_key_revocations,
         })
     }
 
@@ -73,18 +86,165 @@ impl DataStore {
 /// Contains metadata entries for each package known by the Depot
 pub struct PackagesTable {
     pub index: PackagesIndex,
+    pub search_index: PackagesSearchIndex,
     pool: Arc<ConnectionPool>,
 }
 
 impl PackagesTable {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         let pool1 = pool.clone();
+        let pool2 = pool.clone();
         let index = PackagesIndex::new(pool1);
+        let search_index = PackagesSearchIndex::new(pool2);
         PackagesTable {
             pool: pool,
             index: index,
+            search_index: search_index,
+        }
+    }
+
+    /// Returns a page of the package identifiers matching all terms of the given query, sorted
+    /// by the query's sort field and order, along with whether more matches follow the page.
+    /// Only packages for which `visible` returns true are counted towards the page.
+    ///
+    /// Plain terms match either a prefix of any part of a package identifier or a word indexed
+    /// from the package's metadata. Field-qualified terms only match their field's index. The
+    /// terms are intersected in the data store and, when sorting by identifier, the results are
+    /// also paged there, so only the requested page is read.
+    pub fn search<F>(&self,
+                     query: &SearchQuery,
+                     offset: usize,
+                     count: usize,
+                     visible: F)
+                     -> Result<(Vec<depotsrv::PackageIdent>, bool)>
+        where F: FnMut(&depotsrv::PackageIdent) -> bool
+    {
+        let conn = self.pool().get().unwrap();
+        let seq: u64 = try!(conn.incr(PackagesSearchIndex::key("seq"), 1));
+        let result = PackagesSearchIndex::key(format!("tmp:{}", seq));
+        let mut keys = vec![];
+        let mut temp = vec![result.clone()];
+        for (i, term) in query.terms.iter().enumerate() {
+            match *term {
+                SearchTerm::Plain(ref partial) => {
+                    let key = format!("{}:{}", result, i);
+                    let idents: Vec<String> = try!(self.index.search(partial, 0, -1))
+                        .iter()
+                        .map(|ident| ident.to_string())
+                        .collect();
+                    let mut pipe = redis::pipe();
+                    pipe.cmd("SUNIONSTORE")
+                        .arg(key.as_str())
+                        .arg(PackagesSearchIndex::term_idx(SearchField::Keyword, partial))
+                        .ignore();
+                    if !idents.is_empty() {
+                        pipe.sadd(key.as_str(), idents).ignore();
+                    }
+                    pipe.expire(key.as_str(), SEARCH_TTL).ignore();
+                    try!(pipe.query::<()>(conn.deref()));
+                    keys.push(key.clone());
+                    temp.push(key);
+                }
+                SearchTerm::Field(field, ref value) => {
+                    keys.push(PackagesSearchIndex::term_idx(field, value))
+                }
+            }
+        }
+        try!(redis::pipe()
+            .cmd("SINTERSTORE")
+            .arg(result.as_str())
+            .arg(&keys[..])
+            .ignore()
+            .expire(result.as_str(), SEARCH_TTL)
+            .ignore()
+            .query::<()>(conn.deref()));
+        let page = self.search_page(&result, query, offset, count, visible);
+        // The temporary sets also expire on their own should this fail.
+        try!(conn.del::<_, ()>(temp));
+        page
+    }
+
+    fn search_page<F>(&self,
+                      result: &str,
+                      query: &SearchQuery,
+                      offset: usize,
+                      count: usize,
+                      visible: F)
+                      -> Result<(Vec<depotsrv::PackageIdent>, bool)>
+        where F: FnMut(&depotsrv::PackageIdent) -> bool
+    {
+        let conn = self.pool().get().unwrap();
+        let mut page = SearchPage::new(offset, count, visible);
+        if query.sort != SortField::Ident {
+            let ids: Vec<String> = try!(conn.smembers(result));
+            let mut idents: Vec<depotsrv::PackageIdent> =
+                ids.iter().map(|id| to_ident(id)).collect();
+            query.sort(&mut idents);
+            for ident in idents.into_iter() {
+                if page.push(ident) {
+                    return Ok((page.idents, true));
+                }
+            }
+            return Ok((page.idents, false));
+        }
+        let mut start = 0;
+        loop {
+            let mut cmd = redis::cmd("SORT");
+            cmd.arg(result).arg("ALPHA");
+            if query.order == SortOrder::Desc {
+                cmd.arg("DESC");
+            }
+            let ids: Vec<String> =
+                try!(cmd.arg("LIMIT").arg(start).arg(SEARCH_BATCH).query(conn.deref()));
+            start += ids.len();
+            for ident in ids.iter().map(|id| to_ident(id)) {
+                if page.push(ident) {
+                    return Ok((page.idents, true));
+                }
+            }
+            if ids.len() < SEARCH_BATCH {
+                return Ok((page.idents, false));
+            }
         }
     }
+
+    /// Adds every package to the search index unless the index is already up to date, and
+    /// returns the number of packages indexed. Packages uploaded before the search index
+    /// existed are only searchable by identifier until this has run.
+    pub fn backfill_search_index(&self) -> Result<usize> {
+        let conn = self.pool().get().unwrap();
+        let version: Option<u32> = try!(conn.get(PackagesSearchIndex::version_key()));
+        if version == Some(SEARCH_INDEX_VERSION) {
+            return Ok(0);
+        }
+        let idents = try!(self.index.all());
+        for ident in idents.iter() {
+            let record = try!(self.find(ident));
+            let mut pipe = redis::pipe();
+            PackagesSearchIndex::write(&mut pipe, &record);
+            try!(pipe.query::<()>(conn.deref()));
+        }
+        try!(conn.set::<_, _, ()>(PackagesSearchIndex::version_key(), SEARCH_INDEX_VERSION));
+        Ok(idents.len())
+    }
+}
+
+impl PackagesTable {
+    /// Removes a package's metadata and every index entry pointing to it.
+    pub fn delete(&self, record: &depotsrv::Package) -> Result<()> {
+        let conn = self.pool().get().unwrap();
+        let keys = [Self::key(record),
+                    PackagesIndex::origin_idx(&record),
+                    PackagesIndex::name_idx(&record),
+                    PackagesIndex::version_idx(&record)];
+        try!(redis::transaction(conn.deref(), &keys, |mut txn| {
+            txn.del(Self::key(&record)).ignore();
+            PackagesIndex::delete(&mut txn, &record);
+            PackagesSearchIndex::delete(&mut txn, &record);
+            txn.query(conn.deref())
+        }));
+        Ok(())
+    }
 }
 
 impl Bucket for PackagesTable {
@@ -110,6 +270,7 @@ impl BasicSet for PackagesTable {
             let body = record.write_to_bytes().unwrap();
             txn.set(Self::key(&record), body).ignore();
             PackagesIndex::write(&mut txn, &record);
+            PackagesSearchIndex::write(&mut txn, &record);
             txn.query(conn.deref())
         }));
         Ok(())
@@ -127,6 +288,26 @@ impl PackagesIndex {
         PackagesIndex { pool: pool }
     }
 
+    /// Returns the identifier of every package known to the index.
+    pub fn all(&self) -> Result<Vec<depotsrv::PackageIdent>> {
+        let conn = self.pool().get().unwrap();
+        match conn.zrange::<&'static str, Vec<String>>(Self::prefix(), 0, -1) {
+            Ok(ids) => {
+                let ids: HashSet<&str> = ids.iter()
+                    .map(|i| i.split(":").last().unwrap())
+                    .collect();
+                let ids = ids.iter()
+                    .map(|id| {
+                        let p = package::PackageIdent::from_str(id).unwrap();
+                        depotsrv::PackageIdent::from(p)
+                    })
+                    .collect();
+                Ok(ids)
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     pub fn count(&self, id: &str) -> Result<u64> {
         let conn = self.pool().get().unwrap();
         let val = try!(conn.zcount(Self::key(&id.to_string()), 0, 0));
@@ -238,6 +419,23 @@ impl PackagesIndex {
             .ignore();
     }
 
+    pub fn delete(pipe: &mut Pipeline, record: &depotsrv::Package) {
+        pipe.zrem(Self::origin_idx(record), record.to_string())
+            .ignore()
+            .zrem(Self::name_idx(record), record.to_string())
+            .ignore()
+            .zrem(Self::version_idx(record), record.to_string())
+            .ignore();
+        let ident = record.get_ident();
+        let parts = [ident.get_origin(),
+                     ident.get_name(),
+                     ident.get_release(),
+                     ident.get_version()];
+        for part in parts.iter() {
+            pipe.zrem(Self::prefix(), format!("{}:{}", part, record.to_string())).ignore();
+        }
+    }
+
     fn origin_idx(package: &depotsrv::Package) -> String {
         Self::key(package.get_ident().get_origin())
     }
@@ -266,6 +464,58 @@ impl Bucket for PackagesIndex {
     }
 }
 
+/// Contains an index of the terms found in each package's metadata, such as the words of its
+/// description or its licenses and exposed ports, to the identifiers of the packages containing
+/// them.
+pub struct PackagesSearchIndex {
+    pool: Arc<ConnectionPool>,
+}
+
+impl PackagesSearchIndex {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        PackagesSearchIndex { pool: pool }
+    }
+
+    /// Returns the identifiers of all packages which had the given term indexed for a field.
+    pub fn find(&self, field: SearchField, term: &str) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        match conn.smembers(Self::term_idx(field, term)) {
+            Ok(ids) => Ok(ids),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    pub fn write(pipe: &mut Pipeline, record: &depotsrv::Package) {
+        for (field, term) in search::index_terms(record) {
+            pipe.sadd(Self::term_idx(field, &term), record.to_string()).ignore();
+        }
+    }
+
+    pub fn delete(pipe: &mut Pipeline, record: &depotsrv::Package) {
+        for (field, term) in search::index_terms(record) {
+            pipe.srem(Self::term_idx(field, &term), record.to_string()).ignore();
+        }
+    }
+
+    fn term_idx(field: SearchField, term: &str) -> String {
+        Self::key(format!("{}:{}", field, term))
+    }
+
+    fn version_key() -> String {
+        Self::key("version")
+    }
+}
+
+impl Bucket for PackagesSearchIndex {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "package:search:index"
+    }
+}
+
 /// Contains a mapping of view names and the packages found within that view.
 ///
 /// This is how packages will be "promoted" between environments without duplicating data on disk.
@@ -347,6 +597,15 @@ impl PkgViewIndex {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         PkgViewIndex { pool: pool }
     }
+
+    /// Returns the names of every view the given package has been promoted to.
+    pub fn views<T: Identifiable>(&self, pkg: &T) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        match conn.smembers(Self::key(&pkg.to_string())) {
+            Ok(views) => Ok(views),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
 }
 
 impl Bucket for PkgViewIndex {
@@ -433,6 +692,90 @@ impl OriginKeysTable {
         OriginKeysTable { pool: pool }
     }
 
+    /// Returns the revisions of an origin's keys which have not been revoked.
+    pub fn all(&self, origin: &str) -> Result<Vec<depotsrv::OriginKeyIdent>> {
+        let ids = try!(self.active_revisions(origin))
+            .iter()
+            .map(|rev| {
+                let mut ident = depotsrv::OriginKeyIdent::new();
+                ident.set_location(format!("/origins/{}/keys/{}", &origin, &rev));
+                ident.set_origin(origin.to_string());
+                ident.set_revision(rev.to_string());
+                ident
+            })
+            .collect();
+        Ok(ids)
+    }
+
+    pub fn write(&self, origin: &str, revision: &str) -> Result<()> {
+        let conn = self.pool().get().unwrap();
+        try!(conn.sadd(OriginKeysTable::key(&origin.to_string()), revision));
+        Ok(())
+    }
+
+    /// Returns every revision of an origin's keys, including the revoked ones.
+    pub fn revisions(&self, origin: &str) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        match conn.smembers(Self::key(&origin.to_string())) {
+            Ok(revisions) => Ok(revisions),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    /// Forgets every key revision of an origin.
+    pub fn delete(&self, origin: &str) -> Result<()> {
+        let conn = self.pool().get().unwrap();
+        try!(conn.del::<_, ()>(Self::key(&origin.to_string())));
+        Ok(())
+    }
+
+    /// return the latest revision for a given origin key which has not been revoked
+    pub fn latest(&self, origin: &str) -> Result<String> {
+        let mut revisions = try!(self.active_revisions(origin));
+        revisions.sort();
+        match revisions.pop() {
+            Some(revision) => Ok(revision),
+            None => Err(Error::DataStore(dbcache::Error::EntityNotFound)),
+        }
+    }
+
+    fn active_revisions(&self, origin: &str) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        match redis::cmd("SDIFF")
+            .arg(Self::key(&origin.to_string()))
+            .arg(OriginKeyRevocationsTable::key(&origin.to_string()))
+            .query::<Vec<String>>(conn.deref()) {
+            Ok(revisions) => Ok(revisions),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+}
+
+impl Bucket for OriginKeysTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "origin_keys"
+    }
+}
+
+impl IndexSet for OriginKeysTable {
+    type Key = String;
+    type Value = String;
+}
+
+/// Contains the revisions of each origin's keys which have been revoked
+pub struct OriginKeyRevocationsTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl OriginKeyRevocationsTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        OriginKeyRevocationsTable { pool: pool }
+    }
+
     pub fn all(&self, origin: &str) -> Result<Vec<depotsrv::OriginKeyIdent>> {
         let conn = self.pool().get().unwrap();
         match conn.smembers::<String, Vec<String>>(Self::key(&origin.to_string())) {
@@ -440,7 +783,9 @@ impl OriginKeysTable {
                 let ids = ids.iter()
                     .map(|rev| {
                         let mut ident = depotsrv::OriginKeyIdent::new();
-                        ident.set_location(format!("/origins/{}/keys/{}", &origin, &rev));
+                        ident.set_location(format!("/origins/{}/keys/{}/revocation",
+                                                   &origin,
+                                                   &rev));
                         ident.set_origin(origin.to_string());
                         ident.set_revision(rev.to_string());
                         ident
@@ -454,45 +799,34 @@ impl OriginKeysTable {
 
     pub fn write(&self, origin: &str, revision: &str) -> Result<()> {
         let conn = self.pool().get().unwrap();
-        try!(conn.sadd(OriginKeysTable::key(&origin.to_string()), revision));
+        try!(conn.sadd(OriginKeyRevocationsTable::key(&origin.to_string()), revision));
         Ok(())
     }
 
-    /// return the latest revision for a given origin key
-    pub fn latest(&self, origin: &str) -> Result<String> {
+    /// Forgets every key revocation of an origin.
+    pub fn delete(&self, origin: &str) -> Result<()> {
         let conn = self.pool().get().unwrap();
-        let key = OriginKeysTable::key(&origin.to_string());
-
-        match redis::cmd("SORT")
-            .arg(key)
-            .arg("LIMIT")
-            .arg(0)
-            .arg(1)
-            .arg("ALPHA")
-            .arg("DESC")
-            .query::<Vec<String>>(conn.deref()) {
-            Ok(ids) => {
-                if ids.is_empty() {
-                    return Err(Error::DataStore(dbcache::Error::EntityNotFound));
-                }
-                Ok(ids[0].to_string())
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+        try!(conn.del::<_, ()>(Self::key(&origin.to_string())));
+        Ok(())
     }
 }
 
-impl Bucket for OriginKeysTable {
+impl Bucket for OriginKeyRevocationsTable {
     fn pool(&self) -> &ConnectionPool {
         &self.pool
     }
 
     fn prefix() -> &'static str {
-        "origin_keys"
+        "origin_key_revocations"
     }
 }
 
-impl IndexSet for OriginKeysTable {
+impl IndexSet for OriginKeyRevocationsTable {
     type Key = String;
     type Value = String;
 }
+
+fn to_ident(id: &str) -> depotsrv::PackageIdent {
+    let p = package::PackageIdent::from_str(id).unwrap();
+    depotsrv::PackageIdent::from(p)
+}
@@ -23,17 +23,21 @@ use hab_core;
 use hab_core::package::{self, Identifiable};
 use hab_net;
 use hyper;
+use protocol::net::NetError;
 use redis;
 
 #[derive(Debug)]
 pub enum Error {
+    BadKeepCount(String),
     BadPort(String),
     DataStore(dbcache::Error),
     HabitatCore(hab_core::Error),
     HabitatNet(hab_net::Error),
     HTTP(hyper::status::StatusCode),
     InvalidPackageIdent(String),
+    InvalidSearchQuery(String),
     IO(io::Error),
+    NetError(NetError),
     NoXFilename,
     NoFilePart,
     NulError(ffi::NulError),
@@ -46,6 +50,9 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::BadKeepCount(ref e) => {
+                format!("{} is an invalid number of releases to keep.", e)
+            }
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::DataStore(ref e) => format!("DataStore error, {}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
@@ -56,7 +63,9 @@ impl fmt::Display for Error {
                          origin/name (example: acme/redis)",
                         e)
             }
+            Error::InvalidSearchQuery(ref e) => format!("Invalid search query: {}", e),
             Error::IO(ref e) => format!("{}", e),
+            Error::NetError(ref e) => format!("{:?}", e),
             Error::NoXFilename => {
                 format!("Invalid download from a Depot - missing X-Filename header")
             }
@@ -83,6 +92,7 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::BadKeepCount(_) => "Received an invalid number of releases to keep.",
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::DataStore(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
@@ -91,7 +101,11 @@ impl error::Error for Error {
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
             }
+            Error::InvalidSearchQuery(_) => {
+                "Search terms must be plain words or in field:value format (example: license:mit)"
+            }
             Error::IO(ref err) => err.description(),
+            Error::NetError(_) => "Received an error from a Habitat service",
             Error::NulError(_) => {
                 "An attempt was made to build a CString with a null byte inside it"
             }
@@ -0,0 +1,459 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+use dbcache::BasicSet;
+use protocol::depotsrv;
+use time;
+
+use super::Depot;
+use error::{Error, Result};
+
+/// Default number of releases kept for each version of a package.
+pub const DEFAULT_KEEP_RELEASES: usize = 5;
+
+/// Rules deciding which package releases are kept by a garbage collection run.
+///
+/// Regardless of policy, a release is never collected if it has been promoted to a view or if it
+/// is a transitive dependency of any release which is being kept.
+#[derive(Debug)]
+pub struct Policy {
+    /// Number of the most recent releases to keep for each version of a package.
+    pub keep_releases: usize,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Policy { keep_releases: DEFAULT_KEEP_RELEASES }
+    }
+}
+
+#[derive(Debug)]
+/// A struct containing the details of a garbage collection run.
+pub struct Report {
+    /// Start time in nanoseconds since epoch.
+    pub start: u64,
+    /// Finish time in nanoseconds since epoch.
+    pub finish: u64,
+    /// True if the report contained no errors and false otherwise.
+    pub success: bool,
+    /// True if no operations were performed and the report only describes what would be done.
+    pub dry_run: bool,
+    /// A complete list of operations in the order in which they were planned or performed.
+    pub operations: Vec<Operation>,
+}
+
+impl Report {
+    /// Duration in nanoseconds that the collection took to run.
+    pub fn duration(&self) -> u64 {
+        (self.finish - self.start)
+    }
+
+    /// Number of packages which were, or would be, deleted.
+    pub fn deleted(&self) -> usize {
+        self.operations
+            .iter()
+            .filter(|op| match **op {
+                Operation::Planned(OperationType::PackageDelete(_)) |
+                Operation::Success(OperationType::PackageDelete(_)) => true,
+                _ => false,
+            })
+            .count()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for op in self.operations.iter() {
+            try!(writeln!(f, "{}", op));
+        }
+        let verb = if self.dry_run { "Would delete" } else { "Deleted" };
+        write!(f,
+               "{} {} package(s) in {}ms",
+               verb,
+               self.deleted(),
+               self.duration() / 1_000_000)
+    }
+}
+
+struct ReportBuilder {
+    pub operations: Vec<Operation>,
+    pub start: u64,
+    pub dry_run: bool,
+}
+
+impl ReportBuilder {
+    pub fn new(dry_run: bool) -> Self {
+        ReportBuilder {
+            operations: vec![],
+            start: time::precise_time_ns(),
+            dry_run: dry_run,
+        }
+    }
+
+    /// Record an operation which would be performed outside of a dry run.
+    pub fn planned(&mut self, operation: OperationType) -> &mut Self {
+        self.add(Operation::Planned(operation));
+        self
+    }
+
+    /// Record a successful operation.
+    pub fn success(&mut self, operation: OperationType) -> &mut Self {
+        self.add(Operation::Success(operation));
+        self
+    }
+
+    /// Record a failed operation.
+    pub fn failure(&mut self, operation: OperationType, reason: Reason) -> &mut Self {
+        self.add(Operation::Failure(operation, reason));
+        self
+    }
+
+    /// Consumes the report builder and returns a completed report.
+    pub fn generate(self) -> Report {
+        let time = time::precise_time_ns();
+        Report {
+            start: self.start,
+            finish: time,
+            success: self.operations.iter().all(Self::check_success),
+            dry_run: self.dry_run,
+            operations: self.operations,
+        }
+    }
+
+    fn add(&mut self, operation: Operation) {
+        self.operations.push(operation);
+    }
+
+    fn check_success(op: &Operation) -> bool {
+        match *op {
+            Operation::Planned(_) |
+            Operation::Success(_) => true,
+            Operation::Failure(_, _) => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum OperationType {
+    /// Record of a package release being kept. Contains the package identifier and the rule
+    /// which kept it.
+    PackageRetain(String, Retention),
+    /// Record of a package release's archive and metadata being removed from the Depot. Contains
+    /// the package identifier.
+    PackageDelete(String),
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OperationType::PackageRetain(ref ident, ref why) => {
+                write!(f, "keep {} ({})", ident, why)
+            }
+            OperationType::PackageDelete(ref ident) => write!(f, "delete {}", ident),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Retention {
+    /// The release is one of the most recent releases of its version.
+    Recent,
+    /// The release has been promoted to the named views.
+    View(Vec<String>),
+    /// The release is a transitive dependency of the given kept package.
+    TDep(String),
+}
+
+impl fmt::Display for Retention {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Retention::Recent => write!(f, "recent release"),
+            Retention::View(ref views) => write!(f, "in view {}", views.join(", ")),
+            Retention::TDep(ref ident) => write!(f, "dependency of {}", ident),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Reason {
+    DataStore(Error),
+    IO(io::Error),
+}
+
+#[derive(Debug)]
+pub enum Operation {
+    Planned(OperationType),
+    Success(OperationType),
+    Failure(OperationType, Reason),
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Operation::Planned(ref op) => write!(f, "[plan] {}", op),
+            Operation::Success(ref op) => write!(f, "[ok]   {}", op),
+            Operation::Failure(ref op, ref reason) => write!(f, "[fail] {}: {:?}", op, reason),
+        }
+    }
+}
+
+/// The result of applying a `Policy` to the contents of a Depot.
+pub struct Plan {
+    /// Package releases which will be kept and why.
+    pub retain: Vec<(depotsrv::PackageIdent, Retention)>,
+    /// Package releases which will be deleted.
+    pub delete: Vec<depotsrv::Package>,
+}
+
+impl Plan {
+    /// Generates a report of the operations this plan would perform without performing them.
+    pub fn report(&self) -> Report {
+        let mut report = ReportBuilder::new(true);
+        self.report_retained(&mut report);
+        for pkg in self.delete.iter() {
+            report.planned(OperationType::PackageDelete(pkg.get_ident().to_string()));
+        }
+        report.generate()
+    }
+
+    fn report_retained(&self, report: &mut ReportBuilder) {
+        for &(ref ident, ref why) in self.retain.iter() {
+            match *why {
+                Retention::Recent => (),
+                Retention::View(ref views) => {
+                    report.planned(OperationType::PackageRetain(ident.to_string(),
+                                                                Retention::View(views.clone())));
+                }
+                Retention::TDep(ref dep) => {
+                    report.planned(OperationType::PackageRetain(ident.to_string(),
+                                                                Retention::TDep(dep.clone())));
+                }
+            }
+        }
+    }
+}
+
+/// Applies the given policy to every package in the Depot and returns a plan of which releases
+/// would be kept and which would be deleted.
+///
+/// # Failures
+///
+/// * The datastore cannot be read
+pub fn plan(depot: &Depot, policy: &Policy) -> Result<Plan> {
+    if policy.keep_releases < 1 {
+        return Err(Error::BadKeepCount(policy.keep_releases.to_string()));
+    }
+    let (recent, superseded) = partition_releases(try!(depot.datastore.packages.index.all()),
+                                                  policy.keep_releases);
+    let mut retain: Vec<(depotsrv::PackageIdent, Retention)> =
+        recent.into_iter().map(|ident| (ident, Retention::Recent)).collect();
+    let mut candidates = vec![];
+    for ident in superseded.into_iter() {
+        let views = try!(depot.datastore.views.pkg_view_idx.views(&ident));
+        if !views.is_empty() {
+            retain.push((ident, Retention::View(views)));
+            continue;
+        }
+        candidates.push(ident);
+    }
+
+    // Transitive dependencies are already fully expanded for each package so a single pass over
+    // the kept releases is enough to protect everything they depend on.
+    let mut protected: HashMap<String, String> = HashMap::new();
+    for &(ref ident, _) in retain.iter() {
+        let pkg = try!(depot.datastore.packages.find(ident));
+        for dep in pkg.get_tdeps().iter() {
+            protected.entry(dep.to_string()).or_insert(ident.to_string());
+        }
+    }
+
+    let (dependencies, unused) = protect(candidates, &protected);
+    retain.extend(dependencies);
+    let mut delete = vec![];
+    for ident in unused.into_iter() {
+        delete.push(try!(depot.datastore.packages.find(&ident)));
+    }
+    retain.sort_by(|a, b| a.0.to_string().cmp(&b.0.to_string()));
+    delete.sort_by(|a, b| a.get_ident().to_string().cmp(&b.get_ident().to_string()));
+    Ok(Plan {
+        retain: retain,
+        delete: delete,
+    })
+}
+
+/// Splits package releases into the `keep` most recent releases of each package version and
+/// the older releases they supersede.
+fn partition_releases(idents: Vec<depotsrv::PackageIdent>,
+                      keep: usize)
+                      -> (Vec<depotsrv::PackageIdent>, Vec<depotsrv::PackageIdent>) {
+    let mut versions: HashMap<String, Vec<depotsrv::PackageIdent>> = HashMap::new();
+    for ident in idents.into_iter() {
+        let key = format!("{}/{}/{}",
+                          ident.get_origin(),
+                          ident.get_name(),
+                          ident.get_version());
+        versions.entry(key).or_insert(vec![]).push(ident);
+    }
+    let mut recent = vec![];
+    let mut superseded = vec![];
+    for (_, mut releases) in versions.into_iter() {
+        releases.sort_by(|a, b| b.get_release().cmp(a.get_release()));
+        for (i, ident) in releases.into_iter().enumerate() {
+            if i < keep {
+                recent.push(ident);
+            } else {
+                superseded.push(ident);
+            }
+        }
+    }
+    (recent, superseded)
+}
+
+/// Splits candidates for deletion into the releases kept as a dependency of a kept release,
+/// given a map of protected releases to one of their dependents, and those nothing depends on.
+fn protect(candidates: Vec<depotsrv::PackageIdent>,
+           protected: &HashMap<String, String>)
+           -> (Vec<(depotsrv::PackageIdent, Retention)>, Vec<depotsrv::PackageIdent>) {
+    let mut retain = vec![];
+    let mut unused = vec![];
+    for ident in candidates.into_iter() {
+        match protected.get(&ident.to_string()) {
+            Some(dependent) => retain.push((ident, Retention::TDep(dependent.clone()))),
+            None => unused.push(ident),
+        }
+    }
+    (retain, unused)
+}
+
+/// Deletes every package release marked for deletion in the given plan, removing both its
+/// archive from disk and its metadata and index entries from the datastore, and returns a report
+/// of the results.
+///
+/// Metadata is removed before the archive so a failure part way through never leaves the Depot
+/// serving a package it can no longer download.
+pub fn run(depot: &Depot, plan: Plan) -> Report {
+    let mut report = ReportBuilder::new(false);
+    plan.report_retained(&mut report);
+    for pkg in plan.delete.iter() {
+        let ident = pkg.get_ident().to_string();
+        if let Err(e) = depot.datastore.packages.delete(pkg) {
+            report.failure(OperationType::PackageDelete(ident), Reason::DataStore(e));
+            continue;
+        }
+        match fs::remove_file(depot.archive_path(pkg.get_ident())) {
+            Ok(_) => {
+                report.success(OperationType::PackageDelete(ident));
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                report.success(OperationType::PackageDelete(ident));
+            }
+            Err(e) => {
+                report.failure(OperationType::PackageDelete(ident), Reason::IO(e));
+            }
+        }
+    }
+    report.generate()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use hab_core::package::PackageIdent;
+    use protocol::depotsrv;
+
+    use super::{partition_releases, protect, OperationType, Operation, Plan, Retention};
+
+    fn ident(ident: &str) -> depotsrv::PackageIdent {
+        depotsrv::PackageIdent::from(PackageIdent::from_str(ident).unwrap())
+    }
+
+    fn sorted(idents: Vec<depotsrv::PackageIdent>) -> Vec<String> {
+        let mut idents: Vec<String> = idents.iter().map(|i| i.to_string()).collect();
+        idents.sort();
+        idents
+    }
+
+    #[test]
+    fn keeps_most_recent_releases_of_each_version() {
+        let idents = vec![ident("core/redis/3.0.7/20160101000000"),
+                          ident("core/redis/3.0.7/20160301000000"),
+                          ident("core/redis/3.0.7/20160201000000"),
+                          ident("core/redis/3.2.1/20160101000000"),
+                          ident("core/nginx/1.10.1/20160101000000")];
+        let (recent, superseded) = partition_releases(idents, 2);
+        assert_eq!(sorted(recent),
+                   vec!["core/nginx/1.10.1/20160101000000",
+                        "core/redis/3.0.7/20160201000000",
+                        "core/redis/3.0.7/20160301000000",
+                        "core/redis/3.2.1/20160101000000"]);
+        assert_eq!(sorted(superseded), vec!["core/redis/3.0.7/20160101000000"]);
+    }
+
+    #[test]
+    fn always_keeps_latest_release() {
+        let idents = vec![ident("core/redis/3.0.7/20160101000000"),
+                          ident("core/redis/3.0.7/20160301000000")];
+        let (recent, superseded) = partition_releases(idents, 1);
+        assert_eq!(sorted(recent), vec!["core/redis/3.0.7/20160301000000"]);
+        assert_eq!(sorted(superseded), vec!["core/redis/3.0.7/20160101000000"]);
+    }
+
+    #[test]
+    fn keeps_dependencies_of_kept_releases() {
+        let mut protected = HashMap::new();
+        protected.insert("core/glibc/2.22/20160101000000".to_string(),
+                         "core/redis/3.0.7/20160301000000".to_string());
+        let (retain, unused) = protect(vec![ident("core/glibc/2.22/20160101000000"),
+                                            ident("core/glibc/2.22/20150101000000")],
+                                       &protected);
+        assert_eq!(retain.len(), 1);
+        assert_eq!(retain[0].0.to_string(), "core/glibc/2.22/20160101000000");
+        match retain[0].1 {
+            Retention::TDep(ref dependent) => {
+                assert_eq!(dependent, "core/redis/3.0.7/20160301000000")
+            }
+            ref other => panic!("unexpected retention {:?}", other),
+        }
+        assert_eq!(sorted(unused), vec!["core/glibc/2.22/20150101000000"]);
+    }
+
+    #[test]
+    fn plan_report_lists_deletions_and_notable_retentions() {
+        let mut pkg = depotsrv::Package::new();
+        pkg.set_ident(ident("core/redis/3.0.7/20160101000000"));
+        let plan = Plan {
+            retain: vec![(ident("core/redis/3.0.7/20160301000000"), Retention::Recent),
+                         (ident("core/redis/3.0.7/20160201000000"),
+                          Retention::View(vec!["stable".to_string()]))],
+            delete: vec![pkg],
+        };
+        let report = plan.report();
+        assert!(report.dry_run);
+        assert_eq!(report.deleted(), 1);
+        assert_eq!(report.operations.len(), 2);
+        match report.operations[0] {
+            Operation::Planned(OperationType::PackageRetain(ref ident, _)) => {
+                assert_eq!(ident, "core/redis/3.0.7/20160201000000")
+            }
+            ref other => panic!("unexpected operation {:?}", other),
+        }
+    }
+}
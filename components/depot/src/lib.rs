@@ -47,6 +47,8 @@ pub mod config;
 pub mod error;
 pub mod data_store;
 pub mod doctor;
+pub mod gc;
+pub mod search;
 pub mod server;
 
 pub use self::config::Config;
@@ -121,6 +123,18 @@ impl Depot {
             .join(format!("{}-{}.pub", key, rev))
     }
 
+    fn revocation_path(&self, key: &str, rev: &str) -> PathBuf {
+        let mut digest = Sha256::new();
+        let mut output = [0; 64];
+        let key_with_rev = format!("{}-{}.pub", key, rev);
+        digest.input_str(&key_with_rev.to_string());
+        digest.result(&mut output);
+        self.keys_path()
+            .join(format!("{:x}", output[0]))
+            .join(format!("{:x}", output[1]))
+            .join(format!("{}-{}.revoked", key, rev))
+    }
+
     fn keys_path(&self) -> PathBuf {
         Path::new(&self.config.path).join("keys")
     }
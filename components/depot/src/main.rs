@@ -65,6 +65,13 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
             (@arg port: --port +takes_value "Listen port. [default: 9632]")
             (@arg insecure: --insecure)
         )
+        (@subcommand gc =>
+            (about: "Delete superseded package releases from the package Depot")
+            (@arg keep: --keep +takes_value
+                "Number of releases to keep for each package version, at least 1. [default: 5]")
+            (@arg dry_run: --("dry-run")
+                "Report what would be deleted without deleting anything")
+        )
         (@subcommand repair =>
             (about: "Verify and repair data integrity of the package Depot")
         )
@@ -111,6 +118,17 @@ fn config_from_args(matches: &clap::ArgMatches) -> Result<Config> {
 fn dispatch(config: Config, matches: &clap::ArgMatches) -> Result<()> {
     match matches.subcommand_name() {
         Some("start") => start(config),
+        Some(cmd @ "gc") => {
+            let args = matches.subcommand_matches(cmd).unwrap();
+            let mut policy = depot::gc::Policy::default();
+            if let Some(keep) = args.value_of("keep") {
+                match usize::from_str(keep) {
+                    Ok(keep) if keep >= 1 => policy.keep_releases = keep,
+                    _ => return Err(Error::BadKeepCount(keep.to_string())),
+                }
+            }
+            gc(config, policy, args.is_present("dry_run"))
+        }
         Some("repair") => repair(config),
         Some(cmd @ "view") => {
             let args = matches.subcommand_matches(cmd).unwrap();
@@ -147,6 +165,30 @@ fn start(config: Config) -> Result<()> {
     server::run(config)
 }
 
+/// Deletes package releases superseded according to the given policy. A dry-run report of
+/// every release which will be deleted, and every release kept only because it is in a view or
+/// depended on, is always printed before anything is removed.
+///
+/// # Failures
+///
+/// * The database cannot be read
+/// * A write transaction cannot be acquired
+fn gc(config: Config, policy: depot::gc::Policy, dry_run: bool) -> Result<()> {
+    let ctx = Arc::new(Box::new(ServerContext::new()));
+    let depot = try!(depot::Depot::new(config, ctx));
+    let plan = try!(depot::gc::plan(&depot, &policy));
+    println!("{}", plan.report());
+    if dry_run {
+        return Ok(());
+    }
+    let report = depot::gc::run(&depot, plan);
+    println!("{}", &report);
+    if !report.success {
+        println!("Garbage collection completed with errors, see report above");
+    }
+    Ok(())
+}
+
 /// Analyzes the integrity of the depot's metadata by comparing the metadata with the packages
 /// on disk. If a package is found on disk that is not present in the metadata it is added to the
 /// metadata and if an entry in the metadata doesn't have a matching package archive on disk the
@@ -0,0 +1,411 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Query parsing and term extraction for package search.
+//!
+//! A search query is a whitespace separated list of terms. A plain term (`redis`) matches against
+//! the parts of a package identifier as an "auto-complete" prefix and against any word found in
+//! the package's indexed metadata. A field-qualified term (`license:mit`, `port:6379`) matches
+//! only the named field. All terms must match for a package to be returned.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use hab_core::package::{PackageManifest, PackageProvenance};
+use hab_core::package::ident::version_sort;
+use protocol::depotsrv;
+
+use error::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SearchField {
+    Keyword,
+    Description,
+    License,
+    Maintainer,
+    Port,
+    Upstream,
+    Job,
+    Source,
+}
+
+impl fmt::Display for SearchField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let field = match *self {
+            SearchField::Keyword => "keyword",
+            SearchField::Description => "description",
+            SearchField::License => "license",
+            SearchField::Maintainer => "maintainer",
+            SearchField::Port => "port",
+            SearchField::Upstream => "upstream",
+            SearchField::Job => "job",
+            SearchField::Source => "source",
+        };
+        write!(f, "{}", field)
+    }
+}
+
+impl FromStr for SearchField {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_ref() {
+            "description" => Ok(SearchField::Description),
+            "license" => Ok(SearchField::License),
+            "maintainer" => Ok(SearchField::Maintainer),
+            "port" => Ok(SearchField::Port),
+            "upstream" => Ok(SearchField::Upstream),
+            "job" => Ok(SearchField::Job),
+            "source" => Ok(SearchField::Source),
+            _ => Err(Error::InvalidSearchQuery(format!("unknown field '{}'", value))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortField {
+    Ident,
+    Origin,
+    Name,
+    Version,
+    Release,
+}
+
+impl Default for SortField {
+    fn default() -> SortField {
+        SortField::Ident
+    }
+}
+
+impl FromStr for SortField {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_ref() {
+            "ident" => Ok(SortField::Ident),
+            "origin" => Ok(SortField::Origin),
+            "name" => Ok(SortField::Name),
+            "version" => Ok(SortField::Version),
+            "release" => Ok(SortField::Release),
+            _ => Err(Error::InvalidSearchQuery(format!("unknown sort field '{}'", value))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Asc
+    }
+}
+
+impl FromStr for SortOrder {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_ref() {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(Error::InvalidSearchQuery(format!("unknown sort order '{}'", value))),
+        }
+    }
+}
+
+/// A single term of a search query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchTerm {
+    /// A plain term matching an ident prefix or any indexed keyword.
+    Plain(String),
+    /// A term which must match the given field exactly.
+    Field(SearchField, String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+    pub sort: SortField,
+    pub order: SortOrder,
+}
+
+impl SearchQuery {
+    /// Sorts a list of package identifiers by the query's sort field and order.
+    pub fn sort(&self, idents: &mut Vec<depotsrv::PackageIdent>) {
+        let field = self.sort;
+        idents.sort_by(|a, b| compare_idents(field, a, b));
+        if self.order == SortOrder::Desc {
+            idents.reverse();
+        }
+    }
+}
+
+impl FromStr for SearchQuery {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut terms = vec![];
+        for raw in value.split(|c: char| c.is_whitespace() || c == '+') {
+            if raw.is_empty() {
+                continue;
+            }
+            let term = match raw.find(':') {
+                Some(idx) => {
+                    let field = try!(raw[..idx].parse::<SearchField>());
+                    let value = raw[idx + 1..].to_lowercase();
+                    if value.is_empty() {
+                        return Err(Error::InvalidSearchQuery(format!("no value given for \
+                                                                      field '{}'",
+                                                                     field)));
+                    }
+                    SearchTerm::Field(field, value)
+                }
+                None => SearchTerm::Plain(raw.to_lowercase()),
+            };
+            terms.push(term);
+        }
+        if terms.is_empty() {
+            return Err(Error::InvalidSearchQuery("no search terms given".to_string()));
+        }
+        Ok(SearchQuery { terms: terms, ..SearchQuery::default() })
+    }
+}
+
+/// Collects one page of search results in order, leaving out the results the caller may not see.
+pub struct SearchPage<F> {
+    visible: F,
+    offset: usize,
+    count: usize,
+    skipped: usize,
+    pub idents: Vec<depotsrv::PackageIdent>,
+}
+
+impl<F> SearchPage<F>
+    where F: FnMut(&depotsrv::PackageIdent) -> bool
+{
+    /// Creates a page of at most `count` results, following the first `offset` visible results.
+    pub fn new(offset: usize, count: usize, visible: F) -> Self {
+        SearchPage {
+            visible: visible,
+            offset: offset,
+            count: count,
+            skipped: 0,
+            idents: vec![],
+        }
+    }
+
+    /// Offers the next result and returns true once the page is full and another visible result
+    /// follows it, so there is a next page.
+    pub fn push(&mut self, ident: depotsrv::PackageIdent) -> bool {
+        if !(self.visible)(&ident) {
+            return false;
+        }
+        if self.skipped < self.offset {
+            self.skipped += 1;
+        } else if self.idents.len() == self.count {
+            return true;
+        } else {
+            self.idents.push(ident);
+        }
+        false
+    }
+}
+
+/// Returns every field and term pair which should be indexed for the given package.
+///
+/// Each indexed term is also indexed as a `Keyword` so plain search terms will match metadata.
+pub fn index_terms(package: &depotsrv::Package) -> Vec<(SearchField, String)> {
+    let manifest = PackageManifest::parse(package.get_manifest());
+    let mut terms = vec![];
+    if let Some(ref description) = manifest.description {
+        for word in words(description) {
+            terms.push((SearchField::Description, word));
+        }
+    }
+    if let Some(ref maintainer) = manifest.maintainer {
+        for word in words(maintainer) {
+            terms.push((SearchField::Maintainer, word));
+        }
+    }
+    for license in manifest.licenses.iter() {
+        terms.push((SearchField::License, license.to_lowercase()));
+    }
+    if let Some(ref url) = manifest.upstream_url {
+        terms.push((SearchField::Upstream, url.to_lowercase()));
+        if let Some(host) = url_host(url) {
+            terms.push((SearchField::Upstream, host));
+        }
+    }
+    // Provenance is indexed as written; its signatures are verified by clients (`hab pkg info`)
+    // against their own key cache.
+    if let Ok(provenance) = PackageProvenance::from_signed(package.get_provenance()) {
+        if let Some(job) = provenance.builder_job_id {
+            terms.push((SearchField::Job, job.to_lowercase()));
+        }
+        if let Some(ref url) = provenance.source_url {
+            terms.push((SearchField::Source, url.to_lowercase()));
+            if let Some(host) = url_host(url) {
+                terms.push((SearchField::Source, host));
+            }
+        }
+        if let Some(shasum) = provenance.source_shasum {
+            terms.push((SearchField::Source, shasum.to_lowercase()));
+        }
+    }
+    for port in package.get_exposes().iter() {
+        terms.push((SearchField::Port, port.to_string()));
+    }
+    let keywords: Vec<(SearchField, String)> = terms.iter()
+        .filter(|&&(field, _)| field != SearchField::Port)
+        .map(|&(_, ref term)| (SearchField::Keyword, term.clone()))
+        .collect();
+    terms.extend(keywords);
+    terms.sort_by(|a, b| (a.0 as u8, &a.1).cmp(&(b.0 as u8, &b.1)));
+    terms.dedup();
+    terms
+}
+
+/// Splits free text into lowercase alphanumeric words, dropping single characters.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 1)
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+fn url_host(url: &str) -> Option<String> {
+    let rest = match url.find("://") {
+        Some(idx) => &url[idx + 3..],
+        None => url,
+    };
+    let host = rest.split(|c| c == '/' || c == ':' || c == '?').next().unwrap_or("");
+    if host.is_empty() {
+        None
+    } else {
+        Some(host.to_lowercase())
+    }
+}
+
+fn compare_idents(field: SortField,
+                  a: &depotsrv::PackageIdent,
+                  b: &depotsrv::PackageIdent)
+                  -> Ordering {
+    let ordering = match field {
+        SortField::Ident => Ordering::Equal,
+        SortField::Origin => a.get_origin().cmp(b.get_origin()),
+        SortField::Name => a.get_name().cmp(b.get_name()),
+        SortField::Version => {
+            match version_sort(a.get_version(), b.get_version()) {
+                Ok(ordering) => ordering,
+                Err(_) => a.get_version().cmp(b.get_version()),
+            }
+        }
+        SortField::Release => a.get_release().cmp(b.get_release()),
+    };
+    match ordering {
+        Ordering::Equal => a.to_string().cmp(&b.to_string()),
+        ordering => ordering,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hab_core::package::PackageIdent;
+    use protocol::depotsrv;
+
+    use super::*;
+
+    static MANIFEST: &'static str = "# core / redis
+Persistent key-value database, with built-in net interface
+
+* __Maintainer__: The Habitat Maintainers <humans@habitat.sh>
+* __Upstream URL__: [http://redis.io](http://redis.io)
+* __License__: BSD MIT
+";
+
+    fn ident(ident: &str) -> depotsrv::PackageIdent {
+        depotsrv::PackageIdent::from(PackageIdent::from_str(ident).unwrap())
+    }
+
+    #[test]
+    fn parse_query_terms() {
+        let query = "Redis license:MIT+port:6379".parse::<SearchQuery>().unwrap();
+        assert_eq!(query.terms,
+                   vec![SearchTerm::Plain("redis".to_string()),
+                        SearchTerm::Field(SearchField::License, "mit".to_string()),
+                        SearchTerm::Field(SearchField::Port, "6379".to_string())]);
+        assert_eq!(query.sort, SortField::Ident);
+        assert_eq!(query.order, SortOrder::Asc);
+    }
+
+    #[test]
+    fn parse_invalid_queries() {
+        assert!("".parse::<SearchQuery>().is_err());
+        assert!("  + ".parse::<SearchQuery>().is_err());
+        assert!("color:red".parse::<SearchQuery>().is_err());
+        assert!("license:".parse::<SearchQuery>().is_err());
+        assert!("keyword:redis".parse::<SearchQuery>().is_err());
+    }
+
+    #[test]
+    fn index_package_metadata() {
+        let mut package = depotsrv::Package::new();
+        package.set_ident(ident("core/redis/3.0.7/20160614231131"));
+        package.set_manifest(MANIFEST.to_string());
+        package.set_exposes(vec![6379]);
+        let terms = index_terms(&package);
+        for term in &[(SearchField::Description, "database"),
+                      (SearchField::Keyword, "database"),
+                      (SearchField::Maintainer, "habitat"),
+                      (SearchField::License, "bsd"),
+                      (SearchField::License, "mit"),
+                      (SearchField::Upstream, "http://redis.io"),
+                      (SearchField::Upstream, "redis.io"),
+                      (SearchField::Keyword, "redis.io"),
+                      (SearchField::Port, "6379")] {
+            assert!(terms.contains(&(term.0, term.1.to_string())),
+                    "missing {}:{}",
+                    term.0,
+                    term.1);
+        }
+        assert!(!terms.contains(&(SearchField::Keyword, "6379".to_string())));
+        assert!(!terms.contains(&(SearchField::Description, "a".to_string())));
+        let mut deduped = terms.clone();
+        deduped.dedup();
+        assert_eq!(terms, deduped);
+    }
+
+    #[test]
+    fn sort_search_results() {
+        let mut idents = vec![ident("core/redis/3.2.1/20160729192522"),
+                              ident("acme/redis/3.10.0/20160801000000"),
+                              ident("core/redis/3.0.7/20160614231131")];
+        let mut query = "redis".parse::<SearchQuery>().unwrap();
+        query.sort(&mut idents);
+        assert_eq!(idents[0].to_string(), "acme/redis/3.10.0/20160801000000");
+        assert_eq!(idents[2].to_string(), "core/redis/3.2.1/20160729192522");
+
+        query.sort = SortField::Version;
+        query.order = SortOrder::Desc;
+        query.sort(&mut idents);
+        assert_eq!(idents.iter().map(|i| i.get_version()).collect::<Vec<_>>(),
+                   vec!["3.10.0", "3.2.1", "3.0.7"]);
+    }
+}
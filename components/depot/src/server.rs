@@ -13,17 +13,20 @@
 // limitations under the License.
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fs::{self, File};
-use std::io::{Read, Write, BufWriter};
+use std::io::{self, Read, Seek, SeekFrom, Write, BufWriter};
 use std::path::PathBuf;
 use std::result;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use bodyparser;
 use dbcache::{self, BasicSet, IndexSet};
 use hab_core::package::{Identifiable, FromArchive, PackageArchive};
 use hab_core::crypto::keys::{self, PairType};
-use hab_core::crypto::SigKeyPair;
+use hab_core::crypto::{KeyRevocation, SigKeyPair};
+use hab_core::crypto::hash::Hasher;
 use hab_net;
 use hab_net::config::RouteAddrs;
 use hab_net::routing::Broker;
@@ -48,6 +51,7 @@ use urlencoded::UrlEncodedQuery;
 use super::Depot;
 use config::Config;
 use error::{Error, Result};
+use search::{SearchQuery, SortField, SortOrder};
 
 const PAGINATION_RANGE_DEFAULT: isize = 0;
 const PAGINATION_RANGE_MAX: isize = 50;
@@ -73,6 +77,7 @@ fn render_net_error(err: &NetError) -> Response {
         ErrCode::TIMEOUT => status::RequestTimeout,
         ErrCode::BAD_REMOTE_REPLY => status::BadGateway,
         ErrCode::SESSION_EXPIRED => status::Unauthorized,
+        ErrCode::ACCESS_DENIED => status::Forbidden,
         _ => status::InternalServerError,
     };
     Response::with((status, encoded))
@@ -139,6 +144,10 @@ pub fn session_create(depot: &Depot, token: &str) -> result::Result<Session, Res
 
 pub fn authenticate(depot: &Depot, req: &mut Request) -> result::Result<Session, Response> {
     match req.headers.get::<Authorization<Bearer>>() {
+        // Origin tokens only grant access to a single origin and never stand in for an account
+        Some(&Authorization(Bearer { ref token })) if token.starts_with(ORIGIN_TOKEN_PREFIX) => {
+            Err(Response::with(status::Unauthorized))
+        }
         Some(&Authorization(Bearer { ref token })) => {
             let mut conn = Broker::connect(&depot.context).unwrap();
             let mut request = SessionGet::new();
@@ -172,6 +181,93 @@ pub fn authenticate(depot: &Depot, req: &mut Request) -> result::Result<Session,
     }
 }
 
+/// Authenticates a request and ensures the caller holds at least the given role within an origin.
+///
+/// The bearer token may either be a GitHub access token, in which case the account's membership
+/// of the origin is checked, or an origin token, which carries its own role for the single origin
+/// it was created for. The vault caps that role at the current role of the account which created
+/// the token and rejects the token once that account has left the origin. Returns the id of the
+/// acting account; for origin tokens this is the account which created the token.
+pub fn authorize(depot: &Depot,
+                 req: &mut Request,
+                 origin: &str,
+                 role: OriginRole)
+                 -> result::Result<u64, Response> {
+    let origin_token = match req.headers.get::<Authorization<Bearer>>() {
+        Some(&Authorization(Bearer { ref token })) if token.starts_with(ORIGIN_TOKEN_PREFIX) => {
+            Some(token.to_string())
+        }
+        _ => None,
+    };
+    if let Some(token) = origin_token {
+        let origin_token = try!(get_origin_token(depot, &token));
+        if origin_token.get_origin_name() != origin || !origin_token.get_role().satisfies(role) {
+            return Err(Response::with(status::Forbidden));
+        }
+        return Ok(origin_token.get_owner_id());
+    }
+    let session = try!(authenticate(depot, req));
+    if !check_origin_access(depot, session.get_id(), origin, role) {
+        return Err(Response::with(status::Forbidden));
+    }
+    Ok(session.get_id())
+}
+
+fn get_origin_token(depot: &Depot, token: &str) -> result::Result<OriginToken, Response> {
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginTokenGet::new();
+    request.set_token(token.to_string());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginToken" => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    if err.get_code() == ErrCode::ENTITY_NOT_FOUND {
+                        Err(Response::with(status::Unauthorized))
+                    } else {
+                        Err(render_net_error(&err))
+                    }
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("origin token get, err={:?}", e);
+            Err(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+/// Ensures the caller may read the packages of an origin and returns whether the origin is private.
+///
+/// Packages of public origins, and of origins unknown to the vault, are readable by anyone.
+/// Private origins require a session or origin token with at least read-only access.
+fn authorize_read(depot: &Depot,
+                  req: &mut Request,
+                  origin: &str)
+                  -> result::Result<bool, Response> {
+    if try!(is_private(get_origin(depot, origin))) {
+        try!(authorize(depot, req, origin, OriginRole::ReadOnly));
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+/// Returns whether an origin looked up in the vault is private. An origin the vault doesn't know
+/// is public, but a failed lookup is answered with a 503, since the origin may well be private.
+fn is_private(origin: Result<Option<Origin>>) -> result::Result<bool, Response> {
+    match origin {
+        Ok(Some(ref o)) => Ok(o.get_private()),
+        Ok(None) => Ok(false),
+        Err(e) => {
+            error!("authorize_read, err={:?}", e);
+            Err(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
 pub fn origin_create(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let session = match authenticate(&depot, req) {
         Ok(session) => session,
@@ -186,6 +282,11 @@ pub fn origin_create(depot: &Depot, req: &mut Request) -> IronResult<Response> {
                 Some(origin) => request.set_name(origin.as_string().unwrap().to_owned()),
                 _ => return Ok(Response::with(status::BadRequest)),
             }
+            match body.find("private").map(|p| p.as_boolean()) {
+                Some(Some(private)) => request.set_private(private),
+                Some(None) => return Ok(Response::with(status::BadRequest)),
+                None => (),
+            }
         }
         _ => return Ok(Response::with(status::BadRequest)),
     };
@@ -253,6 +354,154 @@ pub fn origin_show(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn origin_update(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(&depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let origin_name = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("origin") {
+            Some(origin) => origin.to_string(),
+            None => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let origin = match try!(get_origin(&depot, &origin_name)) {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    let mut request = OriginUpdate::new();
+    request.set_origin_id(origin.get_id());
+    request.set_requester_id(session.get_id());
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match body.find("private").map(|p| p.as_boolean()) {
+                Some(Some(private)) => request.set_private(private),
+                Some(None) => return Ok(Response::with(status::BadRequest)),
+                None => (),
+            }
+        }
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Origin" => {
+                    let origin: Origin = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&origin.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+pub fn origin_delete(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let origin = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    // packages can't be re-homed, so an origin has to be emptied before it may be deleted
+    match depot.datastore.packages.index.count(origin) {
+        Ok(0) => (),
+        Ok(_) => return Ok(Response::with(status::Conflict)),
+        Err(e) => {
+            error!("origin_delete:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    }
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginDelete::new();
+    request.set_name(origin.to_string());
+    request.set_requester_id(session.get_id());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Origin" => {
+                    let origin: Origin = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    // a later origin of the same name must not inherit the deleted one's keys
+                    if let Err(e) = delete_origin_keys(depot, origin.get_name()) {
+                        error!("origin_delete:2, err={:?}", e);
+                        return Ok(Response::with(status::InternalServerError));
+                    }
+                    let encoded = json::encode(&origin.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+/// Removes the files and records of every key of an origin along with their revocations.
+fn delete_origin_keys(depot: &Depot, origin: &str) -> Result<()> {
+    for revision in try!(depot.datastore.origin_keys.revisions(origin)) {
+        for path in &[depot.key_path(origin, &revision), depot.revocation_path(origin, &revision)] {
+            match fs::remove_file(path) {
+                Ok(()) => (),
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => (),
+                Err(e) => return Err(Error::from(e)),
+            }
+        }
+    }
+    try!(depot.datastore.origin_key_revocations.delete(origin));
+    try!(depot.datastore.origin_keys.delete(origin));
+    Ok(())
+}
+
+/// Looks up an account by name through the session server.
+fn get_account(depot: &Depot, name: &str) -> result::Result<Account, Response> {
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = AccountGet::new();
+    request.set_name(name.to_string());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Account" => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Err(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
 pub fn get_origin(depot: &Depot, origin: &str) -> Result<Option<Origin>> {
     let mut conn = Broker::connect(&depot.context).unwrap();
     let mut request = OriginGet::new();
@@ -266,12 +515,9 @@ pub fn get_origin(depot: &Depot, origin: &str) -> Result<Option<Origin>> {
                     Ok(Some(origin))
                 }
                 "NetError" => {
-                    // TODO: This function can't swallow this error. This has to propogate back
-                    // to the caller so they know there was an issue and the origin may
-                    // potentially exist.
                     let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
                     debug!("get_origin error: {:?}", err);
-                    Ok(None)
+                    missing_origin(err)
                 }
                 _ => unreachable!("unexpected msg: {:?}", rep),
             }
@@ -283,7 +529,22 @@ pub fn get_origin(depot: &Depot, origin: &str) -> Result<Option<Origin>> {
     }
 }
 
-pub fn check_origin_access(depot: &Depot, account_id: u64, origin_name: &str) -> bool {
+/// Returns `None` for an origin the vault doesn't know, and the error for any other failure, so
+/// a vault which can't be reached doesn't pass for a missing origin.
+fn missing_origin(err: NetError) -> Result<Option<Origin>> {
+    if err.get_code() == ErrCode::ENTITY_NOT_FOUND {
+        Ok(None)
+    } else {
+        Err(Error::NetError(err))
+    }
+}
+
+/// Returns true if the account holds at least the given role within the named origin.
+pub fn check_origin_access(depot: &Depot,
+                           account_id: u64,
+                           origin_name: &str,
+                           role: OriginRole)
+                           -> bool {
     let mut conn = Broker::connect(&depot.context).unwrap();
 
     let mut request = CheckOriginAccessRequest::new();
@@ -293,6 +554,7 @@ pub fn check_origin_access(depot: &Depot, account_id: u64, origin_name: &str) ->
     // !!!NOTE!!!
     request.set_account_id(account_id);
     request.set_origin_name(origin_name.to_string());
+    request.set_required_role(role);
 
     conn.route(&request).unwrap();
     match conn.recv() {
@@ -335,7 +597,7 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
            &user_to_invite,
            &origin);
 
-    if !check_origin_access(&depot, session.get_id(), &origin) {
+    if !check_origin_access(&depot, session.get_id(), &origin, OriginRole::Owner) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -398,19 +660,397 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
                     Ok(render_net_error(&err))
                 }
                 _ => unreachable!("unexpected msg: {:?}", rep),
-
+
+            }
+        }
+        Err(e) => {
+            debug!("Error: {}", &e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    }
+}
+
+pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    debug!("list_origin_invitations");
+
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+
+    let origin_name = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_access(&depot, session.get_id(), &origin_name, OriginRole::ReadOnly) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginInvitationListRequest::new();
+
+    let origin = match try!(get_origin(&depot, origin_name)) {
+        Some(o) => o,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    request.set_origin_id(origin.get_id());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginInvitationListResponse" => {
+                    let invites: OriginInvitationListResponse =
+                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&invites.to_json()).unwrap();
+                    let mut response = Response::with((status::Ok, encoded));
+                    dont_cache_response(&mut response);
+                    Ok(response)
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+pub fn list_origin_members(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    debug!("list_origin_members");
+
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+
+    let origin_name = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_access(&depot, session.get_id(), &origin_name, OriginRole::ReadOnly) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginMemberListRequest::new();
+
+    let origin = match try!(get_origin(&depot, origin_name)) {
+        Some(o) => o,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    request.set_origin_id(origin.get_id());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginMemberListResponse" => {
+                    let members: OriginMemberListResponse =
+                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&members.to_json()).unwrap();
+                    let mut response = Response::with((status::Ok, encoded));
+                    dont_cache_response(&mut response);
+                    Ok(response)
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+
+pub fn update_origin_member_role(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let (origin_name, username) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match (params.find("origin"), params.find("username")) {
+            (Some(origin), Some(username)) => (origin.to_string(), username.to_string()),
+            _ => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let role = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match body.find("role").and_then(|r| r.as_string()).map(|r| r.parse::<OriginRole>()) {
+                Some(Ok(role)) => role,
+                _ => return Ok(Response::with(status::UnprocessableEntity)),
+            }
+        }
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let origin = match try!(get_origin(&depot, &origin_name)) {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    let account = match get_account(depot, &username) {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+
+    let mut request = OriginMemberRoleUpdate::new();
+    request.set_origin_id(origin.get_id());
+    request.set_requester_id(session.get_id());
+    request.set_account_id(account.get_id());
+    request.set_account_name(account.get_name().to_string());
+    request.set_role(role);
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginMember" => {
+                    let member: OriginMember = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&member.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+pub fn origin_member_remove(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let (origin_name, username) = match (params.find("origin"), params.find("username")) {
+        (Some(origin), Some(username)) => (origin, username),
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let origin = match try!(get_origin(&depot, origin_name)) {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    let account = match get_account(depot, username) {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginMemberRemove::new();
+    request.set_origin_id(origin.get_id());
+    request.set_user_id(account.get_id());
+    request.set_account_name(account.get_name().to_string());
+    request.set_requester_id(session.get_id());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginMember" => {
+                    let member: OriginMember = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&member.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+pub fn origin_invitation_rescind(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let origin_name = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let invite_id = match params.find("invitation_id").map(|id| id.parse::<u64>()) {
+        Some(Ok(id)) => id,
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let origin = match try!(get_origin(&depot, origin_name)) {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginInvitationRescind::new();
+    request.set_origin_id(origin.get_id());
+    request.set_invite_id(invite_id);
+    request.set_requester_id(session.get_id());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginInvitation" => {
+                    let invite: OriginInvitation = protobuf::parse_from_bytes(rep.get_body())
+                        .unwrap();
+                    let encoded = json::encode(&invite.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+pub fn origin_transfer(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let (origin_name, username) = match (params.find("origin"), params.find("username")) {
+        (Some(origin), Some(username)) => (origin, username),
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let origin = match try!(get_origin(&depot, origin_name)) {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    let account = match get_account(depot, username) {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginTransfer::new();
+    request.set_origin_id(origin.get_id());
+    request.set_requester_id(session.get_id());
+    request.set_account_id(account.get_id());
+    request.set_account_name(account.get_name().to_string());
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Origin" => {
+                    let origin: Origin = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&origin.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+pub fn origin_token_create(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let origin_name = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("origin") {
+            Some(origin) => origin.to_string(),
+            None => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let (name, role) = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            let name = match body.find("name").and_then(|n| n.as_string()) {
+                Some(name) if !name.is_empty() => name.to_string(),
+                _ => return Ok(Response::with(status::UnprocessableEntity)),
+            };
+            match body.find("role").and_then(|r| r.as_string()).map(|r| r.parse::<OriginRole>()) {
+                Some(Ok(role)) => (name, role),
+                _ => return Ok(Response::with(status::UnprocessableEntity)),
+            }
+        }
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let origin = match try!(get_origin(&depot, &origin_name)) {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginTokenCreate::new();
+    request.set_origin_id(origin.get_id());
+    request.set_origin_name(origin.get_name().to_string());
+    request.set_owner_id(session.get_id());
+    request.set_name(name);
+    request.set_role(role);
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginToken" => {
+                    let token: OriginToken = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&token.to_json()).unwrap();
+                    let mut response = Response::with((status::Created, encoded));
+                    dont_cache_response(&mut response);
+                    Ok(response)
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
             }
         }
         Err(e) => {
-            debug!("Error: {}", &e);
-            return Ok(Response::with(status::InternalServerError));
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
         }
     }
 }
 
-pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<Response> {
-    debug!("list_origin_invitations");
-
+pub fn list_origin_tokens(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let session = match authenticate(depot, req) {
         Ok(session) => session,
         Err(response) => return Ok(response),
@@ -422,27 +1062,26 @@ pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<R
         None => return Ok(Response::with(status::BadRequest)),
     };
 
-    if !check_origin_access(&depot, session.get_id(), &origin_name) {
+    if !check_origin_access(&depot, session.get_id(), &origin_name, OriginRole::Maintainer) {
         return Ok(Response::with(status::Forbidden));
     }
 
-    let mut conn = Broker::connect(&depot.context).unwrap();
-    let mut request = OriginInvitationListRequest::new();
-
     let origin = match try!(get_origin(&depot, origin_name)) {
         Some(o) => o,
         None => return Ok(Response::with(status::NotFound)),
     };
 
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginTokenListRequest::new();
     request.set_origin_id(origin.get_id());
     conn.route(&request).unwrap();
     match conn.recv() {
         Ok(rep) => {
             match rep.get_message_id() {
-                "OriginInvitationListResponse" => {
-                    let invites: OriginInvitationListResponse =
+                "OriginTokenListResponse" => {
+                    let tokens: OriginTokenListResponse =
                         protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&invites.to_json()).unwrap();
+                    let encoded = json::encode(&tokens.to_json()).unwrap();
                     let mut response = Response::with((status::Ok, encoded));
                     dont_cache_response(&mut response);
                     Ok(response)
@@ -461,9 +1100,7 @@ pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<R
     }
 }
 
-pub fn list_origin_members(depot: &Depot, req: &mut Request) -> IronResult<Response> {
-    debug!("list_origin_members");
-
+pub fn origin_token_revoke(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let session = match authenticate(depot, req) {
         Ok(session) => session,
         Err(response) => return Ok(response),
@@ -474,32 +1111,26 @@ pub fn list_origin_members(depot: &Depot, req: &mut Request) -> IronResult<Respo
         Some(origin) => origin,
         None => return Ok(Response::with(status::BadRequest)),
     };
-
-    if !check_origin_access(&depot, session.get_id(), &origin_name) {
-        return Ok(Response::with(status::Forbidden));
-    }
-
-    let mut conn = Broker::connect(&depot.context).unwrap();
-    let mut request = OriginMemberListRequest::new();
+    let token_id = match params.find("id").map(|id| id.parse::<u64>()) {
+        Some(Ok(id)) => id,
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
 
     let origin = match try!(get_origin(&depot, origin_name)) {
         Some(o) => o,
         None => return Ok(Response::with(status::NotFound)),
     };
 
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = OriginTokenRevoke::new();
     request.set_origin_id(origin.get_id());
+    request.set_token_id(token_id);
+    request.set_requester_id(session.get_id());
     conn.route(&request).unwrap();
     match conn.recv() {
         Ok(rep) => {
             match rep.get_message_id() {
-                "OriginMemberListResponse" => {
-                    let members: OriginMemberListResponse =
-                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&members.to_json()).unwrap();
-                    let mut response = Response::with((status::Ok, encoded));
-                    dont_cache_response(&mut response);
-                    Ok(response)
-                }
+                "OriginToken" => Ok(Response::with(status::NoContent)),
                 "NetError" => {
                     let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
                     Ok(render_net_error(&err))
@@ -527,12 +1158,15 @@ fn write_string_to_file(filename: &PathBuf, body: String) -> Result<bool> {
     Ok(true)
 }
 
-fn write_file(filename: &PathBuf, body: &mut Body) -> Result<bool> {
+/// Writes the request body to `filename`, hashing it on the way so the file doesn't need to be
+/// read again to compute its checksum. Returns the checksum of the written file.
+fn write_file(filename: &PathBuf, body: &mut Body) -> Result<String> {
     let path = filename.parent().unwrap();
     try!(fs::create_dir_all(path));
     let tempfile = format!("{}.tmp", filename.to_string_lossy());
     let f = try!(File::create(&tempfile));
     let mut writer = BufWriter::new(&f);
+    let mut hasher = Hasher::new();
     let mut written: i64 = 0;
     let mut buf = [0u8; 100000]; // Our byte buffer
     loop {
@@ -548,13 +1182,15 @@ fn write_file(filename: &PathBuf, body: &mut Body) -> Result<bool> {
                 if bytes_written == 0 {
                     return Err(Error::WriteSyncFailed);
                 }
+                hasher.update(&buf[0..bytes_written]);
                 written = written + (bytes_written as i64);
             }
         };
     }
+    try!(writer.flush());
     info!("File added to Depot at {}", filename.to_string_lossy());
     try!(fs::rename(&tempfile, &filename));
-    Ok(true)
+    Ok(hasher.finish())
 }
 
 fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
@@ -578,13 +1214,8 @@ fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     };
 
     if !depot.config.insecure {
-        let session = match authenticate(depot, req) {
-            Ok(session) => session,
-            Err(response) => return Ok(response),
-        };
-
-        if !check_origin_access(&depot, session.get_id(), &origin) {
-            return Ok(Response::with(status::Forbidden));
+        if let Err(response) = authorize(depot, req, &origin, OriginRole::Owner) {
+            return Ok(response);
         }
     }
 
@@ -630,37 +1261,27 @@ fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
 
 fn upload_origin_secret_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     debug!("Upload Origin Secret Key {:?}", req);
-    let session = match authenticate(depot, req) {
-        Ok(session) => session,
-        Err(response) => {
-            return Ok(response);
-        }
-    };
 
-    let params = req.extensions.get::<Router>().unwrap();
-
-    let name = match params.find("origin") {
-        Some(origin) => origin,
-        None => return Ok(Response::with(status::BadRequest)),
+    let (name, revision) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match (params.find("origin"), params.find("revision")) {
+            (Some(origin), Some(revision)) => (origin.to_string(), revision.to_string()),
+            _ => return Ok(Response::with(status::BadRequest)),
+        }
     };
 
-    let revision = match params.find("revision") {
-        Some(revision) => revision,
-        None => return Ok(Response::with(status::BadRequest)),
+    let account_id = match authorize(depot, req, &name, OriginRole::Owner) {
+        Ok(account_id) => account_id,
+        Err(response) => return Ok(response),
     };
 
-    if !check_origin_access(&depot, session.get_id(), &name) {
-        return Ok(Response::with(status::Forbidden));
-    }
-
-
-    let o = match try!(get_origin(&depot, name)) {
+    let o = match try!(get_origin(&depot, &name)) {
         Some(o) => o,
         None => return Ok(Response::with(status::NotFound)),
     };
 
     let mut request = OriginSecretKeyCreate::new();
-    request.set_owner_id(session.get_id());
+    request.set_owner_id(account_id);
     request.set_origin_id(o.get_id());
     request.set_name(name.to_string());
     request.set_revision(revision.to_string());
@@ -720,13 +1341,8 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     };
 
     if !depot.config.insecure {
-        let session = match authenticate(depot, req) {
-            Ok(session) => session,
-            Err(response) => return Ok(response),
-        };
-
-        if !check_origin_access(&depot, session.get_id(), &ident.get_origin()) {
-            return Ok(Response::with(status::Forbidden));
+        if let Err(response) = authorize(depot, req, ident.get_origin(), OriginRole::Maintainer) {
+            return Ok(response);
         }
 
         if !ident.fully_qualified() {
@@ -749,16 +1365,10 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 
     let filename = depot.archive_path(&ident);
-    try!(write_file(&filename, &mut req.body));
+    let checksum_from_artifact = try!(write_file(&filename, &mut req.body));
     let mut archive = PackageArchive::new(filename);
+    archive.set_checksum(checksum_from_artifact.clone());
     debug!("Package Archive: {:#?}", archive);
-    let checksum_from_artifact = match archive.checksum() {
-        Ok(cksum) => cksum,
-        Err(e) => {
-            info!("Could not compute a checksum for {:#?}: {:#?}", archive, e);
-            return Ok(Response::with(status::UnprocessableEntity));
-        }
-    };
     if checksum_from_param != checksum_from_artifact {
         info!("Checksums did not match: from_param={:?}, from_artifact={:?}",
               checksum_from_param,
@@ -841,7 +1451,16 @@ fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Re
         None => return Ok(Response::with(status::BadRequest)),
     };
     debug!("Trying to retreive latest origin key for {}", &origin);
-    let latest_rev = depot.datastore.origin_keys.latest(&origin).unwrap();
+    let latest_rev = match depot.datastore.origin_keys.latest(&origin) {
+        Ok(revision) => revision,
+        Err(Error::DataStore(dbcache::Error::EntityNotFound)) => {
+            return Ok(Response::with(status::NotFound))
+        }
+        Err(e) => {
+            error!("download_latest_origin_key:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
     let origin_keyfile = depot.key_path(&origin, &latest_rev);
     debug!("Looking for {}", &origin_keyfile.to_string_lossy());
     match origin_keyfile.metadata() {
@@ -873,20 +1492,65 @@ fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Re
 
 fn download_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     debug!("Download {:?}", req);
-    let params = req.extensions.get::<Router>().unwrap();
-    let ident = ident_from_params(params);
+    let ident = {
+        let params = req.extensions.get::<Router>().unwrap();
+        ident_from_params(params)
+    };
+    let private = match authorize_read(depot, req, ident.get_origin()) {
+        Ok(private) => private,
+        Err(response) => return Ok(response),
+    };
 
     match depot.datastore.packages.find(&ident) {
         Ok(ident) => {
             if let Some(archive) = depot.archive(&ident) {
                 match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
+                    Ok(metadata) => {
+                        let len = metadata.len();
+                        let mut response = match range_start(req) {
+                            Some(start) if start >= len => {
+                                let mut response = Response::with(status::RangeNotSatisfiable);
+                                response.headers
+                                    .set_raw("Content-Range",
+                                             vec![format!("bytes */{}", len).into_bytes()]);
+                                return Ok(response);
+                            }
+                            Some(start) if start > 0 => {
+                                let file = File::open(&archive.path).and_then(|mut f| {
+                                    f.seek(SeekFrom::Start(start)).map(|_| f)
+                                });
+                                let body: Box<Read + Send> = match file {
+                                    Ok(file) => Box::new(file),
+                                    Err(e) => {
+                                        error!("download_package:2, err={:?}", e);
+                                        return Ok(Response::with(status::InternalServerError));
+                                    }
+                                };
+                                let mut response = Response::with((status::PartialContent,
+                                                                   body));
+                                response.headers.set_raw("Content-Range",
+                                                         vec![format!("bytes {}-{}/{}",
+                                                                      start,
+                                                                      len - 1,
+                                                                      len)
+                                                                  .into_bytes()]);
+                                response.headers.set_raw("Content-Length",
+                                                         vec![format!("{}", len - start)
+                                                                  .into_bytes()]);
+                                response
+                            }
+                            _ => Response::with((status::Ok, archive.path.clone())),
+                        };
+                        response.headers.set_raw("Accept-Ranges", vec![b"bytes".to_vec()]);
+
+                        if cacheable(&ident, private) {
+                            do_cache_response(&mut response);
+                        } else {
+                            dont_cache_response(&mut response);
+                        }
                         // use set_raw because we're having problems with Iron's Hyper 0.8.x
                         // and the newer Hyper 0.9.4. TODO: change back to set() once
                         // Iron updates to Hyper 0.9.x.
-
-                        do_cache_response(&mut response);
                         response.headers
                             .set_raw("X-Filename", vec![archive.file_name().clone().into_bytes()]);
                         response.headers.set_raw("content-disposition",
@@ -913,6 +1577,27 @@ fn download_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Returns the offset of a `Range: bytes=<offset>-` request header, which is the only kind of
+/// range request clients send, to resume an interrupted package download.
+fn range_start(req: &Request) -> Option<u64> {
+    match req.headers.get_raw("Range") {
+        Some(values) if values.len() == 1 => {
+            parse_range_start(&String::from_utf8_lossy(&values[0]))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the offset of the value of a `Range` header of the form `bytes=<offset>-`.
+fn parse_range_start(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if value.starts_with("bytes=") && value.ends_with('-') {
+        value["bytes=".len()..value.len() - 1].parse().ok()
+    } else {
+        None
+    }
+}
+
 fn list_origin_keys(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let params = req.extensions.get::<Router>().unwrap();
     let origin = match params.find("origin") {
@@ -935,11 +1620,152 @@ fn list_origin_keys(depot: &Depot, req: &mut Request) -> IronResult<Response> {
 
 }
 
+fn upload_origin_key_revocation(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    debug!("Upload Origin Key Revocation {:?}", req);
+
+    let (origin, revision) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match (params.find("origin"), params.find("revision")) {
+            (Some(origin), Some(revision)) => (origin.to_string(), revision.to_string()),
+            _ => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+
+    if !depot.config.insecure {
+        if let Err(response) = authorize(depot, req, &origin, OriginRole::Owner) {
+            return Ok(response);
+        }
+    }
+
+    let mut content = String::new();
+    if let Err(e) = req.body.read_to_string(&mut content) {
+        debug!("Can't read key revocation upload content: {}", e);
+        return Ok(Response::with(status::BadRequest));
+    }
+    let revocation = match KeyRevocation::from_str(&content) {
+        Ok(revocation) => revocation,
+        Err(e) => {
+            debug!("Invalid key revocation content: {}", e);
+            return Ok(Response::with(status::BadRequest));
+        }
+    };
+    if revocation.revoked != format!("{}-{}", &origin, &revision) {
+        debug!("Key revocation is for {}, not {}-{}",
+               revocation.revoked,
+               &origin,
+               &revision);
+        return Ok(Response::with(status::BadRequest));
+    }
+    if !depot.key_path(&origin, &revision).is_file() {
+        return Ok(Response::with(status::NotFound));
+    }
+
+    // The signer must be a known, unrevoked key of this origin for the revocation to be accepted
+    let (signer_name, signer_rev) = match keys::parse_name_with_rev(&revocation.signer) {
+        Ok(name_with_rev) => name_with_rev,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if revocation.signer != revocation.revoked &&
+       depot.revocation_path(&signer_name, &signer_rev).is_file() {
+        debug!("Key revocation signer {} has been revoked", revocation.signer);
+        return Ok(Response::with(status::UnprocessableEntity));
+    }
+    let mut signer_content = String::new();
+    match File::open(depot.key_path(&signer_name, &signer_rev)) {
+        Ok(mut f) => try!(f.read_to_string(&mut signer_content).map_err(Error::from)),
+        Err(e) => {
+            debug!("Can't read key revocation signer {}: {}", revocation.signer, e);
+            return Ok(Response::with(status::UnprocessableEntity));
+        }
+    };
+    let verified = SigKeyPair::public_key_from_str(&signer_content)
+        .and_then(|public_key| revocation.verify_with(&public_key));
+    if let Err(e) = verified {
+        debug!("Key revocation failed verification: {}", e);
+        return Ok(Response::with(status::UnprocessableEntity));
+    }
+
+    let revocation_file = depot.revocation_path(&origin, &revision);
+    debug!("Writing key revocation file {}",
+           revocation_file.to_string_lossy());
+    if revocation_file.is_file() {
+        return Ok(Response::with(status::Conflict));
+    }
+
+    try!(write_string_to_file(&revocation_file, content));
+
+    // don't write to Redis if the file wasn't written
+    depot.datastore.origin_key_revocations.write(&origin, &revision).unwrap();
+
+    Ok(Response::with((status::Created,
+                       format!("/origins/{}/keys/{}/revocation", &origin, &revision))))
+}
+
+fn download_origin_key_revocation(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    debug!("Download origin key revocation {:?}", req);
+    let params = req.extensions.get::<Router>().unwrap();
+
+    let origin = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let revision = match params.find("revision") {
+        Some(revision) => revision,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let revocation_file = depot.revocation_path(&origin, &revision);
+    debug!("Looking for {}", &revocation_file.to_string_lossy());
+    if !revocation_file.is_file() {
+        return Ok(Response::with(status::NotFound));
+    }
+
+    let xfilename = revocation_file.file_name().unwrap().to_string_lossy().into_owned();
+    let mut response = Response::with((status::Ok, revocation_file));
+    response.headers.set_raw("X-Filename", vec![xfilename.clone().into_bytes()]);
+    response.headers.set_raw("content-disposition",
+                             vec![format!("attachment; filename=\"{}\"", xfilename.clone())
+                                      .into_bytes()]);
+    do_cache_response(&mut response);
+    Ok(response)
+}
+
+fn list_origin_key_revocations(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let params = req.extensions.get::<Router>().unwrap();
+    let origin = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    match depot.datastore.origin_key_revocations.all(origin) {
+        Ok(revisions) => {
+            let body = json::encode(&revisions.to_json()).unwrap();
+            let mut response = Response::with((status::Ok, body));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(e) => {
+            error!("list_origin_key_revocations:1, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
 fn list_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let (offset, num) = match extract_pagination(req) {
         Ok(range) => range,
         Err(response) => return Ok(response),
     };
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("origin") {
+            Some(origin) => origin.to_string(),
+            None => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    if let Err(response) = authorize_read(depot, req, &origin) {
+        return Ok(response);
+    }
     let params = req.extensions.get::<Router>().unwrap();
     let ident: String = if params.find("pkg").is_none() {
         match params.find("origin") {
@@ -1029,6 +1855,14 @@ fn list_views(depot: &Depot, _req: &mut Request) -> IronResult<Response> {
 }
 
 fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    let private = match authorize_read(depot, req, &origin) {
+        Ok(private) => private,
+        Err(response) => return Ok(response),
+    };
     let params = req.extensions.get::<Router>().unwrap();
     let mut ident = ident_from_params(params);
 
@@ -1087,15 +1921,7 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         }
 
         match depot.datastore.packages.find(&ident) {
-            Ok(pkg) => {
-                // If the request was for a fully qualified ident, cache the response, otherwise do
-                // not cache
-                if ident.fully_qualified() {
-                    render_package(&pkg, true)
-                } else {
-                    render_package(&pkg, false)
-                }
-            }
+            Ok(pkg) => render_package(&pkg, cacheable(&ident, private)),
             Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
             Err(e) => {
                 error!("show_package:6, err={:?}", e);
@@ -1110,12 +1936,57 @@ fn search_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         Ok(range) => range,
         Err(response) => return Ok(response),
     };
-    let params = req.extensions.get::<Router>().unwrap();
-    let partial = params.find("query").unwrap();
-    let packages = depot.datastore.packages.index.search(partial, offset, num).unwrap();
+    let sort = extract_query_value("sort", req);
+    let order = extract_query_value("order", req);
+    let mut query = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("query").unwrap().parse::<SearchQuery>() {
+            Ok(query) => query,
+            Err(e) => return Ok(Response::with((status::BadRequest, e.to_string()))),
+        }
+    };
+    if let Some(sort) = sort {
+        match sort.parse::<SortField>() {
+            Ok(sort) => query.sort = sort,
+            Err(e) => return Ok(Response::with((status::BadRequest, e.to_string()))),
+        }
+    }
+    if let Some(order) = order {
+        match order.parse::<SortOrder>() {
+            Ok(order) => query.order = order,
+            Err(e) => return Ok(Response::with((status::BadRequest, e.to_string()))),
+        }
+    }
+    // Drop packages of private origins the caller cannot read, checking each origin only once
+    let mut readable: HashMap<String, bool> = HashMap::new();
+    let visible = |ident: &depotsrv::PackageIdent| {
+        *readable.entry(ident.get_origin().to_string())
+            .or_insert_with(|| authorize_read(depot, req, ident.get_origin()).is_ok())
+    };
+    let result = depot.datastore
+        .packages
+        .search(&query, offset as usize, (num - offset) as usize, visible);
+    let (packages, more) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            error!("search_packages:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    Ok(search_response(&packages, offset, num, more))
+}
+
+/// Renders a page of search results from `offset` up to `num`. Since packages of private origins
+/// are left out, the total number of results isn't known, so a page followed by more results is
+/// a `206` whose `Next-Range` header holds the one-based range to request next.
+fn search_response(packages: &[depotsrv::PackageIdent],
+                   offset: isize,
+                   num: isize,
+                   more: bool)
+                   -> Response {
     let body = json::encode(&packages).unwrap();
     let next_range = vec![format!("{}", num + 1).into_bytes()];
-    let mut response = if packages.len() as isize >= (num - offset) {
+    let mut response = if more {
         let mut response = Response::with((status::PartialContent, body));
         response.headers.set_raw("Next-Range", next_range);
         response
@@ -1132,7 +2003,7 @@ fn search_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
                                           vec![(Attr::Charset, Value::Utf8)])));
     response.headers.set(Vary::Items(vec![UniCase("range".to_owned())]));
     dont_cache_response(&mut response);
-    Ok(response)
+    response
 }
 
 fn render_package(pkg: &depotsrv::Package, should_cache: bool) -> IronResult<Response> {
@@ -1151,23 +2022,20 @@ fn render_package(pkg: &depotsrv::Package, should_cache: bool) -> IronResult<Res
 }
 
 fn promote_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
-    let session = match authenticate(depot, req) {
-        Ok(session) => session,
-        Err(response) => return Ok(response),
+    let (view, ident) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        (params.find("view").unwrap().to_string(), ident_from_params(params))
     };
 
-    let params = req.extensions.get::<Router>().unwrap();
-    let view = params.find("view").unwrap();
+    if let Err(response) = authorize(depot, req, ident.get_origin(), OriginRole::Maintainer) {
+        return Ok(response);
+    }
 
-    match depot.datastore.views.is_member(view) {
+    match depot.datastore.views.is_member(&view) {
         Ok(true) => {
-            let ident = ident_from_params(params);
-            if !check_origin_access(&depot, session.get_id(), &ident.get_origin()) {
-                return Ok(Response::with(status::Forbidden));
-            }
             match depot.datastore.packages.find(&ident) {
                 Ok(package) => {
-                    depot.datastore.views.associate(view, &package).unwrap();
+                    depot.datastore.views.associate(&view, &package).unwrap();
                     Ok(Response::with(status::Ok))
                 }
                 Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
@@ -1240,6 +2108,12 @@ fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
     }
 }
 
+/// Returns true if a response about a package may be kept by caches and proxies: it names a fully
+/// qualified package, which never changes, of a public origin.
+fn cacheable<T: Identifiable>(ident: &T, private: bool) -> bool {
+    ident.fully_qualified() && !private
+}
+
 fn do_cache_response(response: &mut Response) {
     response.headers.set_raw("Cache-Control",
                              vec![format!("public, max-age={}", ONE_YEAR_IN_SECS).into_bytes()]);
@@ -1294,6 +2168,18 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
     let depot25 = depot.clone();
     let depot26 = depot.clone();
     let depot27 = depot.clone();
+    let depot28 = depot.clone();
+    let depot29 = depot.clone();
+    let depot30 = depot.clone();
+    let depot31 = depot.clone();
+    let depot32 = depot.clone();
+    let depot33 = depot.clone();
+    let depot34 = depot.clone();
+    let depot35 = depot.clone();
+    let depot36 = depot.clone();
+    let depot37 = depot.clone();
+    let depot38 = depot.clone();
+    let depot39 = depot.clone();
 
     let router = router!(
         get "/views" => move |r: &mut Request| list_views(&depot1, r),
@@ -1335,12 +2221,15 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
         },
 
         post "/origins" => move |r: &mut Request| origin_create(&depot18, r),
-        // TODO
-        //delete "/origins/:origin" => move |r: &mut Request| origin_delete(&depot17, r),
+        put "/origins/:origin" => move |r: &mut Request| origin_update(&depot32, r),
+        delete "/origins/:origin" => move |r: &mut Request| origin_delete(&depot33, r),
 
         get "/origins/:origin" => move |r: &mut Request| origin_show(&depot19, r),
 
         get "/origins/:origin/keys" => move |r: &mut Request| list_origin_keys(&depot20, r),
+        get "/origins/:origin/revocations" => {
+            move |r: &mut Request| list_origin_key_revocations(&depot37, r)
+        },
         get "/origins/:origin/keys/latest" => {
             move |r: &mut Request| download_latest_origin_key(&depot21, r)
         },
@@ -1350,6 +2239,12 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
         post "/origins/:origin/keys/:revision" => {
             move |r: &mut Request| upload_origin_key(&depot23, r)
         },
+        get "/origins/:origin/keys/:revision/revocation" => {
+            move |r: &mut Request| download_origin_key_revocation(&depot38, r)
+        },
+        post "/origins/:origin/keys/:revision/revocation" => {
+            move |r: &mut Request| upload_origin_key_revocation(&depot39, r)
+        },
         post "/origins/:origin/secret_keys/:revision" => {
             move |r: &mut Request| upload_origin_secret_key(&depot24, r)
         },
@@ -1362,6 +2257,27 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
         get "/origins/:origin/users" => {
             move |r: &mut Request| list_origin_members(&depot27, r)
         },
+        delete "/origins/:origin/users/:username" => {
+            move |r: &mut Request| origin_member_remove(&depot34, r)
+        },
+        delete "/origins/:origin/invitations/:invitation_id" => {
+            move |r: &mut Request| origin_invitation_rescind(&depot35, r)
+        },
+        post "/origins/:origin/transfer/:username" => {
+            move |r: &mut Request| origin_transfer(&depot36, r)
+        },
+        put "/origins/:origin/users/:username/role" => {
+            move |r: &mut Request| update_origin_member_role(&depot28, r)
+        },
+        get "/origins/:origin/tokens" => {
+            move |r: &mut Request| list_origin_tokens(&depot29, r)
+        },
+        post "/origins/:origin/tokens" => {
+            move |r: &mut Request| origin_token_create(&depot30, r)
+        },
+        delete "/origins/:origin/tokens/:id" => {
+            move |r: &mut Request| origin_token_revoke(&depot31, r)
+        },
     );
     let mut chain = Chain::new(router);
     chain.link_after(Cors);
@@ -1374,6 +2290,10 @@ pub fn run(config: Config) -> Result<()> {
     let ctx = Arc::new(Box::new(ServerContext::new()));
     let ctx1 = ctx.clone();
     let depot = try!(Depot::new(config.clone(), ctx));
+    let indexed = try!(depot.datastore.packages.backfill_search_index());
+    if indexed > 0 {
+        info!("Added {} packages to the search index", indexed);
+    }
     let v1 = try!(router(depot.clone()));
     let broker = Broker::run(Depot::net_ident(), ctx1, &config.route_addrs().clone());
 
@@ -1392,3 +2312,129 @@ impl From<Error> for IronError {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hab_core::package::PackageIdent;
+    use iron::status;
+    use protocol::depotsrv;
+    use protocol::net::{self, ErrCode};
+    use protocol::vault::Origin;
+
+    use error::Error;
+    use search::SearchPage;
+    use super::{cacheable, is_private, missing_origin, parse_range_start, search_response};
+
+    fn ident(ident: &str) -> depotsrv::PackageIdent {
+        depotsrv::PackageIdent::from(PackageIdent::from_str(ident).unwrap())
+    }
+
+    fn public(ident: &depotsrv::PackageIdent) -> bool {
+        ident.get_origin() != "acme"
+    }
+
+    fn origin(private: bool) -> Origin {
+        let mut origin = Origin::new();
+        origin.set_name("acme".to_string());
+        origin.set_private(private);
+        origin
+    }
+
+    #[test]
+    fn parse_range_start_offsets() {
+        assert_eq!(parse_range_start("bytes=0-"), Some(0));
+        assert_eq!(parse_range_start("bytes=1024-"), Some(1024));
+        assert_eq!(parse_range_start(" bytes=1024- "), Some(1024));
+    }
+
+    #[test]
+    fn parse_range_start_unsupported_ranges() {
+        assert_eq!(parse_range_start("bytes=0-1023"), None);
+        assert_eq!(parse_range_start("bytes=-500"), None);
+        assert_eq!(parse_range_start("bytes=1-2,4-"), None);
+        assert_eq!(parse_range_start("items=5-"), None);
+        assert_eq!(parse_range_start("bytes=abc-"), None);
+        assert_eq!(parse_range_start(""), None);
+    }
+
+    #[test]
+    fn missing_origin_only_when_not_found() {
+        let found = missing_origin(net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-get:1"));
+        assert!(found.unwrap().is_none());
+        match missing_origin(net::err(ErrCode::TIMEOUT, "rt:route:1")) {
+            Err(Error::NetError(err)) => assert_eq!(err.get_code(), ErrCode::TIMEOUT),
+            _ => panic!("Expected a timeout to be an error"),
+        }
+        assert!(missing_origin(net::err(ErrCode::INTERNAL, "vt:origin-get:2")).is_err());
+    }
+
+    #[test]
+    fn is_private_origins() {
+        assert_eq!(is_private(Ok(Some(origin(true)))).ok(), Some(true));
+        assert_eq!(is_private(Ok(Some(origin(false)))).ok(), Some(false));
+        assert_eq!(is_private(Ok(None)).ok(), Some(false));
+    }
+
+    #[test]
+    fn is_private_fails_closed() {
+        let lookup = Err(Error::NetError(net::err(ErrCode::TIMEOUT, "rt:route:1")));
+        match is_private(lookup) {
+            Err(response) => assert_eq!(response.status, Some(status::ServiceUnavailable)),
+            Ok(_) => panic!("Expected a failed origin lookup to be refused"),
+        }
+    }
+
+    #[test]
+    fn cacheable_packages() {
+        assert!(cacheable(&ident("core/redis/3.0.7/20160614231131"), false));
+        assert!(!cacheable(&ident("acme/secret/1.0.0/20161010101010"), true));
+        assert!(!cacheable(&ident("core/redis"), false));
+        assert!(!cacheable(&ident("acme/secret"), true));
+    }
+
+    #[test]
+    fn search_page_hides_invisible_origins() {
+        let idents = vec![ident("acme/secret/1.0.0/20161010101010"),
+                          ident("core/redis/3.0.1/20160512012734"),
+                          ident("acme/secret/1.1.0/20161012101010"),
+                          ident("core/redis/3.0.7/20160614231131"),
+                          ident("core/redis-cli/3.0.7/20160614231131")];
+        let mut page = SearchPage::new(1, 1, public);
+        let mut more = false;
+        for ident in idents.clone() {
+            if page.push(ident) {
+                more = true;
+                break;
+            }
+        }
+        assert!(more);
+        assert_eq!(page.idents, vec![ident("core/redis/3.0.7/20160614231131")]);
+
+        let mut page = SearchPage::new(0, 10, public);
+        for ident in idents {
+            assert!(!page.push(ident));
+        }
+        assert_eq!(page.idents.len(), 3);
+        assert!(page.idents.iter().all(|i| i.get_origin() == "core"));
+    }
+
+    #[test]
+    fn search_response_with_more_results() {
+        let packages = vec![ident("core/redis/3.0.7/20160614231131")];
+        let response = search_response(&packages, 50, 100, true);
+        assert_eq!(response.status, Some(status::PartialContent));
+        assert_eq!(response.headers.get_raw("Next-Range"), Some(&[b"101".to_vec()][..]));
+        assert_eq!(response.headers.get_raw("Content-Range"),
+                   Some(&[b"50..100".to_vec()][..]));
+    }
+
+    #[test]
+    fn search_response_on_last_page() {
+        let packages = vec![ident("core/redis/3.0.7/20160614231131")];
+        let response = search_response(&packages, 0, 50, false);
+        assert_eq!(response.status, Some(status::Ok));
+        assert!(response.headers.get_raw("Next-Range").is_none());
+    }
+}
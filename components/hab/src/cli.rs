@@ -16,11 +16,14 @@ use std::path::Path;
 use std::result;
 use std::str::FromStr;
 
-use clap::{App, AppSettings, Arg};
+use clap::{App, AppSettings, Arg, ArgGroup};
+use hcore::crypto::artifact::VerifyPolicy;
 use hcore::crypto::keys::PairType;
 use regex::Regex;
 use url::Url;
 
+use output::OutputFormat;
+
 pub fn get() -> App<'static, 'static> {
     let alias_apply = sub_config_apply()
         .about("Alias for 'config apply'")
@@ -42,6 +45,8 @@ pub fn get() -> App<'static, 'static> {
         (author: "\nAuthors: The Habitat Maintainers <humans@habitat.sh>\n")
         (@setting VersionlessSubcommands)
         (@setting ArgRequiredElseHelp)
+        (@arg OUTPUT_FORMAT: --format +takes_value +global {valid_format}
+            "Output format of results and errors, one of text or json (default: text)")
         (@subcommand cli =>
             (about: "Commands relating to Habitat runtime config")
             (aliases: &["cl"])
@@ -58,6 +63,16 @@ pub fn get() -> App<'static, 'static> {
             (about: "Commands relating to Habitat files")
             (aliases: &["f", "fi", "fil"])
             (@setting ArgRequiredElseHelp)
+            (@subcommand decrypt =>
+                (about: "Decrypts a file encrypted with 'hab file encrypt' using a secret user or \
+                    service key")
+                (aliases: &["d", "de", "dec", "decr", "decry", "decryp"])
+                (@arg FILE: +required {file_exists} "Path to the encrypted file on disk")
+                (@arg OUTPUT: -o --output +takes_value
+                    "Path to write the decrypted file to (default: FILE without its .box \
+                    extension)")
+            )
+            (subcommand: sub_file_encrypt())
             (@subcommand upload =>
                 (about: "Upload a file to the supervisor ring.")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -76,7 +91,7 @@ pub fn get() -> App<'static, 'static> {
             )
         )
         (@subcommand origin =>
-            (about: "Commands relating to Habitat origin keys")
+            (about: "Commands relating to Habitat origins and origin keys")
             (aliases: &["o", "or", "ori", "orig", "origi"])
             (@setting ArgRequiredElseHelp)
             (@subcommand key =>
@@ -97,16 +112,54 @@ pub fn get() -> App<'static, 'static> {
                     (@arg PAIR_TYPE: -t --type +takes_value +required {valid_pair_type}
                     "Export either the `public' or `secret' key")
                 )
+                (@subcommand fingerprint =>
+                    (about: "Outputs the fingerprint of a public origin key as a trust store \
+                        entry")
+                    (aliases: &["f", "fi", "fin", "fing", "finge", "finger", "fingerp",
+                        "fingerpr", "fingerpri", "fingerprin"])
+                    (@arg ORIGIN: +required +takes_value "The origin name")
+                    (@arg REVISION: "The key revision (default: the latest revision)")
+                )
                 (@subcommand generate =>
                     (about: "Generates a Habitat origin key")
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg ORIGIN: "The origin name")
+                    (@arg ENCRYPT: -e --encrypt
+                        "Encrypt the secret key with a passphrase, prompted for unless \
+                        HAB_KEY_PASSPHRASE is set")
+                    (@arg EXPIRE_DAYS: --expire +takes_value {valid_numeric}
+                        "Number of days until the key expires (default: never)")
                 )
                 (@subcommand import =>
                     (about: "Reads a stdin stream containing a public or secret origin key \
                         contents and writes the key to disk")
                     (aliases: &["i", "im", "imp", "impo", "impor"])
                 )
+                (@subcommand list =>
+                    (about: "Lists the revisions of an origin key in HAB_CACHE_KEY_PATH and \
+                        whether each is valid, expired or revoked")
+                    (aliases: &["l", "li", "lis"])
+                    (@arg ORIGIN: "The origin name")
+                )
+                (@subcommand revoke =>
+                    (about: "Revokes an origin key revision, signing the revocation with the \
+                        latest origin key")
+                    (aliases: &["r", "re", "rev", "revo", "revok"])
+                    (@arg ORIGIN: +required +takes_value "The origin name")
+                    (@arg REVISION: +required +takes_value "The key revision to revoke")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
+                (@subcommand rotate =>
+                    (about: "Generates a new origin key, uploads it and revokes the previous \
+                        latest origin key")
+                    (aliases: &["ro", "rot", "rota", "rotat"])
+                    (@arg ORIGIN: +required +takes_value "The origin name")
+                    (@arg WITH_SECRET: -s --secret
+                        "Upload the new secret key in addition to the public key")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
                 (@subcommand upload =>
                     (@group upload =>
                         (@attributes +required)
@@ -124,6 +177,56 @@ pub fn get() -> App<'static, 'static> {
                     (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
                 )
             )
+            (@subcommand delete =>
+                (about: "Deletes an origin which has no packages and no members besides its owner")
+                (aliases: &["de", "del", "dele", "delet"])
+                (@arg ORIGIN: +required +takes_value "The origin name")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+            )
+            (@subcommand invitations =>
+                (about: "Commands relating to pending origin invitations")
+                (aliases: &["i", "in", "inv", "invi", "invit"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand list =>
+                    (about: "Lists the pending invitations of an origin")
+                    (aliases: &["l", "li", "lis"])
+                    (@arg ORIGIN: +required +takes_value "The origin name")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
+                (@subcommand rescind =>
+                    (about: "Withdraws a pending invitation to an origin")
+                    (aliases: &["r", "re", "res", "resc", "resci", "rescin"])
+                    (@arg ORIGIN: +required +takes_value "The origin name")
+                    (@arg INVITATION_ID: +required +takes_value {valid_numeric}
+                        "The id of the invitation, as shown by 'hab origin invitations list'")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
+            )
+            (@subcommand member =>
+                (about: "Commands relating to origin members")
+                (aliases: &["m", "me", "mem", "memb", "membe"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand remove =>
+                    (about: "Removes a member from an origin, or leaves an origin when given \
+                        your own user name")
+                    (aliases: &["r", "re", "rem", "remo", "remov"])
+                    (@arg ORIGIN: +required +takes_value "The origin name")
+                    (@arg USERNAME: +required +takes_value "The user name of the member")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
+            )
+            (@subcommand transfer =>
+                (about: "Transfers ownership of an origin to one of its members")
+                (aliases: &["t", "tr", "tra", "tran", "trans", "transf", "transfe"])
+                (@arg ORIGIN: +required +takes_value "The origin name")
+                (@arg USERNAME: +required +takes_value "The user name of the new owner")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+            )
         )
         (@subcommand pkg =>
             (about: "Commands relating to Habitat packages")
@@ -132,14 +235,66 @@ pub fn get() -> App<'static, 'static> {
             (@subcommand binlink =>
                 (about: "Creates a symlink for a package binary in a common 'PATH' location")
                 (aliases: &["bi", "bin", "binl", "binli", "binlin"])
-                (@arg PKG_IDENT: +required +takes_value
+                (@arg PKG_IDENT: +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
-                (@arg BINARY: +required +takes_value
+                (@arg BINARY: +takes_value
                     "The command to symlink (ex: bash)")
+                (@arg ALL: -a --all "Symlink every binary in the 'PATH' of the package")
+                (@arg LIST: -l --list
+                    "List the symlinks into installed packages instead of creating one")
                 (@arg DEST_DIR: -d --dest +takes_value
                     "Sets the destination directory (default: /bin)")
             )
             (subcommand: sub_pkg_build())
+            (@subcommand bundle =>
+                (about: "Bundles a package, its dependencies and their origin keys into a \
+                    tarball, to install the package where no Depot can be reached")
+                (aliases: &["bun", "bund", "bundl"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg OUTPUT: -o --output +takes_value
+                    "The path of the bundle to write (default: \
+                    <origin>-<name>-<version>-<release>-bundle.tar)")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg AUTH_TOKEN: -z --auth +takes_value
+                    "Authentication token for the Depot, required for packages of private \
+                    origins")
+            )
+            (@subcommand countersign =>
+                (about: "Adds an origin key signature to a signed Habitat Artifact")
+                (aliases: &["co", "cou", "coun", "count", "counte", "counter"])
+                (@arg ORIGIN: --origin +takes_value "Origin key used to create signature")
+                (@arg SOURCE: +required {file_exists}
+                    "A path to a signed Habitat Artifact \
+                    (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg DEST: +required
+                    "The destination path to the countersigned Habitat Artifact \
+                    (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+            )
+            (@subcommand diff =>
+                (about: "Compares the dependencies, exposed ports, default configuration, hooks \
+                    and files of two packages")
+                (aliases: &["di", "dif"])
+                (@arg PKG_A: +required +takes_value
+                    "A package identifier of an installed package (ex: core/redis/3.0.7) or a \
+                    path to a Habitat Artifact")
+                (@arg PKG_B: +required +takes_value
+                    "A package identifier of an installed package (ex: core/redis/3.2.1) or a \
+                    path to a Habitat Artifact")
+                (@arg REMOTE: -r --remote
+                    "Download the package identifiers from the Depot instead of looking them up \
+                    among the installed packages")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg AUTH_TOKEN: -z --auth +takes_value
+                    "Authentication token for the Depot, for packages of private origins")
+            )
+            (@subcommand doctor =>
+                (about: "Reports installed packages with conflicting releases of a dependency \
+                    on their runtime path, and dependencies needed in several releases")
+                (aliases: &["d", "do", "doc", "doct", "docto"])
+                (@arg PKG_IDENT: +takes_value
+                    "Only check releases of a package (ex: core/redis, core/redis/3.0.7)")
+            )
             (@subcommand exec =>
                 (about: "Executes a command using the 'PATH' context of an installed package")
                 (aliases: &["exe"])
@@ -164,12 +319,70 @@ pub fn get() -> App<'static, 'static> {
             )
             (subcommand: sub_pkg_install().aliases(
                 &["i", "in", "ins", "inst", "insta", "instal"]))
+            (@subcommand info =>
+                (about: "Displays the metadata, dependencies and build provenance of a package")
+                (aliases: &["inf"])
+                (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
+                    "A package identifier of an installed package (ex: core/redis) or a path \
+                    to a Habitat Artifact \
+                    (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg REMOTE: -r --remote
+                    "Look the package identifier up in the Depot instead of the installed \
+                    packages")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg AUTH_TOKEN: -z --auth +takes_value
+                    "Authentication token for the Depot, for packages of private origins")
+            )
+            (@subcommand list =>
+                (about: "Lists installed packages, marking those in use by running services")
+                (aliases: &["l", "li", "lis"])
+                (@arg PKG_IDENT: +takes_value
+                    "Only list releases of a package (ex: core/redis, core/redis/3.0.7)")
+            )
+            (@subcommand lock =>
+                (about: "Writes a lockfile of the latest releases of packages and of all their \
+                    dependencies, for installing exactly those releases later")
+                (aliases: &["lo", "loc"])
+                (@arg PKG_IDENT: +required +multiple
+                    "One or more package identifiers (ex: core/redis, core/nginx/1.10.1)")
+                (@arg OUTPUT: -o --output +takes_value
+                    "The path of the lockfile to write (default: hab.lock)")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg AUTH_TOKEN: -z --auth +takes_value
+                    "Authentication token for the Depot, required for packages of private \
+                    origins")
+            )
             (@subcommand path =>
                 (about: "Prints the path to a specific installed release of a package")
                 (aliases: &["p", "pa", "pat"])
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
+            (@subcommand prune =>
+                (about: "Removes all but the newest releases of installed packages which are not \
+                    needed by other installed packages or running services")
+                (aliases: &["pr", "pru", "prun"])
+                (@arg PKG_IDENT: +takes_value
+                    "Only prune releases of a package (ex: core/redis, core/redis/3.0.7)")
+                (@arg KEEP: -k --keep +takes_value {valid_numeric}
+                    "Number of newest releases of each package to keep (default: 1)")
+            )
+            (@subcommand search =>
+                (about: "Searches a Depot for packages matching a query")
+                (aliases: &["se", "sea", "sear", "searc"])
+                (@arg SEARCH_TERM: +required +multiple
+                    "One or more search terms; plain words match package identifiers and \
+                    metadata, while field terms match a single field \
+                    (ex: redis, license:mit, port:6379, maintainer:acme, upstream:redis.io)")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg SORT: -s --sort +takes_value
+                    "Sort results by one of ident, origin, name, version or release \
+                    (default: ident)")
+                (@arg ORDER: -o --order +takes_value
+                    "Order results ascending or descending, one of asc or desc (default: asc)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value
+                    "Authentication token for the Depot, includes packages of private origins")
+            )
             (@subcommand sign =>
                 (about: "Signs an archive with an origin key, generating a Habitat Artifact")
                 (aliases: &["s", "si", "sig"])
@@ -181,6 +394,28 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Removes an installed package")
+                (aliases: &["un", "uni", "unin", "unins", "uninst", "uninsta", "uninstal"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier, the newest installed release is removed unless a \
+                    release is given (ex: core/redis, core/redis/3.0.7/21120102031201)")
+                (@arg FORCE: -f --force
+                    "Remove the package even if other installed packages depend on it or a \
+                    running service uses it")
+            )
+            (@subcommand unlink =>
+                (about: "Removes symlinks into installed packages from a common 'PATH' location")
+                (aliases: &["unl", "unli", "unlin"])
+                (@arg PKG_IDENT: +takes_value
+                    "Only remove symlinks into releases of a package (ex: core/busybox-static)")
+                (@arg BINARY: -b --binary +takes_value
+                    "Only remove the symlink of a command (ex: bash)")
+                (@arg ALL: -a --all
+                    "Remove every symlink into installed packages")
+                (@arg DEST_DIR: -d --dest +takes_value
+                    "Sets the directory to remove symlinks from (default: /bin)")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to a Depot")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -193,6 +428,9 @@ pub fn get() -> App<'static, 'static> {
             (@subcommand verify =>
                 (about: "Verifies a Habitat Artifact with an origin key")
                 (aliases: &["v", "ve", "ver", "veri", "verif"])
+                (@arg POLICY: --policy +takes_value {valid_verify_policy}
+                    "Which signatures must verify: 'any', 'all' (default) or \
+                    '<k>-of:<key>,<key>,...'")
                 (@arg SOURCE: +required {file_exists}
                     "A path to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -220,6 +458,22 @@ pub fn get() -> App<'static, 'static> {
                     (about: "Generates a Habitat ring key")
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg RING: +required +takes_value "Ring key name")
+                    (@arg EXPIRE_DAYS: --expire +takes_value {valid_numeric}
+                        "Number of days until the key expires (default: never)")
+                )
+                (@subcommand rotate =>
+                    (about: "Generates a new revision of a Habitat ring key and distributes it \
+                    to the supervisor ring, encrypted for each service group")
+                    (aliases: &["r", "ro", "rot", "rota", "rotat"])
+                    (@arg RING: +required +takes_value "Ring key name")
+                    (@arg SERVICE_GROUP: +required +takes_value +multiple {valid_service_group}
+                        "One or more target service groups, which must include every service \
+                        group in the ring (ex: redis.default)")
+                    (@arg ORG: --org +takes_value "Name of service organization")
+                    (@arg USER: --user +takes_value "Name of the user key")
+                    (@arg PEER: -p --peer +takes_value
+                        "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                        (default: 127.0.0.1:9634)")
                 )
             )
         )
@@ -236,6 +490,8 @@ pub fn get() -> App<'static, 'static> {
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg SERVICE_GROUP: +required +takes_value {valid_service_group} "Target service group (ex: redis.default)")
                     (@arg ORG: "The service organization")
+                    (@arg EXPIRE_DAYS: --expire +takes_value {valid_numeric}
+                        "Number of days until the key expires (default: never)")
                 )
             )
         )
@@ -259,6 +515,8 @@ pub fn get() -> App<'static, 'static> {
                     (about: "Generates a Habitat user key")
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg USER: +required +takes_value "Name of the user key")
+                    (@arg EXPIRE_DAYS: --expire +takes_value {valid_numeric}
+                        "Number of days until the key expires (default: never)")
                 )
             )
         )
@@ -307,6 +565,30 @@ fn sub_config_apply() -> App<'static, 'static> {
     )
 }
 
+fn sub_file_encrypt() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand encrypt =>
+        (about: "Encrypts a file from a user to another user or to a service")
+        (aliases: &["e", "en", "enc", "encr", "encry", "encryp"])
+        (@arg FILE: +required {file_exists} "Path to local file on disk")
+        (@arg USER: --from +takes_value "Name of the sender's user key")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg OUTPUT: -o --output +takes_value
+            "Path to write the encrypted file to (default: FILE.box)")
+    );
+    sub.arg(Arg::with_name("TO_USER")
+            .help("Name of the recipient's user key")
+            .long("to-user")
+            .takes_value(true))
+        .arg(Arg::with_name("TO_SERVICE")
+            .help("Service group whose service key is the recipient (ex: redis.default)")
+            .long("to-service")
+            .takes_value(true)
+            .validator(valid_service_group))
+        .group(ArgGroup::with_name("recipient")
+            .args(&["TO_USER", "TO_SERVICE"])
+            .required(true))
+}
+
 fn sub_pkg_build() -> App<'static, 'static> {
     let sub = clap_app!(@subcommand build =>
         (about: "Builds a Plan using a Studio")
@@ -337,9 +619,19 @@ fn sub_pkg_install() -> App<'static, 'static> {
     clap_app!(@subcommand install =>
         (about: "Installs a Habitat package from a Depot or locally from a Habitat Artifact")
         (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
-        (@arg PKG_IDENT_OR_ARTIFACT: +required +multiple
+        (@arg AUTH_TOKEN: -z --auth +takes_value
+            "Authentication token for the Depot, required for packages of private origins")
+        (@arg STRICT: --strict
+            "Refuse artifacts signed by origin keys not pinned in the trust store at \
+            HAB_TRUST_STORE (default: /hab/etc/trust.toml)")
+        (@arg OFFLINE: --offline
+            "Install from the artifact and key caches only, without contacting a Depot")
+        (@arg LOCKFILE: --lockfile +takes_value {file_exists}
+            "Install exactly the releases listed in a lockfile written by 'hab pkg lock'")
+        (@arg PKG_IDENT_OR_ARTIFACT: +multiple
             "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths \
-            to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+            to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart) \
+            or bundle (ex: /home/acme-redis-3.0.7-21120102031201-bundle.tar)")
     )
 }
 
@@ -359,6 +651,13 @@ fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_format(val: String) -> result::Result<(), String> {
+    match OutputFormat::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("FORMAT: {} is invalid, must be one of (text, json)", &val)),
+    }
+}
+
 fn valid_pair_type(val: String) -> result::Result<(), String> {
     match PairType::from_str(&val) {
         Ok(_) => Ok(()),
@@ -369,6 +668,13 @@ fn valid_pair_type(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_verify_policy(val: String) -> result::Result<(), String> {
+    match VerifyPolicy::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("POLICY: {}", e)),
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     let regex = Regex::new(r"([A-Za-z_0-9]+)\.([A-Za-z_0-9]+)").unwrap();
     if regex.is_match(&val) {
@@ -384,3 +690,10 @@ fn valid_url(val: String) -> result::Result<(), String> {
         Err(_) => Err(format!("URL: '{}' is not valid", &val)),
     }
 }
+
+fn valid_numeric(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid number", &val)),
+    }
+}
@@ -55,3 +55,75 @@ pub mod upload {
         Ok(())
     }
 }
+
+pub mod encrypt {
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::path::Path;
+
+    use ansi_term::Colour::{Blue, Green, Yellow};
+    use hcore::crypto::BoxKeyPair;
+
+    use error::Result;
+
+    /// Encrypts a file from the sender's box key to the recipient's box key, which can belong to
+    /// either a user or a service, writing a `BOX-1` payload to `dst`.
+    pub fn start(sender: &BoxKeyPair,
+                 recipient: &BoxKeyPair,
+                 src: &Path,
+                 dst: &Path)
+                 -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Encrypting file {}", src.display())));
+        let mut data = Vec::new();
+        try!(try!(File::open(src)).read_to_end(&mut data));
+        let payload = try!(sender.encrypt(&data, recipient));
+        println!("{} from {} to {}",
+                 Green.bold().paint("☛ Encrypting"),
+                 sender.name_with_rev(),
+                 recipient.name_with_rev());
+        try!(try!(File::create(dst)).write_all(&payload));
+        println!("{}",
+                 Blue.paint(format!("★ Wrote encrypted file to {}.", dst.display())));
+        Ok(())
+    }
+}
+
+pub mod decrypt {
+    use std::fs::{self, File, OpenOptions, Permissions};
+    use std::io::Write;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+    use std::path::Path;
+
+    use ansi_term::Colour::{Blue, Green, Yellow};
+    use hcore::crypto::BoxKeyPair;
+
+    use error::Result;
+
+    /// Decrypts a `BOX-1` payload with the recipient's secret box key and the sender's public box
+    /// key from the key cache. The decrypted file is only readable by its owner, as it usually
+    /// holds a credential.
+    pub fn start(payload: &[u8], src: &Path, dst: &Path, cache: &Path) -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Decrypting file {}", src.display())));
+        let (sender, recipient) = try!(BoxKeyPair::payload_key_names(payload));
+        let data = try!(BoxKeyPair::decrypt(payload, cache));
+        println!("{} from {} to {}",
+                 Green.bold().paint("☑ Decrypted"),
+                 sender,
+                 recipient);
+        let mut file: File = try!(OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(dst));
+        // The mode only applies when the file is created, so an existing file is tightened
+        // before the decrypted data is written to it
+        try!(fs::set_permissions(dst, Permissions::from_mode(0o600)));
+        try!(file.write_all(&data));
+        println!("{}",
+                 Blue.paint(format!("★ Wrote decrypted file to {}.", dst.display())));
+        Ok(())
+    }
+}
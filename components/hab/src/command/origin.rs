@@ -12,6 +12,102 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod delete {
+    use ansi_term::Colour::{Blue, Yellow};
+    use depot_client::Client;
+
+    use error::Result;
+
+    pub fn start(depot: &str, token: &str, origin: &str) -> Result<()> {
+        let depot_client = try!(Client::new(depot, None));
+        println!("{}",
+                 Yellow.bold().paint(format!("» Deleting origin {}", origin)));
+        try!(depot_client.delete_origin(origin, token));
+        println!("{}", Blue.paint(format!("★ Deleted origin {}.", origin)));
+        Ok(())
+    }
+}
+
+pub mod invitations {
+    pub mod list {
+        use ansi_term::Colour::Yellow;
+        use depot_client::Client;
+
+        use error::Result;
+
+        pub fn start(depot: &str, token: &str, origin: &str) -> Result<()> {
+            let depot_client = try!(Client::new(depot, None));
+            println!("{}",
+                     Yellow.bold().paint(format!("» Listing pending invitations for {}", origin)));
+            let invitations = try!(depot_client.list_origin_invitations(origin, token));
+            if invitations.is_empty() {
+                println!("No pending invitations.");
+            }
+            for invitation in invitations.iter() {
+                println!("{}\t{}", invitation.get_id(), invitation.get_account_name());
+            }
+            Ok(())
+        }
+    }
+
+    pub mod rescind {
+        use ansi_term::Colour::{Blue, Yellow};
+        use depot_client::Client;
+
+        use error::Result;
+
+        pub fn start(depot: &str, token: &str, origin: &str, invitation_id: u64) -> Result<()> {
+            let depot_client = try!(Client::new(depot, None));
+            println!("{}",
+                     Yellow.bold().paint(format!("» Rescinding invitation {} to {}",
+                                                 invitation_id,
+                                                 origin)));
+            try!(depot_client.rescind_origin_invitation(origin, invitation_id, token));
+            println!("{}",
+                     Blue.paint(format!("★ Rescinded invitation {}.", invitation_id)));
+            Ok(())
+        }
+    }
+}
+
+pub mod member {
+    pub mod remove {
+        use ansi_term::Colour::{Blue, Yellow};
+        use depot_client::Client;
+
+        use error::Result;
+
+        pub fn start(depot: &str, token: &str, origin: &str, username: &str) -> Result<()> {
+            let depot_client = try!(Client::new(depot, None));
+            println!("{}",
+                     Yellow.bold().paint(format!("» Removing {} from {}", username, origin)));
+            try!(depot_client.remove_origin_member(origin, username, token));
+            println!("{}",
+                     Blue.paint(format!("★ Removed {} from {}.", username, origin)));
+            Ok(())
+        }
+    }
+}
+
+pub mod transfer {
+    use ansi_term::Colour::{Blue, Yellow};
+    use depot_client::Client;
+
+    use error::Result;
+
+    pub fn start(depot: &str, token: &str, origin: &str, username: &str) -> Result<()> {
+        let depot_client = try!(Client::new(depot, None));
+        println!("{}",
+                 Yellow.bold().paint(format!("» Transferring ownership of {} to {}",
+                                             origin,
+                                             username)));
+        try!(depot_client.transfer_origin(origin, username, token));
+        println!("{}",
+                 Blue.paint(format!("★ {} is now owned by {}.", origin, username)));
+        Ok(())
+    }
+}
+
 pub mod key {
 
     use std::fs::File;
@@ -20,6 +116,7 @@ pub mod key {
 
     use error::{Error, Result};
     use hcore;
+    use hcore::crypto::keys::base_key_version;
 
     // shared between origin::key::upload and origin::key::upload_latest
     fn get_name_with_rev(keyfile: &Path, expected_vsn: &str) -> Result<String> {
@@ -29,7 +126,7 @@ pub mod key {
         let _ = match lines.next() {
             Some(val) => {
                 let val = try!(val);
-                if &val != expected_vsn {
+                if base_key_version(&val) != expected_vsn {
                     let msg = format!("Unsupported version: {}", &val);
                     return Err(Error::HabitatCore(hcore::Error::CryptoError(msg)));
                 }
@@ -146,6 +243,25 @@ pub mod key {
         }
     }
 
+    pub mod fingerprint {
+        use std::path::Path;
+
+        use hcore::crypto::SigKeyPair;
+
+        use error::Result;
+
+        /// Prints the fingerprint of a public origin key as a line for the `keys` table of a
+        /// trust store. Without a revision, the latest origin key is used.
+        pub fn start(origin: &str, revision: Option<&str>, cache: &Path) -> Result<()> {
+            let pair = match revision {
+                Some(rev) => try!(SigKeyPair::get_pair_for(&format!("{}-{}", origin, rev), cache)),
+                None => try!(SigKeyPair::get_latest_pair_for(origin, cache)),
+            };
+            println!("{} = \"{}\"", pair.name_with_rev(), try!(pair.fingerprint()));
+            Ok(())
+        }
+    }
+
     pub mod generate {
         use std::path::Path;
 
@@ -165,6 +281,81 @@ pub mod key {
         }
     }
 
+    pub mod list {
+        use std::path::Path;
+
+        use hcore::crypto::SigKeyPair;
+        use hcore::crypto::keys::revocation;
+        use rustc_serialize::json::{Json, ToJson};
+
+        use error::Result;
+        use output;
+
+        /// A revision of an origin key in the key cache.
+        pub struct KeyStatus {
+            pub name_with_rev: String,
+            pub public: bool,
+            pub secret: bool,
+            pub revoked: bool,
+            pub expired: bool,
+            pub expires: Option<String>,
+        }
+
+        impl ToJson for KeyStatus {
+            fn to_json(&self) -> Json {
+                output::object(vec![("name_with_rev", self.name_with_rev.to_json()),
+                                    ("public", self.public.to_json()),
+                                    ("secret", self.secret.to_json()),
+                                    ("revoked", self.revoked.to_json()),
+                                    ("expired", self.expired.to_json()),
+                                    ("expires", self.expires.to_json())])
+            }
+        }
+
+        /// Prints every revision of an origin key in the key cache, newest first, with its status
+        /// and which halves of the key pair are present.
+        pub fn start(origin: &str, cache: &Path) -> Result<Vec<KeyStatus>> {
+            let pairs = try!(SigKeyPair::get_pairs_for(origin, cache));
+            if pairs.is_empty() {
+                println!("No origin keys found for {}", origin);
+                return Ok(vec![]);
+            }
+            let mut keys = Vec::new();
+            for pair in pairs {
+                let name_with_rev = pair.name_with_rev();
+                // A secret key may be present but encrypted, so look for the key files rather
+                // than the loaded keys.
+                let key = KeyStatus {
+                    public: SigKeyPair::get_public_key_path(&name_with_rev, cache).is_ok(),
+                    secret: SigKeyPair::get_secret_key_path(&name_with_rev, cache).is_ok(),
+                    revoked: try!(revocation::is_revoked(&name_with_rev, cache)),
+                    expired: pair.is_expired(),
+                    expires: pair.expires.clone(),
+                    name_with_rev: name_with_rev,
+                };
+                let status = if key.revoked {
+                    "revoked".to_string()
+                } else {
+                    match key.expires {
+                        Some(ref expires) if key.expired => format!("expired at {}", expires),
+                        Some(ref expires) => format!("valid, expires at {}", expires),
+                        None => "valid".to_string(),
+                    }
+                };
+                let mut halves = Vec::new();
+                if key.public {
+                    halves.push("public");
+                }
+                if key.secret {
+                    halves.push("secret");
+                }
+                println!("{} ({}) {}", key.name_with_rev, halves.join(", "), status);
+                keys.push(key);
+            }
+            Ok(keys)
+        }
+    }
+
     pub mod import {
         use std::path::Path;
 
@@ -185,6 +376,78 @@ pub mod key {
         }
     }
 
+    pub mod revoke {
+        use std::path::Path;
+
+        use ansi_term::Colour::{Blue, Green, Yellow};
+        use depot_client::Client;
+        use hcore::crypto::{KeyRevocation, SigKeyPair};
+
+        use error::Result;
+
+        pub fn start(depot: &str,
+                     token: &str,
+                     origin: &str,
+                     revision: &str,
+                     cache: &Path)
+                     -> Result<()> {
+            let revoked = format!("{}-{}", origin, revision);
+            println!("{}",
+                     Yellow.bold().paint(format!("» Revoking origin key {}", &revoked)));
+            let signer = try!(SigKeyPair::get_latest_pair_for(origin, cache));
+            let revocation = try!(KeyRevocation::create(&revoked, &signer));
+            println!("{} {} with {}",
+                     Green.paint("☛ Signing revocation of"),
+                     &revoked,
+                     &signer.name_with_rev());
+            try!(revocation.write_file(cache));
+
+            let depot_client = try!(Client::new(depot, None));
+            println!("{} revocation of {}", Green.bold().paint("↑ Uploading"), &revoked);
+            try!(depot_client.put_origin_key_revocation(origin,
+                                                        revision,
+                                                        &revocation.to_string(),
+                                                        token));
+            println!("{}",
+                     Blue.paint(format!("★ Revoked origin key {}.", &revoked)));
+            Ok(())
+        }
+    }
+
+    pub mod rotate {
+        use std::path::Path;
+
+        use ansi_term::Colour::{Blue, Yellow};
+        use hcore::crypto::SigKeyPair;
+
+        use error::Result;
+
+        /// Generates a new origin key, publishes it, and revokes the key it replaces with a
+        /// revocation signed by the new key.
+        pub fn start(depot: &str,
+                     token: &str,
+                     origin: &str,
+                     with_secret: bool,
+                     cache: &Path)
+                     -> Result<()> {
+            let previous = try!(SigKeyPair::get_latest_pair_for(origin, cache));
+            println!("{}",
+                     Yellow.bold().paint(format!("» Rotating origin key {}",
+                                                 &previous.name_with_rev())));
+            let pair = try!(SigKeyPair::generate_pair_for_origin(origin, cache));
+            println!("{}",
+                     Blue.paint(format!("★ Generated origin key pair {}.",
+                                        &pair.name_with_rev())));
+            try!(super::upload_latest::start(depot, token, origin, with_secret, cache));
+            try!(super::revoke::start(depot, token, origin, &previous.rev, cache));
+            println!("{}",
+                     Blue.paint(format!("★ Rotated origin key {} to {}.",
+                                        &previous.name_with_rev(),
+                                        &pair.name_with_rev())));
+            Ok(())
+        }
+    }
+
     pub mod upload {
         use std::path::Path;
 
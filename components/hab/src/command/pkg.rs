@@ -15,16 +15,43 @@
 // Looking for `pkg::install`? That's in the `common` crate. You're welcome :)
 
 pub mod binlink {
+    //! Symlinks binaries of installed packages into a common 'PATH' location, `/bin` by default.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg binlink core/busybox-static bash
+    //! $ hab pkg binlink --all core/busybox-static
+    //! $ hab pkg binlink --list
+    //! $ hab pkg unlink core/busybox-static
+    //! ```
+    //!
+    //! A symlink is owned by Habitat when it points into `/hab/pkgs`. Only those symlinks are
+    //! listed, removed by `hab pkg unlink`, or re-pointed to the newest installed release of
+    //! their package after a release of it is installed. Removing the symlinks of every package
+    //! takes `hab pkg unlink --all`.
+
+    use std::cmp::Ordering;
+    use std::ffi::OsStr;
     use std::fs;
-    use std::path::Path;
     use std::os::unix;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::{Path, PathBuf};
 
-    use ansi_term::Colour::{Blue, Green, Yellow};
-    use hcore::package::{PackageIdent, PackageInstall};
+    use ansi_term::Colour::{Blue, Green, Red, Yellow};
+    use hcore::fs::PKG_PATH;
+    use hcore::package::{Identifiable, PackageIdent, PackageInstall};
 
     use error::{Error, Result};
     use exec::find_command_in_pkg;
 
+    /// A symlink which points into a release of an installed package.
+    pub struct Binlink {
+        pub link: PathBuf,
+        pub target: PathBuf,
+        pub ident: PackageIdent,
+    }
+
     pub fn start(ident: &PackageIdent,
                  binary: &str,
                  dest_path: &Path,
@@ -45,28 +72,337 @@ pub mod binlink {
                                                         binary.to_string())))
             }
         };
+        try!(create_dst_dir(&dst_path));
+        try!(link(&src, &dst));
+        println!("{}",
+                 Blue.paint(format!("★ Binary {} from {} symlinked to {}",
+                                    &binary,
+                                    &pkg_install.ident(),
+                                    &dst.display())));
+        Ok(())
+    }
+
+    /// Symlinks every executable file in the `PATH` directories of a package. Existing files
+    /// are only replaced when they are symlinks into another release of the same package.
+    pub fn start_all(ident: &PackageIdent, dest_path: &Path, fs_root_path: &Path) -> Result<()> {
+        let dst_path = fs_root_path.join(try!(dest_path.strip_prefix("/")));
+        println!("{}",
+                 Yellow.bold().paint(format!("» Symlinking all binaries from {} into {}",
+                                             &ident,
+                                             dst_path.display())));
+        let pkg_install = try!(PackageInstall::load(&ident, Some(fs_root_path)));
+        try!(create_dst_dir(&dst_path));
+        let mut count = 0;
+        for path in try!(pkg_install.paths()) {
+            let dir = fs_root_path.join(try!(path.strip_prefix("/")));
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+            for entry in entries {
+                let entry = try!(entry);
+                let executable = match fs::metadata(entry.path()) {
+                    Ok(m) => m.is_file() && m.permissions().mode() & 0o111 != 0,
+                    Err(_) => false,
+                };
+                if !executable {
+                    continue;
+                }
+                let dst = dst_path.join(entry.file_name());
+                if fs::symlink_metadata(&dst).is_ok() {
+                    match owner(&dst) {
+                        Some(ref owner) if owner.origin() == pkg_install.ident().origin() &&
+                                           owner.name() == pkg_install.ident().name() => (),
+                        Some(owner) => {
+                            println!("{} {}, it belongs to {}",
+                                     Green.paint("→ Skipping"),
+                                     dst.display(),
+                                     owner);
+                            continue;
+                        }
+                        None => {
+                            println!("{} {}, it is not a Habitat symlink",
+                                     Green.paint("→ Skipping"),
+                                     dst.display());
+                            continue;
+                        }
+                    }
+                }
+                try!(link(&path.join(entry.file_name()), &dst));
+                count += 1;
+            }
+        }
+        println!("{}",
+                 Blue.paint(format!("★ {} binaries from {} symlinked into {}",
+                                    count,
+                                    &pkg_install.ident(),
+                                    dst_path.display())));
+        Ok(())
+    }
+
+    /// Prints every symlink into an installed package, marking the ones whose target is gone.
+    pub fn list(dest_path: &Path, fs_root_path: &Path) -> Result<()> {
+        let dst_path = fs_root_path.join(try!(dest_path.strip_prefix("/")));
+        let binlinks = try!(binlinks(&dst_path));
+        if binlinks.is_empty() {
+            println!("No Habitat symlinks found in {}", dst_path.display());
+            return Ok(());
+        }
+        for binlink in binlinks.iter() {
+            let target = fs_root_path.join(try!(binlink.target.strip_prefix("/")));
+            if target.exists() {
+                println!("{} -> {}", binlink.link.display(), binlink.target.display());
+            } else {
+                println!("{} -> {} {}",
+                         binlink.link.display(),
+                         binlink.target.display(),
+                         Red.paint("(broken)"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the symlinks into installed packages, optionally only those into releases of a
+    /// package or only the symlink of one binary. Other files are never touched.
+    pub fn unlink(ident: Option<&PackageIdent>,
+                  binary: Option<&str>,
+                  dest_path: &Path,
+                  fs_root_path: &Path)
+                  -> Result<()> {
+        let dst_path = fs_root_path.join(try!(dest_path.strip_prefix("/")));
+        let mut count = 0;
+        for binlink in try!(binlinks(&dst_path)) {
+            if !ident.map_or(true, |i| binlink.ident.satisfies(i)) {
+                continue;
+            }
+            if !binary.map_or(true, |b| binlink.link.file_name() == Some(OsStr::new(b))) {
+                continue;
+            }
+            try!(fs::remove_file(&binlink.link));
+            println!("{} {}", Green.bold().paint("✗ Removed"), binlink.link.display());
+            count += 1;
+        }
+        println!("{}",
+                 Blue.paint(format!("★ Removed {} symlinks from {}", count, dst_path.display())));
+        Ok(())
+    }
+
+    /// Re-points symlinks into the given packages to the same binary in the newest installed
+    /// release of the package, if that release is newer and still has the binary. A symlink
+    /// which can't be re-pointed is reported and left as it is.
+    pub fn relink(idents: &[PackageIdent], dest_path: &Path, fs_root_path: &Path) -> Result<()> {
+        let dst_path = fs_root_path.join(try!(dest_path.strip_prefix("/")));
+        for binlink in try!(binlinks(&dst_path)) {
+            if !idents.iter()
+                .any(|i| i.origin == binlink.ident.origin && i.name == binlink.ident.name) {
+                continue;
+            }
+            let latest = PackageIdent::new(binlink.ident.origin.clone(),
+                                           binlink.ident.name.clone(),
+                                           None,
+                                           None);
+            let latest = match PackageInstall::load(&latest, Some(fs_root_path)) {
+                Ok(install) => install.ident().clone(),
+                Err(_) => continue,
+            };
+            match latest.partial_cmp(&binlink.ident) {
+                Some(Ordering::Greater) => (),
+                _ => continue,
+            }
+            let binary = match binlink.target.strip_prefix(&release_path(&binlink.ident)) {
+                Ok(binary) => binary.to_path_buf(),
+                Err(_) => continue,
+            };
+            let target = release_path(&latest).join(binary);
+            if !fs_root_path.join(try!(target.strip_prefix("/"))).is_file() {
+                continue;
+            }
+            match link(&target, &binlink.link) {
+                Ok(()) => {
+                    println!("{} {} to {}",
+                             Green.bold().paint("↻ Relinked"),
+                             binlink.link.display(),
+                             &latest)
+                }
+                Err(err) => {
+                    println!("{} {} to {}: {}",
+                             Red.bold().paint("✗ Failed to relink"),
+                             binlink.link.display(),
+                             &latest,
+                             err)
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the symlinks in a directory which point into installed packages.
+    pub fn binlinks(dst_path: &Path) -> Result<Vec<Binlink>> {
+        let mut binlinks = Vec::new();
+        let entries = match fs::read_dir(dst_path) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(binlinks),
+        };
+        for entry in entries {
+            let link = try!(entry).path();
+            let target = match fs::read_link(&link) {
+                Ok(target) => target,
+                Err(_) => continue,
+            };
+            if let Some(ident) = target_ident(&target) {
+                binlinks.push(Binlink {
+                    link: link,
+                    target: target,
+                    ident: ident,
+                });
+            }
+        }
+        binlinks.sort_by(|a, b| a.link.cmp(&b.link));
+        Ok(binlinks)
+    }
+
+    /// Returns the package a symlink points into, if it is owned by Habitat.
+    fn owner(link: &Path) -> Option<PackageIdent> {
+        fs::read_link(link).ok().and_then(|target| target_ident(&target))
+    }
+
+    /// Returns the release of a package a path in `/hab/pkgs` belongs to.
+    fn target_ident(target: &Path) -> Option<PackageIdent> {
+        let rel = match target.strip_prefix(&Path::new("/").join(PKG_PATH)) {
+            Ok(rel) => rel,
+            Err(_) => return None,
+        };
+        let parts: Vec<String> = rel.components()
+            .take(4)
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        if parts.len() < 4 {
+            return None;
+        }
+        Some(PackageIdent::new(parts[0].clone(),
+                               parts[1].clone(),
+                               Some(parts[2].clone()),
+                               Some(parts[3].clone())))
+    }
+
+    fn release_path(ident: &PackageIdent) -> PathBuf {
+        Path::new("/")
+            .join(PKG_PATH)
+            .join(&ident.origin)
+            .join(&ident.name)
+            .join(ident.version().unwrap_or(""))
+            .join(ident.release().unwrap_or(""))
+    }
+
+    fn create_dst_dir(dst_path: &Path) -> Result<()> {
         if !dst_path.is_dir() {
             println!("{} parent directory {}",
                      Green.paint("Ω Creating"),
                      dst_path.display());
             try!(fs::create_dir_all(&dst_path))
         }
-        match fs::read_link(&dst) {
+        Ok(())
+    }
+
+    /// Points `dst` at `src`, replacing a symlink which points elsewhere.
+    fn link(src: &Path, dst: &Path) -> Result<()> {
+        match fs::read_link(dst) {
             Ok(path) => {
                 if path != src {
-                    try!(fs::remove_file(&dst));
-                    try!(unix::fs::symlink(&src, &dst));
+                    try!(fs::remove_file(dst));
+                    try!(unix::fs::symlink(src, dst));
                 }
             }
-            Err(_) => try!(unix::fs::symlink(&src, &dst)),
+            Err(_) => try!(unix::fs::symlink(src, dst)),
         }
-        println!("{}",
-                 Blue.paint(format!("★ Binary {} from {} symlinked to {}",
-                                    &binary,
-                                    &pkg_install.ident(),
-                                    &dst.display())));
         Ok(())
     }
+
+    #[cfg(test)]
+    mod test {
+        use std::fs::{self, File};
+        use std::os::unix;
+        use std::path::{Path, PathBuf};
+        use std::str::FromStr;
+
+        use hcore::package::PackageIdent;
+        use tempdir::TempDir;
+
+        use super::{binlinks, relink, target_ident};
+
+        fn fake_install(root: &TempDir, ident: &str, binaries: &[&str]) {
+            let bin = root.path().join("hab/pkgs").join(ident).join("bin");
+            fs::create_dir_all(&bin).unwrap();
+            for binary in binaries {
+                File::create(bin.join(binary)).unwrap();
+            }
+        }
+
+        fn fake_link(root: &TempDir, binary: &str, target: &str) -> PathBuf {
+            let dst = root.path().join("bin");
+            fs::create_dir_all(&dst).unwrap();
+            unix::fs::symlink(target, dst.join(binary)).unwrap();
+            dst.join(binary)
+        }
+
+        #[test]
+        fn target_ident_of_package_paths() {
+            let target = Path::new("/hab/pkgs/core/busybox-static/1.24.2/20160708162350/bin/ls");
+            assert_eq!(target_ident(target),
+                       Some(PackageIdent::from_str("core/busybox-static/1.24.2/20160708162350")
+                           .unwrap()));
+            assert_eq!(target_ident(Path::new("/hab/pkgs/core/busybox-static/1.24.2")), None);
+            assert_eq!(target_ident(Path::new("/usr/bin/ls")), None);
+            assert_eq!(target_ident(Path::new("hab/pkgs/core/busybox-static/1.24.2/2016/ls")),
+                       None);
+        }
+
+        #[test]
+        fn binlinks_lists_only_links_into_packages() {
+            let root = TempDir::new("fs_root").unwrap();
+            fake_link(&root, "sh", "/hab/pkgs/core/bash/4.3.42/20160729192615/bin/sh");
+            fake_link(&root, "ls", "/hab/pkgs/core/coreutils/8.25/20160729201256/bin/ls");
+            fake_link(&root, "vi", "/usr/bin/vim");
+            File::create(root.path().join("bin/env")).unwrap();
+
+            let binlinks = binlinks(&root.path().join("bin")).unwrap();
+            let links: Vec<(PathBuf, String)> =
+                binlinks.iter().map(|b| (b.link.clone(), b.ident.to_string())).collect();
+            assert_eq!(links,
+                       vec![(root.path().join("bin/ls"),
+                             "core/coreutils/8.25/20160729201256".to_string()),
+                            (root.path().join("bin/sh"),
+                             "core/bash/4.3.42/20160729192615".to_string())]);
+        }
+
+        #[test]
+        fn binlinks_of_missing_directory() {
+            let root = TempDir::new("fs_root").unwrap();
+            assert!(binlinks(&root.path().join("bin")).unwrap().is_empty());
+        }
+
+        #[test]
+        fn relink_points_links_at_newest_release() {
+            let root = TempDir::new("fs_root").unwrap();
+            fake_install(&root, "core/bash/4.3.42/20160729192615", &["bash", "sh"]);
+            fake_install(&root, "core/bash/4.3.46/20161010101010", &["bash"]);
+            fake_install(&root, "core/coreutils/8.25/20160729201256", &["ls"]);
+            fake_install(&root, "core/coreutils/8.26/20161010101010", &["ls"]);
+            let old_bash = "/hab/pkgs/core/bash/4.3.42/20160729192615";
+            let bash = fake_link(&root, "bash", &format!("{}/bin/bash", old_bash));
+            let sh = fake_link(&root, "sh", &format!("{}/bin/sh", old_bash));
+            let ls = fake_link(&root, "ls", "/hab/pkgs/core/coreutils/8.25/20160729201256/bin/ls");
+
+            let installed = PackageIdent::from_str("core/bash/4.3.46/20161010101010").unwrap();
+            relink(&[installed], Path::new("/bin"), root.path()).unwrap();
+            assert_eq!(fs::read_link(&bash).unwrap(),
+                       Path::new("/hab/pkgs/core/bash/4.3.46/20161010101010/bin/bash"));
+            assert_eq!(fs::read_link(&sh).unwrap(),
+                       Path::new("/hab/pkgs/core/bash/4.3.42/20160729192615/bin/sh"));
+            assert_eq!(fs::read_link(&ls).unwrap(),
+                       Path::new("/hab/pkgs/core/coreutils/8.25/20160729201256/bin/ls"));
+        }
+    }
 }
 
 pub mod build {
@@ -103,6 +439,356 @@ pub mod build {
     }
 }
 
+pub mod diff {
+    //! Compares two releases of a package, or two different packages.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg diff core/redis/3.0.7/20160614231131 core/redis/3.2.1/20160729192522
+    //! $ hab pkg diff ./core-redis-3.0.7-20160614231131-x86_64-linux.hart core/redis
+    //! $ hab pkg diff --remote core/redis/3.0.7 core/redis
+    //! ```
+    //!
+    //! Will print the dependencies, transitive dependencies, exposed ports, default configuration
+    //! keys, hooks and files which were added, removed or changed from the first package to the
+    //! second. Each side is a path to a Habitat Artifact or a package identifier, which is looked
+    //! up among the installed packages, or downloaded from the depot with `--remote`.
+
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use ansi_term::Colour::{Blue, Green, Red, Yellow};
+    use common::command::package::install;
+    use hcore::package::{PackageArchive, PackageIdent, PackageInstall};
+    use rustc_serialize::json::{Json, ToJson};
+    use toml;
+
+    use error::Result;
+    use output;
+
+    /// The entries of one aspect of a package which differ between two packages.
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Changes {
+        pub added: Vec<String>,
+        pub removed: Vec<String>,
+        /// The changed entries, along with their values in the first and the second package.
+        pub changed: Vec<(String, String, String)>,
+    }
+
+    impl Changes {
+        pub fn len(&self) -> usize {
+            self.added.len() + self.removed.len() + self.changed.len()
+        }
+    }
+
+    impl ToJson for Changes {
+        fn to_json(&self) -> Json {
+            let changed = self.changed
+                .iter()
+                .map(|&(ref name, ref from, ref to)| {
+                    output::object(vec![("name", name.to_json()),
+                                        ("from", from.to_json()),
+                                        ("to", to.to_json())])
+                })
+                .collect();
+            output::object(vec![("added", self.added.to_json()),
+                                ("removed", self.removed.to_json()),
+                                ("changed", Json::Array(changed))])
+        }
+    }
+
+    /// The differences between two packages.
+    pub struct PackageDiff {
+        pub a: PackageIdent,
+        pub b: PackageIdent,
+        pub deps: Changes,
+        pub tdeps: Changes,
+        pub exposes: Changes,
+        pub config: Changes,
+        pub hooks: Changes,
+        pub files: Changes,
+    }
+
+    impl PackageDiff {
+        fn sections(&self) -> Vec<(&'static str, &Changes)> {
+            vec![("Dependencies", &self.deps),
+                 ("Transitive Dependencies", &self.tdeps),
+                 ("Exposed Ports", &self.exposes),
+                 ("Default Configuration", &self.config),
+                 ("Hooks", &self.hooks),
+                 ("Files", &self.files)]
+        }
+
+        /// Returns the total number of differences.
+        pub fn len(&self) -> usize {
+            self.sections().iter().fold(0, |n, &(_, changes)| n + changes.len())
+        }
+    }
+
+    impl ToJson for PackageDiff {
+        fn to_json(&self) -> Json {
+            output::object(vec![("a", self.a.to_string().to_json()),
+                                ("b", self.b.to_string().to_json()),
+                                ("deps", self.deps.to_json()),
+                                ("tdeps", self.tdeps.to_json()),
+                                ("exposes", self.exposes.to_json()),
+                                ("config", self.config.to_json()),
+                                ("hooks", self.hooks.to_json()),
+                                ("files", self.files.to_json()),
+                                ("differences", self.len().to_json())])
+        }
+    }
+
+    /// What is compared of a package.
+    struct Side {
+        ident: PackageIdent,
+        deps: Vec<PackageIdent>,
+        tdeps: Vec<PackageIdent>,
+        exposes: Vec<String>,
+        config: Option<String>,
+        hooks: BTreeMap<String, String>,
+        files: Vec<String>,
+    }
+
+    /// Prints the differences between two packages and returns them. A path to a file is read as
+    /// an artifact, while a package identifier is looked up among the installed packages, or
+    /// downloaded from the depot at `depot_url` when one is given.
+    pub fn start(a: &str,
+                 b: &str,
+                 depot_url: Option<&str>,
+                 token: Option<&str>,
+                 fs_root_path: &Path,
+                 cache_artifact_path: &Path)
+                 -> Result<PackageDiff> {
+        let a = try!(load(a, depot_url, token, fs_root_path, cache_artifact_path));
+        let b = try!(load(b, depot_url, token, fs_root_path, cache_artifact_path));
+        let diff = PackageDiff {
+            deps: compare(&dep_map(&a.deps), &dep_map(&b.deps)),
+            tdeps: compare(&dep_map(&a.tdeps), &dep_map(&b.tdeps)),
+            exposes: compare(&set_map(&a.exposes), &set_map(&b.exposes)),
+            config: compare(&config_map(a.config.as_ref()), &config_map(b.config.as_ref())),
+            hooks: compare(&a.hooks, &b.hooks),
+            files: compare(&set_map(&a.files), &set_map(&b.files)),
+            a: a.ident,
+            b: b.ident,
+        };
+
+        println!("{}",
+                 Yellow.bold().paint(format!("» Comparing {} with {}", diff.a, diff.b)));
+        for (title, changes) in diff.sections() {
+            println!("{}", Yellow.bold().paint(format!("» {}", title)));
+            if changes.len() == 0 {
+                println!("  No changes");
+                continue;
+            }
+            for name in changes.added.iter() {
+                println!("  {} {}", Green.bold().paint("+"), name);
+            }
+            for name in changes.removed.iter() {
+                println!("  {} {}", Red.bold().paint("-"), name);
+            }
+            for &(ref name, ref from, ref to) in changes.changed.iter() {
+                if title == "Hooks" {
+                    println!("  {} {}", Yellow.bold().paint("~"), name);
+                } else {
+                    println!("  {} {}: {} -> {}", Yellow.bold().paint("~"), name, from, to);
+                }
+            }
+        }
+        println!("{}", Blue.paint(format!("★ {} differences", diff.len())));
+        Ok(diff)
+    }
+
+    fn load(ident_or_archive: &str,
+            depot_url: Option<&str>,
+            token: Option<&str>,
+            fs_root_path: &Path,
+            cache_artifact_path: &Path)
+            -> Result<Side> {
+        if Path::new(ident_or_archive).is_file() {
+            return from_archive(PackageArchive::new(Path::new(ident_or_archive)));
+        }
+        let ident = try!(PackageIdent::from_str(ident_or_archive));
+        match depot_url {
+            Some(url) => {
+                let mut archives = try!(install::download_all(url,
+                                                              token,
+                                                              &[ident],
+                                                              None,
+                                                              fs_root_path,
+                                                              cache_artifact_path));
+                from_archive(archives.pop().unwrap())
+            }
+            None => {
+                let install = try!(PackageInstall::load(&ident, Some(fs_root_path)));
+                Ok(Side {
+                    ident: install.ident().clone(),
+                    deps: try!(install.deps()),
+                    tdeps: try!(install.tdeps()),
+                    exposes: try!(install.exposes())
+                        .into_iter()
+                        .filter(|p| !p.is_empty())
+                        .collect(),
+                    config: try!(install.config()),
+                    hooks: try!(install.hooks()),
+                    files: try!(install.files()),
+                })
+            }
+        }
+    }
+
+    fn from_archive(mut archive: PackageArchive) -> Result<Side> {
+        Ok(Side {
+            ident: try!(archive.ident()),
+            deps: try!(archive.deps()),
+            tdeps: try!(archive.tdeps()),
+            exposes: try!(archive.exposes()).iter().map(|port| port.to_string()).collect(),
+            config: try!(archive.config()),
+            hooks: try!(archive.hooks()),
+            files: try!(archive.files()),
+        })
+    }
+
+    /// Compares two maps of names to values.
+    fn compare(a: &BTreeMap<String, String>, b: &BTreeMap<String, String>) -> Changes {
+        let mut changes = Changes::default();
+        for (name, from) in a.iter() {
+            match b.get(name) {
+                Some(to) if to != from => {
+                    changes.changed.push((name.clone(), from.clone(), to.clone()))
+                }
+                Some(_) => (),
+                None => changes.removed.push(name.clone()),
+            }
+        }
+        changes.added = b.keys().filter(|name| !a.contains_key(*name)).cloned().collect();
+        changes
+    }
+
+    /// Maps the origin and name of each dependency to the release depended on.
+    fn dep_map(deps: &[PackageIdent]) -> BTreeMap<String, String> {
+        deps.iter()
+            .map(|dep| (format!("{}/{}", dep.origin, dep.name), dep.to_string()))
+            .collect()
+    }
+
+    fn set_map(names: &[String]) -> BTreeMap<String, String> {
+        names.iter().map(|name| (name.clone(), String::new())).collect()
+    }
+
+    /// Maps each key of a default configuration, as a dotted path through its tables, to its
+    /// value. A configuration which does not parse is compared as a whole.
+    fn config_map(config: Option<&String>) -> BTreeMap<String, String> {
+        let mut map = BTreeMap::new();
+        if let Some(config) = config {
+            match config.parse::<toml::Value>() {
+                Ok(value) => flatten("", &value, &mut map),
+                Err(_) => {
+                    debug!("Default configuration does not parse, comparing it as a whole");
+                    map.insert("default.toml".to_string(), config.clone());
+                }
+            }
+        }
+        map
+    }
+
+    fn flatten(prefix: &str, value: &toml::Value, map: &mut BTreeMap<String, String>) {
+        match *value {
+            toml::Value::Table(ref table) => {
+                for (key, value) in table.iter() {
+                    let key = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    flatten(&key, value, map);
+                }
+            }
+            _ => {
+                map.insert(prefix.to_string(), value.to_string());
+            }
+        }
+    }
+}
+
+pub mod doctor {
+    //! Checks installed packages for conflicting dependencies.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg doctor
+    //! $ hab pkg doctor core/redis
+    //! ```
+    //!
+    //! Will report every installed package whose runtime path combines more than one release of
+    //! a dependency, marking the packages of running services, and every dependency of which
+    //! the installed packages need different releases. Only the former is an error, since
+    //! packages which are not combined on a runtime path may well use different releases.
+
+    use std::collections::{HashMap, HashSet};
+    use std::path::Path;
+
+    use ansi_term::Colour::{Green, Red, Yellow};
+    use hcore::package::{PackageIdent, PackageInstall};
+    use hcore::package::install::release_conflicts;
+
+    use error::{Error, Result};
+    use super::list;
+
+    pub fn start(ident: Option<&PackageIdent>, fs_root_path: &Path) -> Result<()> {
+        let packages = try!(PackageInstall::installed(ident, Some(fs_root_path)));
+        let services: HashSet<PackageIdent> =
+            try!(list::running_services()).into_iter().collect();
+        let mut broken = 0;
+        let mut dependents: HashMap<PackageIdent, Vec<PackageIdent>> = HashMap::new();
+        for package in packages.iter() {
+            let install = try!(PackageInstall::load(package, Some(fs_root_path)));
+            let conflicts = try!(install.runtime_conflicts());
+            for (_, releases) in conflicts.iter() {
+                let releases: Vec<String> = releases.iter().map(|r| r.to_string()).collect();
+                let service = if services.contains(package) {
+                    " (running service)"
+                } else {
+                    ""
+                };
+                println!("{} {}{} has {} on its runtime path",
+                         Red.bold().paint("✗"),
+                         package,
+                         service,
+                         releases.join(" and "));
+            }
+            if !conflicts.is_empty() {
+                broken += 1;
+            }
+            for dep in try!(install.tdeps()) {
+                dependents.entry(dep).or_insert(vec![]).push(package.clone());
+            }
+        }
+        let shared = release_conflicts(dependents.keys());
+        for (name, releases) in shared.iter() {
+            println!("{} {} is needed in {} releases:",
+                     Yellow.bold().paint("!"),
+                     name,
+                     releases.len());
+            for release in releases.iter() {
+                let users: Vec<String> =
+                    dependents[release].iter().map(|u| u.to_string()).collect();
+                println!("    {} by {}", release, users.join(", "));
+            }
+        }
+        if broken > 0 {
+            return Err(Error::DependencyConflicts(broken));
+        }
+        if shared.is_empty() {
+            println!("{} No dependency conflicts found", Green.bold().paint("✓"));
+        }
+        Ok(())
+    }
+}
+
 pub mod exec {
     use std::env;
     use std::ffi::OsString;
@@ -212,10 +898,12 @@ pub mod export {
                              &format_ident.to_string(),
                              &default_depot_url());
                     try!(install::from_url(&default_depot_url(),
+                                           None,
                                            format_ident,
                                            Path::new(FS_ROOT_PATH),
                                            &cache_artifact_path(None),
-                                           &default_cache_key_path(None)));
+                                           &default_cache_key_path(None),
+                                           None));
                 }
             }
             let pkg_arg = OsString::from(&ident.to_string());
@@ -259,10 +947,383 @@ pub mod hash {
 
     use error::Result;
 
-    pub fn start(src: &str) -> Result<()> {
+    pub fn start(src: &str) -> Result<String> {
         let h = try!(hash::hash_file(&src));
         println!("{}", h);
-        Ok(())
+        Ok(h)
+    }
+}
+
+pub mod info {
+    //! Displays the metadata of an installed package, a Habitat Artifact or a package in a
+    //! [depot](../depot).
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg info core/redis
+    //! $ hab pkg info ./core-redis-3.0.7-20160614231131-x86_64-linux.hart
+    //! $ hab pkg info --remote core/redis
+    //! ```
+    //!
+    //! Will print the package's identifier, checksum, signing keys, manifest fields, dependencies,
+    //! exposed ports, default configuration and build provenance. The provenance is verified
+    //! against the origin keys in the key cache. The checksum and signing keys of an installed
+    //! package are read from its artifact in the artifact cache, when it is still there.
+
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use ansi_term::Colour::{Green, Red, Yellow};
+    use depot_client::Client;
+    use hcore::crypto::artifact;
+    use hcore::package::{PackageArchive, PackageIdent, PackageInstall, PackageManifest,
+                         PackageProvenance};
+    use rustc_serialize::json::{Json, ToJson};
+
+    use error::Result;
+    use output;
+
+    /// Everything known about a package.
+    pub struct PackageInfo {
+        pub ident: PackageIdent,
+        /// Where the package was found, one of `installed`, `artifact` or `depot`.
+        pub source: &'static str,
+        pub checksum: Option<String>,
+        pub signers: Option<Vec<String>>,
+        pub manifest: String,
+        pub deps: Vec<PackageIdent>,
+        pub tdeps: Vec<PackageIdent>,
+        pub exposes: Vec<String>,
+        pub config: Option<String>,
+        /// The build provenance, along with the keys which signed it when its signatures verify.
+        pub provenance: Option<(PackageProvenance, Option<Vec<String>>)>,
+    }
+
+    impl ToJson for PackageInfo {
+        fn to_json(&self) -> Json {
+            let manifest = PackageManifest::parse(&self.manifest);
+            let provenance = self.provenance.as_ref().map(|&(ref provenance, ref signers)| {
+                output::object(vec![("verified", signers.is_some().to_json()),
+                                    ("signers", signers.to_json()),
+                                    ("plan_shasum", provenance.plan_shasum.to_json()),
+                                    ("studio_ident", provenance.studio_ident.to_json()),
+                                    ("builder_job_id", provenance.builder_job_id.to_json()),
+                                    ("build_timestamp", provenance.build_timestamp.to_json()),
+                                    ("source_url", provenance.source_url.to_json()),
+                                    ("source_shasum", provenance.source_shasum.to_json())])
+            });
+            output::object(vec![("ident", self.ident.to_string().to_json()),
+                                ("source", self.source.to_json()),
+                                ("checksum", self.checksum.to_json()),
+                                ("signers", self.signers.to_json()),
+                                ("description", manifest.description.to_json()),
+                                ("maintainer", manifest.maintainer.to_json()),
+                                ("licenses", manifest.licenses.to_json()),
+                                ("upstream_url", manifest.upstream_url.to_json()),
+                                ("deps", idents_to_json(&self.deps)),
+                                ("tdeps", idents_to_json(&self.tdeps)),
+                                ("exposes", self.exposes.to_json()),
+                                ("config", self.config.to_json()),
+                                ("manifest", self.manifest.to_json()),
+                                ("provenance", provenance.to_json())])
+        }
+    }
+
+    /// Prints the metadata of a package and returns it. A path to a file is read as an artifact,
+    /// while a package identifier is looked up among the installed packages, or in the depot at
+    /// `depot_url` when one is given.
+    pub fn start(ident_or_archive: &str,
+                 depot_url: Option<&str>,
+                 token: Option<&str>,
+                 fs_root_path: &Path,
+                 cache_artifact_path: &Path,
+                 cache_key_path: &Path)
+                 -> Result<PackageInfo> {
+        let (mut info, provenance) = if Path::new(ident_or_archive).is_file() {
+            try!(from_archive(Path::new(ident_or_archive)))
+        } else {
+            let ident = try!(PackageIdent::from_str(ident_or_archive));
+            match depot_url {
+                Some(url) => try!(from_depot(url, token, &ident, fs_root_path)),
+                None => try!(from_install(&ident, fs_root_path, cache_artifact_path)),
+            }
+        };
+        let manifest = PackageManifest::parse(&info.manifest);
+
+        println!("{}", Yellow.bold().paint(format!("» {}", info.ident)));
+        print_field("Description", manifest.description.as_ref());
+        print_field("Maintainer", manifest.maintainer.as_ref());
+        print_field("License", Some(&manifest.licenses.join(" ")));
+        print_field("Upstream URL", manifest.upstream_url.as_ref());
+        print_field("Checksum", info.checksum.as_ref());
+        if let Some(ref signers) = info.signers {
+            print_field("Signed By", Some(&signers.join(", ")));
+        }
+        print_field("Exposes", Some(&info.exposes.join(" ")));
+
+        info.provenance = match provenance {
+            Some(content) => Some(try!(verify_provenance(&content, cache_key_path))),
+            None => {
+                println!("{}", Yellow.bold().paint("» No provenance recorded"));
+                None
+            }
+        };
+
+        print_idents("Dependencies", &info.deps);
+        print_idents("Transitive Dependencies", &info.tdeps);
+        match info.config {
+            Some(ref config) => {
+                println!("{}", Yellow.bold().paint("» Default configuration"));
+                for line in config.lines() {
+                    println!("  {}", line);
+                }
+            }
+            None => println!("{}", Yellow.bold().paint("» No default configuration")),
+        }
+        Ok(info)
+    }
+
+    /// Reads the metadata of an artifact, returning it along with the signed provenance.
+    fn from_archive(src: &Path) -> Result<(PackageInfo, Option<String>)> {
+        let mut archive = PackageArchive::new(src);
+        let info = PackageInfo {
+            ident: try!(archive.ident()),
+            source: "artifact",
+            checksum: Some(try!(archive.checksum())),
+            signers: Some(try!(artifact::artifact_signers(&src))),
+            manifest: try!(archive.manifest()),
+            deps: try!(archive.deps()),
+            tdeps: try!(archive.tdeps()),
+            exposes: try!(archive.exposes()).iter().map(|port| port.to_string()).collect(),
+            config: try!(archive.config()),
+            provenance: None,
+        };
+        Ok((info, try!(archive.provenance())))
+    }
+
+    /// Reads the metadata of the latest installed release satisfying `ident`. The checksum and
+    /// signing keys are taken from the release's artifact, if it is in the artifact cache.
+    fn from_install(ident: &PackageIdent,
+                    fs_root_path: &Path,
+                    cache_artifact_path: &Path)
+                    -> Result<(PackageInfo, Option<String>)> {
+        let install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+        let (checksum, signers) = match install.ident().archive_name() {
+            Some(name) if cache_artifact_path.join(&name).is_file() => {
+                let src = cache_artifact_path.join(&name);
+                (Some(try!(PackageArchive::new(src.clone()).checksum())),
+                 Some(try!(artifact::artifact_signers(&src))))
+            }
+            _ => (None, None),
+        };
+        let info = PackageInfo {
+            ident: install.ident().clone(),
+            source: "installed",
+            checksum: checksum,
+            signers: signers,
+            manifest: try!(install.manifest()),
+            deps: try!(install.deps()),
+            tdeps: try!(install.tdeps()),
+            exposes: try!(install.exposes()).into_iter().filter(|p| !p.is_empty()).collect(),
+            config: try!(install.config()),
+            provenance: None,
+        };
+        Ok((info, try!(install.provenance())))
+    }
+
+    /// Reads the metadata of the latest release satisfying `ident` from a depot.
+    fn from_depot(url: &str,
+                  token: Option<&str>,
+                  ident: &PackageIdent,
+                  fs_root_path: &Path)
+                  -> Result<(PackageInfo, Option<String>)> {
+        let depot_client = try!(Client::new(url, Some(fs_root_path)));
+        let pkg = try!(depot_client.show_package(ident.clone(), token));
+        let info = PackageInfo {
+            ident: pkg.get_ident().clone().into(),
+            source: "depot",
+            checksum: Some(pkg.get_checksum().to_string()),
+            signers: None,
+            manifest: pkg.get_manifest().to_string(),
+            deps: pkg.get_deps().iter().map(|dep| (*dep).clone().into()).collect(),
+            tdeps: pkg.get_tdeps().iter().map(|dep| (*dep).clone().into()).collect(),
+            exposes: pkg.get_exposes().iter().map(|port| port.to_string()).collect(),
+            config: if pkg.has_config() {
+                Some(pkg.get_config().to_string())
+            } else {
+                None
+            },
+            provenance: None,
+        };
+        let provenance = if pkg.has_provenance() {
+            Some(pkg.get_provenance().to_string())
+        } else {
+            None
+        };
+        Ok((info, provenance))
+    }
+
+    /// Prints the fields of a signed provenance, along with whether its signatures verify.
+    fn verify_provenance(content: &str,
+                         cache_key_path: &Path)
+                         -> Result<(PackageProvenance, Option<Vec<String>>)> {
+        let (provenance, signers) = match PackageProvenance::verify(content, cache_key_path) {
+            Ok((signers, provenance)) => {
+                println!("{} provenance signed with {}",
+                         Green.bold().paint("✓ Verified"),
+                         signers.join(", "));
+                (provenance, Some(signers))
+            }
+            Err(e) => {
+                println!("{} provenance: {}", Red.bold().paint("✗ Unverified"), e);
+                (try!(PackageProvenance::from_signed(content)), None)
+            }
+        };
+        for (name, value) in provenance.fields() {
+            print_field(name, value);
+        }
+        Ok((provenance, signers))
+    }
+
+    fn print_field(name: &str, value: Option<&String>) {
+        match value {
+            Some(value) if !value.is_empty() => println!("  {}: {}", name, value),
+            _ => println!("  {}: -", name),
+        }
+    }
+
+    fn print_idents(title: &str, idents: &[PackageIdent]) {
+        if idents.is_empty() {
+            println!("{}", Yellow.bold().paint(format!("» No {}", title.to_lowercase())));
+            return;
+        }
+        println!("{}", Yellow.bold().paint(format!("» {}", title)));
+        for ident in idents.iter() {
+            println!("  {}", ident);
+        }
+    }
+
+    fn idents_to_json(idents: &[PackageIdent]) -> Json {
+        Json::Array(idents.iter().map(|ident| ident.to_string().to_json()).collect())
+    }
+}
+
+pub mod list {
+    //! Lists the packages installed under `/hab/pkgs`.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg list
+    //! $ hab pkg list core/redis
+    //! ```
+    //!
+    //! Will print the identifier of every installed release, oldest first, marking the releases
+    //! which are in use by a running service, either as the service itself or as one of its
+    //! transitive dependencies.
+
+    use std::collections::HashSet;
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use ansi_term::Colour::Green;
+    use hcore::fs::svc_root;
+    use hcore::package::{PackageIdent, PackageInstall};
+    use libc;
+    use toml;
+
+    use error::Result;
+
+    /// Prints the installed packages and returns each of them along with whether it is in use.
+    pub fn start(ident: Option<&PackageIdent>,
+                 fs_root_path: &Path)
+                 -> Result<Vec<(PackageIdent, bool)>> {
+        let packages = try!(PackageInstall::installed(ident, Some(fs_root_path)));
+        if packages.is_empty() {
+            println!("No installed packages found");
+            return Ok(vec![]);
+        }
+        let in_use = try!(in_use(fs_root_path));
+        let mut listing = Vec::new();
+        for package in packages {
+            if in_use.contains(&package) {
+                println!("{} {}", package, Green.paint("(in use)"));
+                listing.push((package, true));
+            } else {
+                println!("{}", package);
+                listing.push((package, false));
+            }
+        }
+        Ok(listing)
+    }
+
+    /// Returns the packages of the running services along with their transitive dependencies.
+    pub fn in_use(fs_root_path: &Path) -> Result<HashSet<PackageIdent>> {
+        let mut in_use = HashSet::new();
+        for ident in try!(running_services()) {
+            if let Ok(install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+                in_use.extend(try!(install.tdeps()));
+            }
+            in_use.insert(ident);
+        }
+        Ok(in_use)
+    }
+
+    /// Returns the packages of the services whose process is alive. The package of a service is
+    /// read from the `pkg` table of the configuration the Supervisor writes into the service
+    /// directory.
+    pub fn running_services() -> Result<Vec<PackageIdent>> {
+        let mut running = Vec::new();
+        let svc_root = svc_root();
+        if !svc_root.is_dir() {
+            return Ok(running);
+        }
+        for entry in try!(fs::read_dir(&svc_root)) {
+            let svc_path = try!(entry).path();
+            if !is_running(&svc_path.join("PID")) {
+                continue;
+            }
+            let config = match read_file(&svc_path.join("config.toml")) {
+                Some(config) => config,
+                None => continue,
+            };
+            let ident = match config.parse::<toml::Value>() {
+                Ok(config) => {
+                    config.lookup("pkg.ident")
+                        .and_then(|i| i.as_str())
+                        .and_then(|i| PackageIdent::from_str(i).ok())
+                }
+                Err(_) => None,
+            };
+            if let Some(ident) = ident {
+                running.push(ident);
+            }
+        }
+        Ok(running)
+    }
+
+    /// Is the process named by a PID file alive?
+    fn is_running(pid_file: &Path) -> bool {
+        match read_file(pid_file).and_then(|p| p.trim().parse::<libc::pid_t>().ok()) {
+            Some(pid) => unsafe { libc::kill(pid, 0) == 0 },
+            None => false,
+        }
+    }
+
+    fn read_file(path: &Path) -> Option<String> {
+        let mut content = String::new();
+        match File::open(path) {
+            Ok(mut f) => {
+                match f.read_to_string(&mut content) {
+                    Ok(_) => Some(content),
+                    Err(_) => None,
+                }
+            }
+            Err(_) => None,
+        }
     }
 }
 
@@ -273,28 +1334,145 @@ pub mod path {
 
     use error::Result;
 
-    pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    /// Prints the path of the latest installed release satisfying `ident`, and returns the
+    /// installed package.
+    pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<PackageInstall> {
         let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
         println!("{}", pkg_install.installed_path().display());
+        Ok(pkg_install)
+    }
+}
+
+pub mod prune {
+    //! Removes old releases of installed packages.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg prune
+    //! $ hab pkg prune core/redis --keep 2
+    //! ```
+    //!
+    //! Will keep the newest releases of each installed package and remove the older ones, except
+    //! for releases which are transitive dependencies of a package that stays installed or which
+    //! are in use by a running service.
+
+    use std::collections::{BTreeMap, HashSet};
+    use std::path::Path;
+
+    use ansi_term::Colour::{Blue, Yellow};
+    use hcore::package::{PackageIdent, PackageInstall};
+
+    use error::Result;
+
+    pub fn start(ident: Option<&PackageIdent>, keep: usize, fs_root_path: &Path) -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Pruning all but the newest {} release(s)", keep)));
+        // Releases of each package, oldest first
+        let mut releases: BTreeMap<(String, String), Vec<PackageIdent>> = BTreeMap::new();
+        for package in try!(PackageInstall::installed(ident, Some(fs_root_path))) {
+            releases.entry((package.origin.clone(), package.name.clone()))
+                .or_insert(Vec::new())
+                .push(package);
+        }
+        let mut candidates = HashSet::new();
+        for (_, mut packages) in releases {
+            let len = packages.len();
+            if len > keep {
+                candidates.extend(packages.drain(..len - keep));
+            }
+        }
+
+        let mut required = try!(super::list::in_use(fs_root_path));
+        for package in try!(PackageInstall::installed(None, Some(fs_root_path))) {
+            if !candidates.contains(&package) {
+                let install = try!(PackageInstall::load(&package, Some(fs_root_path)));
+                required.extend(try!(install.tdeps()));
+            }
+        }
+
+        let mut candidates: Vec<PackageIdent> = candidates.into_iter().collect();
+        candidates.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        let mut removed = 0;
+        for package in candidates {
+            if required.contains(&package) {
+                println!("Keeping {}, which is still needed", package);
+                continue;
+            }
+            try!(try!(PackageInstall::load(&package, Some(fs_root_path))).uninstall());
+            println!("Removed {}", package);
+            removed += 1;
+        }
+        println!("{}", Blue.paint(format!("★ Pruned {} release(s).", removed)));
         Ok(())
     }
 }
 
+pub mod search {
+    //! Searches a [Depot](../depot) for packages.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg search redis license:bsd --sort version --order desc
+    //! ```
+    //!
+    //! Will list every package matching `redis` which is released under a BSD license, newest
+    //! version first.
+
+    use ansi_term::Colour::{Blue, Yellow};
+    use depot_client::Client;
+
+    use error::Result;
+
+    pub fn start(url: &str,
+                 query: &str,
+                 sort: Option<&str>,
+                 order: Option<&str>,
+                 token: Option<&str>)
+                 -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Searching {} for '{}'", url, query)));
+        let depot_client = try!(Client::new(url, None));
+        let mut offset = 0;
+        let mut count = 0;
+        loop {
+            let (packages, next) = try!(depot_client.search_package(query,
+                                                                    sort,
+                                                                    order,
+                                                                    offset,
+                                                                    token));
+            for package in packages.iter() {
+                println!("{}", package);
+            }
+            count += packages.len();
+            match next {
+                Some(next) if !packages.is_empty() => offset = next,
+                _ => {
+                    println!("{}",
+                             Blue.paint(format!("★ Found {} matching package(s).", count)));
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
 pub mod sign {
     use std::path::Path;
 
     use ansi_term::Colour::{Blue, Green, Yellow};
-    use hcore::crypto::{artifact, SigKeyPair};
+    use hcore::crypto::{artifact, Signer};
 
     use error::Result;
 
-    pub fn start(origin: &SigKeyPair, src: &Path, dst: &Path) -> Result<()> {
+    pub fn start(origin: &Signer, src: &Path, dst: &Path) -> Result<()> {
         println!("{}",
                  Yellow.bold().paint(format!("» Signing {}", src.display())));
         println!("{} {} with {} to create {}",
                  Green.paint("☛ Signing"),
                  src.display(),
-                 &origin.name_with_rev(),
+                 &origin.key_name_with_rev(),
                  dst.display());
         try!(artifact::sign(src, dst, origin));
         println!("{}",
@@ -303,6 +1481,78 @@ pub mod sign {
     }
 }
 
+pub mod countersign {
+    use std::path::Path;
+
+    use ansi_term::Colour::{Blue, Green, Yellow};
+    use hcore::crypto::{artifact, Signer};
+
+    use error::Result;
+
+    pub fn start(origin: &Signer, src: &Path, dst: &Path) -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Countersigning {}", src.display())));
+        println!("{} {} with {} to create {}",
+                 Green.paint("☛ Countersigning"),
+                 src.display(),
+                 &origin.key_name_with_rev(),
+                 dst.display());
+        try!(artifact::countersign(src, dst, origin));
+        println!("{}",
+                 Blue.paint(format!("★ Countersigned artifact {}.", dst.display())));
+        Ok(())
+    }
+}
+
+pub mod uninstall {
+    //! Removes an installed package.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg uninstall core/redis
+    //! $ hab pkg uninstall core/redis/3.0.7/20160614231131 --force
+    //! ```
+    //!
+    //! Will remove the newest installed release of a package, or the given release. A release
+    //! which another installed package depends on or which a running service uses is only removed
+    //! with `--force`.
+
+    use std::path::Path;
+
+    use ansi_term::Colour::{Blue, Yellow};
+    use hcore::package::{PackageIdent, PackageInstall};
+
+    use error::{Error, Result};
+
+    pub fn start(ident: &PackageIdent, fs_root_path: &Path, force: bool) -> Result<()> {
+        let install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+        let ident = install.ident().clone();
+        if !force {
+            let mut users = Vec::new();
+            for package in try!(PackageInstall::installed(None, Some(fs_root_path))) {
+                if package == ident {
+                    continue;
+                }
+                let dependent = try!(PackageInstall::load(&package, Some(fs_root_path)));
+                if try!(dependent.tdeps()).contains(&ident) {
+                    users.push(package.to_string());
+                }
+            }
+            if try!(super::list::in_use(fs_root_path)).contains(&ident) {
+                users.push("a running service".to_string());
+            }
+            if !users.is_empty() {
+                return Err(Error::PackageInUse(ident.to_string(), users));
+            }
+        }
+        println!("{}", Yellow.bold().paint(format!("» Uninstalling {}", &ident)));
+        try!(install.uninstall());
+        println!("{}", Blue.paint(format!("★ Uninstalled {}.", &ident)));
+        Ok(())
+    }
+}
+
 pub mod upload {
     //! Uploads a package to a [Depot](../depot).
     //!
@@ -345,7 +1595,7 @@ pub mod upload {
                                  token: &str,
                                  archive_path: &P,
                                  key_path: &P)
-                                 -> Result<()> {
+                                 -> Result<PackageIdent> {
         let mut archive = PackageArchive::new(PathBuf::from(archive_path.as_ref()));
 
         let hart_header = try!(get_artifact_header(&archive_path.as_ref()));
@@ -391,7 +1641,7 @@ pub mod upload {
 
         let tdeps = try!(archive.tdeps());
         for dep in tdeps.into_iter() {
-            match depot_client.show_package(dep.clone()) {
+            match depot_client.show_package(dep.clone(), Some(token)) {
                 Ok(_) => println!("{} {}", Green.paint("→ Exists"), &dep),
                 Err(depot_client::Error::RemotePackageNotFound(_)) => {
                     let candidate_path = match archive_path.as_ref().parent() {
@@ -404,7 +1654,7 @@ pub mod upload {
             }
         }
         let ident = try!(archive.ident());
-        match depot_client.show_package(ident.clone()) {
+        match depot_client.show_package(ident.clone(), Some(token)) {
             Ok(_) => println!("{} {}", Green.paint("→ Exists"), &ident),
             Err(_) => {
                 try!(upload_into_depot(&depot_client, token, &ident, &mut archive));
@@ -413,7 +1663,7 @@ pub mod upload {
         println!("{}",
                  Blue.paint(format!("★ Upload of {} complete.", &ident)));
 
-        Ok(())
+        Ok(ident)
     }
 
     fn upload_into_depot(depot_client: &Client,
@@ -483,20 +1733,24 @@ pub mod verify {
     use std::path::Path;
 
     use ansi_term::Colour::{Blue, Green, Yellow};
-    use hcore::crypto::artifact;
+    use hcore::crypto::artifact::{self, VerifyPolicy};
 
     use error::Result;
 
-    pub fn start(src: &Path, cache: &Path) -> Result<()> {
+    /// Verifies an artifact, returning the keys it was signed with and its checksum.
+    pub fn start(src: &Path,
+                 policy: &VerifyPolicy,
+                 cache: &Path)
+                 -> Result<(Vec<String>, String)> {
         println!("{}",
                  Yellow.bold().paint(format!("» Verifying artifact {}", &src.display())));
-        let (name_with_rev, hash) = try!(artifact::verify(src, cache));
+        let (signers, hash) = try!(artifact::verify_with_policy(src, cache, policy));
         println!("{} checksum {} signed with {}",
                  Green.bold().paint("✓ Verifed"),
                  &hash,
-                 &name_with_rev);
+                 signers.join(", "));
         println!("{}",
                  Blue.paint(format!("★ Verified artifact {}.", &src.display())));
-        Ok(())
+        Ok((signers, hash))
     }
 }
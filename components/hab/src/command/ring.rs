@@ -51,6 +51,58 @@ pub mod key {
         }
     }
 
+    pub mod rotate {
+        use std::env;
+        use std::path::Path;
+
+        use ansi_term::Colour::{Blue, Green, Yellow};
+        use hcore::crypto::{BoxKeyPair, SymKey, KEY_PASSPHRASE_ENV_VAR,
+                            KEY_PASSPHRASE_FILE_ENV_VAR};
+
+        use command::file;
+        use error::Result;
+
+        /// The version number of the gossip file carrying a ring key. Every revision has its own
+        /// file name, so the file is never superseded by a later version.
+        const RING_KEY_FILE_VERSION: u64 = 1;
+
+        pub fn start(ring: &str,
+                     peers: &Vec<String>,
+                     user_pair: &BoxKeyPair,
+                     service_pairs: &[BoxKeyPair],
+                     cache: &Path)
+                     -> Result<()> {
+            println!("{}",
+                     Yellow.bold().paint(format!("» Rotating ring key for {}", &ring)));
+            // Supervisors only hold the current revision until the new one arrives, so the new
+            // revision has to travel through the ring encrypted with the current one.
+            let current = try!(SymKey::get_latest_pair_for(ring, cache));
+            // The new revision is gossiped as is and Supervisors have no passphrase to open it
+            // with, so it is always written unencrypted.
+            env::remove_var(KEY_PASSPHRASE_ENV_VAR);
+            env::remove_var(KEY_PASSPHRASE_FILE_ENV_VAR);
+            let pair = try!(SymKey::generate_pair_for_ring(ring, cache));
+            println!("{} ring key pair {}",
+                     Green.bold().paint("✓ Generated"),
+                     &pair.name_with_rev());
+            let path = try!(SymKey::get_secret_key_path(&pair.name_with_rev(), cache));
+            for service_pair in service_pairs {
+                try!(file::upload::start(peers,
+                                         Some(&current),
+                                         user_pair,
+                                         service_pair,
+                                         RING_KEY_FILE_VERSION,
+                                         &path));
+            }
+            println!("{}",
+                     Blue.paint(format!("★ Rotated ring key {} to {}. Supervisors will encrypt \
+                                         with it after a grace period.",
+                                        &current.name_with_rev(),
+                                        &pair.name_with_rev())));
+            Ok(())
+        }
+    }
+
     pub mod import {
         use std::path::Path;
 
@@ -76,7 +76,7 @@ mod inner {
     use std::env;
     use std::ffi::OsString;
 
-    use hcore::crypto::default_cache_key_path;
+    use hcore::crypto::{default_cache_key_path, KEY_PASSPHRASE_ENV_VAR};
     use hcore::env as henv;
     use hcore::fs::{CACHE_KEY_PATH, find_command};
 
@@ -103,7 +103,11 @@ mod inner {
                                                "--tty".into(),
                                                "--interactive".into(),
                                                "--privileged".into()];
-        let env_vars = vec!["HAB_DEPOT_URL", "HAB_ORIGIN", "http_proxy", "https_proxy"];
+        let env_vars = vec!["HAB_DEPOT_URL",
+                            "HAB_ORIGIN",
+                            "HAB_ORIGIN_SIGNER",
+                            "http_proxy",
+                            "https_proxy"];
         for var in env_vars {
             if let Ok(val) = henv::var(var) {
                 debug!("Propagating environment variable into container: {}={}",
@@ -113,6 +117,11 @@ mod inner {
                 cmd_args.push(format!("{}={}", var, val).into());
             }
         }
+        // The key passphrase is propagated by name only, so its value is not on the command line
+        if henv::var(KEY_PASSPHRASE_ENV_VAR).is_ok() {
+            cmd_args.push("--env".into());
+            cmd_args.push(KEY_PASSPHRASE_ENV_VAR.into());
+        }
         cmd_args.push("--volume".into());
         cmd_args.push("/var/run/docker.sock:/var/run/docker.sock".into());
         cmd_args.push("--volume".into());
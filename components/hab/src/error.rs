@@ -19,6 +19,7 @@ use std::io;
 use std::path;
 use std::result;
 
+use clap;
 use depot_client;
 use common;
 use hcore;
@@ -29,8 +30,10 @@ pub type Result<T> = result::Result<T, Error>;
 #[allow(dead_code)]
 pub enum Error {
     ArgumentError(&'static str),
+    ClapError(clap::Error),
     CommandNotFoundInPkg((String, String)),
     CryptoCLI(String),
+    DependencyConflicts(usize),
     DepotClient(depot_client::Error),
     ExecCommandNotFound(String),
     FFINulError(ffi::NulError),
@@ -39,21 +42,102 @@ pub enum Error {
     HabitatCore(hcore::Error),
     IO(io::Error),
     PackageArchiveMalformed(String),
+    PackageInUse(String, Vec<String>),
     PathPrefixError(path::StripPrefixError),
     SubcommandNotSupported(String),
     UnsupportedExportFormat(String),
 }
 
+impl Error {
+    /// Returns a stable name for the kind of the error, reported in machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match *self {
+            Error::ArgumentError(_) |
+            Error::ClapError(_) |
+            Error::SubcommandNotSupported(_) |
+            Error::UnsupportedExportFormat(_) => "argument",
+            Error::CommandNotFoundInPkg(_) |
+            Error::ExecCommandNotFound(_) |
+            Error::FileNotFound(_) => "not_found",
+            Error::CryptoCLI(_) |
+            Error::PackageArchiveMalformed(_) => "verification",
+            Error::DependencyConflicts(_) |
+            Error::PackageInUse(_, _) => "conflict",
+            Error::DepotClient(ref err) => depot_client_kind(err),
+            Error::HabitatCommon(ref err) => {
+                match *err {
+                    common::Error::ArtifactChecksumMismatch(_, _, _) |
+                    common::Error::CryptoKeyError(_) |
+                    common::Error::InvalidBundle(_) |
+                    common::Error::InvalidBundleEntry(_, _) => "verification",
+                    common::Error::ArtifactNotCached(_) => "not_found",
+                    common::Error::DepotClient(ref err) => depot_client_kind(err),
+                    common::Error::HabitatCore(ref err) => core_kind(err),
+                    _ => "error",
+                }
+            }
+            Error::HabitatCore(ref err) => core_kind(err),
+            _ => "error",
+        }
+    }
+
+    /// Returns the exit code `hab` exits with on the error. Scripts may rely on these codes:
+    ///
+    /// * `1`: any error not listed below
+    /// * `2`: invalid arguments
+    /// * `3`: a file, package, key or command was not found
+    /// * `4`: a request to the Depot failed
+    /// * `5`: an artifact or key failed verification
+    /// * `6`: a package is in use or has conflicting dependencies
+    pub fn exit_code(&self) -> i32 {
+        match self.kind() {
+            "argument" => 2,
+            "not_found" => 3,
+            "depot" => 4,
+            "verification" => 5,
+            "conflict" => 6,
+            _ => 1,
+        }
+    }
+}
+
+fn core_kind(err: &hcore::Error) -> &'static str {
+    match *err {
+        hcore::Error::InvalidPackageIdent(_) |
+        hcore::Error::InvalidServiceGroup(_) => "argument",
+        hcore::Error::FileNotFound(_) |
+        hcore::Error::MetaFileNotFound(_) |
+        hcore::Error::PackageNotFound(_) => "not_found",
+        hcore::Error::CryptoError(_) => "verification",
+        _ => "error",
+    }
+}
+
+fn depot_client_kind(err: &depot_client::Error) -> &'static str {
+    match *err {
+        depot_client::Error::RemoteOriginKeyNotFound(_) |
+        depot_client::Error::RemotePackageNotFound(_) => "not_found",
+        depot_client::Error::HabitatCore(ref err) => core_kind(err),
+        _ => "depot",
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::ArgumentError(ref e) => format!("{}", e),
+            Error::ClapError(ref err) => format!("{}", err),
             Error::CommandNotFoundInPkg((ref p, ref c)) => {
                 format!("`{}' was not found under any 'PATH' directories in the {} package",
                         c,
                         p)
             }
             Error::CryptoCLI(ref e) => format!("{}", e),
+            Error::DependencyConflicts(ref n) => {
+                format!("Found {} packages combining several releases of a dependency on their \
+                         runtime path",
+                        n)
+            }
             Error::DepotClient(ref err) => format!("{}", err),
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
@@ -67,6 +151,11 @@ impl fmt::Display for Error {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
             }
+            Error::PackageInUse(ref p, ref users) => {
+                format!("Package {} is in use by {}, use --force to remove it anyway",
+                        p,
+                        users.join(", "))
+            }
             Error::PathPrefixError(ref err) => format!("{}", err),
             Error::SubcommandNotSupported(ref e) => {
                 format!("Subcommand `{}' not supported on this operating system", e)
@@ -81,10 +170,12 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::ArgumentError(_) => "There was an error parsing an error or with it's value",
+            Error::ClapError(ref err) => err.description(),
             Error::CommandNotFoundInPkg(_) => {
                 "Command was not found under any 'PATH' directories in the package"
             }
             Error::CryptoCLI(_) => "A cryptographic error has occurred",
+            Error::DependencyConflicts(_) => "Packages have conflicting dependencies",
             Error::DepotClient(ref err) => err.description(),
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::FFINulError(ref err) => err.description(),
@@ -95,6 +186,7 @@ impl error::Error for Error {
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
+            Error::PackageInUse(_, _) => "Package is in use by other packages or services",
             Error::PathPrefixError(ref err) => err.description(),
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
@@ -137,3 +229,79 @@ impl From<path::StripPrefixError> for Error {
         Error::PathPrefixError(err)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io;
+    use std::str::FromStr;
+
+    use common;
+    use depot_client;
+    use hcore;
+    use hcore::package::PackageIdent;
+    use hyper::status::StatusCode;
+
+    use cli;
+    use super::Error;
+
+    fn kind_and_exit_code(err: Error) -> (&'static str, i32) {
+        (err.kind(), err.exit_code())
+    }
+
+    #[test]
+    fn argument_errors() {
+        assert_eq!(kind_and_exit_code(Error::ArgumentError("Missing a package identifier")),
+                   ("argument", 2));
+        let ident = hcore::Error::InvalidPackageIdent("core".to_string());
+        assert_eq!(kind_and_exit_code(Error::HabitatCore(ident)), ("argument", 2));
+    }
+
+    #[test]
+    fn clap_errors_are_argument_errors() {
+        let err = cli::get().get_matches_from_safe(vec!["hab", "--format", "yaml", "pkg"]);
+        match err {
+            Err(err) => assert_eq!(kind_and_exit_code(Error::ClapError(err)), ("argument", 2)),
+            Ok(_) => panic!("Expected an invalid --format to be refused"),
+        }
+    }
+
+    #[test]
+    fn not_found_errors() {
+        assert_eq!(kind_and_exit_code(Error::FileNotFound("/tmp/foo.hart".to_string())),
+                   ("not_found", 3));
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        let err = Error::DepotClient(depot_client::Error::RemotePackageNotFound(ident));
+        assert_eq!(kind_and_exit_code(err), ("not_found", 3));
+        let err = Error::HabitatCommon(common::Error::ArtifactNotCached("core/redis".to_string()));
+        assert_eq!(kind_and_exit_code(err), ("not_found", 3));
+    }
+
+    #[test]
+    fn depot_errors() {
+        let err = Error::DepotClient(depot_client::Error::HTTP(StatusCode::BadGateway));
+        assert_eq!(kind_and_exit_code(err), ("depot", 4));
+        let err = common::Error::DepotClient(depot_client::Error::HTTP(StatusCode::Forbidden));
+        assert_eq!(kind_and_exit_code(Error::HabitatCommon(err)), ("depot", 4));
+    }
+
+    #[test]
+    fn verification_errors() {
+        assert_eq!(kind_and_exit_code(Error::CryptoCLI("Bad signature".to_string())),
+                   ("verification", 5));
+        let err = hcore::Error::CryptoError("Signature verification failed".to_string());
+        assert_eq!(kind_and_exit_code(Error::HabitatCore(err)), ("verification", 5));
+    }
+
+    #[test]
+    fn conflict_errors() {
+        assert_eq!(kind_and_exit_code(Error::DependencyConflicts(2)), ("conflict", 6));
+        let err = Error::PackageInUse("core/openssl".to_string(), vec!["core/curl".to_string()]);
+        assert_eq!(kind_and_exit_code(err), ("conflict", 6));
+    }
+
+    #[test]
+    fn other_errors() {
+        let err = Error::IO(io::Error::new(io::ErrorKind::Other, "disk on fire"));
+        assert_eq!(kind_and_exit_code(err), ("error", 1));
+    }
+}
@@ -98,10 +98,12 @@ pub fn command_from_pkg(command: &str,
                      Cyan.bold()
                          .paint(format!("∵ Package for {} not found, installing", &ident)));
             try!(common::command::package::install::from_url(&default_depot_url(),
+                                                             None,
                                                              ident,
                                                              fs_root_path,
                                                              &cache_artifact_path(None),
-                                                             cache_key_path));
+                                                             cache_key_path,
+                                                             None));
             command_from_pkg(&command, &ident, &cache_key_path, retry + 1)
         }
         Err(e) => return Err(Error::from(e)),
@@ -22,6 +22,7 @@ extern crate ansi_term;
 extern crate clap;
 extern crate env_logger;
 extern crate hyper;
+extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate pbr;
@@ -32,6 +33,8 @@ extern crate url;
 // Temporary depdency for gossip/rumor injection code duplication.
 extern crate utp;
 extern crate uuid;
+#[cfg(test)]
+extern crate tempdir;
 
 mod analytics;
 mod cli;
@@ -40,27 +43,39 @@ mod config;
 mod error;
 mod exec;
 mod gossip;
+mod output;
+mod passphrase;
 
 use std::env;
 use std::ffi::OsString;
-use std::io::{self, Read};
-use std::path::Path;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::thread;
 
 use ansi_term::Colour::Red;
-use clap::ArgMatches;
+use clap::{ArgMatches, ErrorKind};
+use rustc_serialize::json::{Json, ToJson};
 
 use error::{Error, Result};
 use hcore::env as henv;
-use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SigKeyPair, SymKey};
-use hcore::crypto::keys::PairType;
+use hcore::config::ConfigFile;
+use hcore::crypto::{init, default_cache_key_path, default_trust_store_path, BoxKeyPair,
+                    CommandSigner, SigKeyPair, Signer, SymKey, TrustStore,
+                    KEY_EXPIRE_DAYS_ENV_VAR, SECRET_BOX_KEY_SUFFIX, SECRET_SIG_KEY_SUFFIX,
+                    SECRET_SYM_KEY_SUFFIX};
+use hcore::crypto::artifact::VerifyPolicy;
+use hcore::crypto::keys::{revocation, PairType};
+use hcore::crypto::signer::origin_signer_program;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path, FS_ROOT_PATH};
 use hcore::service::ServiceGroup;
-use hcore::package::PackageIdent;
+use hcore::package::{Lockfile, PackageIdent};
+use hcore::package::lockfile::DEFAULT_LOCKFILE;
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
 use gossip::hab_gossip;
+use output::OutputFormat;
 
 const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
 
@@ -79,13 +94,25 @@ const DEFAULT_BINLINK_DIR: &'static str = "/bin";
 
 const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
 
+/// The extension of files encrypted with `hab file encrypt`
+const ENCRYPTED_FILE_EXT: &'static str = "box";
+
 fn main() {
     env_logger::init().unwrap();
     thread::spawn(|| analytics::instrument_subcommand());
-    if let Err(e) = start() {
-        println!("{}",
-                 Red.bold().paint(format!("✗✗✗\n✗✗✗ {}\n✗✗✗", e)));
-        std::process::exit(1)
+    match start() {
+        Ok(()) => output::finish(),
+        Err(e) => {
+            if output::is_json() {
+                output::error(&e);
+            } else if let Error::ClapError(ref err) = e {
+                let _ = writeln!(io::stderr(), "{}", err.message);
+            } else {
+                println!("{}",
+                         Red.bold().paint(format!("✗✗✗\n✗✗✗ {}\n✗✗✗", e)));
+            }
+            std::process::exit(e.exit_code())
+        }
     }
 }
 
@@ -95,11 +122,24 @@ fn start() -> Result<()> {
     let (args, remaining_args) = raw_parse_args();
     debug!("clap cli args: {:?}", &args);
     debug!("remaining cli args: {:?}", &remaining_args);
-    let app_matches =
-        cli::get().get_matches_from_safe_borrow(&mut args.iter()).unwrap_or_else(|e| {
+    let app_matches = match cli::get().get_matches_from_safe_borrow(&mut args.iter()) {
+        Ok(matches) => matches,
+        Err(e) => {
             analytics::instrument_clap_error(&e);
-            e.exit();
-        });
+            match e.kind {
+                ErrorKind::HelpDisplayed | ErrorKind::VersionDisplayed => e.exit(),
+                _ => {
+                    if let Some(format) = raw_format_arg(&args) {
+                        try!(output::set_format(format));
+                    }
+                    return Err(Error::ClapError(e));
+                }
+            }
+        }
+    };
+    if let Some(format) = format_arg(&app_matches) {
+        try!(output::set_format(try!(OutputFormat::from_str(format))));
+    }
     match app_matches.subcommand() {
         ("apply", Some(m)) => try!(sub_config_apply(m)),
         ("cli", Some(matches)) => {
@@ -116,6 +156,8 @@ fn start() -> Result<()> {
         }
         ("file", Some(matches)) => {
             match matches.subcommand() {
+                ("decrypt", Some(m)) => try!(sub_file_decrypt(m)),
+                ("encrypt", Some(m)) => try!(sub_file_encrypt(m)),
                 ("upload", Some(m)) => try!(sub_file_upload(m)),
                 _ => unreachable!(),
             }
@@ -127,12 +169,31 @@ fn start() -> Result<()> {
                     match m.subcommand() {
                         ("download", Some(sc)) => try!(sub_origin_key_download(sc)),
                         ("export", Some(sc)) => try!(sub_origin_key_export(sc)),
+                        ("fingerprint", Some(sc)) => try!(sub_origin_key_fingerprint(sc)),
                         ("generate", Some(sc)) => try!(sub_origin_key_generate(sc)),
                         ("import", Some(_)) => try!(sub_origin_key_import()),
+                        ("list", Some(sc)) => try!(sub_origin_key_list(sc)),
+                        ("revoke", Some(sc)) => try!(sub_origin_key_revoke(sc)),
+                        ("rotate", Some(sc)) => try!(sub_origin_key_rotate(sc)),
                         ("upload", Some(sc)) => try!(sub_origin_key_upload(sc)),
                         _ => unreachable!(),
                     }
                 }
+                ("delete", Some(m)) => try!(sub_origin_delete(m)),
+                ("invitations", Some(m)) => {
+                    match m.subcommand() {
+                        ("list", Some(sc)) => try!(sub_origin_invitations_list(sc)),
+                        ("rescind", Some(sc)) => try!(sub_origin_invitations_rescind(sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                ("member", Some(m)) => {
+                    match m.subcommand() {
+                        ("remove", Some(sc)) => try!(sub_origin_member_remove(sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                ("transfer", Some(m)) => try!(sub_origin_transfer(m)),
                 _ => unreachable!(),
             }
         }
@@ -140,12 +201,23 @@ fn start() -> Result<()> {
             match matches.subcommand() {
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(m)),
                 ("build", Some(m)) => try!(sub_pkg_build(m)),
+                ("bundle", Some(m)) => try!(sub_pkg_bundle(m)),
+                ("diff", Some(m)) => try!(sub_pkg_diff(m)),
+                ("doctor", Some(m)) => try!(sub_pkg_doctor(m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
                 ("export", Some(m)) => try!(sub_pkg_export(m)),
                 ("hash", Some(m)) => try!(sub_pkg_hash(m)),
                 ("install", Some(m)) => try!(sub_pkg_install(m)),
+                ("info", Some(m)) => try!(sub_pkg_info(m)),
+                ("list", Some(m)) => try!(sub_pkg_list(m)),
+                ("lock", Some(m)) => try!(sub_pkg_lock(m)),
                 ("path", Some(m)) => try!(sub_pkg_path(m)),
+                ("prune", Some(m)) => try!(sub_pkg_prune(m)),
+                ("search", Some(m)) => try!(sub_pkg_search(m)),
+                ("countersign", Some(m)) => try!(sub_pkg_countersign(m)),
                 ("sign", Some(m)) => try!(sub_pkg_sign(m)),
+                ("uninstall", Some(m)) => try!(sub_pkg_uninstall(m)),
+                ("unlink", Some(m)) => try!(sub_pkg_unlink(m)),
                 ("upload", Some(m)) => try!(sub_pkg_upload(m)),
                 ("verify", Some(m)) => try!(sub_pkg_verify(m)),
                 _ => unreachable!(),
@@ -158,6 +230,7 @@ fn start() -> Result<()> {
                         ("export", Some(sc)) => try!(sub_ring_key_export(sc)),
                         ("import", Some(_)) => try!(sub_ring_key_import()),
                         ("generate", Some(sc)) => try!(sub_ring_key_generate(sc)),
+                        ("rotate", Some(sc)) => try!(sub_ring_key_rotate(sc)),
                         _ => unreachable!(),
                     }
                 }
@@ -221,7 +294,10 @@ fn sub_config_apply(m: &ArgMatches) -> Result<()> {
     init();
     let cache = default_cache_key_path(fs_root_path);
     let ring_key = match m.value_of("RING") {
-        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        Some(name) => {
+            try!(passphrase::unlock_latest(&name, SECRET_SYM_KEY_SUFFIX, &cache));
+            Some(try!(SymKey::get_latest_pair_for(&name, &cache)))
+        }
         None => None,
     };
 
@@ -237,6 +313,65 @@ fn sub_config_apply(m: &ArgMatches) -> Result<()> {
     command::config::apply::start(&peers, ring_key.as_ref(), &sg, number, file_path)
 }
 
+fn sub_file_decrypt(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let file_path = Path::new(m.value_of("FILE").unwrap());
+    let output = match m.value_of("OUTPUT") {
+        Some(output) => PathBuf::from(output),
+        None => {
+            match file_path.extension() {
+                Some(ext) if ext == ENCRYPTED_FILE_EXT => file_path.with_extension(""),
+                _ => {
+                    return Err(Error::CryptoCLI(format!("{} has no .{} extension, specify \
+                                                         where to write the decrypted file \
+                                                         with --output",
+                                                        file_path.display(),
+                                                        ENCRYPTED_FILE_EXT)))
+                }
+            }
+        }
+    };
+    let mut payload = Vec::new();
+    try!(try!(File::open(file_path)).read_to_end(&mut payload));
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let (_, recipient) = try!(BoxKeyPair::payload_key_names(&payload));
+    let keyfile = try!(BoxKeyPair::get_secret_key_path(&recipient, &cache));
+    try!(passphrase::unlock_keyfile(&keyfile));
+
+    command::file::decrypt::start(&payload, file_path, &output, &cache)
+}
+
+fn sub_file_encrypt(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let file_path = Path::new(m.value_of("FILE").unwrap());
+    let output = match m.value_of("OUTPUT") {
+        Some(output) => PathBuf::from(output),
+        None => PathBuf::from(format!("{}.{}", file_path.display(), ENCRYPTED_FILE_EXT)),
+    };
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let recipient = match m.value_of("TO_SERVICE") {
+        Some(to) => {
+            let mut sg = try!(ServiceGroup::from_str(to));
+            if sg.organization.is_none() {
+                sg.organization = Some(try!(org_param_or_env(&m)));
+            }
+            sg.to_string()
+        }
+        None => m.value_of("TO_USER").unwrap().to_string(),
+    };
+    let recipient_pair = try!(BoxKeyPair::get_latest_pair_for(&recipient, &cache));
+
+    let user = try!(user_param_or_env(&m));
+    try!(passphrase::unlock_latest(&user, SECRET_BOX_KEY_SUFFIX, &cache));
+    let user_pair = try!(BoxKeyPair::get_latest_pair_for(&user, &cache));
+
+    command::file::encrypt::start(&user_pair, &recipient_pair, file_path, &output)
+}
+
 fn sub_file_upload(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -267,7 +402,10 @@ fn sub_file_upload(m: &ArgMatches) -> Result<()> {
     init();
     let cache = default_cache_key_path(fs_root_path);
     let ring_key = match m.value_of("RING") {
-        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        Some(name) => {
+            try!(passphrase::unlock_latest(&name, SECRET_SYM_KEY_SUFFIX, &cache));
+            Some(try!(SymKey::get_latest_pair_for(&name, &cache)))
+        }
         None => None,
     };
 
@@ -279,6 +417,7 @@ fn sub_file_upload(m: &ArgMatches) -> Result<()> {
     let service_pair = try!(BoxKeyPair::get_latest_pair_for(&sg.to_string(), &cache));
 
     let user = try!(user_param_or_env(&m));
+    try!(passphrase::unlock_latest(&user, SECRET_BOX_KEY_SUFFIX, &cache));
     let user_pair = try!(BoxKeyPair::get_latest_pair_for(&user, &cache));
 
     command::file::upload::start(&peers,
@@ -289,6 +428,55 @@ fn sub_file_upload(m: &ArgMatches) -> Result<()> {
                                  file_path)
 }
 
+fn sub_origin_delete(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let origin = m.value_of("ORIGIN").unwrap();
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::origin::delete::start(&url, &token, &origin)
+}
+
+fn sub_origin_invitations_list(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let origin = m.value_of("ORIGIN").unwrap();
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::origin::invitations::list::start(&url, &token, &origin)
+}
+
+fn sub_origin_invitations_rescind(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let origin = m.value_of("ORIGIN").unwrap();
+    // validated as numeric by the CLI
+    let invitation_id = m.value_of("INVITATION_ID").unwrap().parse::<u64>().unwrap();
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::origin::invitations::rescind::start(&url, &token, &origin, invitation_id)
+}
+
+fn sub_origin_member_remove(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let origin = m.value_of("ORIGIN").unwrap();
+    let username = m.value_of("USERNAME").unwrap();
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::origin::member::remove::start(&url, &token, &origin, &username)
+}
+
+fn sub_origin_transfer(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let origin = m.value_of("ORIGIN").unwrap();
+    let username = m.value_of("USERNAME").unwrap();
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::origin::transfer::start(&url, &token, &origin, &username)
+}
+
 fn sub_origin_key_download(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -313,10 +501,26 @@ fn sub_origin_key_export(m: &ArgMatches) -> Result<()> {
     command::origin::key::export::start(origin, pair_type, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_origin_key_fingerprint(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let origin = m.value_of("ORIGIN").unwrap();
+    let revision = m.value_of("REVISION");
+    init();
+
+    command::origin::key::fingerprint::start(origin,
+                                             revision,
+                                             &default_cache_key_path(fs_root_path))
+}
+
 fn sub_origin_key_generate(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
     let origin = try!(origin_param_or_env(&m));
+    if m.is_present("ENCRYPT") {
+        try!(passphrase::set_new());
+    }
+    set_key_expiry(&m);
     init();
 
     command::origin::key::generate::start(&origin, &default_cache_key_path(fs_root_path))
@@ -327,11 +531,55 @@ fn sub_origin_key_import() -> Result<()> {
     let fs_root_path = Some(Path::new(&fs_root));
     let mut content = String::new();
     try!(io::stdin().read_to_string(&mut content));
+    try!(passphrase::unlock_key_str(&content));
     init();
 
     command::origin::key::import::start(&content, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_origin_key_list(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let origin = try!(origin_param_or_env(&m));
+    init();
+
+    let keys = try!(command::origin::key::list::start(&origin,
+                                                      &default_cache_key_path(fs_root_path)));
+    output::result(&output::object(vec![("origin", origin.to_json()), ("keys", keys.to_json())]));
+    Ok(())
+}
+
+fn sub_origin_key_revoke(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+    let origin = m.value_of("ORIGIN").unwrap();
+    let revision = m.value_of("REVISION").unwrap();
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    try!(passphrase::unlock_latest(origin, SECRET_SIG_KEY_SUFFIX, &cache));
+
+    command::origin::key::revoke::start(url, &token, origin, revision, &cache)
+}
+
+fn sub_origin_key_rotate(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+    let origin = m.value_of("ORIGIN").unwrap();
+    init();
+
+    command::origin::key::rotate::start(url,
+                                        &token,
+                                        origin,
+                                        m.is_present("WITH_SECRET"),
+                                        &default_cache_key_path(fs_root_path))
+}
+
 fn sub_origin_key_upload(m: &ArgMatches) -> Result<()> {
 
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
@@ -362,11 +610,23 @@ fn sub_origin_key_upload(m: &ArgMatches) -> Result<()> {
 fn sub_pkg_binlink(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Path::new(&fs_root);
-    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
-    let binary = m.value_of("BINARY").unwrap();
     let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
+    if m.is_present("LIST") {
+        return command::pkg::binlink::list(&dest_dir, &fs_root_path);
+    }
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => try!(PackageIdent::from_str(ident)),
+        None => return Err(Error::ArgumentError("Missing a package identifier")),
+    };
 
-    command::pkg::binlink::start(&ident, &binary, &dest_dir, &fs_root_path)
+    if m.is_present("ALL") {
+        command::pkg::binlink::start_all(&ident, &dest_dir, &fs_root_path)
+    } else {
+        match m.value_of("BINARY") {
+            Some(binary) => command::pkg::binlink::start(&ident, &binary, &dest_dir, &fs_root_path),
+            None => Err(Error::ArgumentError("Missing a binary to symlink, or --all")),
+        }
+    }
 }
 
 fn sub_pkg_build(m: &ArgMatches) -> Result<()> {
@@ -381,6 +641,9 @@ fn sub_pkg_build(m: &ArgMatches) -> Result<()> {
             init();
             for key in keys.clone() {
                 // Validate that all secret keys are present
+                try!(passphrase::unlock_latest(key,
+                                               SECRET_SIG_KEY_SUFFIX,
+                                               &default_cache_key_path(fs_root_path)));
                 let pair = try!(SigKeyPair::get_latest_pair_for(key,
                                 &default_cache_key_path(fs_root_path)));
                 let _ = pair.secret();
@@ -412,11 +675,68 @@ fn sub_pkg_export(m: &ArgMatches) -> Result<()> {
     command::pkg::export::start(&ident, &export_fmt)
 }
 
+fn sub_pkg_bundle(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    let dst = m.value_of("OUTPUT").map(Path::new);
+    let token = optional_auth_token(&m);
+    init();
+
+    try!(common::command::package::bundle::start(url,
+                                                 token.as_ref().map(String::as_str),
+                                                 &ident,
+                                                 dst,
+                                                 Path::new(&fs_root),
+                                                 &cache_artifact_path(fs_root_path),
+                                                 &default_cache_key_path(fs_root_path)));
+    Ok(())
+}
+
+fn sub_pkg_diff(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let a = m.value_of("PKG_A").unwrap();
+    let b = m.value_of("PKG_B").unwrap();
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = if m.is_present("REMOTE") {
+        Some(m.value_of("DEPOT_URL").unwrap_or(&env_or_default))
+    } else {
+        None
+    };
+    let token = optional_auth_token(&m);
+    init();
+
+    let diff = try!(command::pkg::diff::start(a,
+                                              b,
+                                              url,
+                                              token.as_ref().map(String::as_str),
+                                              &fs_root_path,
+                                              &cache_artifact_path(Some(fs_root_path))));
+    output::result(&diff);
+    Ok(())
+}
+
+fn sub_pkg_doctor(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => Some(try!(PackageIdent::from_str(ident))),
+        None => None,
+    };
+
+    command::pkg::doctor::start(ident.as_ref(), &fs_root_path)
+}
+
 fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
     let source = m.value_of("SOURCE").unwrap();
 
     init();
-    command::pkg::hash::start(&source)
+    let hash = try!(command::pkg::hash::start(&source));
+    output::result(&output::object(vec![("path", source.to_json()), ("checksum", hash.to_json())]));
+    Ok(())
 }
 
 fn sub_pkg_install(m: &ArgMatches) -> Result<()> {
@@ -424,16 +744,116 @@ fn sub_pkg_install(m: &ArgMatches) -> Result<()> {
     let fs_root_path = Some(Path::new(&fs_root));
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
-    let ident_or_artifacts = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap();
+    let ident_or_artifacts: Vec<&str> =
+        m.values_of("PKG_IDENT_OR_ARTIFACT").map(|v| v.collect()).unwrap_or(vec![]);
+    if ident_or_artifacts.is_empty() && !m.is_present("LOCKFILE") {
+        return Err(Error::ArgumentError("Missing a package identifier, artifact or lockfile"));
+    }
+    let token = optional_auth_token(&m);
     init();
+    let trust_store = if m.is_present("STRICT") {
+        Some(try!(TrustStore::from_file(default_trust_store_path(fs_root_path))))
+    } else {
+        None
+    };
 
+    let mut installed = Vec::new();
+    if let Some(lockfile) = m.value_of("LOCKFILE") {
+        let lockfile = try!(Lockfile::from_file(lockfile));
+        let artifact_path = cache_artifact_path(fs_root_path);
+        let key_path = default_cache_key_path(fs_root_path);
+        try!(common::command::package::install::from_lockfile(url,
+                                                              token.as_ref().map(String::as_str),
+                                                              &lockfile,
+                                                              Path::new(&fs_root),
+                                                              &artifact_path,
+                                                              &key_path,
+                                                              trust_store.as_ref(),
+                                                              m.is_present("OFFLINE")));
+        installed.extend(lockfile.packages.iter().map(|package| package.ident.clone()));
+    }
     for ident_or_artifact in ident_or_artifacts {
-        try!(common::command::package::install::start(url,
-                                                      ident_or_artifact,
-                                                      Path::new(&fs_root),
-                                                      &cache_artifact_path(fs_root_path),
-                                                      &default_cache_key_path(fs_root_path)));
+        let ident =
+            try!(common::command::package::install::start(url,
+                                                          token.as_ref().map(String::as_str),
+                                                          ident_or_artifact,
+                                                          Path::new(&fs_root),
+                                                          &cache_artifact_path(fs_root_path),
+                                                          &default_cache_key_path(fs_root_path),
+                                                          trust_store.as_ref(),
+                                                          m.is_present("OFFLINE")));
+        installed.push(ident);
+    }
+    // The packages are installed already, so a failure to relink their binaries doesn't fail
+    if let Err(err) = command::pkg::binlink::relink(&installed,
+                                                     Path::new(DEFAULT_BINLINK_DIR),
+                                                     Path::new(&fs_root)) {
+        println!("{} {}", Red.bold().paint("✗ Failed to relink binaries:"), err);
+    }
+    let installed: Vec<String> = installed.iter().map(|ident| ident.to_string()).collect();
+    output::result(&output::object(vec![("installed", installed.to_json())]));
+    Ok(())
+}
+
+fn sub_pkg_info(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident_or_artifact = m.value_of("PKG_IDENT_OR_ARTIFACT").unwrap();
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = if m.is_present("REMOTE") {
+        Some(m.value_of("DEPOT_URL").unwrap_or(&env_or_default))
+    } else {
+        None
+    };
+    let token = optional_auth_token(&m);
+    init();
+
+    let info = try!(command::pkg::info::start(ident_or_artifact,
+                                              url,
+                                              token.as_ref().map(String::as_str),
+                                              &fs_root_path,
+                                              &cache_artifact_path(Some(fs_root_path)),
+                                              &default_cache_key_path(Some(fs_root_path))));
+    output::result(&info);
+    Ok(())
+}
+
+fn sub_pkg_list(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => Some(try!(PackageIdent::from_str(ident))),
+        None => None,
+    };
+
+    let packages = try!(command::pkg::list::start(ident.as_ref(), &fs_root_path));
+    let packages: Vec<Json> = packages.into_iter()
+        .map(|(ident, in_use)| {
+            output::object(vec![("ident", ident.to_string().to_json()),
+                                ("in_use", in_use.to_json())])
+        })
+        .collect();
+    output::result(&output::object(vec![("packages", packages.to_json())]));
+    Ok(())
+}
+
+fn sub_pkg_lock(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let mut idents = Vec::new();
+    for ident in m.values_of("PKG_IDENT").unwrap() {
+        idents.push(try!(PackageIdent::from_str(ident)));
     }
+    let dst = Path::new(m.value_of("OUTPUT").unwrap_or(DEFAULT_LOCKFILE));
+    let token = optional_auth_token(&m);
+    init();
+
+    try!(common::command::package::lock::start(url,
+                                               token.as_ref().map(String::as_str),
+                                               &idents,
+                                               dst,
+                                               Path::new(&fs_root)));
     Ok(())
 }
 
@@ -442,7 +862,55 @@ fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     let fs_root_path = Path::new(&fs_root);
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
-    command::pkg::path::start(&ident, &fs_root_path)
+    let pkg_install = try!(command::pkg::path::start(&ident, &fs_root_path));
+    output::result(&output::object(vec![("ident", pkg_install.ident().to_string().to_json()),
+                                        ("path",
+                                         pkg_install.installed_path()
+                                             .to_string_lossy()
+                                             .to_json())]));
+    Ok(())
+}
+
+fn sub_pkg_prune(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => Some(try!(PackageIdent::from_str(ident))),
+        None => None,
+    };
+    let keep = value_t!(m, "KEEP", usize).unwrap_or(1);
+    if keep == 0 {
+        return Err(Error::ArgumentError("--keep must be at least 1"));
+    }
+
+    command::pkg::prune::start(ident.as_ref(), keep, &fs_root_path)
+}
+
+fn sub_pkg_search(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let terms: Vec<&str> = m.values_of("SEARCH_TERM").unwrap().collect();
+    let query = terms.join(" ");
+    let token = optional_auth_token(&m);
+
+    command::pkg::search::start(&url,
+                                &query,
+                                m.value_of("SORT"),
+                                m.value_of("ORDER"),
+                                token.as_ref().map(String::as_str))
+}
+
+fn sub_pkg_countersign(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let src = Path::new(m.value_of("SOURCE").unwrap());
+    let dst = Path::new(m.value_of("DEST").unwrap());
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let origin = try!(origin_param_or_env(&m));
+    let signer = try!(origin_signer(&origin, &cache));
+
+    command::pkg::countersign::start(&*signer, &src, &dst)
 }
 
 fn sub_pkg_sign(m: &ArgMatches) -> Result<()> {
@@ -451,10 +919,37 @@ fn sub_pkg_sign(m: &ArgMatches) -> Result<()> {
     let src = Path::new(m.value_of("SOURCE").unwrap());
     let dst = Path::new(m.value_of("DEST").unwrap());
     init();
-    let pair = try!(SigKeyPair::get_latest_pair_for(&try!(origin_param_or_env(&m)),
-                                                    &default_cache_key_path(fs_root_path)));
+    let cache = default_cache_key_path(fs_root_path);
+    let origin = try!(origin_param_or_env(&m));
+    let signer = try!(origin_signer(&origin, &cache));
 
-    command::pkg::sign::start(&pair, &src, &dst)
+    command::pkg::sign::start(&*signer, &src, &dst)
+}
+
+fn sub_pkg_uninstall(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+
+    command::pkg::uninstall::start(&ident, &fs_root_path, m.is_present("FORCE"))
+}
+
+fn sub_pkg_unlink(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => Some(try!(PackageIdent::from_str(ident))),
+        None => None,
+    };
+    if ident.is_none() && !m.is_present("BINARY") && !m.is_present("ALL") {
+        return Err(Error::ArgumentError("Missing a package identifier, a --binary, or --all"));
+    }
+    let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
+
+    command::pkg::binlink::unlink(ident.as_ref(),
+                                  m.value_of("BINARY"),
+                                  &dest_dir,
+                                  &fs_root_path)
 }
 
 fn sub_pkg_upload(m: &ArgMatches) -> Result<()> {
@@ -468,9 +963,12 @@ fn sub_pkg_upload(m: &ArgMatches) -> Result<()> {
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
     let token = try!(auth_token_param_or_env(&m));
     let artifact_paths = m.values_of("HART_FILE").unwrap();
+    let mut uploaded = Vec::new();
     for artifact_path in artifact_paths {
-        try!(command::pkg::upload::start(&url, &token, &artifact_path, &key_path));
+        let ident = try!(command::pkg::upload::start(&url, &token, &artifact_path, &key_path));
+        uploaded.push(ident.to_string());
     }
+    output::result(&output::object(vec![("uploaded", uploaded.to_json())]));
     Ok(())
 }
 
@@ -478,9 +976,18 @@ fn sub_pkg_verify(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
     let src = Path::new(m.value_of("SOURCE").unwrap());
+    let policy = match m.value_of("POLICY") {
+        Some(policy) => try!(VerifyPolicy::from_str(policy)),
+        None => VerifyPolicy::default(),
+    };
     init();
 
-    command::pkg::verify::start(&src, &default_cache_key_path(fs_root_path))
+    let (signers, hash) =
+        try!(command::pkg::verify::start(&src, &policy, &default_cache_key_path(fs_root_path)));
+    output::result(&output::object(vec![("artifact", src.to_string_lossy().to_json()),
+                                        ("signers", signers.to_json()),
+                                        ("checksum", hash.to_json())]));
+    Ok(())
 }
 
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
@@ -496,6 +1003,7 @@ fn sub_ring_key_generate(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
     let ring = m.value_of("RING").unwrap();
+    set_key_expiry(&m);
     init();
 
     command::ring::key::generate::start(ring, &default_cache_key_path(fs_root_path))
@@ -506,16 +1014,51 @@ fn sub_ring_key_import() -> Result<()> {
     let fs_root_path = Some(Path::new(&fs_root));
     let mut content = String::new();
     try!(io::stdin().read_to_string(&mut content));
+    try!(passphrase::unlock_key_str(&content));
     init();
 
     command::ring::key::import::start(&content, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_ring_key_rotate(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let ring = m.value_of("RING").unwrap();
+
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&hab_gossip::GOSSIP_DEFAULT_PORT.to_string());
+        }
+    }
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    try!(passphrase::unlock_latest(&ring, SECRET_SYM_KEY_SUFFIX, &cache));
+
+    let org = try!(org_param_or_env(&m));
+    let mut service_pairs = Vec::new();
+    for sg in m.values_of("SERVICE_GROUP").unwrap() {
+        let mut sg = try!(ServiceGroup::from_str(sg));
+        sg.organization = Some(org.to_string());
+        service_pairs.push(try!(BoxKeyPair::get_latest_pair_for(&sg.to_string(), &cache)));
+    }
+
+    let user = try!(user_param_or_env(&m));
+    try!(passphrase::unlock_latest(&user, SECRET_BOX_KEY_SUFFIX, &cache));
+    let user_pair = try!(BoxKeyPair::get_latest_pair_for(&user, &cache));
+
+    command::ring::key::rotate::start(ring, &peers, &user_pair, &service_pairs, &cache)
+}
+
 fn sub_service_key_generate(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
     let org = try!(org_param_or_env(&m));
     let service_group = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    set_key_expiry(&m);
     init();
 
     command::service::key::generate::start(&org,
@@ -527,6 +1070,7 @@ fn sub_user_key_generate(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
     let user = m.value_of("USER").unwrap(); // clap required
+    set_key_expiry(&m);
     init();
 
     command::user::key::generate::start(user, &default_cache_key_path(fs_root_path))
@@ -583,6 +1127,45 @@ fn auth_token_param_or_env(m: &ArgMatches) -> Result<String> {
     }
 }
 
+/// Like `auth_token_param_or_env`, but for commands where authentication is optional, such as
+/// reading packages which may belong to a private origin.
+fn optional_auth_token(m: &ArgMatches) -> Option<String> {
+    auth_token_param_or_env(m).ok()
+}
+
+/// Returns the value of the global --format option. Clap keeps the value of a global option in
+/// the matches of the subcommand it was given after, so the matches of every subcommand level
+/// are searched.
+fn format_arg<'a>(m: &'a ArgMatches) -> Option<&'a str> {
+    match m.value_of("OUTPUT_FORMAT") {
+        Some(format) => Some(format),
+        None => m.subcommand().1.and_then(format_arg),
+    }
+}
+
+/// Returns the output format asked for on the command line, for reporting arguments which
+/// clap failed to parse.
+fn raw_format_arg(args: &[OsString]) -> Option<OutputFormat> {
+    let mut args = args.iter().map(|arg| arg.to_string_lossy());
+    while let Some(arg) = args.next() {
+        if arg == "--format" {
+            return args.next().and_then(|format| OutputFormat::from_str(&format).ok());
+        }
+        if arg.starts_with("--format=") {
+            return OutputFormat::from_str(&arg["--format=".len()..]).ok();
+        }
+    }
+    None
+}
+
+/// Exports the number of days newly generated keys are valid for into the environment, where key
+/// generation picks it up. An expiry already set in the environment is kept without the option.
+fn set_key_expiry(m: &ArgMatches) {
+    if let Some(days) = m.value_of("EXPIRE_DAYS") {
+        env::set_var(KEY_EXPIRE_DAYS_ENV_VAR, days);
+    }
+}
+
 /// Check to see if the user has passed in an ORIGIN param.  If not, check the HABITAT_ORIGIN env
 /// var. If not, check the CLI config to see if there is a default origin set. If that's empty too,
 /// then error.
@@ -619,6 +1202,24 @@ fn org_param_or_env(m: &ArgMatches) -> Result<String> {
     }
 }
 
+/// Returns the signer for the latest key of an origin. When `HAB_ORIGIN_SIGNER` names an external
+/// signer it is used, otherwise the secret origin key is loaded from the key cache.
+fn origin_signer(origin: &str, cache: &Path) -> Result<Box<Signer>> {
+    let signer: Box<Signer> = match origin_signer_program() {
+        Some(program) => Box::new(try!(CommandSigner::for_origin(&program, origin, cache))),
+        None => {
+            try!(passphrase::unlock_latest(origin, SECRET_SIG_KEY_SUFFIX, cache));
+            Box::new(try!(SigKeyPair::get_latest_pair_for(origin, cache)))
+        }
+    };
+    if try!(revocation::is_revoked(&signer.key_name_with_rev(), cache)) {
+        return Err(Error::CryptoCLI(format!("Origin key {} has been revoked, rotate to a new \
+                                             key with 'hab origin key rotate'",
+                                            signer.key_name_with_rev())));
+    }
+    Ok(signer)
+}
+
 /// Check to see if the user has passed in a USER param.
 /// If not, check the HAB_USER env var. If that's
 /// empty too, then return an error.
@@ -633,3 +1234,44 @@ fn user_param_or_env(m: &ArgMatches) -> Result<String> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsString;
+
+    use cli;
+    use output::OutputFormat;
+    use super::{format_arg, raw_format_arg};
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn format_arg_at_any_level() {
+        let m = cli::get().get_matches_from(vec!["hab", "--format", "json", "pkg", "path", "a/b"]);
+        assert_eq!(format_arg(&m), Some("json"));
+        let m = cli::get().get_matches_from(vec!["hab", "pkg", "path", "--format", "json", "a/b"]);
+        assert_eq!(format_arg(&m), Some("json"));
+    }
+
+    #[test]
+    fn format_arg_ignores_export_format() {
+        let m = cli::get().get_matches_from(vec!["hab", "pkg", "export", "docker", "core/redis"]);
+        assert_eq!(format_arg(&m), None);
+        assert_eq!(m.subcommand_matches("pkg")
+                       .and_then(|m| m.subcommand_matches("export"))
+                       .and_then(|m| m.value_of("FORMAT")),
+                   Some("docker"));
+    }
+
+    #[test]
+    fn raw_format_arg_forms() {
+        assert_eq!(raw_format_arg(&args(&["hab", "pkg", "--format", "json", "bogus"])),
+                   Some(OutputFormat::Json));
+        assert_eq!(raw_format_arg(&args(&["hab", "--format=json", "pkg", "bogus"])),
+                   Some(OutputFormat::Json));
+        assert_eq!(raw_format_arg(&args(&["hab", "pkg", "export", "docker"])), None);
+        assert_eq!(raw_format_arg(&args(&["hab", "--format"])), None);
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Machine-readable output for scripts, selected with `--format json`.
+//!
+//! In JSON mode the standard output of `hab` carries a single JSON document: the result of the
+//! command, an empty object for commands without a result, or the error it failed with.
+//! Everything a command prints for humans, such as its progress and progress bars, is sent to the
+//! standard error instead, so it no longer needs to be scraped. A failed command prints:
+//!
+//! ```json
+//! {"error":{"exit_code":3,"kind":"not_found","message":"File not found at: /tmp/foo.hart"}}
+//! ```
+//!
+//! and exits with the same exit code as in text mode, see `Error::exit_code`.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Write};
+use std::os::unix::io::{FromRawFd, IntoRawFd, RawFd};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering, ATOMIC_BOOL_INIT, ATOMIC_ISIZE_INIT};
+
+use libc;
+use rustc_serialize::json::{Json, ToJson};
+
+use error::{Error, Result};
+
+static JSON: AtomicBool = ATOMIC_BOOL_INIT;
+static JSON_FD: AtomicIsize = ATOMIC_ISIZE_INIT;
+static EMITTED: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// The formats `hab` can print the results of a command in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(Error::ArgumentError("Output format must be one of text or json")),
+        }
+    }
+}
+
+/// Selects the output format for the rest of the process. Selecting JSON keeps the original
+/// standard output for the JSON document and points the standard output at the standard error.
+pub fn set_format(format: OutputFormat) -> Result<()> {
+    if format == OutputFormat::Text || is_json() {
+        return Ok(());
+    }
+    try!(io::stdout().flush());
+    let fd = unsafe { libc::dup(libc::STDOUT_FILENO) };
+    if fd < 0 || unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } < 0 {
+        return Err(Error::IO(io::Error::last_os_error()));
+    }
+    JSON_FD.store(fd as isize, Ordering::SeqCst);
+    JSON.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Returns true if the results of commands are printed as JSON.
+pub fn is_json() -> bool {
+    JSON.load(Ordering::SeqCst)
+}
+
+/// Prints the result of a command as JSON, if JSON is the selected format.
+pub fn result<T: ToJson>(result: &T) {
+    if is_json() {
+        emit(&result.to_json());
+    }
+}
+
+/// Prints an error as JSON, if JSON is the selected format.
+pub fn error(err: &Error) {
+    if is_json() {
+        emit(&object(vec![("error",
+                           object(vec![("kind", err.kind().to_json()),
+                                       ("message", err.to_string().to_json()),
+                                       ("exit_code", err.exit_code().to_json())]))]));
+    }
+}
+
+/// Prints an empty object if JSON is the selected format and the command had no result.
+pub fn finish() {
+    if is_json() && !EMITTED.load(Ordering::SeqCst) {
+        emit(&object(vec![]));
+    }
+}
+
+/// Builds a JSON object out of its fields.
+pub fn object(fields: Vec<(&str, Json)>) -> Json {
+    let mut map = BTreeMap::new();
+    for (key, value) in fields {
+        map.insert(key.to_string(), value);
+    }
+    Json::Object(map)
+}
+
+fn emit(value: &Json) {
+    EMITTED.store(true, Ordering::SeqCst);
+    let _ = io::stdout().flush();
+    let mut out = unsafe { File::from_raw_fd(JSON_FD.load(Ordering::SeqCst) as RawFd) };
+    if let Err(e) = writeln!(out, "{}", value) {
+        debug!("Error writing JSON output: {}", e);
+    }
+    // The descriptor stays open for the rest of the process.
+    out.into_raw_fd();
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Passphrases for secret keys which are encrypted at rest.
+//!
+//! The core crypto library only reads a passphrase from the `HAB_KEY_PASSPHRASE` environment
+//! variable. When a command needs an encrypted secret key and the variable is unset, the
+//! passphrase is prompted for on the terminal and exported into this process's environment so the
+//! key can be loaded.
+
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::mem;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+
+use hcore::crypto::KEY_PASSPHRASE_ENV_VAR;
+use hcore::crypto::keys::{check_key_passphrase, is_encrypted_secret_key, key_passphrase,
+                          latest_secret_keyfile};
+use libc;
+
+use error::{Error, Result};
+
+/// Number of times a passphrase is prompted for before giving up
+const PROMPT_ATTEMPTS: usize = 3;
+
+/// Ensures the passphrase for the secret key of the latest revision of a key is available, if
+/// that secret key is encrypted. The `suffix` selects the kind of secret key, ex:
+/// `SECRET_SIG_KEY_SUFFIX`.
+pub fn unlock_latest(name: &str, suffix: &str, cache: &Path) -> Result<()> {
+    match try!(latest_secret_keyfile(name, suffix, cache)) {
+        Some(keyfile) => unlock_keyfile(&keyfile),
+        None => Ok(()),
+    }
+}
+
+/// Ensures the passphrase for a secret key file is available, if the key is encrypted.
+pub fn unlock_keyfile(keyfile: &Path) -> Result<()> {
+    let mut content = String::new();
+    try!(try!(File::open(keyfile)).read_to_string(&mut content));
+    unlock_key_str(&content)
+}
+
+/// Ensures the passphrase for the contents of a secret key is available, if the key is
+/// encrypted.
+///
+/// A passphrase set in the environment is checked against the key. Otherwise the passphrase is
+/// prompted for and, once it decrypts the key, exported into the environment.
+pub fn unlock_key_str(content: &str) -> Result<()> {
+    if !is_encrypted_secret_key(content) {
+        return Ok(());
+    }
+    if let Some(passphrase) = key_passphrase() {
+        return check_key_passphrase(content, &passphrase).map_err(Error::from);
+    }
+    let name = content.lines().nth(1).unwrap_or("");
+    for _ in 0..PROMPT_ATTEMPTS {
+        let passphrase = try!(prompt(&format!("Passphrase for secret key {}", name)));
+        match check_key_passphrase(content, &passphrase) {
+            Ok(()) => {
+                env::set_var(KEY_PASSPHRASE_ENV_VAR, passphrase);
+                return Ok(());
+            }
+            Err(e) => println!("{}", e),
+        }
+    }
+    Err(Error::CryptoCLI(format!("Could not decrypt secret key {}", name)))
+}
+
+/// Prompts for a new passphrase, which is confirmed by entering it twice, and exports it into
+/// the environment so newly generated secret keys are encrypted with it. A passphrase already
+/// set in the environment is used as is.
+pub fn set_new() -> Result<()> {
+    if key_passphrase().is_some() {
+        return Ok(());
+    }
+    for _ in 0..PROMPT_ATTEMPTS {
+        let passphrase = try!(prompt("New passphrase for secret key"));
+        if passphrase.is_empty() {
+            println!("The passphrase can't be empty");
+            continue;
+        }
+        if passphrase != try!(prompt("Confirm passphrase")) {
+            println!("The passphrases don't match");
+            continue;
+        }
+        env::set_var(KEY_PASSPHRASE_ENV_VAR, passphrase);
+        return Ok(());
+    }
+    Err(Error::CryptoCLI("No passphrase was set".to_string()))
+}
+
+/// Reads a line from the terminal without echoing it.
+fn prompt(question: &str) -> Result<String> {
+    let mut tty = match OpenOptions::new().read(true).write(true).open("/dev/tty") {
+        Ok(tty) => tty,
+        Err(_) => {
+            return Err(Error::CryptoCLI(format!("No terminal to prompt for a passphrase on, \
+                                                 set {} instead",
+                                                KEY_PASSPHRASE_ENV_VAR)))
+        }
+    };
+    let fd = tty.as_raw_fd();
+    let mut term: libc::termios = unsafe { mem::zeroed() };
+    let is_term = unsafe { libc::tcgetattr(fd, &mut term) } == 0;
+    let original = term;
+    if is_term {
+        term.c_lflag &= !libc::ECHO;
+        unsafe { libc::tcsetattr(fd, libc::TCSANOW, &term) };
+    }
+    try!(write!(tty, "{}: ", question));
+    try!(tty.flush());
+    let mut line = String::new();
+    let read = BufReader::new(&tty).read_line(&mut line);
+    if is_term {
+        unsafe { libc::tcsetattr(fd, libc::TCSANOW, &original) };
+    }
+    try!(writeln!(tty, ""));
+    try!(read);
+    Ok(line.trim_right_matches(|c| c == '\r' || c == '\n').to_string())
+}
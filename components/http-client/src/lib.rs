@@ -23,20 +23,35 @@ pub mod error;
 
 use std::sync::Arc;
 use std::path::Path;
+use std::time::Duration;
 
+use hab_core::env;
 use hab_core::util::sys;
-use hyper::client::Client;
+use hyper::client::{Client, ProxyConfig};
 use hyper::client::pool::{Config, Pool};
 use hyper::header::UserAgent;
 use hyper::http::h1::Http11Protocol;
-use hyper::net::{HttpsConnector, Openssl};
+use hyper::net::{HttpConnector, HttpsConnector, Openssl};
 use hyper::Url;
 use openssl::ssl::{SslContext, SslMethod, SSL_OP_NO_SSLV2, SSL_OP_NO_SSLV3, SSL_OP_NO_COMPRESSION};
 
 pub use error::{Error, Result};
 
+/// Overrides the number of seconds a client waits on a read from or write to a connection before
+/// the request fails.
+pub const TIMEOUT_ENVVAR: &'static str = "HAB_HTTP_TIMEOUT";
+
+/// The number of seconds a client waits on a read from or write to a connection, unless
+/// `HAB_HTTP_TIMEOUT` says otherwise.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
 /// Builds a new hyper HTTP client with appropriate SSL configuration and HTTP/HTTPS proxy support.
 ///
+/// Requests are sent through the proxy named by the `http_proxy` environment variable, unless
+/// the domain of `for_domain` is exempted by `no_proxy`. Connections are pooled, so a client
+/// reuses them across requests to the same host, and reads and writes time out after
+/// `HAB_HTTP_TIMEOUT` seconds.
+///
 /// ## Linux Platforms
 ///
 /// We need a set of root certificates when connected to SSL/TLS web endpoints and this usually
@@ -65,11 +80,47 @@ pub use error::{Error, Result};
 /// library will default to using this on the Mac. Therefore the behavior on the Mac remains
 /// unchanged and will use the system's certificates.
 ///
-pub fn new_hyper_client(_for_domain: Option<&Url>, fs_root_path: Option<&Path>) -> Result<Client> {
+pub fn new_hyper_client(for_domain: Option<&Url>, fs_root_path: Option<&Path>) -> Result<Client> {
     let ctx = try!(ssl_ctx(fs_root_path));
-    let connector = HttpsConnector::new(Openssl { context: Arc::new(ctx) });
-    let pool = Pool::with_connector(Config::default(), connector);
-    Ok(Client::with_protocol(Http11Protocol::with_connector(pool)))
+    let ssl = Openssl { context: Arc::new(ctx) };
+    let proxy = match for_domain.and_then(|url| url.host_str()) {
+        Some(domain) => try!(env::http_proxy_unless_domain_exempted(domain)),
+        None => try!(env::http_proxy()),
+    };
+    let mut client = match proxy {
+        Some((host, port)) => {
+            debug!("Using proxy {}:{}", &host, port);
+            let pool = Pool::with_connector(Config::default(), HttpConnector);
+            Client::with_proxy_config(ProxyConfig::new("http", host, port, pool, ssl))
+        }
+        None => {
+            let pool = Pool::with_connector(Config::default(), HttpsConnector::new(ssl));
+            Client::with_protocol(Http11Protocol::with_connector(pool))
+        }
+    };
+    let timeout = Duration::from_secs(timeout_secs());
+    client.set_read_timeout(Some(timeout));
+    client.set_write_timeout(Some(timeout));
+    Ok(client)
+}
+
+/// Returns the number of seconds to wait on a connection, from `HAB_HTTP_TIMEOUT` if it holds a
+/// number of seconds.
+fn timeout_secs() -> u64 {
+    match env::var(TIMEOUT_ENVVAR) {
+        Ok(val) => {
+            match val.parse::<u64>() {
+                Ok(secs) => secs,
+                Err(_) => {
+                    warn!("Ignoring {}={}, which is not a number of seconds",
+                          TIMEOUT_ENVVAR,
+                          &val);
+                    DEFAULT_TIMEOUT_SECS
+                }
+            }
+        }
+        Err(_) => DEFAULT_TIMEOUT_SECS,
+    }
 }
 
 /// Returns an HTTP User-Agent string type for use by Hyper when making HTTP requests.
@@ -176,3 +227,21 @@ mod ssl {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::{timeout_secs, DEFAULT_TIMEOUT_SECS, TIMEOUT_ENVVAR};
+
+    #[test]
+    fn timeout_secs_from_environment() {
+        env::remove_var(TIMEOUT_ENVVAR);
+        assert_eq!(timeout_secs(), DEFAULT_TIMEOUT_SECS);
+        env::set_var(TIMEOUT_ENVVAR, "5");
+        assert_eq!(timeout_secs(), 5);
+        env::set_var(TIMEOUT_ENVVAR, "forever");
+        assert_eq!(timeout_secs(), DEFAULT_TIMEOUT_SECS);
+        env::remove_var(TIMEOUT_ENVVAR);
+    }
+}
@@ -438,6 +438,10 @@ _on_exit() {
 trap _on_exit 1 2 3 15 ERR
 
 _ensure_origin_key_present() {
+  if [[ -n "${HAB_ORIGIN_SIGNER:-}" ]]; then
+    debug "Signing with external origin signer $HAB_ORIGIN_SIGNER"
+    return 0
+  fi
   local cache="$HAB_CACHE_KEY_PATH"
   local keys_found="$(find $cache -name "${pkg_origin}-*.sig.key" | wc -l)"
   if [[ $keys_found -eq 0 ]]; then
@@ -1799,6 +1803,8 @@ do_default_install() {
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
+# * `$pkg_prefix/PROVENANCE` - Signed record of how and from what the package
+#   was built
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=""
@@ -1915,9 +1921,26 @@ _build_metadata() {
   echo "${pkg_origin}/${pkg_name}/${pkg_version}/${pkg_release}" \
     >> $pkg_prefix/IDENT
 
+  pushd "$HAB_CACHE_SRC_PATH/$pkg_dirname" > /dev/null
+  # Record the provenance of the package as a small TOML document, signed with
+  # the origin key. The builder job id and Studio ident are only known when
+  # the build was started by Builder or a Studio, and are otherwise left
+  # empty. No trailing newline is written, as metafile contents are trimmed
+  # when read back which would invalidate the signature.
+  build_line "Generating signed metadata PROVENANCE"
+  printf '%s = "%s"\n%s = "%s"\n%s = "%s"\n%s = "%s"\n%s = "%s"\n%s = "%s"' \
+    plan_shasum "$($_shasum_cmd $PLAN_CONTEXT/plan.sh | cut -d ' ' -f 1)" \
+    studio_ident "${HAB_STUDIO_IDENT:-}" \
+    builder_job_id "${HAB_BUILDER_JOB_ID:-}" \
+    build_timestamp "$(date -u +%Y-%m-%dT%H:%M:%SZ)" \
+    source_url "${pkg_source:-}" \
+    source_shasum "${pkg_shasum:-}" \
+    > ${pkg_name}_provenance
+  $HAB_BIN pkg sign --origin $pkg_origin ${pkg_name}_provenance \
+    $pkg_prefix/PROVENANCE
+
   # Generate the blake2b hashes of all the files in the package. This
   # is not in the resulting MANIFEST because MANIFEST is included!
-  pushd "$HAB_CACHE_SRC_PATH/$pkg_dirname" > /dev/null
   build_line "Generating blake2b hashes of all files in the package"
   find $pkg_prefix -type f \
     | $_sort_cmd \
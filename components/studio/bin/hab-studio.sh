@@ -83,8 +83,14 @@ SUBCOMMANDS:
     version   Prints version information
 
 ENVIRONMENT VARIABLES:
+    HAB_KEY_PASSPHRASE
+                      Propagates this variable into any studios, to use
+                      encrypted secret keys
     HAB_ORIGIN        Propagates this variable into any studios
     HAB_ORIGIN_KEYS   Installs secret keys (\`-k' option overrides)
+    HAB_ORIGIN_SIGNER Propagates this variable into any studios, to sign
+                      with an external signer program instead of secret
+                      keys (the program must be reachable in the Studio)
     HAB_STUDIOS_HOME  Sets a home path for all Studios (default: /hab/studios)
     HAB_STUDIO_ROOT   Sets a Studio root (\`-r' option overrides)
     NO_SRC_PATH       If set, do not mount source path (\`-n' flag overrides)
@@ -652,6 +658,7 @@ enter_studio() {
     STUDIO_TYPE=unknown
   fi
 
+  stage_key_passphrase
   local env="$(chroot_env "$studio_path" "$studio_enter_environment")"
 
   info "Entering Studio at $HAB_STUDIO_ROOT ($STUDIO_TYPE)"
@@ -662,8 +669,9 @@ enter_studio() {
     set -x
   fi
 
-  # Become the `chroot` process
-  exec $bb chroot "$HAB_STUDIO_ROOT" \
+  # Run the `chroot` process in the foreground rather than becoming it, so that
+  # a staged key passphrase file is removed when the session ends
+  $bb chroot "$HAB_STUDIO_ROOT" \
     $studio_env_command -i $env $studio_enter_command $*
 }
 
@@ -688,6 +696,7 @@ build_studio() {
     exit_with "Studio at $HAB_STUDIO_ROOT ($STUDIO_TYPE) does not support 'build'" 10
   fi
 
+  stage_key_passphrase
   local env="$(chroot_env "$studio_path" "$studio_build_environment")"
 
   info "Building '$*' in Studio at $HAB_STUDIO_ROOT ($STUDIO_TYPE)"
@@ -717,6 +726,7 @@ run_studio() {
     STUDIO_TYPE=unknown
   fi
 
+  stage_key_passphrase
   local env="$(chroot_env "$studio_path" "$studio_run_environment")"
 
   info "Running '$*' in Studio at $HAB_STUDIO_ROOT ($STUDIO_TYPE)"
@@ -835,6 +845,24 @@ exit_with() {
   exit $2
 }
 
+# **Internal** Writes the secret key passphrase, if one is set, to a file in
+# the Studio which is only readable by its owner. The path of the file inside
+# the Studio is stored in `$passphrase_file` and the file is removed when the
+# program exits.
+stage_key_passphrase() {
+  passphrase_file=""
+  if [ -z "${HAB_KEY_PASSPHRASE:-}" ]; then
+    return 0
+  fi
+  $bb mkdir -p $HAB_STUDIO_ROOT/tmp
+  local file="$(umask 077 && $bb mktemp $HAB_STUDIO_ROOT/tmp/.hab-key-passphrase.XXXXXX)"
+  trap "$bb rm -f $file" EXIT
+  # `printf` is a shell builtin, so the passphrase is not on a command line
+  printf '%s\n' "$HAB_KEY_PASSPHRASE" > $file
+  passphrase_file="${file#$HAB_STUDIO_ROOT}"
+  return 0
+}
+
 # **Internal** Builds up the environment set to pass to an `env(1)` command for
 # use in a `chroot` environment which is printed on stdout.
 chroot_env() {
@@ -846,6 +874,9 @@ chroot_env() {
   env="LC_ALL=POSIX HOME=/root TERM=${TERM:-} PATH=$studio_path"
   # Add `STUDIO_TYPE` to the environment
   env="$env STUDIO_TYPE=$STUDIO_TYPE"
+  # Add the Studio's own package identifier to the environment, which is
+  # recorded in the provenance of any package built inside it
+  env="$env HAB_STUDIO_IDENT=core/hab-studio/$version"
   # Add any additional environment variables from the Studio config, based on
   # type
   if [ -n "$extra_env" ]; then
@@ -861,6 +892,23 @@ chroot_env() {
   if [ -n "${HAB_ORIGIN:-}" ]; then
     env="$env HAB_ORIGIN=$HAB_ORIGIN"
   fi
+  # If a secret key passphrase was staged, then propagate the path of its file
+  # into the Studio's environment so encrypted origin keys can sign packages.
+  # The passphrase itself is never put on the `env(1)` command line, where it
+  # would be visible to other users in the process table.
+  if [ -n "${passphrase_file:-}" ]; then
+    env="$env HAB_KEY_PASSPHRASE_FILE=$passphrase_file"
+  fi
+  # If an external origin signer is set, then propagate it into the Studio's
+  # environment so packages can be signed without secret origin keys.
+  if [ -n "${HAB_ORIGIN_SIGNER:-}" ]; then
+    env="$env HAB_ORIGIN_SIGNER=$HAB_ORIGIN_SIGNER"
+  fi
+  # If a Builder job id is set, then propagate it into the Studio's
+  # environment so it is recorded in the provenance of built packages.
+  if [ -n "${HAB_BUILDER_JOB_ID:-}" ]; then
+    env="$env HAB_BUILDER_JOB_ID=$HAB_BUILDER_JOB_ID"
+  fi
   # If HTTP proxy variables are detected in the current environment, propagate
   # them into the Studio's environment.
   if [ -n "${http_proxy:-}" ]; then
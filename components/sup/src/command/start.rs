@@ -55,6 +55,13 @@
 //!
 //! See the [documentation on topologies](../topology) for a deeper discussion of how they function.
 //!
+//! ```bash
+//! $ hab-sup start acme/redis --strict-trust
+//! ```
+//!
+//! Will only install or update to `redis` packages signed by origin keys pinned in the trust
+//! store.
+//!
 
 use std::env;
 use std::path::Path;
@@ -64,12 +71,14 @@ use common::command::ProgressBar;
 use common::command::package::install;
 use depot_client::Client;
 use hcore::crypto::default_cache_key_path;
+use hcore::crypto::artifact::VerifyPolicy;
 use hcore::fs::{cache_artifact_path, FS_ROOT_PATH};
 use hcore::package::PackageIdent;
 
 use error::{Error, Result};
 use config::{Config, UpdateStrategy};
 use package::Package;
+use package::updater::load_trust_store;
 use topology::{self, Topology};
 
 static LOGKEY: &'static str = "CS";
@@ -100,16 +109,27 @@ pub fn package(config: &Config) -> Result<()> {
                         // updates for any releases, regardless of version number, for the started  package.
                         let depot_client = try!(Client::new(url, None));
                         let latest_pkg_data =
-                            try!(depot_client.show_package((*config.package()).clone()));
+                            try!(depot_client.show_package((*config.package()).clone(), None));
                         let latest_ident: PackageIdent = latest_pkg_data.get_ident().clone().into();
                         if &latest_ident > package.ident() {
                             outputln!("Downloading latest version from remote: {}", latest_ident);
                             let mut progress = ProgressBar::default();
                             let archive = try!(depot_client.fetch_package(latest_ident,
+                                               None,
                                                &cache_artifact_path(None),
                                                Some(&mut progress)));
-                            try!(archive.verify(&default_cache_key_path(None)));
-                            try!(archive.unpack(None));
+                            try!(install::sync_origin_key_revocations(
+                                &depot_client,
+                                &latest_ident.origin,
+                                &default_cache_key_path(None)));
+                            if let Some(trust_store) =
+                                   try!(load_trust_store(config.strict_trust())) {
+                                try!(trust_store.check_signers(&archive.path,
+                                                               &default_cache_key_path(None)));
+                            }
+                            try!(archive.unpack_verified(None,
+                                                         &default_cache_key_path(None),
+                                                         &VerifyPolicy::All));
                         } else {
                             outputln!("Already running latest.");
                         };
@@ -126,11 +146,14 @@ pub fn package(config: &Config) -> Result<()> {
                     outputln!("Searching for {} in remote {}",
                               Yellow.bold().paint(config.package().to_string()),
                               url);
+                    let trust_store = try!(load_trust_store(config.strict_trust()));
                     let new_pkg_data = try!(install::from_url(url,
+                                                              None,
                                                               config.package(),
                                                               Path::new(FS_ROOT_PATH),
                                                               &cache_artifact_path(None),
-                                                              &default_cache_key_path(None)));
+                                                              &default_cache_key_path(None),
+                                                              trust_store.as_ref()));
                     let package = try!(Package::load(&new_pkg_data.get_ident().clone().into(),
                                                      None));
                     start_package(package, config)
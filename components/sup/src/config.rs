@@ -93,7 +93,6 @@ pub struct Config {
     bind: Vec<String>,
     key: String,
     email: Option<String>,
-    expire_days: Option<u16>,
     gossip_listen_ip: String,
     gossip_listen_port: u16,
     http_listen_ip: String,
@@ -104,6 +103,7 @@ pub struct Config {
     outfile: Option<String>,
     gossip_peer: Vec<String>,
     gossip_permanent: bool,
+    strict_trust: bool,
     update_strategy: UpdateStrategy,
     service_group: String,
     file_path: String,
@@ -216,16 +216,6 @@ impl Config {
         &self.outfile
     }
 
-    /// Set the key expire days
-    pub fn set_expire_days(&mut self, expire_days: u16) -> &mut Config {
-        self.expire_days = Some(expire_days);
-        self
-    }
-
-    pub fn expire_days(&self) -> &Option<u16> {
-        &self.expire_days
-    }
-
     /// Set the path
     pub fn set_path(&mut self, path: String) -> &mut Config {
         self.path = path;
@@ -326,6 +316,16 @@ impl Config {
         self
     }
 
+    /// Whether packages must be signed only by origin keys pinned in the trust store
+    pub fn strict_trust(&self) -> bool {
+        self.strict_trust
+    }
+
+    pub fn set_strict_trust(&mut self, strict: bool) -> &mut Config {
+        self.strict_trust = strict;
+        self
+    }
+
     pub fn gossip_peer(&self) -> &[String] {
         &self.gossip_peer
     }
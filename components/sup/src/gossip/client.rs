@@ -22,40 +22,40 @@ use std::net::ToSocketAddrs;
 use std::str;
 
 use common::wire_message::WireMessage;
-use hcore::crypto::SymKey;
 use rustc_serialize::json;
 use utp::UtpSocket;
 
 use error::Result;
+use gossip::ring_key::RingKeys;
 use gossip::rumor::{Protocol, Peer, RumorList};
 
 pub const BUFFER_SIZE: usize = 10000;
 
 /// A Gossip Client.
-pub struct Client<'a> {
+pub struct Client {
     pub socket: UtpSocket,
-    ring_key: Option<&'a SymKey>,
+    ring_keys: RingKeys,
 }
 
-impl<'a> Client<'a> {
+impl Client {
     /// Create a new client from anything that can become a `SocketAddr`.
     ///
     /// # Errors
     ///
     /// * If we cannot connect the UTP socket
-    pub fn new<A: ToSocketAddrs>(dst: A, ring_key: Option<&'a SymKey>) -> Result<Client> {
+    pub fn new<A: ToSocketAddrs>(dst: A, ring_keys: RingKeys) -> Result<Client> {
         let socket = try!(UtpSocket::connect(dst));
         Ok(Client {
             socket: socket,
-            ring_key: ring_key,
+            ring_keys: ring_keys,
         })
     }
 
     /// Create a new client from a `UtpSocket`
-    pub fn from_socket(socket: UtpSocket, ring_key: Option<&'a SymKey>) -> Client {
+    pub fn from_socket(socket: UtpSocket, ring_keys: RingKeys) -> Client {
         Client {
             socket: socket,
-            ring_key: ring_key,
+            ring_keys: ring_keys,
         }
     }
 
@@ -121,7 +121,7 @@ impl<'a> Client<'a> {
                json_str);
 
         let wire_msg: WireMessage = try!(json::decode(&json_str));
-        Ok(try!(wire_msg.msg(self.ring_key)))
+        Ok(try!(wire_msg.msg_with_keys(&self.ring_keys.decrypt_keys())))
     }
 
     /// Send a message.
@@ -132,8 +132,8 @@ impl<'a> Client<'a> {
     /// * We fail to send the encoded buffer to the remote
     pub fn send_message(&mut self, msg: Protocol) -> Result<()> {
         let encoded = {
-            let wire_msg = match self.ring_key.as_ref() {
-                Some(key) => try!(WireMessage::encrypted(&msg, &key)),
+            let wire_msg = match self.ring_keys.encrypt_key() {
+                Some(key) => try!(WireMessage::encrypted(&msg, key)),
                 None => try!(WireMessage::plain(&msg)),
             };
             try!(json::encode(&wire_msg))
@@ -0,0 +1,178 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The set of ring key revisions a Supervisor gossips with.
+//!
+//! Every loaded revision of the ring key is accepted when decrypting a message, so a ring keeps
+//! talking while a new revision is distributed with `hab ring key rotate`. Messages are encrypted
+//! with the newest revision, but a revision imported while running only starts being used for
+//! encryption after `ROTATION_GRACE_SECS`. This gives the gossip file carrying the new revision
+//! time to reach every member before any member relies on it.
+
+use std::path::{Path, PathBuf};
+
+use hcore::crypto::SymKey;
+use hcore::crypto::keys::{get_key_revisions, parse_name_with_rev};
+use time::{Duration, SteadyTime};
+
+use error::Result;
+
+static LOGKEY: &'static str = "RK";
+
+/// Number of seconds a newly imported ring key revision waits before encrypting messages
+pub const ROTATION_GRACE_SECS: i64 = 300;
+
+/// The loaded revisions of a ring key.
+#[derive(Clone)]
+pub struct RingKeys {
+    /// The name of the ring, if the ring is encrypted
+    name: Option<String>,
+    /// The key cache the revisions are loaded from
+    cache_key_path: PathBuf,
+    /// The loaded revisions, newest first, paired with the time each may start encrypting
+    keys: Vec<(SymKey, SteadyTime)>,
+}
+
+impl RingKeys {
+    /// Creates an empty set of ring keys, for a ring whose messages are not encrypted.
+    pub fn none() -> RingKeys {
+        RingKeys {
+            name: None,
+            cache_key_path: PathBuf::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Loads every revision of the ring key named by `name_with_rev` from the key cache. All
+    /// revisions present at startup may be used to encrypt immediately.
+    ///
+    /// # Errors
+    ///
+    /// * If `name_with_rev` can't be parsed
+    /// * If a revision of the ring key can't be loaded
+    pub fn load<P: AsRef<Path> + ?Sized>(name_with_rev: &str,
+                                         cache_key_path: &P)
+                                         -> Result<RingKeys> {
+        let (name, _) = try!(parse_name_with_rev(name_with_rev));
+        let now = SteadyTime::now();
+        let keys = try!(SymKey::get_pairs_for(&name, cache_key_path))
+            .into_iter()
+            .map(|k| (k, now))
+            .collect();
+        Ok(RingKeys {
+            name: Some(name),
+            cache_key_path: cache_key_path.as_ref().to_path_buf(),
+            keys: keys,
+        })
+    }
+
+    /// Loads any revisions of the ring key which were added to the key cache since the keys were
+    /// last loaded. New revisions may be used to encrypt after `ROTATION_GRACE_SECS`. A revision
+    /// which can't be loaded, ex: because its secret key is encrypted, is reported and skipped.
+    ///
+    /// # Errors
+    ///
+    /// * If the revisions of the ring key in the key cache can't be listed
+    pub fn reload(&mut self) -> Result<()> {
+        let name = match self.name {
+            Some(ref name) => name.clone(),
+            None => return Ok(()),
+        };
+        let activate_at = SteadyTime::now() + Duration::seconds(ROTATION_GRACE_SECS);
+        let mut keys = Vec::new();
+        for name_with_rev in try!(get_key_revisions(&name, &self.cache_key_path)) {
+            let key = match SymKey::get_pair_for(&name_with_rev, &self.cache_key_path) {
+                Ok(key) => key,
+                Err(e) => {
+                    outputln!("Skipping ring key {}, it could not be loaded: {}",
+                              name_with_rev,
+                              e);
+                    continue;
+                }
+            };
+            let activation = match self.keys
+                .iter()
+                .find(|&&(ref k, _)| k.name_with_rev() == key.name_with_rev()) {
+                Some(&(_, activation)) => activation,
+                None => {
+                    outputln!("Loaded ring key {}, encrypting with it in {} seconds",
+                              key.name_with_rev(),
+                              ROTATION_GRACE_SECS);
+                    activate_at
+                }
+            };
+            keys.push((key, activation));
+        }
+        self.keys = keys;
+        Ok(())
+    }
+
+    /// Returns the newest revision which may be used to encrypt messages, or `None` if the ring
+    /// is not encrypted.
+    pub fn encrypt_key(&self) -> Option<&SymKey> {
+        let now = SteadyTime::now();
+        self.keys.iter().find(|&&(_, activation)| activation <= now).map(|&(ref k, _)| k)
+    }
+
+    /// Returns every loaded revision, each of which is accepted when decrypting messages.
+    pub fn decrypt_keys(&self) -> Vec<&SymKey> {
+        self.keys.iter().map(|&(ref k, _)| k).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+    use std::time::Duration as StdDuration;
+
+    use hcore::crypto::SymKey;
+    use tempdir::TempDir;
+
+    use super::RingKeys;
+
+    #[test]
+    fn none_has_no_keys() {
+        let keys = RingKeys::none();
+        assert!(keys.encrypt_key().is_none());
+        assert!(keys.decrypt_keys().is_empty());
+    }
+
+    #[test]
+    fn load_encrypts_with_newest() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let old_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        thread::sleep(StdDuration::from_millis(1000));
+        let new_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+
+        let keys = RingKeys::load(&old_key.name_with_rev(), cache.path()).unwrap();
+        assert_eq!(keys.encrypt_key().unwrap().name_with_rev(),
+                   new_key.name_with_rev());
+        assert_eq!(keys.decrypt_keys().len(), 2);
+    }
+
+    #[test]
+    fn reload_delays_encrypting_with_new_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let old_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let mut keys = RingKeys::load(&old_key.name_with_rev(), cache.path()).unwrap();
+        thread::sleep(StdDuration::from_millis(1000));
+        let new_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+
+        keys.reload().unwrap();
+        let decrypt: Vec<String> = keys.decrypt_keys().iter().map(|k| k.name_with_rev()).collect();
+        assert_eq!(decrypt, vec![new_key.name_with_rev(), old_key.name_with_rev()]);
+        assert_eq!(keys.encrypt_key().unwrap().name_with_rev(),
+                   old_key.name_with_rev());
+    }
+}
@@ -24,18 +24,18 @@
 use threadpool::ThreadPool;
 
 use std::thread;
-use std::ops::Deref;
 use std::time::Duration;
 use std::sync::{Arc, RwLock};
 use std::net;
 
 use common::gossip_file::GossipFileList;
-use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::crypto::default_cache_key_path;
 use hcore::service::ServiceGroup;
 use utp::{UtpListener, UtpSocket};
 
 use gossip::client::Client;
 use gossip::member::{Member, MemberList, Health};
+use gossip::ring_key::RingKeys;
 use gossip::rumor::{Peer, Protocol, Rumor, RumorList, Message};
 use gossip::detector::Detector;
 use election::ElectionList;
@@ -71,8 +71,8 @@ pub struct Server {
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
     /// Our 'peer' entry, used to generate SWIM protocol messages.
     pub peer: Peer,
-    /// An optional ring key used to encrypt messages with peers
-    ring_key: Arc<Option<SymKey>>,
+    /// The revisions of the ring key used to encrypt messages with peers, if any
+    ring_keys: Arc<RwLock<RingKeys>>,
 }
 
 impl Server {
@@ -107,9 +107,9 @@ impl Server {
 
         let census_list = CensusList::new(Census::new(ce.clone()));
 
-        let ring_key = match ring_name_with_rev {
-            Some(rnwr) => Some(SymKey::get_pair_for(&rnwr, &default_cache_key_path(None)).unwrap()),
-            None => None,
+        let ring_keys = match ring_name_with_rev {
+            Some(rnwr) => RingKeys::load(&rnwr, &default_cache_key_path(None)).unwrap(),
+            None => RingKeys::none(),
         };
 
         let server = Server {
@@ -124,7 +124,7 @@ impl Server {
                 Arc::new(RwLock::new(GossipFileList::new(ServiceGroup::new(service,
                                                                            group,
                                                                            organization)))),
-            ring_key: Arc::new(ring_key),
+            ring_keys: Arc::new(RwLock::new(ring_keys)),
         };
 
         // Write our Alive Rumor
@@ -151,7 +151,7 @@ impl Server {
     /// * If we cannot bind to the listener
     pub fn start_inbound(&self) -> Result<()> {
         outputln!("Starting inbound gossip listener");
-        let key = self.ring_key.clone();
+        let key = self.ring_keys.clone();
         let ml = self.member_list.clone();
         let rl = self.rumor_list.clone();
         let cl = self.census_list.clone();
@@ -169,7 +169,7 @@ impl Server {
     /// Starts the outbound gossip distributor.
     pub fn start_outbound(&self) {
         outputln!("Starting outbound gossip distributor");
-        let key = self.ring_key.clone();
+        let key = self.ring_keys.clone();
         let ml = self.member_list.clone();
         let rl = self.rumor_list.clone();
         let my_peer = self.peer.clone();
@@ -182,7 +182,7 @@ impl Server {
     /// Starts the failure detector.
     pub fn start_failure_detector(&self) {
         outputln!("Starting gossip failure detector");
-        let key = self.ring_key.clone();
+        let key = self.ring_keys.clone();
         let my_peer = self.peer.clone();
         let ml = self.member_list.clone();
         let rl = self.rumor_list.clone();
@@ -192,6 +192,16 @@ impl Server {
             .spawn(move || failure_detector(key, my_peer, ml, rl, detector));
     }
 
+    /// Loads any new revisions of the ring key from the key cache, such as one imported from a
+    /// gossip file by `hab ring key rotate`.
+    ///
+    /// # Errors
+    ///
+    /// * If a revision of the ring key can't be loaded
+    pub fn reload_ring_keys(&self) -> Result<()> {
+        self.ring_keys.write().unwrap().reload()
+    }
+
     /// Sends blocking SWIM requests to our initial gossip peers.
     ///
     /// # Errors
@@ -221,7 +231,7 @@ impl Server {
         let mut initialized = false;
         for to in peer_listeners {
             outputln!("Joining gossip peer at {}", to);
-            let mut c = match Client::new(&to[..], self.ring_key.deref().as_ref()) {
+            let mut c = match Client::new(&to[..], self.ring_keys.read().unwrap().clone()) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Error creating gossip client - {:?}", e);
@@ -254,7 +264,7 @@ impl Server {
 ///
 /// New requests are handled by passing them to `receive`.
 pub fn inbound(listener: UtpListener,
-               ring_key: Arc<Option<SymKey>>,
+               ring_keys: Arc<RwLock<RingKeys>>,
                my_peer: Peer,
                member_list: Arc<RwLock<MemberList>>,
                rumor_list: Arc<RwLock<RumorList>>,
@@ -281,7 +291,7 @@ pub fn inbound(listener: UtpListener,
                        pool.active_count(),
                        pool.max_count());
 
-                let key = ring_key.clone();
+                let key = ring_keys.clone();
                 let my_peer = my_peer.clone();
                 let ml = member_list.clone();
                 let rl = rumor_list.clone();
@@ -317,7 +327,7 @@ pub fn inbound(listener: UtpListener,
 /// * Forward along the RumorList to that Peer as a Proxy Ping.
 fn receive(socket: UtpSocket,
            src: net::SocketAddr,
-           ring_key: Arc<Option<SymKey>>,
+           ring_keys: Arc<RwLock<RingKeys>>,
            my_peer: Peer,
            member_list: Arc<RwLock<MemberList>>,
            rumor_list: Arc<RwLock<RumorList>>,
@@ -325,7 +335,7 @@ fn receive(socket: UtpSocket,
            detector: Arc<RwLock<Detector>>,
            election_list: Arc<RwLock<ElectionList>>,
            gossip_file_list: Arc<RwLock<GossipFileList>>) {
-    let mut client = Client::from_socket(socket, ring_key.deref().as_ref());
+    let mut client = Client::from_socket(socket, ring_keys.read().unwrap().clone());
     let msg = match client.recv_message() {
         Ok(msg) => msg,
         Err(e) => {
@@ -353,7 +363,7 @@ fn receive(socket: UtpSocket,
             };
 
             // Create a client for that peer
-            let mut c = match Client::new(&respond_to[..], ring_key.deref().as_ref()) {
+            let mut c = match Client::new(&respond_to[..], ring_keys.read().unwrap().clone()) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Failed to create a gossip client for {:?}; aborting: {}",
@@ -405,7 +415,7 @@ fn receive(socket: UtpSocket,
             if from_peer.proxy_to.is_some() {
                 debug!("Proxy Ack for {:?}", from_peer);
                 let forward_to = from_peer.proxy_to.take().unwrap();
-                let mut c = match Client::new(&forward_to[..], ring_key.deref().as_ref()) {
+                let mut c = match Client::new(&forward_to[..], ring_keys.read().unwrap().clone()) {
                     Ok(c) => c,
                     Err(e) => {
                         debug!("Failed to create a gossip client to forward for {:?}; aborting: \
@@ -444,7 +454,7 @@ fn receive(socket: UtpSocket,
                     return;
                 }
             };
-            let mut c = match Client::new(&proxy_to[..], ring_key.deref().as_ref()) {
+            let mut c = match Client::new(&proxy_to[..], ring_keys.read().unwrap().clone()) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Failed to create a gossip connection for sending ping-req to {} for \
@@ -560,7 +570,7 @@ pub fn process_rumors(remote_rumors: RumorList,
 ///
 /// Like inbound, it is backed by a thread pool - if we have more than OUTBOUND_MAX_THREADS running
 /// at once, we delay the next outbound message until a thread is free.
-pub fn outbound(ring_key: Arc<Option<SymKey>>,
+pub fn outbound(ring_keys: Arc<RwLock<RingKeys>>,
                 my_peer: Peer,
                 member_list: Arc<RwLock<MemberList>>,
                 rumor_list: Arc<RwLock<RumorList>>,
@@ -603,7 +613,7 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
         };
 
         if !running_request {
-            let key1 = ring_key.clone();
+            let key1 = ring_keys.clone();
             let rl1 = rumor_list.clone();
             let ml1 = member_list.clone();
             let mp1 = my_peer.clone();
@@ -622,7 +632,7 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
 }
 
 /// Send an outbound Ping. If we fail to send, we initiate a PingReq.
-pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
+pub fn send_outbound(ring_keys: Arc<RwLock<RingKeys>>,
                      my_peer: Peer,
                      member: Member,
                      rumor_list: Arc<RwLock<RumorList>>,
@@ -633,13 +643,13 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
         d.start(member.id.clone());
     }
 
-    let mut c = match Client::new(&member.gossip_listener[..], ring_key.deref().as_ref()) {
+    let mut c = match Client::new(&member.gossip_listener[..], ring_keys.read().unwrap().clone()) {
         Ok(c) => c,
         Err(e) => {
             debug!("Failed to create a gossip connection for {}; sending ping-req: {}",
                    member.id,
                    e);
-            send_pingreq(ring_key.clone(),
+            send_pingreq(ring_keys.clone(),
                          my_peer,
                          member,
                          rumor_list,
@@ -664,7 +674,7 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
         Ok(_) => {}
         Err(e) => {
             debug!("Failed to ping {:?}: {:?}", my_peer, e);
-            send_pingreq(ring_key.clone(),
+            send_pingreq(ring_keys.clone(),
                          my_peer,
                          member,
                          rumor_list,
@@ -682,7 +692,7 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
 
 /// Send a PingReq for a failed Ping. We pick targets from the Member List, and then send a PingReq
 /// to each of them, with our information filled in.
-pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
+pub fn send_pingreq(ring_keys: Arc<RwLock<RingKeys>>,
                     my_peer: Peer,
                     member: Member,
                     rumor_list: Arc<RwLock<RumorList>>,
@@ -707,7 +717,7 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
                member.id,
                pingreq_member.id);
         let mut c = match Client::new(&pingreq_member.gossip_listener[..],
-                                      ring_key.deref().as_ref()) {
+                                      ring_keys.read().unwrap().clone()) {
             Ok(c) => c,
             Err(e) => {
                 debug!("Failed to create a gossip connection for {}; aborting ping-req request: \
@@ -736,7 +746,7 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
 /// The failure detector. Every 100ms, we check for any failed for confirmed timeouts within the
 /// detector. If we find a timeout, we update our rumor and the members entry. Additionally, if we
 /// mark a member as Suspect through a rumor we were passed, we set up its entry in the detector.
-pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
+pub fn failure_detector(ring_keys: Arc<RwLock<RingKeys>>,
                         my_peer: Peer,
                         member_list: Arc<RwLock<MemberList>>,
                         rumor_list: Arc<RwLock<RumorList>>,
@@ -800,7 +810,7 @@ pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
         for member_id in pingreq.iter() {
             let ml = member_list.read().unwrap();
             let member = ml.get(&member_id).unwrap().clone();
-            send_pingreq(ring_key.clone(),
+            send_pingreq(ring_keys.clone(),
                          my_peer.clone(),
                          member,
                          rumor_list.clone(),
@@ -110,10 +110,6 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<Config> {
             t => return Err(sup_error!(Error::UnknownTopology(String::from(t)))),
         }
     }
-    if sub_args.value_of("expire-days").is_some() {
-        let ed = value_t!(sub_args.value_of("expire-days"), u16).unwrap_or_else(|e| e.exit());
-        config.set_expire_days(ed);
-    }
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = sub_args.value_of("url").unwrap_or(&env_or_default);
     config.set_url(url.to_string());
@@ -167,6 +163,9 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<Config> {
     if sub_args.is_present("permanent-peer") {
         config.set_gossip_permanent(true);
     }
+    if sub_args.is_present("strict-trust") {
+        config.set_strict_trust(true);
+    }
     if let Some(sg) = sub_args.value_of("service-group") {
         config.set_service_group(sg.to_string());
     }
@@ -296,7 +295,11 @@ fn main() {
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
-            .help("If this service is a permanent peer"));
+            .help("If this service is a permanent peer"))
+        .arg(Arg::with_name("strict-trust")
+            .long("strict-trust")
+            .help("Refuse packages signed by origin keys not pinned in the trust store at \
+                   HAB_TRUST_STORE [default: /hab/etc/trust.toml]"));
     let sub_bash = SubCommand::with_name("bash")
         .about("Start an interactive shell (bash)")
         .aliases(&["b", "ba", "bas"]);
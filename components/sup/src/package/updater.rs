@@ -16,14 +16,17 @@ use std::sync::{Arc, RwLock};
 use std::path::Path;
 
 use common::command::ProgressBar;
+use common::command::package::install;
 use depot_client::Client;
-use hcore::crypto::default_cache_key_path;
+use hcore::config::ConfigFile;
+use hcore::crypto::{default_cache_key_path, default_trust_store_path, TrustStore};
+use hcore::crypto::artifact::VerifyPolicy;
 use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
 use hcore::package::PackageIdent;
 use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, ActorSender, ActorResult};
 
-use error::SupError;
+use error::{Result, SupError};
 use package::Package;
 
 const TIMEOUT_MS: u64 = 60_000;
@@ -33,8 +36,8 @@ pub type PackageUpdaterActor = wonder::actor::Actor<UpdaterMessage>;
 pub struct PackageUpdater;
 
 impl PackageUpdater {
-    pub fn start(url: &str, package: Arc<RwLock<Package>>) -> PackageUpdaterActor {
-        let state = UpdaterState::new(url.to_string(), package);
+    pub fn start(url: &str, package: Arc<RwLock<Package>>, strict: bool) -> PackageUpdaterActor {
+        let state = UpdaterState::new(url.to_string(), package, strict);
         wonder::actor::Builder::new(PackageUpdater)
             .name("package-updater".to_string())
             .start(state)
@@ -53,18 +56,31 @@ pub struct UpdaterState {
     pub depot: String,
     pub package: Arc<RwLock<Package>>,
     pub status: UpdaterStatus,
+    /// Only update to packages signed by origin keys pinned in the trust store
+    pub strict: bool,
 }
 
 impl UpdaterState {
-    pub fn new(depot: String, package: Arc<RwLock<Package>>) -> Self {
+    pub fn new(depot: String, package: Arc<RwLock<Package>>, strict: bool) -> Self {
         UpdaterState {
             depot: depot,
             package: package,
             status: UpdaterStatus::Stopped,
+            strict: strict,
         }
     }
 }
 
+/// Loads the trust store when running in strict mode. The trust store is read each time it is
+/// needed, so changes to it are picked up without restarting the Supervisor.
+pub fn load_trust_store(strict: bool) -> Result<Option<TrustStore>> {
+    if strict {
+        Ok(Some(try!(TrustStore::from_file(default_trust_store_path(None)))))
+    } else {
+        Ok(None)
+    }
+}
+
 #[derive(Debug)]
 pub enum UpdaterMessage {
     Ok,
@@ -105,20 +121,45 @@ impl GenServer for PackageUpdater {
                 return HandleResult::NoReply(Some(TIMEOUT_MS));
             }
         };
-        match depot_client.show_package(ident) {
+        match depot_client.show_package(ident, None) {
             Ok(remote) => {
                 let latest_ident: PackageIdent = remote.get_ident().clone().into();
                 if &latest_ident > package.ident() {
                     let mut progress = ProgressBar::default();
                     match depot_client.fetch_package(latest_ident.clone(),
+                                                     None,
                                                      &Path::new(FS_ROOT_PATH)
                                                          .join(CACHE_ARTIFACT_PATH),
                                                      Some(&mut progress)) {
                         Ok(archive) => {
                             debug!("Updater downloaded new package to {:?}", archive);
-                            // JW TODO: actually handle verify and unpack results
-                            archive.verify(&default_cache_key_path(None)).unwrap();
-                            archive.unpack(None).unwrap();
+                            if let Err(e) = install::sync_origin_key_revocations(
+                                &depot_client,
+                                &latest_ident.origin,
+                                &default_cache_key_path(None)) {
+                                debug!("Failed to sync origin key revocations: {:?}", e);
+                                return HandleResult::NoReply(Some(TIMEOUT_MS));
+                            }
+                            let trusted = match load_trust_store(state.strict) {
+                                Ok(Some(trust_store)) => {
+                                    trust_store.check_signers(&archive.path,
+                                                              &default_cache_key_path(None))
+                                }
+                                Ok(None) => Ok(()),
+                                Err(e) => {
+                                    debug!("Failed to load the trust store: {}", e);
+                                    return HandleResult::NoReply(Some(TIMEOUT_MS));
+                                }
+                            };
+                            let unpacked = trusted.and_then(|_| {
+                                archive.unpack_verified(None,
+                                                        &default_cache_key_path(None),
+                                                        &VerifyPolicy::All)
+                            });
+                            if let Err(e) = unpacked {
+                                debug!("Refusing update to {}: {}", latest_ident, e);
+                                return HandleResult::NoReply(Some(TIMEOUT_MS));
+                            }
                             let latest_package = Package::load(&latest_ident, None).unwrap();
                             state.status = UpdaterStatus::Stopped;
                             let msg = wonder::actor::Message::Cast(UpdaterMessage::Update(latest_package));
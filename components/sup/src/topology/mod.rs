@@ -141,7 +141,9 @@ impl<'a> Worker<'a> {
             _ => {
                 let pkg_lock_2 = pkg_lock.clone();
                 if let &Some(ref url) = config.url() {
-                    pkg_updater = Some(package::PackageUpdater::start(url, pkg_lock_2));
+                    pkg_updater = Some(package::PackageUpdater::start(url,
+                                                                      pkg_lock_2,
+                                                                      config.strict_trust()));
                 }
             }
         }
@@ -347,8 +349,8 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                 let census = census_list.local_census();
                 census.in_event
             };
-            let (needs_file_updated, needs_reconfigure) = if in_event {
-                (false, false)
+            let (needs_file_updated, needs_reconfigure, needs_ring_reload) = if in_event {
+                (false, false, false)
             } else {
                 let mut gossip_file_list = worker.gossip_file_list.write().unwrap();
                 let needs_write = {
@@ -357,9 +359,15 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                 if needs_write {
                     try!(gossip_file_list.write())
                 } else {
-                    (false, false)
+                    (false, false, false)
                 }
             };
+            if needs_ring_reload {
+                if let Err(e) = worker.gossip_server.reload_ring_keys() {
+                    outputln!("Failed to reload ring keys, gossiping with the loaded ones: {}",
+                              e);
+                }
+            }
             if needs_file_updated {
                 let service_config = worker.service_config.read().unwrap();
                 let package = worker.package.read().unwrap();